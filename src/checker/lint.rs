@@ -0,0 +1,151 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+};
+
+use serde_json::Value;
+use tree_sitter::Range;
+
+use crate::checker::{analyze, DYNAMIC_DIRECTIVE};
+use crate::interpreter::tree_sitter_parser::{self, ParseError};
+
+// inline comment, that turns off listed rules for its line and the next one
+const ALLOW_DIRECTIVE: &str = "// frugurt-allow(";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    UnusedVariable,
+    UnusedParameter,
+    Shadowing,
+    Unreachable,
+    ConstantCondition,
+    RedundantCommutative,
+    EmptyType,
+}
+
+/// Problem, that does not break the program, but most likely is a mistake
+#[derive(Debug)]
+pub struct Warning {
+    pub rule: Rule,
+    pub position: Range,
+    pub message: String,
+}
+
+/// Rules, that are turned off, everything is on by default
+#[derive(Debug, Default)]
+pub struct LintConfig {
+    disabled: HashSet<Rule>,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 7] = [
+        Rule::UnusedVariable,
+        Rule::UnusedParameter,
+        Rule::Shadowing,
+        Rule::Unreachable,
+        Rule::ConstantCondition,
+        Rule::RedundantCommutative,
+        Rule::EmptyType,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::UnusedVariable => "unused_variable",
+            Rule::UnusedParameter => "unused_parameter",
+            Rule::Shadowing => "shadowing",
+            Rule::Unreachable => "unreachable",
+            Rule::ConstantCondition => "constant_condition",
+            Rule::RedundantCommutative => "redundant_commutative",
+            Rule::EmptyType => "empty_type",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Rule> {
+        Rule::ALL.into_iter().find(|x| x.name() == name)
+    }
+}
+
+impl LintConfig {
+    /// Parses config like `{ "shadowing": false }`, where keys are rule names
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|err| err.to_string())?;
+
+        let Value::Object(rules) = value else {
+            return Err("lint config should be an object".to_string());
+        };
+
+        let mut config = Self::default();
+
+        for (name, enabled) in rules {
+            let Some(rule) = Rule::from_name(&name) else {
+                return Err(format!("unknown lint rule `{}`", name));
+            };
+
+            match enabled {
+                Value::Bool(true) => {}
+                Value::Bool(false) => {
+                    config.disabled.insert(rule);
+                }
+                _ => return Err(format!("lint rule `{}` should be `true` or `false`", name)),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn is_enabled(&self, rule: Rule) -> bool {
+        !self.disabled.contains(&rule)
+    }
+}
+
+/// Parses source code and returns warnings of enabled rules, that are not allowed by comments
+pub fn lint_source(source: &str, config: &LintConfig) -> Result<Vec<Warning>, ParseError> {
+    let ast = tree_sitter_parser::parse(source.to_string())?;
+
+    let dynamic = source.lines().any(|line| line.trim() == DYNAMIC_DIRECTIVE);
+
+    let lines: Vec<_> = source.lines().collect();
+
+    let allowed = |warning: &Warning| {
+        let row = warning.position.start_point.row;
+
+        [Some(row), row.checked_sub(1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|row| lines.get(row))
+            .any(|line| allowed_rules(line).contains(&warning.rule.name()))
+    };
+
+    Ok(analyze(&ast, dynamic)
+        .1
+        .into_iter()
+        .filter(|x| config.is_enabled(x.rule) && !allowed(x))
+        .collect())
+}
+
+fn allowed_rules(line: &str) -> Vec<&str> {
+    let Some((_, rest)) = line.split_once(ALLOW_DIRECTIVE) else {
+        return Vec::new();
+    };
+
+    let Some((rules, _)) = rest.split_once(')') else {
+        return Vec::new();
+    };
+
+    rules.split(',').map(str::trim).collect()
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "warning[{}]: {} at {}:{}-{}:{}",
+            self.rule.name(),
+            self.message,
+            self.position.start_point.row + 1,
+            self.position.start_point.column,
+            self.position.end_point.row + 1,
+            self.position.end_point.column
+        )
+    }
+}
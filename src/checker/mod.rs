@@ -0,0 +1,922 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use tree_sitter::{Point, Range};
+
+use crate::checker::lint::{Rule, Warning};
+use crate::interpreter::{
+    ast_helpers::{FormatStringPart, Pattern},
+    builtins::functions::builtin_functions,
+    error::{did_you_mean, ErrorKind},
+    expression::FruExpression,
+    identifier::{id, Identifier},
+    statement::FruStatement,
+    tree_sitter_parser::{self, ParseError},
+    value::{
+        fru_type::Property,
+        fru_value::FruValue,
+        function::{Argument, ArgumentList, FormalParameters},
+    },
+};
+
+/// Problem, that is found without running the code
+#[derive(Debug)]
+pub struct CheckError {
+    pub kind: ErrorKind,
+    pub position: Range,
+    pub message: String,
+    pub note: Option<String>,
+}
+
+pub mod lint;
+
+#[derive(Clone)]
+enum Declaration {
+    Variable,
+    Function(FormalParameters),
+    Type {
+        fields: Vec<Identifier>, // in declaration order
+        // fields with default values, that can be omitted in instantiation
+        optional: Vec<Identifier>,
+        // names, that are visible in methods, including the ones of embedded types
+        members: Vec<Identifier>,
+        statics: Vec<Identifier>,
+    },
+}
+
+#[derive(Clone)]
+struct Declared {
+    declaration: Declaration,
+    position: Range,
+    is_parameter: bool,
+    is_used: bool,
+}
+
+/// Compile time picture of `Scope`
+#[derive(Default)]
+struct Frame<'a> {
+    declarations: HashMap<Identifier, Declared>,
+    // frame of block or function, so its unused declarations are reported
+    is_local: bool,
+    // captured with `scope()` or modified with `scope ... {}`, so variables can appear in it dynamically
+    dynamic: bool,
+    // function bodies are checked when the frame is complete, as they may use later declarations
+    deferred: Vec<Deferred<'a>>,
+}
+
+struct Deferred<'a> {
+    position: Range,
+    frame: Frame<'a>,
+    parameters: Option<&'a FormalParameters>,
+    body: &'a FruStatement,
+}
+
+struct Checker<'a> {
+    frames: Vec<Frame<'a>>,
+    position: Range,
+    errors: Vec<CheckError>,
+    warnings: Vec<Warning>,
+}
+
+// position of things, that are not in the code, like builtins
+const NOWHERE: Range = Range {
+    start_byte: 0,
+    end_byte: 0,
+    start_point: Point { row: 0, column: 0 },
+    end_point: Point { row: 0, column: 0 },
+};
+
+// comment, that marks file as one, that expects variables from outside (for example, from importer)
+const DYNAMIC_DIRECTIVE: &str = "// frugurt-check: dynamic";
+
+/// Parses and checks source code, see `check`
+pub fn check_source(source: &str) -> Result<Vec<CheckError>, ParseError> {
+    let ast = tree_sitter_parser::parse(source.to_string())?;
+
+    let dynamic = source.lines().any(|line| line.trim() == DYNAMIC_DIRECTIVE);
+
+    Ok(check(&ast, dynamic))
+}
+
+/// Reports references to undeclared variables, duplicate declarations,
+/// calls and instantiations, that can never succeed.
+/// (`break`/`continue` outside loops are reported by the parser)
+/// Code, that runs in a dynamically modified scope, is not checked for undeclared variables,
+/// `dynamic` makes the whole program such
+pub fn check(ast: &FruStatement, dynamic: bool) -> Vec<CheckError> {
+    analyze(ast, dynamic).0
+}
+
+/// Errors and lint warnings of the program, both are found in one pass
+fn analyze(ast: &FruStatement, dynamic: bool) -> (Vec<CheckError>, Vec<Warning>) {
+    let builtins = frame_of(&builtin_functions().into_keys().collect::<Vec<_>>());
+
+    let mut checker = Checker {
+        frames: vec![builtins],
+        position: NOWHERE,
+        errors: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let global = Frame {
+        dynamic,
+        ..Default::default()
+    };
+
+    checker.scoped(global, |checker| checker.statement(ast));
+
+    checker.errors.sort_by_key(|x| x.position.start_byte);
+    // several unused declarations can share position, so message makes order stable
+    checker.warnings.sort_by(|a, b| {
+        (a.position.start_byte, &a.message).cmp(&(b.position.start_byte, &b.message))
+    });
+
+    (checker.errors, checker.warnings)
+}
+
+impl<'a> Checker<'a> {
+    fn error(&mut self, kind: ErrorKind, message: String) {
+        self.noted_error(kind, message, None);
+    }
+
+    fn noted_error(&mut self, kind: ErrorKind, message: String, note: Option<String>) {
+        self.errors.push(CheckError {
+            kind,
+            position: self.position,
+            message,
+            note,
+        });
+    }
+
+    fn warning(&mut self, rule: Rule, message: String) {
+        self.warnings.push(Warning {
+            rule,
+            position: self.position,
+            message,
+        });
+    }
+
+    /// Runs `f` inside of a new frame, then checks functions declared in it
+    fn scoped(&mut self, frame: Frame<'a>, f: impl FnOnce(&mut Self)) {
+        self.frames.push(frame);
+
+        f(self);
+
+        while let Some(deferred) = self.frames.last_mut().unwrap().deferred.pop() {
+            self.function(deferred);
+        }
+
+        let frame = self.frames.pop().unwrap();
+
+        if frame.is_local && !frame.dynamic {
+            for (ident, declared) in frame.declarations {
+                if declared.is_used || ident.to_string().starts_with('_') {
+                    continue;
+                }
+
+                let (rule, what) = match declared.is_parameter {
+                    true => (Rule::UnusedParameter, "parameter"),
+                    false => (Rule::UnusedVariable, "variable"),
+                };
+
+                self.warnings.push(Warning {
+                    rule,
+                    position: declared.position,
+                    message: format!("{} `{}` is never used", what, ident),
+                });
+            }
+        }
+    }
+
+    fn function(&mut self, deferred: Deferred<'a>) {
+        self.position = deferred.position;
+
+        self.scoped(deferred.frame, |checker| {
+            checker.scoped(local_frame(), |checker| {
+                if let Some(parameters) = deferred.parameters {
+                    checker.parameters(parameters);
+                }
+
+                checker.statement(deferred.body);
+            })
+        });
+    }
+
+    fn defer(
+        &mut self,
+        frame: Frame<'a>,
+        parameters: Option<&'a FormalParameters>,
+        body: &'a FruStatement,
+    ) {
+        let deferred = Deferred {
+            position: self.position,
+            frame,
+            parameters,
+            body,
+        };
+
+        self.frames.last_mut().unwrap().deferred.push(deferred);
+    }
+
+    fn parameters(&mut self, parameters: &'a FormalParameters) {
+        for parameter in &parameters.args {
+            if let Some(default) = &parameter.default {
+                self.expression(default);
+            }
+
+            self.declare(parameter.ident, Declaration::Variable, true);
+        }
+
+        for ident in parameters.rest.iter().chain(&parameters.kwargs) {
+            self.declare(*ident, Declaration::Variable, true);
+        }
+    }
+
+    fn declare(&mut self, ident: Identifier, declaration: Declaration, is_parameter: bool) {
+        let dynamic = self.frames.last().unwrap().dynamic;
+
+        // builtins are not reported, as they are not visible in the code
+        let shadows = self.frames[1..self.frames.len() - 1]
+            .iter()
+            .any(|x| x.declarations.contains_key(&ident));
+
+        if shadows && !dynamic {
+            self.warning(
+                Rule::Shadowing,
+                format!("`{}` shadows declaration from outer scope", ident),
+            );
+        }
+
+        let declared = Declared {
+            declaration,
+            position: self.position,
+            is_parameter,
+            is_used: false,
+        };
+
+        if self.frames.last_mut().unwrap().declarations.insert(ident, declared).is_some()
+            && !dynamic
+        {
+            self.error(
+                ErrorKind::AlreadyDeclared,
+                format!("variable `{}` is already declared in this block", ident),
+            );
+        }
+    }
+
+    /// Finds declaration and marks it as used
+    fn lookup(&mut self, ident: Identifier) -> Option<Declaration> {
+        self.find(ident).map(|declared| {
+            declared.is_used = true;
+            declared.declaration.clone()
+        })
+    }
+
+    fn find(&mut self, ident: Identifier) -> Option<&mut Declared> {
+        if !self.frames.iter().any(|x| x.declarations.contains_key(&ident)) {
+            if !self.frames.iter().any(|x| x.dynamic) {
+                let name = ident.to_string();
+                let names: Vec<_> = self
+                    .frames
+                    .iter()
+                    .flat_map(|x| x.declarations.keys())
+                    .map(ToString::to_string)
+                    .collect();
+
+                let note = did_you_mean(&name, names.iter().map(String::as_str))
+                    .map(|x| format!("did you mean `{}`?", x));
+
+                self.noted_error(
+                    ErrorKind::UndefinedVariable,
+                    format!("variable `{}` is not declared", ident),
+                    note,
+                );
+            }
+
+            return None;
+        }
+
+        self.frames.iter_mut().rev().find_map(|x| x.declarations.get_mut(&ident))
+    }
+
+    /// Statements of one block, the first one after `return`, `break` or `continue` is reported
+    fn body(&mut self, body: &'a [FruStatement]) {
+        let mut diverged = false;
+
+        for statement in body {
+            if diverged {
+                if let FruStatement::Positioned { position, .. } = statement {
+                    self.position = *position;
+                }
+
+                self.warning(Rule::Unreachable, "unreachable statement".to_string());
+            }
+
+            self.statement(statement);
+
+            let mut inner = statement;
+            while let FruStatement::Positioned { statement, .. } = inner {
+                inner = statement;
+            }
+
+            diverged = matches!(
+                inner,
+                FruStatement::Return { .. }
+                    | FruStatement::Break { .. }
+                    | FruStatement::Continue { .. }
+            );
+        }
+    }
+
+    fn condition(&mut self, condition: &'a FruExpression) {
+        if let FruExpression::Literal {
+            value: FruValue::Bool(value),
+        } = condition
+        {
+            self.warning(
+                Rule::ConstantCondition,
+                format!("condition is always `{}`", value),
+            );
+        }
+
+        self.expression(condition);
+    }
+
+    fn statement(&mut self, statement: &'a FruStatement) {
+        match statement {
+            FruStatement::SourceCode { body } => self.body(body),
+
+            FruStatement::Block { body } => {
+                self.scoped(local_frame(), |checker| checker.body(body))
+            }
+
+            FruStatement::Positioned {
+                position,
+                statement,
+            } => {
+                self.position = *position;
+                self.statement(statement);
+            }
+
+            FruStatement::ScopeModifier { what, body } => {
+                self.expression(what);
+
+                self.scoped(dynamic_frame(), |checker| checker.body(body));
+            }
+
+            FruStatement::Expression { value } => self.expression(value),
+
+            FruStatement::Let { ident, value } => {
+                let declaration = match &**value {
+                    FruExpression::Function { args, .. } => Declaration::Function(args.clone()),
+                    _ => Declaration::Variable,
+                };
+
+                self.expression(value);
+                self.declare(*ident, declaration, false);
+            }
+
+            FruStatement::If {
+                condition,
+                then_body,
+                else_body,
+                ..
+            } => {
+                self.condition(condition);
+                self.statement(then_body);
+
+                if let Some(else_body) = else_body {
+                    self.statement(else_body);
+                }
+            }
+
+            FruStatement::While {
+                condition, body, ..
+            } => {
+                self.expression(condition);
+                self.statement(body);
+            }
+
+            FruStatement::Return { value } | FruStatement::Break { value, .. } => {
+                if let Some(value) = value {
+                    self.expression(value);
+                }
+            }
+
+            FruStatement::Continue { .. } => {}
+
+            FruStatement::Callable { body, .. } => self.statement(body),
+
+            FruStatement::Test { body, .. } => self.defer(Frame::default(), None, body),
+
+            FruStatement::Operator {
+                commutative,
+                left_ident,
+                left_type_ident,
+                right_ident,
+                right_type_ident,
+                body,
+                ..
+            } => {
+                if *commutative && left_type_ident == right_type_ident {
+                    self.warning(
+                        Rule::RedundantCommutative,
+                        format!(
+                            "operator is commutative, but both operands have type `{}`",
+                            left_type_ident
+                        ),
+                    );
+                }
+
+                let frame = frame_of(&[*left_ident, *right_ident]);
+
+                self.defer(frame, None, body);
+            }
+
+            FruStatement::Type {
+                ident,
+                fields,
+                static_fields,
+                properties,
+                static_properties,
+                methods,
+                ..
+            } => {
+                if fields.is_empty()
+                    && static_fields.is_empty()
+                    && properties.is_empty()
+                    && static_properties.is_empty()
+                    && methods.is_empty()
+                {
+                    self.warning(Rule::EmptyType, format!("type `{}` has no members", ident));
+                }
+
+                for static_field in static_fields {
+                    if let Some(value) = &static_field.value {
+                        self.expression(value);
+                    }
+                }
+
+                let mut statics: Vec<_> = static_fields
+                    .iter()
+                    .map(|x| x.ident)
+                    .chain(static_properties.keys().copied())
+                    .chain(methods.iter().filter(|x| x.is_static).map(|x| x.ident))
+                    .collect();
+
+                let mut members: Vec<_> = statics
+                    .iter()
+                    .copied()
+                    .chain(fields.iter().map(|x| x.ident))
+                    .chain(properties.keys().copied())
+                    .chain(methods.iter().filter(|x| !x.is_static).map(|x| x.ident))
+                    .collect();
+
+                for field in fields.iter().filter(|x| x.is_embedded) {
+                    if let Some(Declaration::Type {
+                        members: embedded_members,
+                        statics: embedded_statics,
+                        ..
+                    }) = self.lookup(field.ident)
+                    {
+                        members.extend(embedded_members);
+                        statics.extend(embedded_statics);
+                    }
+                }
+
+                self.declare(
+                    *ident,
+                    Declaration::Type {
+                        fields: fields.iter().map(|x| x.ident).collect(),
+                        optional: fields
+                            .iter()
+                            .filter(|x| x.default.is_some())
+                            .map(|x| x.ident)
+                            .collect(),
+                        members: members.clone(),
+                        statics: statics.clone(),
+                    },
+                    false,
+                );
+
+                // defaults are evaluated in the scope of the type
+                self.scoped(frame_of(&statics), |checker| {
+                    for default in fields.iter().filter_map(|x| x.default.as_deref()) {
+                        checker.expression(default);
+                    }
+                });
+
+                for method in methods {
+                    let frame = frame_of(if method.is_static { &statics } else { &members });
+
+                    self.defer(frame, Some(&method.parameters), &method.body);
+                }
+
+                for property in properties.values() {
+                    self.property(property, &members);
+                }
+
+                for property in static_properties.values() {
+                    self.property(property, &statics);
+                }
+            }
+
+            FruStatement::Enum {
+                ident,
+                variants,
+                methods,
+            } => {
+                if variants.is_empty() {
+                    self.warning(Rule::EmptyType, format!("enum `{}` has no variants", ident));
+                }
+
+                let statics: Vec<_> = variants
+                    .iter()
+                    .map(|x| x.ident)
+                    .chain(methods.iter().filter(|x| x.is_static).map(|x| x.ident))
+                    .collect();
+
+                // methods are shared, so fields of every variant are visible in them
+                let members: Vec<_> = statics
+                    .iter()
+                    .copied()
+                    .chain(variants.iter().flat_map(|x| x.fields.iter().copied()))
+                    .chain(methods.iter().filter(|x| !x.is_static).map(|x| x.ident))
+                    .chain([id::VARIANT])
+                    .collect();
+
+                self.declare(
+                    *ident,
+                    Declaration::Type {
+                        fields: Vec::new(),
+                        optional: Vec::new(),
+                        members: members.clone(),
+                        statics: statics.clone(),
+                    },
+                    false,
+                );
+
+                for method in methods {
+                    let frame = frame_of(if method.is_static { &statics } else { &members });
+
+                    self.defer(frame, Some(&method.parameters), &method.body);
+                }
+            }
+        }
+    }
+
+    fn property(&mut self, property: &'a Property, members: &[Identifier]) {
+        if let Some(getter) = &property.getter {
+            self.defer(frame_of(members), None, getter);
+        }
+
+        if let Some((value_ident, setter)) = &property.setter {
+            let frame = frame_of(&[members, &[*value_ident]].concat());
+
+            self.defer(frame, None, setter);
+        }
+    }
+
+    fn expression(&mut self, expression: &'a FruExpression) {
+        match expression {
+            FruExpression::Literal { .. } => {}
+
+            FruExpression::FormatString { parts } => {
+                for part in parts {
+                    if let FormatStringPart::Interpolation { value, .. } = part {
+                        self.expression(value);
+                    }
+                }
+            }
+
+            FruExpression::Variable { ident } => {
+                self.lookup(*ident);
+            }
+
+            FruExpression::ScopeAccessor => self.frames.last_mut().unwrap().dynamic = true,
+
+            FruExpression::Function { args, body, .. } => {
+                self.defer(Frame::default(), Some(args), body)
+            }
+
+            FruExpression::Positioned { value, .. } => self.expression(value),
+
+            FruExpression::Block { body, expr } => self.scoped(local_frame(), |checker| {
+                checker.body(body);
+                checker.expression(expr);
+            }),
+
+            FruExpression::ScopeModifier { what, body, expr } => {
+                self.expression(what);
+
+                self.scoped(dynamic_frame(), |checker| {
+                    checker.body(body);
+                    checker.expression(expr);
+                });
+            }
+
+            FruExpression::Call { what, args } | FruExpression::CurryCall { what, args } => {
+                let curry = matches!(expression, FruExpression::CurryCall { .. });
+
+                if let FruExpression::Variable { ident } = &**what {
+                    if let Some(Declaration::Function(parameters)) = self.lookup(*ident) {
+                        self.call(*ident, &parameters, args, curry);
+                    }
+                } else {
+                    self.expression(what);
+                }
+
+                self.arguments(args);
+            }
+
+            FruExpression::Instantiation { what, args } => {
+                if let FruExpression::Variable { ident } = &**what {
+                    if let Some(Declaration::Type {
+                        fields, optional, ..
+                    }) = self.lookup(*ident)
+                    {
+                        self.instantiation(*ident, &fields, &optional, args);
+                    }
+                } else {
+                    self.expression(what);
+                }
+
+                self.arguments(args);
+            }
+
+            FruExpression::PropAccess { what, .. } => self.expression(what),
+
+            FruExpression::Index { what, index } => {
+                self.expression(what);
+                self.expression(index);
+            }
+
+            FruExpression::Slice { start, end } => {
+                for bound in [start, end].into_iter().flatten() {
+                    self.expression(bound);
+                }
+            }
+
+            FruExpression::Not { value } => self.expression(value),
+
+            FruExpression::Set { ident, value, .. } => {
+                self.expression(value);
+
+                if let Some(declared) = self.find(*ident) {
+                    // new value can be anything
+                    declared.declaration = Declaration::Variable;
+                }
+            }
+
+            FruExpression::SetProp { what, value, .. } => {
+                self.expression(what);
+                self.expression(value);
+            }
+
+            FruExpression::SetIndex {
+                what, index, value, ..
+            } => {
+                self.expression(what);
+                self.expression(index);
+                self.expression(value);
+            }
+
+            FruExpression::Binary { left, right, .. }
+            | FruExpression::Logical { left, right, .. }
+            | FruExpression::Coalesce { left, right } => {
+                self.expression(left);
+                self.expression(right);
+            }
+
+            FruExpression::If {
+                condition,
+                then_body,
+                else_body,
+                ..
+            } => {
+                self.condition(condition);
+                self.expression(then_body);
+                self.expression(else_body);
+            }
+
+            FruExpression::Match { value, arms } => {
+                self.expression(value);
+
+                for arm in arms {
+                    self.scoped(local_frame(), |checker| {
+                        if let Pattern::Value { value, bindings } = &arm.pattern {
+                            checker.expression(value);
+
+                            for ident in bindings {
+                                checker.declare(*ident, Declaration::Variable, false);
+                            }
+                        }
+
+                        if let Some(guard) = &arm.guard {
+                            checker.expression(guard);
+                        }
+
+                        checker.expression(&arm.body);
+                    });
+                }
+            }
+
+            FruExpression::Loop { body, .. } => self.statement(body),
+
+            FruExpression::Import { path } => self.expression(path),
+        }
+    }
+
+    fn arguments(&mut self, args: &'a ArgumentList) {
+        for arg in &args.args {
+            match arg {
+                Argument::Positional(value)
+                | Argument::Named(_, value)
+                | Argument::Spread(value)
+                | Argument::KwargsSpread(value) => self.expression(value),
+            }
+        }
+    }
+
+    fn call(
+        &mut self,
+        ident: Identifier,
+        parameters: &FormalParameters,
+        args: &ArgumentList,
+        curry: bool,
+    ) {
+        let spread = args.args.iter().any(|x| matches!(x, Argument::Spread(_)));
+        let kwargs_spread = args.args.iter().any(|x| matches!(x, Argument::KwargsSpread(_)));
+
+        let positional = args.args.iter().filter(|x| matches!(x, Argument::Positional(_))).count();
+
+        if !spread && parameters.rest.is_none() && positional > parameters.args.len() {
+            self.error(
+                ErrorKind::ArgumentMismatch,
+                format!(
+                    "too many arguments in call of `{}`, expected at most {}, got {}",
+                    ident,
+                    parameters.args.len(),
+                    positional
+                ),
+            );
+        }
+
+        let mut named = Vec::new();
+
+        for arg in &args.args {
+            let Argument::Named(name, _) = arg else {
+                continue;
+            };
+
+            match parameters.args.iter().position(|x| x.ident == *name) {
+                Some(n) if n < positional && !spread => self.error(
+                    ErrorKind::ArgumentMismatch,
+                    format!("argument `{}` is set twice in call of `{}`", name, ident),
+                ),
+
+                Some(_) => named.push(*name),
+
+                None if parameters.kwargs.is_none() => {
+                    let names: Vec<_> =
+                        parameters.args.iter().map(|x| x.ident.to_string()).collect();
+
+                    let note = did_you_mean(&name.to_string(), names.iter().map(String::as_str))
+                        .map(|x| format!("did you mean `{}`?", x));
+
+                    self.noted_error(
+                        ErrorKind::ArgumentMismatch,
+                        format!("unknown argument `{}` in call of `{}`", name, ident),
+                        note,
+                    )
+                }
+
+                None => {}
+            }
+        }
+
+        if curry || spread || kwargs_spread {
+            return;
+        }
+
+        for parameter in parameters.args.iter().skip(positional) {
+            if parameter.default.is_none() && !named.contains(&parameter.ident) {
+                self.error(
+                    ErrorKind::ArgumentMismatch,
+                    format!(
+                        "missing argument `{}` in call of `{}`",
+                        parameter.ident, ident
+                    ),
+                );
+            }
+        }
+    }
+
+    fn instantiation(
+        &mut self,
+        ident: Identifier,
+        fields: &[Identifier],
+        optional: &[Identifier],
+        args: &ArgumentList,
+    ) {
+        if args
+            .args
+            .iter()
+            .any(|x| matches!(x, Argument::Spread(_) | Argument::KwargsSpread(_)))
+        {
+            return;
+        }
+
+        let mut set = Vec::new();
+
+        for (n, arg) in args.args.iter().enumerate() {
+            let field = match arg {
+                Argument::Named(name, _) => *name,
+
+                _ => match fields.get(n) {
+                    Some(field) => *field,
+
+                    None => {
+                        self.error(
+                            ErrorKind::InvalidInstantiation,
+                            format!(
+                                "too many fields in instantiation of `{}`, expected {}, got {}",
+                                ident,
+                                fields.len(),
+                                args.args.len()
+                            ),
+                        );
+                        return;
+                    }
+                },
+            };
+
+            if !fields.contains(&field) {
+                self.error(
+                    ErrorKind::InvalidInstantiation,
+                    format!("field `{}` does not exist in `{}`", field, ident),
+                );
+            } else if set.contains(&field) {
+                self.error(
+                    ErrorKind::InvalidInstantiation,
+                    format!("field `{}` is set more than once", field),
+                );
+            }
+
+            set.push(field);
+        }
+
+        for field in fields {
+            if !set.contains(field) && !optional.contains(field) {
+                self.error(
+                    ErrorKind::InvalidInstantiation,
+                    format!("missing field `{}` in instantiation of `{}`", field, ident),
+                );
+            }
+        }
+    }
+}
+
+fn frame_of<'a>(idents: &[Identifier]) -> Frame<'a> {
+    let declared = Declared {
+        declaration: Declaration::Variable,
+        position: NOWHERE,
+        is_parameter: false,
+        is_used: false,
+    };
+
+    Frame {
+        declarations: idents.iter().map(|x| (*x, declared.clone())).collect(),
+        ..Default::default()
+    }
+}
+
+fn local_frame<'a>() -> Frame<'a> {
+    Frame {
+        is_local: true,
+        ..Default::default()
+    }
+}
+
+fn dynamic_frame<'a>() -> Frame<'a> {
+    Frame {
+        dynamic: true,
+        ..Default::default()
+    }
+}
+
+impl Display for CheckError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at {}:{}-{}:{}",
+            self.message,
+            self.position.start_point.row + 1,
+            self.position.start_point.column,
+            self.position.end_point.row + 1,
+            self.position.end_point.column
+        )
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use tree_sitter::{Node, Range};
+
+use crate::interpreter::{
+    debug::{self, DebugHook},
+    error::FruError,
+    scope::Scope,
+    tree_sitter_parser,
+};
+
+/// `if`, `while` or `match` arm, that can go two ways
+#[derive(Debug, PartialEq, Eq)]
+pub struct Branch {
+    pub line: usize,
+    // `None` if the condition was never evaluated
+    pub taken: Option<usize>,
+    pub not_taken: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct FileCoverage {
+    pub path: PathBuf,
+    // lines, where statements start, and how many times the most executed of them ran
+    pub lines: BTreeMap<usize, usize>,
+    pub branches: Vec<Branch>,
+}
+
+/// Coverage of every file, executed with `cover`, and files, imported by them
+#[derive(Debug, Default)]
+pub struct Coverage {
+    pub files: Vec<FileCoverage>,
+}
+
+// file, `None` for code, that was not read from a file, and position in it
+type Point = (Option<Rc<Path>>, Range);
+
+#[derive(Default)]
+struct Recorder {
+    statements: HashMap<Point, usize>,
+    branches: HashMap<Point, (usize, usize)>,
+}
+
+struct Collector(Rc<RefCell<Recorder>>);
+
+impl DebugHook for Collector {
+    fn statement(&mut self, position: Range, scope: &Rc<Scope>) -> Result<(), FruError> {
+        let point = (scope.get_file(), position);
+
+        *self.0.borrow_mut().statements.entry(point).or_default() += 1;
+        Ok(())
+    }
+
+    fn enter_call(&mut self, _: &str, _: Range, _: &Rc<Scope>) {}
+
+    fn leave_call(&mut self) {}
+
+    fn branch(&mut self, position: Range, taken: bool, scope: &Rc<Scope>) {
+        let mut recorder = self.0.borrow_mut();
+        let counts = recorder.branches.entry((scope.get_file(), position)).or_default();
+
+        if taken {
+            counts.0 += 1;
+        } else {
+            counts.1 += 1;
+        }
+    }
+}
+
+impl Coverage {
+    /// Runs `f`, recording, which statements and branches of `source` and of files,
+    /// imported by it, were executed. Code, that was not read from a file, is counted as `path`
+    pub fn cover<T>(&mut self, path: &Path, source: &str, f: impl FnOnce() -> T) -> T {
+        let recorder = Rc::new(RefCell::new(Recorder::default()));
+
+        debug::set_hook(Some(Box::new(Collector(recorder.clone()))));
+
+        let result = f();
+
+        debug::set_hook(None);
+
+        let recorder = recorder.take();
+
+        let file_of = |file: &Option<Rc<Path>>| file.as_deref().unwrap_or(path).to_path_buf();
+
+        let statements: HashMap<_, _> = recorder
+            .statements
+            .iter()
+            .map(|((file, position), hits)| ((file_of(file), *position), *hits))
+            .collect();
+
+        let branches: HashMap<_, _> = recorder
+            .branches
+            .iter()
+            .map(|((file, position), counts)| ((file_of(file), *position), *counts))
+            .collect();
+
+        let imported: BTreeSet<_> = statements
+            .keys()
+            .map(|(file, _)| file.clone())
+            .filter(|file| file != path)
+            .collect();
+
+        self.add(file_coverage(path, source, &statements, &branches));
+
+        for file in imported {
+            let source = fs::read_to_string(&file).unwrap_or_default();
+
+            self.add(file_coverage(&file, &source, &statements, &branches));
+        }
+
+        result
+    }
+
+    /// File, that was already covered, like a module, imported by several files, is merged
+    fn add(&mut self, coverage: FileCoverage) {
+        let Some(file) = self.files.iter_mut().find(|x| x.path == coverage.path) else {
+            self.files.push(coverage);
+            return;
+        };
+
+        for (line, hits) in coverage.lines {
+            *file.lines.entry(line).or_insert(0) += hits;
+        }
+
+        for (branch, other) in file.branches.iter_mut().zip(coverage.branches) {
+            let add = |a: Option<usize>, b: Option<usize>| match (a, b) {
+                (None, None) => None,
+                (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+            };
+
+            branch.taken = add(branch.taken, other.taken);
+            branch.not_taken = add(branch.not_taken, other.not_taken);
+        }
+    }
+
+    /// Covered lines and branches of every file, and lines, that were not executed
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+
+        let mut total = (0, 0, 0, 0);
+
+        for file in &self.files {
+            let (lines_hit, lines_found) = file.lines_hit();
+            let (branches_hit, branches_found) = file.branches_hit();
+
+            summary += &format!(
+                "{}: lines {}, branches {}\n",
+                file.path.display(),
+                ratio(lines_hit, lines_found),
+                ratio(branches_hit, branches_found)
+            );
+
+            let missed = file.missed_lines();
+
+            if !missed.is_empty() {
+                summary += &format!("    not executed: {}\n", missed);
+            }
+
+            total.0 += lines_hit;
+            total.1 += lines_found;
+            total.2 += branches_hit;
+            total.3 += branches_found;
+        }
+
+        summary += &format!(
+            "total: lines {}, branches {}\n",
+            ratio(total.0, total.1),
+            ratio(total.2, total.3)
+        );
+
+        summary
+    }
+
+    /// Report in LCOV tracefile format
+    pub fn lcov(&self) -> String {
+        let mut lcov = String::new();
+
+        for file in &self.files {
+            lcov += &format!("TN:\nSF:{}\n", file.path.display());
+
+            for (line, hits) in &file.lines {
+                lcov += &format!("DA:{},{}\n", line, hits);
+            }
+
+            for (block, branch) in file.branches.iter().enumerate() {
+                for (i, count) in [branch.taken, branch.not_taken].into_iter().enumerate() {
+                    let count = count.map_or("-".to_string(), |x| x.to_string());
+                    lcov += &format!("BRDA:{},{},{},{}\n", branch.line, block, i, count);
+                }
+            }
+
+            let (lines_hit, lines_found) = file.lines_hit();
+            let (branches_hit, branches_found) = file.branches_hit();
+
+            lcov += &format!("BRF:{}\nBRH:{}\n", branches_found, branches_hit);
+            lcov += &format!("LF:{}\nLH:{}\n", lines_found, lines_hit);
+            lcov += "end_of_record\n";
+        }
+
+        lcov
+    }
+}
+
+impl FileCoverage {
+    fn lines_hit(&self) -> (usize, usize) {
+        let hit = self.lines.values().filter(|x| **x > 0).count();
+
+        (hit, self.lines.len())
+    }
+
+    fn branches_hit(&self) -> (usize, usize) {
+        let hit = self
+            .branches
+            .iter()
+            .flat_map(|x| [x.taken, x.not_taken])
+            .filter(|x| x.is_some_and(|x| x > 0))
+            .count();
+
+        (hit, self.branches.len() * 2)
+    }
+
+    /// Lines, that were not executed, joined into ranges, like `3, 7-9`
+    fn missed_lines(&self) -> String {
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+        for (line, _) in self.lines.iter().filter(|(_, hits)| **hits == 0) {
+            match ranges.last_mut() {
+                // lines without statements between missed ones do not break the range
+                Some((_, end)) if self.lines.range(*end + 1..*line).all(|(_, x)| *x == 0) => {
+                    *end = *line;
+                }
+
+                _ => ranges.push((*line, *line)),
+            }
+        }
+
+        ranges
+            .into_iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Statements and branches of `source`, found by parser, with counts, recorded for `path`
+fn file_coverage(
+    path: &Path,
+    source: &str,
+    statements: &HashMap<(PathBuf, Range), usize>,
+    branches: &HashMap<(PathBuf, Range), (usize, usize)>,
+) -> FileCoverage {
+    let tree = tree_sitter_parser::parse_tree(source);
+
+    let mut statement_points = Vec::new();
+    let mut branch_points = Vec::new();
+    collect_points(tree.root_node(), &mut statement_points, &mut branch_points);
+
+    let mut lines = BTreeMap::new();
+
+    for position in statement_points {
+        let hits = statements.get(&(path.to_path_buf(), position)).copied().unwrap_or(0);
+        let line = lines.entry(position.start_point.row + 1).or_insert(0);
+
+        *line = hits.max(*line);
+    }
+
+    let branches = branch_points
+        .into_iter()
+        .map(|position| {
+            let counts = branches.get(&(path.to_path_buf(), position));
+
+            Branch {
+                line: position.start_point.row + 1,
+                taken: counts.map(|x| x.0),
+                not_taken: counts.map(|x| x.1),
+            }
+        })
+        .collect();
+
+    FileCoverage {
+        path: path.to_path_buf(),
+        lines,
+        branches,
+    }
+}
+
+fn ratio(hit: usize, found: usize) -> String {
+    if found == 0 {
+        return "0/0".to_string();
+    }
+
+    format!(
+        "{}/{} ({:.1}%)",
+        hit,
+        found,
+        hit as f64 * 100.0 / found as f64
+    )
+}
+
+/// Finds nodes, that parser wraps with positions, so their ranges match the ones, reported to hook
+fn collect_points(node: Node, statements: &mut Vec<Range>, branches: &mut Vec<Range>) {
+    if node.kind().ends_with("_statement") {
+        statements.push(node.range());
+    }
+
+    if matches!(
+        node.kind(),
+        "if_statement" | "if_expression" | "while_statement" | "match_arm"
+    ) {
+        branches.push(node.range());
+    }
+
+    if matches!(
+        node.kind(),
+        "block_expression" | "scope_modifier_expression"
+    ) {
+        if let Some(expr) = node.child_by_field_name("expr") {
+            statements.push(expr.range());
+        }
+    }
+
+    if node.kind() == "match_arm" {
+        if let Some(body) = node.child_by_field_name("body") {
+            statements.push(body.range());
+        }
+    }
+
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        collect_points(child, statements, branches);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+use crate::debugger::{evaluate, scope_chain, Breakpoints, Frame, Frontend, Resume, StopReason};
+
+const HELP: &str = "\
+continue, c            run until the next breakpoint
+next, n                step over calls
+step, s                step into calls
+out, o                 step out of the current call
+break, b [FILE:]LINE   set breakpoint, in the debugged file by default
+delete, d [FILE:]LINE  remove breakpoint
+backtrace, bt          show calls
+locals, l              show scope chain of the current call
+print, p EXPR          evaluate expression in the current scope
+quit, q                stop the program";
+
+/// Debugger, controlled by text commands
+pub struct Console {
+    path: PathBuf,
+    lines: Vec<String>,
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+}
+
+impl Console {
+    pub fn new(
+        path: PathBuf,
+        source: &str,
+        input: impl BufRead + 'static,
+        output: impl Write + 'static,
+    ) -> Self {
+        Self {
+            path,
+            lines: source.lines().map(str::to_string).collect(),
+            input: Box::new(input),
+            output: Box::new(output),
+        }
+    }
+}
+
+impl Frontend for Console {
+    fn paused(
+        &mut self,
+        reason: StopReason,
+        frames: &[Frame],
+        breakpoints: &mut Breakpoints,
+    ) -> Resume {
+        // if the user can not be asked, there is no one to debug for
+        self.prompt(reason, frames, breakpoints).unwrap_or(Resume::Stop)
+    }
+}
+
+impl Console {
+    fn prompt(
+        &mut self,
+        reason: StopReason,
+        frames: &[Frame],
+        breakpoints: &mut Breakpoints,
+    ) -> io::Result<Resume> {
+        let frame = frames.last().unwrap();
+        let position = frame.position.unwrap();
+        let file = frame.file().map_or(self.path.clone(), |x| x.to_path_buf());
+
+        writeln!(
+            self.output,
+            "stopped ({:?}) at {}:{}:{}",
+            reason,
+            file.display(),
+            position.start_point.row + 1,
+            position.start_point.column + 1
+        )?;
+
+        // imported files are read only when the program stops in them
+        let lines = if file == self.path {
+            self.lines.clone()
+        } else {
+            let source = fs::read_to_string(&file).unwrap_or_default();
+            source.lines().map(str::to_string).collect()
+        };
+
+        if let Some(line) = lines.get(position.start_point.row) {
+            writeln!(
+                self.output,
+                "{:>5} | {}",
+                position.start_point.row + 1,
+                line
+            )?;
+        }
+
+        loop {
+            write!(self.output, "(debug) ")?;
+            self.output.flush()?;
+
+            let mut command = String::new();
+
+            if self.input.read_line(&mut command)? == 0 {
+                return Ok(Resume::Stop);
+            }
+
+            let (command, argument) = match command.trim().split_once(' ') {
+                Some((command, argument)) => (command, argument.trim()),
+                None => (command.trim(), ""),
+            };
+
+            match command {
+                "continue" | "c" => return Ok(Resume::Continue),
+                "next" | "n" => return Ok(Resume::StepOver),
+                "step" | "s" => return Ok(Resume::StepInto),
+                "out" | "o" => return Ok(Resume::StepOut),
+                "quit" | "q" => return Ok(Resume::Stop),
+
+                "break" | "b" | "delete" | "d" => match self.breakpoint(argument) {
+                    Some(breakpoint) if command.starts_with('b') => {
+                        breakpoints.insert(breakpoint);
+                    }
+
+                    Some(breakpoint) => {
+                        breakpoints.remove(&breakpoint);
+                    }
+
+                    None => writeln!(self.output, "expected line number, got `{}`", argument)?,
+                },
+
+                "backtrace" | "bt" => {
+                    for (i, frame) in frames.iter().rev().enumerate() {
+                        match frame.position {
+                            Some(position) => writeln!(
+                                self.output,
+                                "#{} {} at {}:{}",
+                                i,
+                                frame.name,
+                                position.start_point.row + 1,
+                                position.start_point.column + 1
+                            )?,
+
+                            None => writeln!(self.output, "#{} {}", i, frame.name)?,
+                        }
+                    }
+                }
+
+                "locals" | "l" => {
+                    for view in scope_chain(frame.scope.as_ref().unwrap()) {
+                        writeln!(self.output, "{}:", view.name)?;
+
+                        for (name, value) in view.variables {
+                            writeln!(self.output, "    {} = {:?}", name, value)?;
+                        }
+                    }
+                }
+
+                "print" | "p" => match evaluate(argument, frame.scope.as_ref().unwrap()) {
+                    Ok(value) => writeln!(self.output, "{:?}", value)?,
+                    Err(err) => writeln!(self.output, "error: {}", err)?,
+                },
+
+                "" => {}
+
+                _ => writeln!(self.output, "{}", HELP)?,
+            }
+        }
+    }
+    /// `FILE:LINE` or `LINE` of the debugged file
+    fn breakpoint(&self, argument: &str) -> Option<(PathBuf, usize)> {
+        let (file, line) = match argument.rsplit_once(':') {
+            Some((file, line)) => (PathBuf::from(file), line),
+            None => (self.path.clone(), argument),
+        };
+
+        Some((file, line.parse().ok()?))
+    }
+}
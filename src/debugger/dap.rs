@@ -0,0 +1,452 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use serde_json::{json, Value};
+
+use crate::debugger::{
+    children, debug_file, evaluate, scope_chain, Breakpoints, Debugger, Frame, Frontend, Resume,
+    StopReason,
+};
+use crate::interpreter::{debug, value::fru_value::FruValue};
+
+type DapResult<T> = Result<T, Box<dyn Error>>;
+
+// the only thread of the program
+const THREAD_ID: i64 = 1;
+
+/// Debug Adapter Protocol messages over stdio
+struct Connection {
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+    seq: i64,
+    disconnected: bool,
+}
+
+/// Runs Debug Adapter Protocol server until the client disconnects, program output is sent as events
+pub fn run(input: impl BufRead + 'static, output: impl Write + 'static) -> DapResult<()> {
+    let connection = Rc::new(RefCell::new(Connection {
+        input: Box::new(input),
+        output: Box::new(output),
+        seq: 0,
+        disconnected: false,
+    }));
+
+    let mut program = None;
+    let mut breakpoints = Breakpoints::new();
+    let mut stop_on_entry = false;
+
+    // configuration, program starts after `configurationDone`
+    loop {
+        let Some(request) = connection.borrow_mut().receive()? else {
+            return Ok(());
+        };
+
+        let arguments = &request["arguments"];
+
+        let body = match request["command"].as_str().unwrap_or_default() {
+            "initialize" => {
+                connection.borrow_mut().respond(
+                    &request,
+                    json!({ "supportsConfigurationDoneRequest": true, "supportsEvaluateForHovers": true }),
+                )?;
+
+                connection.borrow_mut().event("initialized", json!({}))?;
+                continue;
+            }
+
+            "launch" => {
+                program = arguments["program"].as_str().map(PathBuf::from);
+                stop_on_entry = arguments["stopOnEntry"].as_bool().unwrap_or(false);
+                json!({})
+            }
+
+            "setBreakpoints" => set_breakpoints(&mut breakpoints, arguments, program.as_deref()),
+
+            "threads" => threads_body(),
+
+            "configurationDone" => {
+                connection.borrow_mut().respond(&request, json!({}))?;
+                break;
+            }
+
+            "disconnect" => return connection.borrow_mut().respond(&request, json!({})),
+
+            command => {
+                connection
+                    .borrow_mut()
+                    .respond_error(&request, &format!("`{}` is not supported now", command))?;
+                continue;
+            }
+        };
+
+        connection.borrow_mut().respond(&request, body)?;
+    }
+
+    let Some(program) = program else {
+        return Err("program to debug was not launched".into());
+    };
+
+    let output = connection.clone();
+
+    debug::set_output(Some(Box::new(move |text| {
+        // program output is lost, if the client is gone, there is nobody to show it to anyway
+        let _ = output
+            .borrow_mut()
+            .event("output", json!({ "category": "stdout", "output": text }));
+    })));
+
+    let frontend = Adapter {
+        connection: connection.clone(),
+        program: program.clone(),
+        references: Vec::new(),
+    };
+
+    let result = debug_file(
+        &program,
+        Debugger::new(Box::new(frontend), breakpoints, stop_on_entry),
+    );
+
+    debug::set_output(None);
+
+    let mut connection = connection.borrow_mut();
+
+    if connection.disconnected {
+        return Ok(());
+    }
+
+    if let Err(err) = &result {
+        connection.event(
+            "output",
+            json!({ "category": "stderr", "output": format!("{}\n", err) }),
+        )?;
+    }
+
+    connection.event(
+        "exited",
+        json!({ "exitCode": if result.is_ok() { 0 } else { 1 } }),
+    )?;
+    connection.event("terminated", json!({}))?;
+
+    while let Some(request) = connection.receive()? {
+        match request["command"].as_str().unwrap_or_default() {
+            "disconnect" => return connection.respond(&request, json!({})),
+            "threads" => connection.respond(&request, threads_body())?,
+            _ => connection.respond_error(&request, "program has finished")?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Frontend, that reports stops to the client and serves its requests until it resumes the program
+struct Adapter {
+    connection: Rc<RefCell<Connection>>,
+    program: PathBuf,
+    // things, that can be expanded by client, reference is index + 1, valid until the program resumes
+    references: Vec<Vec<(String, FruValue)>>,
+}
+
+impl Frontend for Adapter {
+    fn paused(
+        &mut self,
+        reason: StopReason,
+        frames: &[Frame],
+        breakpoints: &mut Breakpoints,
+    ) -> Resume {
+        self.references.clear();
+
+        let reason = match reason {
+            StopReason::Entry => "entry",
+            StopReason::Breakpoint => "breakpoint",
+            StopReason::Step => "step",
+        };
+
+        let stopped = json!({ "reason": reason, "threadId": THREAD_ID, "allThreadsStopped": true });
+
+        if self.connection.borrow_mut().event("stopped", stopped).is_err() {
+            return Resume::Stop;
+        }
+
+        loop {
+            // connection is not borrowed while the request is handled, as evaluation can print
+            let request = match self.connection.borrow_mut().receive() {
+                Ok(Some(request)) => request,
+                _ => return Resume::Stop,
+            };
+
+            let (body, resume) = self.handle(&request, frames, breakpoints);
+
+            let mut connection = self.connection.borrow_mut();
+
+            let sent = match body {
+                Ok(body) => connection.respond(&request, body),
+                Err(message) => connection.respond_error(&request, &message),
+            };
+
+            if sent.is_err() {
+                return Resume::Stop;
+            }
+
+            if resume == Some(Resume::Stop) {
+                connection.disconnected = true;
+            }
+
+            if let Some(resume) = resume {
+                return resume;
+            }
+        }
+    }
+}
+
+impl Adapter {
+    fn handle(
+        &mut self,
+        request: &Value,
+        frames: &[Frame],
+        breakpoints: &mut Breakpoints,
+    ) -> (Result<Value, String>, Option<Resume>) {
+        let arguments = &request["arguments"];
+
+        let resume = match request["command"].as_str().unwrap_or_default() {
+            "continue" => Resume::Continue,
+            "next" => Resume::StepOver,
+            "stepIn" => Resume::StepInto,
+            "stepOut" => Resume::StepOut,
+            "disconnect" => Resume::Stop,
+
+            "threads" => return (Ok(threads_body()), None),
+
+            "setBreakpoints" => {
+                let body = set_breakpoints(breakpoints, arguments, Some(&self.program));
+                return (Ok(body), None);
+            }
+
+            "stackTrace" => return (Ok(self.stack_trace(frames)), None),
+
+            "scopes" => {
+                let body = frame(frames, &arguments["frameId"]).map(|frame| self.scopes(frame));
+                return (body, None);
+            }
+
+            "variables" => {
+                let body = arguments["variablesReference"]
+                    .as_u64()
+                    .and_then(|x| self.references.get((x as usize).checked_sub(1)?).cloned())
+                    .ok_or("unknown variables reference".to_string())
+                    .map(|variables| self.variables(variables));
+
+                return (body, None);
+            }
+
+            "evaluate" => {
+                let frame_id = match &arguments["frameId"] {
+                    Value::Null => json!(frames.len() - 1),
+                    frame_id => frame_id.clone(),
+                };
+
+                let expression = arguments["expression"].as_str().unwrap_or_default();
+
+                let body = frame(frames, &frame_id)
+                    .and_then(|frame| evaluate(expression, frame.scope.as_ref().unwrap()))
+                    .map(|value| {
+                        let reference = self.reference(&value);
+                        json!({ "result": format!("{:?}", value), "variablesReference": reference })
+                    });
+
+                return (body, None);
+            }
+
+            command => return (Err(format!("`{}` is not supported", command)), None),
+        };
+
+        let body = match resume {
+            Resume::Continue => json!({ "allThreadsContinued": true }),
+            _ => json!({}),
+        };
+
+        (Ok(body), Some(resume))
+    }
+
+    /// Frames with positions, innermost first, frame id is index in `frames`
+    fn stack_trace(&self, frames: &[Frame]) -> Value {
+        let stack_frames: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, frame)| {
+                let position = frame.position?;
+                let file = frame.file().map_or(self.program.clone(), |x| x.to_path_buf());
+
+                Some(json!({
+                    "id": i,
+                    "name": frame.name,
+                    "line": position.start_point.row + 1,
+                    "column": position.start_point.column + 1,
+                    "source": { "path": file },
+                }))
+            })
+            .collect();
+
+        json!({ "totalFrames": stack_frames.len(), "stackFrames": stack_frames })
+    }
+
+    fn scopes(&mut self, frame: &Frame) -> Value {
+        let scopes: Vec<_> = scope_chain(frame.scope.as_ref().unwrap())
+            .into_iter()
+            .map(|view| {
+                self.references.push(view.variables);
+
+                json!({
+                    "name": view.name,
+                    "variablesReference": self.references.len(),
+                    "expensive": false,
+                })
+            })
+            .collect();
+
+        json!({ "scopes": scopes })
+    }
+
+    fn variables(&mut self, variables: Vec<(String, FruValue)>) -> Value {
+        let variables: Vec<_> = variables
+            .into_iter()
+            .map(|(name, value)| {
+                json!({
+                    "name": name,
+                    "value": format!("{:?}", value),
+                    "type": value.get_type_identifier().to_string(),
+                    "variablesReference": self.reference(&value),
+                })
+            })
+            .collect();
+
+        json!({ "variables": variables })
+    }
+
+    /// Reference to fields of value, `0` if it has none
+    fn reference(&mut self, value: &FruValue) -> usize {
+        let children = children(value);
+
+        if children.is_empty() {
+            return 0;
+        }
+
+        self.references.push(children);
+        self.references.len()
+    }
+}
+
+fn frame<'a>(frames: &'a [Frame], frame_id: &Value) -> Result<&'a Frame, String> {
+    frame_id
+        .as_u64()
+        .and_then(|x| frames.get(x as usize))
+        .filter(|x| x.scope.is_some())
+        .ok_or("unknown frame".to_string())
+}
+
+/// Replaces breakpoints of the source, the program is the source, if the client did not give one
+fn set_breakpoints(
+    breakpoints: &mut Breakpoints,
+    arguments: &Value,
+    program: Option<&Path>,
+) -> Value {
+    let source = match arguments["source"]["path"].as_str() {
+        Some(path) => PathBuf::from(path),
+        None => program.map(Path::to_path_buf).unwrap_or_default(),
+    };
+
+    breakpoints.retain(|(path, _)| *path != source);
+
+    let lines: Vec<_> = arguments["breakpoints"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|x| x["line"].as_u64())
+        .map(|x| x as usize)
+        .collect();
+
+    breakpoints.extend(lines.iter().map(|line| (source.clone(), *line)));
+
+    let breakpoints: Vec<_> =
+        lines.iter().map(|line| json!({ "verified": true, "line": line })).collect();
+
+    json!({ "breakpoints": breakpoints })
+}
+
+fn threads_body() -> Value {
+    json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })
+}
+
+impl Connection {
+    /// `None` if the client closed the stream
+    fn receive(&mut self) -> DapResult<Option<Value>> {
+        let mut length = None;
+
+        loop {
+            let mut header = String::new();
+
+            if self.input.read_line(&mut header)? == 0 {
+                return Ok(None);
+            }
+
+            let header = header.trim();
+
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some(value) = header.strip_prefix("Content-Length: ") {
+                length = Some(value.parse()?);
+            }
+        }
+
+        let mut body = vec![0; length.ok_or("message without Content-Length")?];
+        self.input.read_exact(&mut body)?;
+
+        Ok(Some(serde_json::from_slice(&body)?))
+    }
+
+    fn send(&mut self, mut message: Value) -> DapResult<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+
+        let text = message.to_string();
+
+        write!(
+            self.output,
+            "Content-Length: {}\r\n\r\n{}",
+            text.len(),
+            text
+        )?;
+        Ok(self.output.flush()?)
+    }
+
+    fn respond(&mut self, request: &Value, body: Value) -> DapResult<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": true,
+            "command": request["command"],
+            "body": body,
+        }))
+    }
+
+    fn respond_error(&mut self, request: &Value, message: &str) -> DapResult<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": false,
+            "command": request["command"],
+            "message": message,
+        }))
+    }
+
+    fn event(&mut self, event: &str, body: Value) -> DapResult<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+}
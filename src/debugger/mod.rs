@@ -0,0 +1,322 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use tree_sitter::Range;
+
+use crate::interpreter::{
+    control::Control,
+    debug::{self, DebugHook},
+    error::FruError,
+    runner::execute_file,
+    scope::Scope,
+    statement::FruStatement,
+    tree_sitter_parser,
+    value::fru_value::FruValue,
+};
+
+pub mod cli;
+pub mod dap;
+
+/// Function call, that is being executed
+pub struct Frame {
+    pub name: String,
+    // statement, that is being executed in this frame, `None` until the first one starts
+    pub position: Option<Range>,
+    pub scope: Option<Rc<Scope>>,
+}
+
+impl Frame {
+    /// File of the statement, that is being executed in this frame
+    pub fn file(&self) -> Option<Rc<Path>> {
+        self.scope.as_ref().and_then(|x| x.get_file())
+    }
+}
+
+/// Files with lines to stop at, lines start from 1
+pub type Breakpoints = BTreeSet<(PathBuf, usize)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Entry,
+    Breakpoint,
+    Step,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resume {
+    Continue,
+    StepOver,
+    StepInto,
+    StepOut,
+    Stop,
+}
+
+/// User interface of debugger, it is asked what to do every time the program stops
+pub trait Frontend {
+    /// `frames` start from the outermost one
+    fn paused(
+        &mut self,
+        reason: StopReason,
+        frames: &[Frame],
+        breakpoints: &mut Breakpoints,
+    ) -> Resume;
+}
+
+/// One level of scope chain, as it is shown to the user
+pub struct ScopeView {
+    pub name: String,
+    pub variables: Vec<(String, FruValue)>,
+}
+
+enum Mode {
+    Run,
+    Pause,
+    // depths of the frame, where stepping started
+    StepOver(usize),
+    StepOut(usize),
+}
+
+pub struct Debugger {
+    frontend: Box<dyn Frontend>,
+    breakpoints: Breakpoints,
+    mode: Mode,
+    frames: Vec<Frame>,
+    // depth, file and line of the last stop, so statements nested in the same line
+    // do not hit its breakpoint again
+    last_stop: Option<(usize, Option<Rc<Path>>, usize)>,
+}
+
+impl Debugger {
+    pub fn new(frontend: Box<dyn Frontend>, breakpoints: Breakpoints, stop_on_entry: bool) -> Self {
+        Self {
+            frontend,
+            breakpoints,
+            mode: if stop_on_entry {
+                Mode::Pause
+            } else {
+                Mode::Run
+            },
+            frames: vec![Frame {
+                name: "<program>".to_string(),
+                position: None,
+                scope: None,
+            }],
+            last_stop: None,
+        }
+    }
+
+    fn is_breakpoint(&self, file: Option<&Path>, line: usize) -> bool {
+        let Some(file) = file else {
+            return false;
+        };
+
+        // paths are compared only for lines with breakpoints, as it touches the file system
+        self.breakpoints
+            .iter()
+            .filter(|(_, x)| *x == line)
+            .any(|(path, _)| is_same_file(path, file))
+    }
+}
+
+/// Relative and absolute paths of the same file are the same
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+impl DebugHook for Debugger {
+    fn statement(&mut self, position: Range, scope: &Rc<Scope>) -> Result<(), FruError> {
+        let is_entry = self.frames.len() == 1 && self.frames[0].position.is_none();
+        let frame = self.frames.last_mut().unwrap();
+
+        frame.position = Some(position);
+        frame.scope = Some(scope.clone());
+
+        let depth = self.frames.len();
+        let file = scope.get_file();
+        let line = position.start_point.row + 1;
+
+        let stop = Some((depth, file.clone(), line));
+
+        if self.last_stop != stop {
+            self.last_stop = None;
+        }
+
+        let reason = match self.mode {
+            Mode::Pause if is_entry => Some(StopReason::Entry),
+            Mode::Pause => Some(StopReason::Step),
+            Mode::StepOver(from) if depth <= from => Some(StopReason::Step),
+            Mode::StepOut(from) if depth < from => Some(StopReason::Step),
+            _ if self.last_stop.is_none() && self.is_breakpoint(file.as_deref(), line) => {
+                Some(StopReason::Breakpoint)
+            }
+            _ => None,
+        };
+
+        let Some(reason) = reason else {
+            return Ok(());
+        };
+
+        self.last_stop = stop;
+
+        self.mode = match self.frontend.paused(reason, &self.frames, &mut self.breakpoints) {
+            Resume::Continue => Mode::Run,
+            Resume::StepOver => Mode::StepOver(depth),
+            Resume::StepInto => Mode::Pause,
+            Resume::StepOut => Mode::StepOut(depth),
+            Resume::Stop => return FruError::new_res("execution stopped by debugger"),
+        };
+
+        Ok(())
+    }
+
+    fn enter_call(&mut self, name: &str, _: Range, _: &Rc<Scope>) {
+        self.frames.push(Frame {
+            name: name.to_string(),
+            position: None,
+            scope: None,
+        });
+    }
+
+    fn leave_call(&mut self) {
+        self.frames.pop();
+    }
+
+    fn branch(&mut self, _: Range, _: bool, _: &Rc<Scope>) {}
+}
+
+/// Executes file, stopping, when debugger asks to
+pub fn debug_file(path: &Path, debugger: Debugger) -> Result<(), FruError> {
+    debug::set_hook(Some(Box::new(debugger)));
+
+    let result = execute_file(path);
+
+    debug::set_hook(None);
+
+    result.map(|_| ())
+}
+
+/// Levels of scope chain from the innermost one, empty levels and builtins are omitted
+pub fn scope_chain(scope: &Rc<Scope>) -> Vec<ScopeView> {
+    let mut chain = Vec::new();
+    let mut current = scope.clone();
+
+    loop {
+        let parent = current.get_parent();
+
+        let name = if parent.is_none() {
+            "Globals"
+        } else if chain.is_empty() {
+            "Locals"
+        } else {
+            "Block"
+        };
+
+        let variables = variables(&current);
+
+        if !variables.is_empty() {
+            chain.push(ScopeView {
+                name: name.to_string(),
+                variables,
+            });
+        }
+
+        let Some((parent, owner)) = parent else {
+            break;
+        };
+
+        let type_ = match owner {
+            Some(FruValue::Object(object)) => {
+                chain.push(ScopeView {
+                    name: format!("Fields of `{}`", object.get_type().get_ident()),
+                    variables: children(&FruValue::Object(object.clone())),
+                });
+
+                Some(object.get_type())
+            }
+
+            Some(FruValue::Type(type_)) => Some(type_),
+
+            _ => None,
+        };
+
+        if let Some(type_) = type_ {
+            chain.push(ScopeView {
+                name: format!("Statics of `{}`", type_.get_ident()),
+                variables: children(&FruValue::Type(type_)),
+            });
+        }
+
+        current = parent;
+    }
+
+    chain
+}
+
+fn variables(scope: &Scope) -> Vec<(String, FruValue)> {
+    scope
+        .get_variables()
+        .into_iter()
+        .map(|(ident, value)| (ident.to_string(), value))
+        .collect()
+}
+
+/// Fields of objects and static fields of types, that can be expanded
+pub fn children(value: &FruValue) -> Vec<(String, FruValue)> {
+    match value {
+        FruValue::Object(object) => object
+            .get_type()
+            .get_fields()
+            .iter()
+            .filter_map(|field| Some((field.ident.to_string(), object.get_prop(field.ident).ok()?)))
+            .collect(),
+
+        FruValue::Type(type_) => type_
+            .get_static_fields()
+            .into_iter()
+            .map(|(ident, value)| (ident.to_string(), value))
+            .collect(),
+
+        _ => Vec::new(),
+    }
+}
+
+/// Evaluates expression or executes statements in a new scope inside of given one,
+/// so declarations do not leak into the program
+pub fn evaluate(source: &str, scope: &Rc<Scope>) -> Result<FruValue, String> {
+    let source = source.trim();
+
+    let source = if source.ends_with(';') || source.ends_with('}') {
+        source.to_string()
+    } else {
+        format!("{};", source)
+    };
+
+    let ast = tree_sitter_parser::parse(source).map_err(|err| err.to_string())?;
+
+    let FruStatement::SourceCode { body } = *ast else {
+        unreachable!("parser always returns source code")
+    };
+
+    let scope = Scope::new_with_parent(scope.clone());
+
+    // value of the last statement is shown, if it is an expression
+    let result = match body.split_last() {
+        Some((FruStatement::Positioned { statement, .. }, rest)) => rest
+            .iter()
+            .try_for_each(|x| x.execute(scope.clone()))
+            .and_then(|()| match &**statement {
+                FruStatement::Expression { value } => value.evaluate(scope),
+                statement => statement.execute(scope).map(|()| FruValue::Nah),
+            }),
+
+        _ => Ok(FruValue::Nah),
+    };
+
+    result.map_err(|signal| match signal {
+        Control::Error(err) => err.to_string(),
+        other => format!("unexpected signal: {:?}", other),
+    })
+}
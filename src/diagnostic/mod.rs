@@ -0,0 +1,200 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{json, Value};
+use tree_sitter::Range;
+
+use crate::checker::{lint::Warning, CheckError};
+use crate::interpreter::{
+    error::{char_column, describe_at, ErrorKind, FruError},
+    tree_sitter_parser::ParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Error or warning of any stage, in the form, that is shown to user or written as JSON for tools
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // stable code, like `E0001`, lint warnings use name of the rule
+    pub code: String,
+    // name of error kind or lint rule, like `undefined_variable`
+    pub kind: String,
+    pub message: String,
+    // file of the span, like imported file, where runtime error happened
+    pub file: PathBuf,
+    // `None` if error is not tied to a place in code, like when file can not be read
+    pub span: Option<Range>,
+    pub notes: Vec<String>,
+    // positions of calls and imports with their files, that led to runtime error, the innermost first
+    pub traceback: Vec<(Range, PathBuf)>,
+}
+
+impl Diagnostic {
+    /// Error of running program, its span is where it happened, `file` is the executed one,
+    /// it is used for positions, which file is unknown
+    pub fn runtime(file: &Path, err: &FruError) -> Diagnostic {
+        let mut traceback = err.get_traceback().iter().map(|(position, position_file)| {
+            (
+                *position,
+                position_file.as_deref().unwrap_or(file).to_path_buf(),
+            )
+        });
+
+        let (span, span_file) = match traceback.next() {
+            Some((position, position_file)) => (Some(position), position_file),
+            None => (None, file.to_path_buf()),
+        };
+
+        Diagnostic {
+            severity: Severity::Error,
+            code: err.get_kind().code().to_string(),
+            kind: err.get_kind().name().to_string(),
+            message: err.get_message().to_string(),
+            file: span_file,
+            span,
+            notes: err.get_notes().to_vec(),
+            traceback: traceback.collect(),
+        }
+    }
+
+    /// One diagnostic for every syntax error
+    pub fn syntax(file: &Path, err: &ParseError) -> Vec<Diagnostic> {
+        err.errors()
+            .into_iter()
+            .map(|x| Diagnostic {
+                severity: Severity::Error,
+                code: err.kind().code().to_string(),
+                kind: err.kind().name().to_string(),
+                message: x.message,
+                file: file.to_path_buf(),
+                span: Some(x.position),
+                notes: x.note.into_iter().collect(),
+                traceback: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Error, that is not tied to a place in code, like failure to read the file
+    pub fn io(file: &Path, err: impl Display) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: ErrorKind::Io.code().to_string(),
+            kind: ErrorKind::Io.name().to_string(),
+            message: err.to_string(),
+            file: file.to_path_buf(),
+            span: None,
+            notes: Vec::new(),
+            traceback: Vec::new(),
+        }
+    }
+
+    pub fn check(file: &Path, err: &CheckError) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: err.kind.code().to_string(),
+            kind: err.kind.name().to_string(),
+            message: err.message.clone(),
+            file: file.to_path_buf(),
+            span: Some(err.position),
+            notes: err.note.iter().cloned().collect(),
+            traceback: Vec::new(),
+        }
+    }
+
+    pub fn lint(file: &Path, warning: &Warning) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Warning,
+            code: warning.rule.name().to_string(),
+            kind: warning.rule.name().to_string(),
+            message: warning.message.clone(),
+            file: file.to_path_buf(),
+            span: Some(warning.position),
+            notes: Vec::new(),
+            traceback: Vec::new(),
+        }
+    }
+
+    /// `file:line:column: error[code]: message` with excerpt of `source` and notes,
+    /// followed by the positions of the calls, which led to the error. `source` is the code of `file`
+    pub fn human(&self, source: &str) -> String {
+        let heading = format!("{}[{}]: {}", self.severity.name(), self.code, self.message);
+
+        let mut description = match self.span {
+            Some(span) => describe_at(&self.file, source, span, &heading, &self.notes),
+
+            None => {
+                let mut description = format!("{}: {}\n", self.file.display(), heading);
+
+                for note in &self.notes {
+                    description += &format!("      = note: {}\n", note);
+                }
+
+                description
+            }
+        };
+
+        for (position, file) in &self.traceback {
+            let start = position.start_point;
+
+            // lines of other files are not at hand, their columns stay in bytes
+            let column = match source.lines().nth(start.row) {
+                Some(line) if *file == self.file => char_column(line, start.column),
+                _ => start.column,
+            };
+
+            description += &format!(
+                "    at {}:{}:{}\n",
+                file.display(),
+                start.row + 1,
+                column + 1
+            );
+        }
+
+        description
+    }
+
+    /// Positions are objects with `row` and `column`, both starting from 0, like in `frugurt parse --ast`
+    pub fn json(&self) -> Value {
+        json!({
+            "severity": self.severity.name(),
+            "code": self.code,
+            "kind": self.kind,
+            "message": self.message,
+            "file": self.file.display().to_string(),
+            "span": self.span.map(|x| json!({
+                "start": point(x.start_point),
+                "end": point(x.end_point),
+            })),
+            "notes": self.notes,
+            "traceback": self
+                .traceback
+                .iter()
+                .map(|(position, file)| json!({
+                    "file": file.display().to_string(),
+                    "row": position.start_point.row,
+                    "column": position.start_point.column,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn point(point: tree_sitter::Point) -> Value {
+    json!({ "row": point.row, "column": point.column })
+}
@@ -0,0 +1,588 @@
+use serde_json::{json, Value};
+use tree_sitter::{Node, Range};
+
+use crate::interpreter::{
+    ast_helpers::{FormatStringPart, MatchArm, Pattern, RawMethod, RawStaticField, RawVariant},
+    expression::FruExpression,
+    format::{Alignment, FormatSpec},
+    statement::FruStatement,
+    tree_sitter_parser::{self, ParseError},
+    value::{
+        fru_type::{FruField, Property, TypeType},
+        fru_value::FruValue,
+        function::{Argument, ArgumentList, FormalParameters},
+    },
+};
+
+/// Concrete syntax tree as S-expression, like `tree-sitter parse` shows it.
+/// Only named nodes are shown, with field names and `[row, column]` ranges, starting from 0
+pub fn dump_cst(source: &str) -> String {
+    let tree = tree_sitter_parser::parse_tree(source);
+
+    let mut result = String::new();
+    cst_node(tree.root_node(), None, 0, &mut result);
+    result.push('\n');
+
+    result
+}
+
+fn cst_node(node: Node, field: Option<&str>, depth: usize, result: &mut String) {
+    if depth > 0 {
+        result.push('\n');
+    }
+
+    result.push_str(&"  ".repeat(depth));
+
+    if let Some(field) = field {
+        result.push_str(&format!("{}: ", field));
+    }
+
+    let kind = if node.is_missing() {
+        format!("MISSING {:?}", node.kind())
+    } else {
+        node.kind().to_string()
+    };
+
+    let start = node.start_position();
+    let end = node.end_position();
+
+    result.push_str(&format!(
+        "({} [{}, {}] - [{}, {}]",
+        kind, start.row, start.column, end.row, end.column
+    ));
+
+    let mut cursor = node.walk();
+
+    for (i, child) in node.children(&mut cursor).enumerate() {
+        if child.is_named() || child.is_missing() {
+            cst_node(
+                child,
+                node.field_name_for_child(i as u32),
+                depth + 1,
+                result,
+            );
+        }
+    }
+
+    result.push(')');
+}
+
+/// Abstract syntax tree as JSON. Every node is an object with `kind`, named after the variant,
+/// other keys are named after its fields
+pub fn dump_ast(source: &str) -> Result<Value, ParseError> {
+    Ok(statement(&*tree_sitter_parser::parse(source.to_string())?))
+}
+
+fn statement(node: &FruStatement) -> Value {
+    match node {
+        FruStatement::SourceCode { body } => json!({
+            "kind": "SourceCode",
+            "body": statements(body),
+        }),
+
+        FruStatement::Block { body } => json!({
+            "kind": "Block",
+            "body": statements(body),
+        }),
+
+        FruStatement::Positioned {
+            position,
+            statement: inner,
+        } => json!({
+            "kind": "Positioned",
+            "position": range(position),
+            "statement": statement(inner),
+        }),
+
+        FruStatement::ScopeModifier { what, body } => json!({
+            "kind": "ScopeModifier",
+            "what": expression(what),
+            "body": statements(body),
+        }),
+
+        FruStatement::Expression { value } => json!({
+            "kind": "Expression",
+            "value": expression(value),
+        }),
+
+        FruStatement::Let { ident, value } => json!({
+            "kind": "Let",
+            "ident": ident.to_string(),
+            "value": expression(value),
+        }),
+
+        FruStatement::If {
+            position,
+            condition,
+            then_body,
+            else_body,
+        } => json!({
+            "kind": "If",
+            "position": range(position),
+            "condition": expression(condition),
+            "then_body": statement(then_body),
+            "else_body": else_body.as_deref().map(statement),
+        }),
+
+        FruStatement::While {
+            position,
+            label,
+            condition,
+            body,
+        } => json!({
+            "kind": "While",
+            "position": range(position),
+            "label": label.map(|x| x.to_string()),
+            "condition": expression(condition),
+            "body": statement(body),
+        }),
+
+        FruStatement::Return { value } => json!({
+            "kind": "Return",
+            "value": value.as_deref().map(expression),
+        }),
+
+        FruStatement::Break { label, value } => json!({
+            "kind": "Break",
+            "label": label.map(|x| x.to_string()),
+            "value": value.as_deref().map(expression),
+        }),
+
+        FruStatement::Continue { label } => json!({
+            "kind": "Continue",
+            "label": label.map(|x| x.to_string()),
+        }),
+
+        FruStatement::Operator {
+            ident,
+            commutative,
+            left_ident,
+            left_type_ident,
+            right_ident,
+            right_type_ident,
+            body,
+        } => json!({
+            "kind": "Operator",
+            "ident": ident.to_string(),
+            "commutative": commutative,
+            "left_ident": left_ident.to_string(),
+            "left_type_ident": left_type_ident.to_string(),
+            "right_ident": right_ident.to_string(),
+            "right_type_ident": right_type_ident.to_string(),
+            "body": statement(body),
+        }),
+
+        FruStatement::Test { name, body } => json!({
+            "kind": "Test",
+            "name": name,
+            "body": statement(body),
+        }),
+
+        FruStatement::Callable {
+            name,
+            position,
+            body,
+        } => json!({
+            "kind": "Callable",
+            "name": name,
+            "position": range(position),
+            "body": statement(body),
+        }),
+
+        FruStatement::Type {
+            type_type,
+            ident,
+            fields,
+            static_fields,
+            properties,
+            static_properties,
+            methods,
+        } => json!({
+            "kind": "Type",
+            "type_type": match type_type {
+                TypeType::Struct => "struct",
+                TypeType::Class => "class",
+                TypeType::Data => "data",
+                TypeType::Enum => "enum",
+            },
+            "ident": ident.to_string(),
+            "fields": fields.iter().map(field).collect::<Vec<_>>(),
+            "static_fields": static_fields.iter().map(static_field).collect::<Vec<_>>(),
+            "properties": sorted_properties(properties.values()),
+            "static_properties": sorted_properties(static_properties.values()),
+            "methods": methods.iter().map(method).collect::<Vec<_>>(),
+        }),
+
+        FruStatement::Enum {
+            ident,
+            variants,
+            methods,
+        } => json!({
+            "kind": "Enum",
+            "ident": ident.to_string(),
+            "variants": variants.iter().map(variant).collect::<Vec<_>>(),
+            "methods": methods.iter().map(method).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn expression(node: &FruExpression) -> Value {
+    match node {
+        FruExpression::Literal { value } => json!({
+            "kind": "Literal",
+            "type": value.get_type_identifier().to_string(),
+            "value": literal(value),
+        }),
+
+        FruExpression::FormatString { parts } => json!({
+            "kind": "FormatString",
+            "parts": parts.iter().map(format_string_part).collect::<Vec<_>>(),
+        }),
+
+        FruExpression::Variable { ident } => json!({
+            "kind": "Variable",
+            "ident": ident.to_string(),
+        }),
+
+        FruExpression::ScopeAccessor => json!({
+            "kind": "ScopeAccessor",
+        }),
+
+        FruExpression::Function { ident, args, body } => json!({
+            "kind": "Function",
+            "ident": ident.map(|x| x.to_string()),
+            "args": parameters(args),
+            "body": statement(body),
+        }),
+
+        FruExpression::Block { body, expr } => json!({
+            "kind": "Block",
+            "body": statements(body),
+            "expr": expression(expr),
+        }),
+
+        FruExpression::Positioned { position, value } => json!({
+            "kind": "Positioned",
+            "position": range(position),
+            "value": expression(value),
+        }),
+
+        FruExpression::ScopeModifier { what, body, expr } => json!({
+            "kind": "ScopeModifier",
+            "what": expression(what),
+            "body": statements(body),
+            "expr": expression(expr),
+        }),
+
+        FruExpression::Call { what, args } => json!({
+            "kind": "Call",
+            "what": expression(what),
+            "args": arguments(args),
+        }),
+
+        FruExpression::CurryCall { what, args } => json!({
+            "kind": "CurryCall",
+            "what": expression(what),
+            "args": arguments(args),
+        }),
+
+        FruExpression::Instantiation { what, args } => json!({
+            "kind": "Instantiation",
+            "what": expression(what),
+            "args": arguments(args),
+        }),
+
+        FruExpression::PropAccess {
+            what,
+            ident,
+            optional,
+        } => json!({
+            "kind": "PropAccess",
+            "what": expression(what),
+            "ident": ident.to_string(),
+            "optional": optional,
+        }),
+
+        FruExpression::Index { what, index } => json!({
+            "kind": "Index",
+            "what": expression(what),
+            "index": expression(index),
+        }),
+
+        FruExpression::Slice { start, end } => json!({
+            "kind": "Slice",
+            "start": start.as_deref().map(expression),
+            "end": end.as_deref().map(expression),
+        }),
+
+        FruExpression::Binary {
+            operator,
+            left,
+            right,
+        } => json!({
+            "kind": "Binary",
+            "operator": operator.to_string(),
+            "left": expression(left),
+            "right": expression(right),
+        }),
+
+        FruExpression::Logical {
+            operator,
+            left,
+            right,
+        } => json!({
+            "kind": "Logical",
+            "operator": operator.to_string(),
+            "left": expression(left),
+            "right": expression(right),
+        }),
+
+        FruExpression::Set {
+            ident,
+            operator,
+            value,
+        } => json!({
+            "kind": "Set",
+            "ident": ident.to_string(),
+            "operator": operator.map(|x| x.to_string()),
+            "value": expression(value),
+        }),
+
+        FruExpression::SetProp {
+            what,
+            ident,
+            operator,
+            value,
+        } => json!({
+            "kind": "SetProp",
+            "what": expression(what),
+            "ident": ident.to_string(),
+            "operator": operator.map(|x| x.to_string()),
+            "value": expression(value),
+        }),
+
+        FruExpression::SetIndex {
+            what,
+            index,
+            operator,
+            value,
+        } => json!({
+            "kind": "SetIndex",
+            "what": expression(what),
+            "index": expression(index),
+            "operator": operator.map(|x| x.to_string()),
+            "value": expression(value),
+        }),
+
+        FruExpression::Coalesce { left, right } => json!({
+            "kind": "Coalesce",
+            "left": expression(left),
+            "right": expression(right),
+        }),
+
+        FruExpression::Not { value } => json!({
+            "kind": "Not",
+            "value": expression(value),
+        }),
+
+        FruExpression::If {
+            position,
+            condition,
+            then_body,
+            else_body,
+        } => json!({
+            "kind": "If",
+            "position": range(position),
+            "condition": expression(condition),
+            "then_body": expression(then_body),
+            "else_body": expression(else_body),
+        }),
+
+        FruExpression::Match { value, arms } => json!({
+            "kind": "Match",
+            "value": expression(value),
+            "arms": arms.iter().map(match_arm).collect::<Vec<_>>(),
+        }),
+
+        FruExpression::Loop { label, body } => json!({
+            "kind": "Loop",
+            "label": label.map(|x| x.to_string()),
+            "body": statement(body),
+        }),
+
+        FruExpression::Import { path } => json!({
+            "kind": "Import",
+            "path": expression(path),
+        }),
+    }
+}
+
+fn statements(body: &[FruStatement]) -> Value {
+    body.iter().map(statement).collect()
+}
+
+fn range(range: &Range) -> Value {
+    json!({
+        "start": { "row": range.start_point.row, "column": range.start_point.column },
+        "end": { "row": range.end_point.row, "column": range.end_point.column },
+    })
+}
+
+fn literal(value: &FruValue) -> Value {
+    match value {
+        FruValue::Nah => Value::Null,
+        FruValue::Number(x) => json!(x),
+        FruValue::Bool(x) => json!(x),
+        FruValue::String(x) => json!(x),
+        // parser produces only the values above
+        other => json!(format!("{:?}", other)),
+    }
+}
+
+fn format_string_part(part: &FormatStringPart) -> Value {
+    match part {
+        FormatStringPart::Literal(text) => json!({
+            "kind": "Literal",
+            "value": text,
+        }),
+
+        FormatStringPart::Interpolation { value, spec } => json!({
+            "kind": "Interpolation",
+            "value": expression(value),
+            "spec": format_spec(spec),
+        }),
+    }
+}
+
+fn format_spec(spec: &FormatSpec) -> Value {
+    json!({
+        "fill": spec.fill.to_string(),
+        "align": spec.align.map(|x| match x {
+            Alignment::Left => "<",
+            Alignment::Center => "^",
+            Alignment::Right => ">",
+        }),
+        "width": spec.width,
+        "precision": spec.precision,
+    })
+}
+
+fn parameters(parameters: &FormalParameters) -> Value {
+    let args: Vec<_> = parameters
+        .args
+        .iter()
+        .map(|x| {
+            json!({
+                "ident": x.ident.to_string(),
+                "default": x.default.as_ref().map(expression),
+            })
+        })
+        .collect();
+
+    json!({
+        "args": args,
+        "rest": parameters.rest.map(|x| x.to_string()),
+        "kwargs": parameters.kwargs.map(|x| x.to_string()),
+    })
+}
+
+fn arguments(arguments: &ArgumentList) -> Value {
+    arguments
+        .args
+        .iter()
+        .map(|arg| match arg {
+            Argument::Positional(value) => json!({
+                "kind": "Positional",
+                "value": expression(value),
+            }),
+
+            Argument::Named(ident, value) => json!({
+                "kind": "Named",
+                "ident": ident.to_string(),
+                "value": expression(value),
+            }),
+
+            Argument::Spread(value) => json!({
+                "kind": "Spread",
+                "value": expression(value),
+            }),
+
+            Argument::KwargsSpread(value) => json!({
+                "kind": "KwargsSpread",
+                "value": expression(value),
+            }),
+        })
+        .collect()
+}
+
+fn field(field: &FruField) -> Value {
+    json!({
+        "is_public": field.is_public,
+        "ident": field.ident.to_string(),
+        "type_ident": field.type_ident.map(|x| x.to_string()),
+        "is_embedded": field.is_embedded,
+        "default": field.default.as_deref().map(expression),
+    })
+}
+
+fn static_field(field: &RawStaticField) -> Value {
+    json!({
+        "ident": field.ident.to_string(),
+        "value": field.value.as_deref().map(expression),
+    })
+}
+
+// properties are stored in a map, so they are sorted to keep output stable
+fn sorted_properties<'a>(properties: impl Iterator<Item = &'a Property>) -> Value {
+    let mut properties: Vec<_> = properties.collect();
+    properties.sort_by_key(|x| x.ident.to_string());
+
+    properties
+        .into_iter()
+        .map(|property| {
+            json!({
+                "ident": property.ident.to_string(),
+                "getter": property.getter.as_deref().map(statement),
+                "setter": property.setter.as_ref().map(|(ident, body)| json!({
+                    "ident": ident.to_string(),
+                    "body": statement(body),
+                })),
+            })
+        })
+        .collect()
+}
+
+fn method(method: &RawMethod) -> Value {
+    json!({
+        "is_static": method.is_static,
+        "ident": method.ident.to_string(),
+        "parameters": parameters(&method.parameters),
+        "body": statement(&method.body),
+    })
+}
+
+fn variant(variant: &RawVariant) -> Value {
+    json!({
+        "ident": variant.ident.to_string(),
+        "fields": variant.fields.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    })
+}
+
+fn match_arm(arm: &MatchArm) -> Value {
+    json!({
+        "position": range(&arm.position),
+        "pattern": match &arm.pattern {
+            Pattern::Wildcard => json!({
+                "kind": "Wildcard",
+            }),
+
+            Pattern::Value { value, bindings } => json!({
+                "kind": "Value",
+                "value": expression(value),
+                "bindings": bindings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            }),
+        },
+        "guard": arm.guard.as_ref().map(expression),
+        "body": expression(&arm.body),
+    })
+}
@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::interpreter::tree_sitter_parser::{self, ParseError};
+
+const INDENT: &str = "    ";
+
+// nodes, which contents go between `{` and `}` on separate lines
+const BLOCKS: [&str; 7] = [
+    "block_statement",
+    "block_expression",
+    "scope_modifier_statement",
+    "scope_modifier_expression",
+    "type_statement",
+    "type_impl",
+    "type_property",
+];
+
+// nodes, which children are placed on separate lines, paired with the field of these children
+const LINE_ITEMS: [(&str, &str); 8] = [
+    ("source_file", "body"),
+    ("block_statement", "body"),
+    ("block_expression", "body"),
+    ("scope_modifier_statement", "body"),
+    ("scope_modifier_expression", "body"),
+    ("type_statement", "members"),
+    ("type_impl", "methods"),
+    ("type_property", "items"),
+];
+
+// nodes, which comma separated children go between `{` and `}` on separate lines
+const SEPARATED_BLOCKS: [(&str, &str); 2] =
+    [("enum_statement", "variants"), ("match_expression", "arms")];
+
+/// Formats Frugurt source code, code with syntax errors is not formatted
+pub fn format_source(source: &str) -> Result<String, ParseError> {
+    // full parse also catches errors, that tree-sitter accepts
+    tree_sitter_parser::parse(source.to_string())?;
+
+    let tree = tree_sitter_parser::parse_tree(source);
+
+    Ok(Formatter::new(tree.root_node(), source).format())
+}
+
+/// Token based formatter: every leaf of the syntax tree is printed as is,
+/// and whitespace between leaves is decided by their place in the tree
+struct Formatter<'a> {
+    source: &'a str,
+    tokens: Vec<Node<'a>>,
+
+    line_ends: HashSet<usize>, // tokens followed by a line break
+    opens: HashSet<usize>,     // tokens after which indentation increases
+    closes: HashSet<usize>,    // tokens before which indentation decreases
+    lists: HashSet<usize>,     // multiline lists, that get a trailing comma
+
+    result: String,
+    indent: usize,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(root: Node<'a>, source: &'a str) -> Self {
+        let mut formatter = Self {
+            source,
+            tokens: Vec::new(),
+            line_ends: HashSet::new(),
+            opens: HashSet::new(),
+            closes: HashSet::new(),
+            lists: HashSet::new(),
+            result: String::new(),
+            indent: 0,
+        };
+
+        formatter.collect(root);
+
+        formatter
+    }
+
+    fn collect(&mut self, node: Node<'a>) {
+        if node.child_count() == 0 {
+            self.tokens.push(node);
+            return;
+        }
+
+        for (parent, field) in LINE_ITEMS {
+            if node.kind() == parent {
+                let mut cursor = node.walk();
+
+                for child in node.children_by_field_name(field, &mut cursor) {
+                    self.line_ends.insert(last_leaf(child).id());
+                }
+            }
+        }
+
+        if BLOCKS.contains(&node.kind()) && !self.is_inline_block(node) {
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+
+            if let Some(open) = children.iter().find(|x| x.kind() == "{") {
+                self.opens.insert(open.id());
+            }
+
+            if let Some(close) = children.iter().rev().find(|x| x.kind() == "}") {
+                self.closes.insert(close.id());
+            }
+        }
+
+        if matches!(node.kind(), "argument_list" | "formal_parameters")
+            && node.named_child_count() > 0
+            && node.start_position().row != node.end_position().row
+        {
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+
+            self.opens.insert(children[0].id());
+            self.closes.insert(children[children.len() - 1].id());
+            self.lists.insert(children[children.len() - 1].id());
+
+            for comma in children.iter().filter(|x| x.kind() == ",") {
+                self.line_ends.insert(comma.id());
+            }
+        }
+
+        for (parent, field) in SEPARATED_BLOCKS {
+            if node.kind() == parent && node.child_by_field_name(field).is_some() {
+                let mut cursor = node.walk();
+                let children: Vec<_> = node.children(&mut cursor).collect();
+
+                if let Some(open) = children.iter().find(|x| x.kind() == "{") {
+                    self.opens.insert(open.id());
+                }
+
+                if let Some(close) = children.iter().rev().find(|x| x.kind() == "}") {
+                    self.closes.insert(close.id());
+                    self.lists.insert(close.id());
+                }
+
+                for comma in children.iter().filter(|x| x.kind() == ",") {
+                    self.line_ends.insert(comma.id());
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor).collect::<Vec<_>>() {
+            self.collect(child);
+        }
+    }
+
+    /// Block, that stays on one line: `{}` or `{ expression }` written on one line
+    fn is_inline_block(&self, node: Node) -> bool {
+        let mut cursor = node.walk();
+        let has_body = node.children_by_field_name("body", &mut cursor).next().is_some();
+
+        match node.kind() {
+            "block_statement"
+            | "type_statement"
+            | "type_impl"
+            | "type_property"
+            | "scope_modifier_statement" => {
+                // `type_statement` and `scope_modifier_statement` have named children before `{`
+                let open = node.children(&mut cursor).position(|x| x.kind() == "{");
+                let inside = node.children(&mut cursor).skip(open.unwrap_or(0) + 1);
+
+                inside.filter(|x| x.kind() != "}").count() == 0
+            }
+
+            _ => !has_body && node.start_position().row == node.end_position().row,
+        }
+    }
+
+    fn format(mut self) -> String {
+        let mut pending_newline = false;
+
+        for i in 0..self.tokens.len() {
+            let token = self.tokens[i];
+
+            if self.closes.contains(&token.id()) {
+                self.indent -= 1;
+
+                if self.lists.contains(&token.id()) && self.tokens[i - 1].kind() != "," {
+                    self.result += ",";
+                }
+            }
+
+            if i > 0 {
+                let prev = self.tokens[i - 1];
+
+                let trailing_comment = token.kind() == "comment"
+                    && token.start_position().row == prev.end_position().row
+                    && !is_line_comment(prev, self.source);
+
+                if trailing_comment {
+                    self.result += " ";
+                } else if pending_newline
+                    || self.closes.contains(&token.id())
+                    || token.kind() == "comment"
+                {
+                    let keep_blank_line = token.start_position().row > prev.end_position().row + 1
+                        && !self.opens.contains(&prev.id())
+                        && !self.closes.contains(&token.id());
+
+                    self.newline(keep_blank_line);
+                    pending_newline = false;
+                } else if needs_space(prev, token) {
+                    self.result += " ";
+                }
+            }
+
+            self.result += &self.source[token.byte_range()];
+
+            if self.opens.contains(&token.id()) {
+                self.indent += 1;
+            }
+
+            // code after a block comment stays on its line only if it was written there
+            let ends_line = token.kind() == "comment"
+                && (is_line_comment(token, self.source)
+                    || self
+                        .tokens
+                        .get(i + 1)
+                        .is_some_and(|next| next.start_position().row > token.end_position().row));
+
+            pending_newline |= self.line_ends.contains(&token.id())
+                || self.opens.contains(&token.id())
+                || ends_line;
+        }
+
+        let mut result: String =
+            self.result.lines().map(str::trim_end).collect::<Vec<_>>().join("\n");
+
+        if !result.is_empty() {
+            result += "\n";
+        }
+
+        result
+    }
+
+    fn newline(&mut self, keep_blank_line: bool) {
+        if keep_blank_line {
+            self.result += "\n";
+        }
+
+        self.result += "\n";
+        self.result += &INDENT.repeat(self.indent);
+    }
+}
+
+fn needs_space(prev: Node, token: Node) -> bool {
+    let parent_kind = |node: Node| node.parent().map_or("", |x| x.kind());
+
+    match (prev.kind(), token.kind()) {
+        (_, "," | ";" | ")" | "." | "?." | "$(" | "[" | "]") => false,
+
+        ("(" | "$(" | "." | "?." | "[", _) => false,
+
+        // `a[1..3]`
+        ("..", _) if parent_kind(prev) == "slice" => false,
+        (_, "..") if parent_kind(token) == "slice" => false,
+
+        ("{" | ":{", "}") => false,
+
+        // `'outer: while`
+        ("label", ":") => false,
+
+        ("!", _) if parent_kind(prev) == "unary_expression" => false,
+
+        (".." | "**", _)
+            if matches!(
+                parent_kind(prev),
+                "rest_parameter"
+                    | "kwargs_parameter"
+                    | "spread_argument"
+                    | "kwargs_spread_argument"
+            ) =>
+        {
+            false
+        }
+
+        (_, "(") => match parent_kind(token) {
+            "argument_list" | "scope_expression" | "type_property_item" => false,
+
+            // `fn (x)` but `fn f(x)`
+            "formal_parameters" => {
+                let function = token.parent().and_then(|x| x.parent());
+
+                function.is_some_and(|x| {
+                    x.kind() == "function_expression" && x.child_by_field_name("ident").is_none()
+                })
+            }
+
+            _ => true,
+        },
+
+        // `f(a: 1)` but `x : Number`
+        (_, ":") => parent_kind(token) != "named_argument",
+
+        _ => true,
+    }
+}
+
+fn is_line_comment(node: Node, source: &str) -> bool {
+    node.kind() == "comment" && source[node.byte_range()].starts_with("//")
+}
+
+fn last_leaf(node: Node) -> Node {
+    let mut node = node;
+
+    while node.child_count() > 0 {
+        node = node.child(node.child_count() - 1).unwrap();
+    }
+
+    node
+}
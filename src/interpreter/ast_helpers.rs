@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
+use tree_sitter::Range;
+
 use crate::interpreter::{
-    expression::FruExpression, identifier::Identifier, statement::FruStatement,
+    expression::FruExpression, format::FormatSpec, identifier::Identifier, statement::FruStatement,
     value::function::FormalParameters,
 };
 
@@ -18,3 +20,38 @@ pub struct RawMethod {
     pub parameters: FormalParameters,
     pub body: Rc<FruStatement>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RawVariant {
+    pub ident: Identifier,
+    pub fields: Vec<Identifier>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    // position of the arm, to report taken branch
+    pub position: Range,
+    pub pattern: Pattern,
+    pub guard: Option<FruExpression>,
+    pub body: FruExpression,
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    // `_`, matches anything
+    Wildcard,
+    // type, which object the value must be, with fields to bind, or value to compare with
+    Value {
+        value: FruExpression,
+        bindings: Vec<Identifier>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum FormatStringPart {
+    Literal(String),
+    Interpolation {
+        value: FruExpression,
+        spec: FormatSpec,
+    },
+}
@@ -2,41 +2,63 @@
 
 use std::{collections::HashMap, io, io::Write};
 
+use macros::static_ident;
+
 use crate::interpreter::{
-    error::FruError,
+    debug,
+    error::{ErrorKind, FruError},
+    format::{split_template, FormatSpec, TemplatePiece},
     identifier::Identifier,
     value::fru_value::{FruValue, TFnBuiltin},
     value::function::{AnyFunction, BuiltinFunction, EvaluatedArgumentList},
 };
+use crate::stdlib::list::fru_list::{extract_list_from_value, FruList};
 
 pub fn builtin_functions() -> HashMap<Identifier, FruValue> {
     HashMap::from(
         [
             ("print", b_print as TFnBuiltin),
             ("input", b_input as TFnBuiltin),
+            ("assert", b_assert as TFnBuiltin),
             ("assert_eq", b_assert_eq as TFnBuiltin),
+            ("assert_ne", b_assert_ne as TFnBuiltin),
+            ("assert_approx", b_assert_approx as TFnBuiltin),
+            ("assert_throws", b_assert_throws as TFnBuiltin),
+            ("format", b_format as TFnBuiltin),
+            ("str", b_str as TFnBuiltin),
+            ("repr", b_repr as TFnBuiltin),
+            ("partial", b_partial as TFnBuiltin),
+            ("flip", b_flip as TFnBuiltin),
+            ("compose", b_compose as TFnBuiltin),
         ]
         .map(|(ident, function)| {
+            let ident = Identifier::new(ident);
+
             (
-                Identifier::new(ident),
-                FruValue::Function(AnyFunction::BuiltinFunction(BuiltinFunction::new(function))),
+                ident,
+                FruValue::Function(AnyFunction::BuiltinFunction(BuiltinFunction::new(
+                    ident, function,
+                ))),
             )
         }),
     )
 }
 
 fn b_print(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
-    for arg in args.args {
-        print!("{:?} ", arg.1);
-    }
-    println!();
+    let texts = args
+        .args
+        .iter()
+        .map(|(_, arg)| arg.to_display_string())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    debug::write_output(&format!("{}\n", texts.join(" ")));
 
     Ok(FruValue::Nah)
 }
 
 fn b_input(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
     if args.args.len() == 1 {
-        print!("{:?}", args.args[0].1);
+        debug::write_output(&args.args[0].1.to_display_string()?);
         io::stdout().flush().unwrap();
     }
 
@@ -55,3 +77,260 @@ fn b_assert_eq(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
         ))
     }
 }
+
+fn b_assert(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, FruValue::Bool(true))]
+        | [(None, FruValue::Bool(true)), (None, FruValue::String(_))] => Ok(FruValue::Nah),
+
+        [(None, FruValue::Bool(false))] => {
+            FruError::new_kind_res(ErrorKind::AssertionFailed, "assertion failed")
+        }
+
+        [(None, FruValue::Bool(false)), (None, FruValue::String(message))] => {
+            FruError::new_kind_res(
+                ErrorKind::AssertionFailed,
+                format!("assertion failed: {}", message),
+            )
+        }
+
+        _ => FruError::new_res("assert takes `Bool` condition and optional `String` message"),
+    }
+}
+
+fn b_assert_ne(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, left), (None, right)] if left != right => Ok(FruValue::Nah),
+
+        [(None, left), (None, right)] => FruError::new_kind_res(
+            ErrorKind::AssertionFailed,
+            format!("assertion failed: {:?} == {:?}", left, right),
+        ),
+
+        _ => FruError::new_res("assert_ne takes exactly two positional arguments"),
+    }
+}
+
+fn b_assert_approx(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    let (left, right, tolerance) = match args.args.as_slice() {
+        [(None, FruValue::Number(left)), (None, FruValue::Number(right))] => (left, right, &1e-9),
+
+        [(None, FruValue::Number(left)), (None, FruValue::Number(right)), (name, FruValue::Number(tolerance))]
+            if name.is_none() || *name == Some(static_ident!("tolerance")) =>
+        {
+            (left, right, tolerance)
+        }
+
+        _ => {
+            return FruError::new_kind_res(
+                ErrorKind::ArgumentMismatch,
+                "assert_approx takes two `Number` values and optional `Number` tolerance",
+            );
+        }
+    };
+
+    if (left - right).abs() <= *tolerance {
+        Ok(FruValue::Nah)
+    } else {
+        FruError::new_res(format!(
+            "assertion failed: {} is not approximately equal to {} (tolerance {})",
+            left, right, tolerance
+        ))
+    }
+}
+
+/// Calls function without arguments and returns message of the error, it has thrown
+fn b_assert_throws(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    let (function, expected) = match args.args.as_slice() {
+        [(None, function)] => (function, None),
+        [(None, function), (None, FruValue::String(expected))] => (function, Some(expected)),
+        _ => {
+            return FruError::new_res(
+                "assert_throws takes a function and optional `String` message",
+            )
+        }
+    };
+
+    match function.call(EvaluatedArgumentList { args: Vec::new() }) {
+        Ok(_) => FruError::new_kind_res(
+            ErrorKind::AssertionFailed,
+            "assertion failed: function did not throw",
+        ),
+
+        Err(err) => match expected {
+            Some(expected) if !err.get_message().contains(expected.as_str()) => {
+                FruError::new_res(format!(
+                    "assertion failed: expected error containing {:?}, got {:?}",
+                    expected,
+                    err.get_message()
+                ))
+            }
+
+            _ => Ok(FruValue::String(err.get_message().to_string())),
+        },
+    }
+}
+
+fn b_format(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    let mut positional = Vec::new();
+    let mut named = HashMap::new();
+
+    for (ident, value) in args.args {
+        match ident {
+            Some(ident) => {
+                named.insert(ident, value);
+            }
+            None => positional.push(value),
+        }
+    }
+
+    let template = match positional.first() {
+        Some(FruValue::String(template)) => template.clone(),
+
+        Some(other) => {
+            return FruError::new_res(format!(
+                "Expected `String` as format template, got `{}`",
+                other.get_type_identifier()
+            ));
+        }
+
+        None => return FruError::new_res("format template is not provided"),
+    };
+
+    let mut next_positional = 1;
+    let mut result = String::new();
+
+    for piece in split_template(&template).map_err(FruError::new)? {
+        match piece {
+            TemplatePiece::Literal(s) => result.push_str(s),
+
+            TemplatePiece::EscapedBrace(c) => result.push(c),
+
+            TemplatePiece::Field(field) => {
+                let (key, spec) = match field.split_once(':') {
+                    Some((key, spec)) => {
+                        (key.trim(), FormatSpec::parse(spec).map_err(FruError::new)?)
+                    }
+                    None => (field.trim(), FormatSpec::default()),
+                };
+
+                let value = if key.is_empty() {
+                    next_positional += 1;
+                    positional.get(next_positional - 1)
+                } else if let Ok(n) = key.parse::<usize>() {
+                    positional.get(n + 1)
+                } else {
+                    named.get(&Identifier::new(key))
+                };
+
+                match value {
+                    Some(value) => result.push_str(&spec.apply(value)?),
+
+                    None => {
+                        return FruError::new_res(format!(
+                            "format argument `{}` is not provided",
+                            if key.is_empty() {
+                                (next_positional - 2).to_string()
+                            } else {
+                                key.to_string()
+                            }
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(FruValue::String(result))
+}
+
+fn b_str(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, value)] => Ok(FruValue::String(value.to_display_string()?)),
+
+        _ => FruError::new_res("str takes exactly one positional argument"),
+    }
+}
+
+fn b_repr(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, value)] => Ok(FruValue::String(format!("{:?}", value))),
+
+        [(None, value), (_, FruValue::Bool(pretty))] => Ok(FruValue::String(if *pretty {
+            format!("{:#?}", value)
+        } else {
+            format!("{:?}", value)
+        })),
+
+        _ => FruError::new_res("repr takes a value and optional `Bool` pretty flag"),
+    }
+}
+
+fn b_partial(mut args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.first() {
+        Some((None, _)) => {
+            let (_, function) = args.args.remove(0);
+            function.curry_call(args)
+        }
+
+        _ => FruError::new_res("partial takes a function and arguments to save"),
+    }
+}
+
+fn b_flip(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, function)] => {
+            Ok(BuiltinFunction::new(static_ident!("flip"), b_flipped).bind(function.clone()))
+        }
+
+        _ => FruError::new_res("flip takes exactly one function"),
+    }
+}
+
+fn b_flipped(mut args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    let (_, function) = args.args.remove(0);
+
+    let positional: Vec<_> = args
+        .args
+        .iter()
+        .enumerate()
+        .filter(|(_, (ident, _))| ident.is_none())
+        .map(|(i, _)| i)
+        .collect();
+
+    match positional.as_slice() {
+        [first, second, ..] => args.args.swap(*first, *second),
+
+        _ => return FruError::new_res("flipped function takes at least two positional arguments"),
+    }
+
+    function.call(args)
+}
+
+fn b_compose(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    if args.args.is_empty() || args.args.iter().any(|(ident, _)| ident.is_some()) {
+        return FruError::new_res("compose takes one or more functions");
+    }
+
+    let functions = FruList::new_value(args.args.into_iter().map(|(_, f)| f).collect());
+
+    Ok(BuiltinFunction::new(static_ident!("compose"), b_composed).bind(functions))
+}
+
+// compose(f, g)(x) is f(g(x))
+fn b_composed(mut args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    let (_, functions) = args.args.remove(0);
+    let functions = extract_list_from_value(&functions).unwrap().borrow().clone();
+
+    let (last, rest) = functions.split_last().unwrap();
+
+    let mut result = last.call(args)?;
+
+    for function in rest.iter().rev() {
+        result = function.call(EvaluatedArgumentList {
+            args: vec![(None, result)],
+        })?;
+    }
+
+    Ok(result)
+}
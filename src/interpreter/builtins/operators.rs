@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::interpreter::{
-    error::FruError,
+    error::{ErrorKind, FruError},
     identifier::{id, OperatorIdentifier},
     value::{fru_value::FruValue, operator::AnyOperator},
 };
@@ -53,12 +53,6 @@ pub fn builtin_operators() -> HashMap<OperatorIdentifier, AnyOperator> {
         ]
     ));
 
-    res.extend(operator_group!(
-        BOOL,
-        BOOL,
-        [(AND, bool_and_bool), (OR, bool_or_bool)]
-    ));
-
     res.extend(operator_group!(
         STRING,
         STRING,
@@ -73,6 +67,21 @@ pub fn builtin_operators() -> HashMap<OperatorIdentifier, AnyOperator> {
         ]
     ));
 
+    // `nah` is equal only to itself, it can be compared with anything,
+    // the other side is reflected, like `x != nah`
+    res.extend(operator_group!(
+        NAH,
+        ANY,
+        [(EQ, nah_eq_any), (NOT_EQ, nah_not_eq_any)]
+    ));
+
+    // types are equal only to themselves, like variant of enum, taken with `variant`
+    res.extend(operator_group!(
+        TYPE,
+        TYPE,
+        [(EQ, type_eq_type), (NOT_EQ, type_not_eq_type)]
+    ));
+
     res.extend([
         (
             OperatorIdentifier::new(id::MULTIPLY, id::STRING, id::NUMBER),
@@ -94,7 +103,7 @@ builtin_operator!(num_mul_num, Number, Number, Number, *);
 fn num_div_num(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
     if let (FruValue::Number(l), FruValue::Number(r)) = (left, right) {
         if r == 0.0 {
-            return FruError::new_res("division by zero");
+            return FruError::new_kind_res(ErrorKind::DivisionByZero, "division by zero");
         }
         return Ok(FruValue::Number(l / r));
     }
@@ -105,7 +114,7 @@ fn num_div_num(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
 fn num_mod_num(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
     if let (FruValue::Number(l), FruValue::Number(r)) = (left, right) {
         if r == 0.0 {
-            return FruError::new_res("division by zero");
+            return FruError::new_kind_res(ErrorKind::DivisionByZero, "division by zero");
         }
         return Ok(FruValue::Number(l.rem_euclid(r)));
     }
@@ -127,9 +136,18 @@ builtin_operator!(num_greater_eq_num, Number, Number, Bool, >=);
 builtin_operator!(num_eq_num, Number, Number, Bool, ==);
 builtin_operator!(num_not_eq_num, Number, Number, Bool, !=);
 
-// bool
-builtin_operator!(bool_or_bool, Bool, Bool, Bool, ||);
-builtin_operator!(bool_and_bool, Bool, Bool, Bool, &&);
+// nah
+fn nah_eq_any(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
+    Ok(FruValue::Bool(left == right))
+}
+
+fn nah_not_eq_any(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
+    Ok(FruValue::Bool(left != right))
+}
+
+// type
+builtin_operator!(type_eq_type, Type, Type, Bool, ==);
+builtin_operator!(type_not_eq_type, Type, Type, Bool, !=);
 
 // string
 builtin_operator!(string_less_string, String, String, Bool, <);
@@ -1,16 +1,26 @@
-use crate::interpreter::{error::FruError, value::fru_value::FruValue};
+use crate::interpreter::{
+    error::{ErrorKind, FruError},
+    identifier::Identifier,
+    value::fru_value::FruValue,
+};
 
 #[derive(Debug)]
 pub enum Control {
-    Continue,
-    Break,
+    // label is `None` for the innermost loop
+    Continue {
+        label: Option<Identifier>,
+    },
+    Break {
+        label: Option<Identifier>,
+        value: FruValue,
+    },
     Return(FruValue),
     Error(FruError),
 }
 
 impl Control {
-    pub fn new_err<T>(message: impl Into<String>) -> Result<T, Control> {
-        Err(Control::Error(FruError::new(message.into())))
+    pub fn new_kind_err<T>(kind: ErrorKind, message: impl Into<String>) -> Result<T, Control> {
+        Err(Control::Error(FruError::new_kind(kind, message)))
     }
 }
 
@@ -20,6 +30,21 @@ impl From<FruError> for Control {
     }
 }
 
+/// Handles signal of one loop iteration, `Some` means, that the loop is finished with the value
+pub fn loop_iteration(
+    x: Result<(), Control>,
+    label: Option<Identifier>,
+) -> Result<Option<FruValue>, Control> {
+    let targets = |target: Option<Identifier>| target.is_none() || target == label;
+
+    match x {
+        Ok(()) => Ok(None),
+        Err(Control::Continue { label }) if targets(label) => Ok(None),
+        Err(Control::Break { label, value }) if targets(label) => Ok(Some(value)),
+        Err(signal) => Err(signal),
+    }
+}
+
 pub fn returned(x: Result<FruValue, Control>) -> Result<FruValue, FruError> {
     match x {
         Ok(x) => Ok(x),
@@ -0,0 +1,83 @@
+use std::{cell::RefCell, rc::Rc};
+
+use tree_sitter::Range;
+
+use crate::interpreter::{error::FruError, scope::Scope};
+
+/// Observes execution, used by debugger
+pub trait DebugHook {
+    /// Called before every statement, that has position in source code,
+    /// returned error stops the program
+    fn statement(&mut self, position: Range, scope: &Rc<Scope>) -> Result<(), FruError>;
+
+    /// Called, when body of function, method, operator or property accessor starts,
+    /// `scope` is the one of the body
+    fn enter_call(&mut self, name: &str, position: Range, scope: &Rc<Scope>);
+
+    fn leave_call(&mut self);
+
+    /// Called, when condition of `if` or `while`, or pattern of `match` arm at `position` is evaluated
+    /// in `scope`
+    fn branch(&mut self, position: Range, taken: bool, scope: &Rc<Scope>);
+}
+
+type Output = Box<dyn FnMut(&str)>;
+
+thread_local! {
+    static HOOK: RefCell<Option<Box<dyn DebugHook>>> = RefCell::new(None);
+
+    // replaces stdout for `print` and `input`, when set
+    static OUTPUT: RefCell<Option<Output>> = RefCell::new(None);
+}
+
+pub fn set_hook(hook: Option<Box<dyn DebugHook>>) {
+    HOOK.with(|x| *x.borrow_mut() = hook);
+}
+
+pub fn set_output(output: Option<Output>) {
+    OUTPUT.with(|x| *x.borrow_mut() = output);
+}
+
+/// Hook is taken out while it works, so code, that it evaluates, is not observed
+fn with_hook<T>(f: impl FnOnce(&mut dyn DebugHook) -> T) -> Option<T> {
+    let mut hook = HOOK.with(|x| x.borrow_mut().take())?;
+
+    let result = f(hook.as_mut());
+
+    HOOK.with(|x| *x.borrow_mut() = Some(hook));
+
+    Some(result)
+}
+
+pub fn statement(position: Range, scope: &Rc<Scope>) -> Result<(), FruError> {
+    with_hook(|hook| hook.statement(position, scope)).unwrap_or(Ok(()))
+}
+
+pub fn call<T>(name: &str, position: Range, scope: &Rc<Scope>, f: impl FnOnce() -> T) -> T {
+    with_hook(|hook| hook.enter_call(name, position, scope));
+
+    let result = f();
+
+    with_hook(|hook| hook.leave_call());
+
+    result
+}
+
+pub fn branch(position: Range, taken: bool, scope: &Rc<Scope>) {
+    with_hook(|hook| hook.branch(position, taken, scope));
+}
+
+pub fn write_output(text: &str) {
+    let written = OUTPUT.with(|x| match x.borrow_mut().as_mut() {
+        Some(output) => {
+            output(text);
+            true
+        }
+
+        None => false,
+    });
+
+    if !written {
+        print!("{}", text);
+    }
+}
@@ -1,27 +1,273 @@
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
 use thiserror::Error;
+use tree_sitter::Range;
+
+use crate::interpreter::{tree_sitter_parser::ParseError, value::function::ArgumentError};
+
+/// What went wrong, every kind has a code, that does not change between versions,
+/// so tools can rely on it instead of the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Other,
+    UndefinedVariable,
+    ArgumentMismatch,
+    UndefinedOperator,
+    UndefinedProperty,
+    UnexpectedType,
+    InvalidInstantiation,
+    AlreadyDeclared,
+    AssertionFailed,
+    DivisionByZero,
+    OutOfBounds,
+    SyntaxError,
+    InvalidSyntax,
+    Io,
+    UnmatchedValue,
+}
 
-use crate::interpreter::value::function::ArgumentError;
+impl ErrorKind {
+    pub fn code(self) -> &'static str {
+        match self {
+            ErrorKind::Other => "E0000",
+            ErrorKind::UndefinedVariable => "E0001",
+            ErrorKind::ArgumentMismatch => "E0002",
+            ErrorKind::UndefinedOperator => "E0003",
+            ErrorKind::UndefinedProperty => "E0004",
+            ErrorKind::UnexpectedType => "E0005",
+            ErrorKind::InvalidInstantiation => "E0006",
+            ErrorKind::AlreadyDeclared => "E0007",
+            ErrorKind::AssertionFailed => "E0008",
+            ErrorKind::DivisionByZero => "E0009",
+            ErrorKind::OutOfBounds => "E0010",
+            ErrorKind::SyntaxError => "E0011",
+            ErrorKind::InvalidSyntax => "E0012",
+            ErrorKind::Io => "E0013",
+            ErrorKind::UnmatchedValue => "E0014",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::Other => "other",
+            ErrorKind::UndefinedVariable => "undefined_variable",
+            ErrorKind::ArgumentMismatch => "argument_mismatch",
+            ErrorKind::UndefinedOperator => "undefined_operator",
+            ErrorKind::UndefinedProperty => "undefined_property",
+            ErrorKind::UnexpectedType => "unexpected_type",
+            ErrorKind::InvalidInstantiation => "invalid_instantiation",
+            ErrorKind::AlreadyDeclared => "already_declared",
+            ErrorKind::AssertionFailed => "assertion_failed",
+            ErrorKind::DivisionByZero => "division_by_zero",
+            ErrorKind::OutOfBounds => "out_of_bounds",
+            ErrorKind::SyntaxError => "syntax_error",
+            ErrorKind::InvalidSyntax => "invalid_syntax",
+            ErrorKind::Io => "io",
+            ErrorKind::UnmatchedValue => "unmatched_value",
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 #[error("{message}")]
 pub struct FruError {
+    kind: ErrorKind,
     message: String,
+    // hints, shown after the message, like `did you mean ...?`
+    notes: Vec<String>,
+    // innermost statement of every call with its file, starting from the one, where error happened
+    traceback: Vec<(Range, Option<Rc<Path>>)>,
+    left_call: bool,
+    // errors of the file, that could not be parsed, with its path, when it was read from a file
+    syntax: Option<Box<(Option<PathBuf>, ParseError)>>,
 }
 
 impl FruError {
     pub fn new(message: String) -> FruError {
-        FruError { message }
+        FruError::new_kind(ErrorKind::Other, message)
     }
 
-    pub fn new_res<T>(message: impl Into<String>) -> Result<T, FruError> {
-        Err(FruError {
+    pub fn new_kind(kind: ErrorKind, message: impl Into<String>) -> FruError {
+        FruError {
+            kind,
             message: message.into(),
-        })
+            notes: Vec::new(),
+            traceback: Vec::new(),
+            left_call: false,
+            syntax: None,
+        }
+    }
+
+    /// Keeps the errors as they are, so they are rendered once, by the one, who reports them
+    pub fn new_syntax(err: ParseError, file: Option<&Path>) -> FruError {
+        let mut error = FruError::new_kind(err.kind(), err.to_string());
+        error.syntax = Some(Box::new((file.map(Path::to_path_buf), err)));
+        error
+    }
+
+    pub fn new_res<T>(message: impl Into<String>) -> Result<T, FruError> {
+        Err(FruError::new(message.into()))
+    }
+
+    pub fn new_kind_res<T>(kind: ErrorKind, message: impl Into<String>) -> Result<T, FruError> {
+        Err(FruError::new_kind(kind, message))
+    }
+
+    pub fn with_note(mut self, note: Option<String>) -> FruError {
+        self.notes.extend(note);
+        self
+    }
+
+    /// Changes the message, keeping everything else
+    pub fn map_message(mut self, f: impl FnOnce(String) -> String) -> FruError {
+        self.message = f(self.message);
+        self
+    }
+
+    pub fn get_kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn get_notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub fn get_traceback(&self) -> &[(Range, Option<Rc<Path>>)] {
+        &self.traceback
+    }
+
+    /// File, that could not be parsed, if it is known, and its errors
+    pub fn get_syntax(&self) -> Option<(Option<&Path>, &ParseError)> {
+        self.syntax.as_deref().map(|(file, err)| (file.as_deref(), err))
+    }
+
+    /// Called by every statement, the error goes through, only the innermost one of each call is saved
+    pub fn add_position(&mut self, position: Range, file: Option<Rc<Path>>) {
+        if self.traceback.is_empty() || self.left_call {
+            self.traceback.push((position, file));
+            self.left_call = false;
+        }
+    }
+
+    /// Called, when error leaves a function or imported file, so the next statement is the one
+    /// with the call or import
+    pub fn leave_call(&mut self) {
+        self.left_call = true;
     }
 }
 
 impl From<ArgumentError> for FruError {
     fn from(err: ArgumentError) -> Self {
-        FruError::new(format!("{:?}", err))
+        let note = match &err {
+            ArgumentError::DoesNotExist { suggestion, .. } => {
+                suggestion.map(|x| format!("did you mean `{}`?", x))
+            }
+            _ => None,
+        };
+
+        FruError::new_kind(ErrorKind::ArgumentMismatch, err.to_string()).with_note(note)
+    }
+}
+
+/// The candidate, closest to `word`, if it is close enough to be a typo
+pub fn did_you_mean<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let threshold = (word.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|x| *x != word)
+        .map(|x| (edit_distance(word, x), x))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min()
+        .map(|(_, x)| x)
+}
+
+/// Levenshtein distance, where swap of two neighbouring characters is one edit, as it is a common typo
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between first `i` characters of `a` and first `j` of `b`
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
     }
+
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance =
+                substitution.min(distances[i - 1][j] + 1).min(distances[i][j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// `file:line:column: heading`, where line and column start from 1, followed by the line of `source`,
+/// where `position` is pointed at with carets, and notes
+pub fn describe_at(
+    file: &Path,
+    source: &str,
+    position: Range,
+    heading: &str,
+    notes: &[String],
+) -> String {
+    let start = position.start_point;
+    let end = position.end_point;
+
+    let line = source.lines().nth(start.row).unwrap_or_default();
+
+    let column = char_column(line, start.column);
+
+    let width = if start.row == end.row {
+        char_column(line, end.column).saturating_sub(column)
+    } else {
+        line.chars().count().saturating_sub(column)
+    };
+
+    let mut description = format!(
+        "{}:{}:{}: {}\n{:>5} | {}\n      | {}{}\n",
+        file.display(),
+        start.row + 1,
+        column + 1,
+        heading,
+        start.row + 1,
+        line,
+        " ".repeat(column),
+        "^".repeat(width.max(1))
+    );
+
+    for note in notes {
+        description += &format!("      = note: {}\n", note);
+    }
+
+    description
+}
+
+/// Column in characters, tree-sitter counts columns of `line` in bytes
+pub fn char_column(line: &str, column: usize) -> usize {
+    line.char_indices().take_while(|(i, _)| *i < column).count() + column.saturating_sub(line.len())
 }
@@ -1,26 +1,44 @@
 use std::{path::PathBuf, rc::Rc};
 
+use tree_sitter::Range;
+
 use crate::interpreter::{
-    control::Control,
-    identifier::{Identifier, OperatorIdentifier},
+    ast_helpers::{FormatStringPart, MatchArm, Pattern},
+    control::{loop_iteration, Control},
+    debug,
+    error::{ErrorKind, FruError},
+    identifier::{id, Identifier, OperatorIdentifier},
     runner,
     scope::Scope,
     statement::FruStatement,
+    value::fru_type::suggestion,
     value::fru_value::FruValue,
-    value::function::{ArgumentList, EvaluatedArgumentList, FormalParameters, FruFunction},
+    value::function::{
+        Argument, ArgumentList, EvaluatedArgumentList, FormalParameters, FruFunction,
+    },
+    value::operator::AnyOperator,
+};
+use crate::stdlib::{
+    list::fru_list::extract_list_from_value,
+    map::fru_map::extract_map_from_value,
+    range::fru_range::FruRange,
+    scope::fru_scope::{extract_scope_from_value, FruScope},
 };
-use crate::stdlib::scope::fru_scope::{extract_scope_from_value, FruScope};
 
 #[derive(Debug, Clone)]
 pub enum FruExpression {
     Literal {
         value: FruValue,
     },
+    FormatString {
+        parts: Vec<FormatStringPart>,
+    },
     Variable {
         ident: Identifier,
     },
     ScopeAccessor,
     Function {
+        ident: Option<Identifier>,
         args: FormalParameters,
         body: Rc<FruStatement>,
     },
@@ -28,6 +46,10 @@ pub enum FruExpression {
         body: Vec<FruStatement>,
         expr: Box<FruExpression>,
     },
+    Positioned {
+        position: Range,
+        value: Box<FruExpression>,
+    },
     ScopeModifier {
         what: Box<FruExpression>,
         body: Vec<FruStatement>,
@@ -48,44 +70,389 @@ pub enum FruExpression {
     PropAccess {
         what: Box<FruExpression>,
         ident: Identifier,
+        // `?.`, gives `nah` instead of failing on `nah`
+        optional: bool,
+    },
+    Index {
+        what: Box<FruExpression>,
+        index: Box<FruExpression>,
+    },
+    // bounds of `a[1..3]`, evaluates to `Range`
+    Slice {
+        start: Option<Box<FruExpression>>,
+        end: Option<Box<FruExpression>>,
     },
     Binary {
         operator: Identifier,
         left: Box<FruExpression>,
         right: Box<FruExpression>,
     },
+    // `&&` and `||`
+    Logical {
+        operator: Identifier,
+        left: Box<FruExpression>,
+        right: Box<FruExpression>,
+    },
+    Set {
+        ident: Identifier,
+        // `Some` for compound assignment, like `+` for `+=`
+        operator: Option<Identifier>,
+        value: Box<FruExpression>,
+    },
+    SetProp {
+        what: Box<FruExpression>,
+        ident: Identifier,
+        operator: Option<Identifier>,
+        value: Box<FruExpression>,
+    },
+    SetIndex {
+        what: Box<FruExpression>,
+        index: Box<FruExpression>,
+        operator: Option<Identifier>,
+        value: Box<FruExpression>,
+    },
+    Coalesce {
+        left: Box<FruExpression>,
+        right: Box<FruExpression>,
+    },
+    Not {
+        value: Box<FruExpression>,
+    },
     If {
+        position: Range,
         condition: Box<FruExpression>,
         then_body: Box<FruExpression>,
         else_body: Box<FruExpression>,
     },
+    Match {
+        value: Box<FruExpression>,
+        arms: Vec<MatchArm>,
+    },
+    Loop {
+        label: Option<Identifier>,
+        body: Box<FruStatement>,
+    },
     Import {
         path: Box<FruExpression>,
     },
 }
 
+/// Operator for types of the operands, or error, that lists declarations of the operator
+fn find_operator(
+    scope: &Scope,
+    operator: Identifier,
+    left: FruValue,
+    right: FruValue,
+) -> Result<(AnyOperator, FruValue, FruValue), FruError> {
+    let ident = OperatorIdentifier::new(
+        operator,
+        left.get_type_identifier(),
+        right.get_type_identifier(),
+    );
+
+    // only overloads, that take an operand of type, declared in the program, are worth showing,
+    // overloads of builtin types, like `Number + Number`, are known anyway
+    let declared: Vec<_> = [&left, &right]
+        .into_iter()
+        .filter(|x| matches!(x, FruValue::Object(_)))
+        .map(FruValue::get_type_identifier)
+        .collect();
+
+    if let Some(found) = lookup_operator(scope, operator, left, right) {
+        return Ok(found);
+    }
+
+    // `Any` accepts every operand, so a side must be declared for the very type of an operand
+    let mut overloads: Vec<_> = scope
+        .operator_overloads(operator)
+        .iter()
+        .filter(|x| declared.contains(&x.get_left()) || declared.contains(&x.get_right()))
+        .map(|x| format!("`{}`", x))
+        .collect();
+    overloads.sort();
+
+    Err(FruError::new_kind(
+        ErrorKind::UndefinedOperator,
+        format!("operator `{}` does not exist", ident),
+    )
+    .with_note((!overloads.is_empty()).then(|| format!("candidates: {}", overloads.join(", ")))))
+}
+
+/// Looks up operator for the operand types, then reflected comparison with swapped operands,
+/// like `b > a` for `a < b`. If there is none, it is looked up for embedded parts
+/// of the left operand, then of the right one, and the part replaces the operand
+fn lookup_operator(
+    scope: &Scope,
+    operator: Identifier,
+    left: FruValue,
+    right: FruValue,
+) -> Option<(AnyOperator, FruValue, FruValue)> {
+    let left_type = left.get_type_identifier();
+    let right_type = right.get_type_identifier();
+
+    if let Some(op) = lookup_typed_operator(scope, operator, left_type, right_type) {
+        return Some((op, left, right));
+    }
+
+    if let Some(reflected) = reflected_operator(operator) {
+        if let Some(op) = lookup_typed_operator(scope, reflected, right_type, left_type) {
+            return Some((op, right, left));
+        }
+    }
+
+    for part in embedded_parts(&left) {
+        if let Some(found) = lookup_operator(scope, operator, part, right.clone()) {
+            return Some(found);
+        }
+    }
+
+    for part in embedded_parts(&right) {
+        if let Some(found) = lookup_operator(scope, operator, left.clone(), part) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Exact declaration is preferred, then the one with `Any` on the right,
+/// then with `Any` on the left, then with `Any` on both sides
+fn lookup_typed_operator(
+    scope: &Scope,
+    operator: Identifier,
+    left_type: Identifier,
+    right_type: Identifier,
+) -> Option<AnyOperator> {
+    [
+        (left_type, right_type),
+        (left_type, id::ANY),
+        (id::ANY, right_type),
+        (id::ANY, id::ANY),
+    ]
+    .into_iter()
+    .find_map(|(left, right)| scope.get_operator(OperatorIdentifier::new(operator, left, right)))
+}
+
+/// Operator, that gives the same result with swapped operands
+fn reflected_operator(operator: Identifier) -> Option<Identifier> {
+    match operator {
+        id::EQ => Some(id::EQ),
+        id::NOT_EQ => Some(id::NOT_EQ),
+        id::LESS => Some(id::GREATER),
+        id::GREATER => Some(id::LESS),
+        id::LESS_EQ => Some(id::GREATER_EQ),
+        id::GREATER_EQ => Some(id::LESS_EQ),
+        _ => None,
+    }
+}
+
+fn apply_operator(
+    scope: &Scope,
+    operator: Identifier,
+    left: FruValue,
+    right: FruValue,
+) -> Result<FruValue, FruError> {
+    let (op, left, right) = find_operator(scope, operator, left, right)?;
+
+    op.operate(left, right)
+}
+
+/// `a += b` calls operator `+=`, if it is declared for the operands, and keeps `a`,
+/// that is changed by the operator. Otherwise it is `a = a + b`
+fn apply_compound_operator(
+    scope: &Scope,
+    operator: Identifier,
+    left: FruValue,
+    right: FruValue,
+) -> Result<FruValue, FruError> {
+    let compound = Identifier::new(&format!("{}=", operator));
+
+    if let Some((op, l, r)) = lookup_operator(scope, compound, left.clone(), right.clone()) {
+        op.operate(l, r)?;
+
+        return Ok(left);
+    }
+
+    apply_operator(scope, operator, left, right)
+}
+
+fn embedded_parts(value: &FruValue) -> Vec<FruValue> {
+    match value {
+        FruValue::Object(obj) => {
+            obj.get_embedded_parts().into_iter().map(FruValue::Object).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Whether `value` fits `pattern`, bindings of the pattern are declared in `arm_scope`.
+/// Type pattern matches objects of this type, any other pattern matches equal values
+fn matches_pattern(
+    pattern: &Pattern,
+    value: &FruValue,
+    scope: Rc<Scope>,
+    arm_scope: &Scope,
+) -> Result<bool, Control> {
+    let (pattern, bindings) = match pattern {
+        Pattern::Wildcard => return Ok(true),
+
+        Pattern::Value { value, bindings } => (value.evaluate(scope)?, bindings),
+    };
+
+    let object = match (&pattern, value) {
+        (FruValue::Type(type_), FruValue::Object(obj)) if obj.get_type() == *type_ => obj,
+
+        (FruValue::Type(_), _) => return Ok(bindings.is_empty() && pattern == *value),
+
+        _ if bindings.is_empty() => return Ok(pattern == *value),
+
+        _ => {
+            return Control::new_kind_err(
+                ErrorKind::UnexpectedType,
+                format!(
+                    "only type patterns can bind fields, got `{}`",
+                    pattern.get_type_identifier()
+                ),
+            )
+        }
+    };
+
+    let type_ = object.get_type();
+
+    for ident in bindings {
+        if type_.get_field_k(*ident).is_none() {
+            let names: Vec<_> = type_.get_fields().iter().map(|x| x.ident.to_string()).collect();
+
+            return Err(Control::Error(
+                FruError::new_kind(
+                    ErrorKind::UndefinedProperty,
+                    format!("`{:?}` has no field `{}`", type_, ident),
+                )
+                .with_note(suggestion(*ident, &names)),
+            ));
+        }
+
+        arm_scope.let_variable(*ident, object.get_prop(*ident)?)?;
+    }
+
+    Ok(true)
+}
+
 fn eval_args(args: &ArgumentList, scope: Rc<Scope>) -> Result<EvaluatedArgumentList, Control> {
-    Ok(EvaluatedArgumentList {
-        args: args
-            .args
-            .iter()
-            .map(|(ident, arg)| -> Result<_, Control> {
-                Ok((*ident, arg.evaluate(scope.clone())?))
-            })
-            .collect::<Result<_, _>>()?,
-    })
+    let mut res = Vec::new();
+
+    for arg in &args.args {
+        match arg {
+            Argument::Positional(value) => res.push((None, value.evaluate(scope.clone())?)),
+
+            Argument::Named(ident, value) => {
+                res.push((Some(*ident), value.evaluate(scope.clone())?))
+            }
+
+            Argument::Spread(value) => {
+                let value = value.evaluate(scope.clone())?;
+
+                let Some(items) = extract_list_from_value(&value) else {
+                    return Control::new_kind_err(
+                        ErrorKind::UnexpectedType,
+                        format!(
+                            "cannot spread `{}` with `..`, expected `List`",
+                            value.get_type_identifier()
+                        ),
+                    );
+                };
+
+                res.extend(items.borrow().iter().map(|x| (None, x.clone())));
+            }
+
+            Argument::KwargsSpread(value) => {
+                let value = value.evaluate(scope.clone())?;
+
+                let Some(items) = extract_map_from_value(&value) else {
+                    return Control::new_kind_err(
+                        ErrorKind::UnexpectedType,
+                        format!(
+                            "cannot spread `{}` with `**`, expected `Map`",
+                            value.get_type_identifier()
+                        ),
+                    );
+                };
+
+                res.extend(
+                    items.borrow().iter().map(|(k, v)| (Some(Identifier::new(k)), v.clone())),
+                );
+            }
+        }
+    }
+
+    Ok(EvaluatedArgumentList { args: res })
 }
 
 impl FruExpression {
+    /// Evaluates prop accesses, indexing and calls, that form a chain. `None` means, that some `?.`
+    /// of the chain met `nah`, so the rest of the chain is skipped
+    fn evaluate_chain(&self, scope: Rc<Scope>) -> Result<Option<FruValue>, Control> {
+        match self {
+            FruExpression::Call { what, args } => {
+                let Some(callee) = what.evaluate_chain(scope.clone())? else {
+                    return Ok(None);
+                };
+
+                let args = eval_args(args, scope)?;
+
+                Ok(Some(callee.call(args)?))
+            }
+
+            FruExpression::PropAccess {
+                what,
+                ident,
+                optional,
+            } => match what.evaluate_chain(scope)? {
+                None => Ok(None),
+                Some(FruValue::Nah) if *optional => Ok(None),
+                Some(what) => Ok(Some(what.get_prop(*ident)?)),
+            },
+
+            FruExpression::Index { what, index } => {
+                let Some(what) = what.evaluate_chain(scope.clone())? else {
+                    return Ok(None);
+                };
+
+                let index = index.evaluate(scope)?;
+
+                Ok(Some(what.get_index(index)?))
+            }
+
+            _ => self.evaluate(scope).map(Some),
+        }
+    }
+
     pub fn evaluate(&self, mut scope: Rc<Scope>) -> Result<FruValue, Control> {
         match self {
             FruExpression::Literal { value } => Ok(value.clone()),
 
+            FruExpression::FormatString { parts } => {
+                let mut result = String::new();
+
+                for part in parts {
+                    match part {
+                        FormatStringPart::Literal(s) => result.push_str(s),
+
+                        FormatStringPart::Interpolation { value, spec } => {
+                            result.push_str(&spec.apply(&value.evaluate(scope.clone())?)?)
+                        }
+                    }
+                }
+
+                Ok(FruValue::String(result))
+            }
+
             FruExpression::Variable { ident } => Ok(scope.get_variable(*ident)?),
 
             FruExpression::ScopeAccessor => Ok(FruScope::new_value(scope)),
 
-            FruExpression::Function { args, body } => Ok(FruFunction {
+            FruExpression::Function { ident, args, body } => Ok(FruFunction {
+                ident: *ident,
                 parameters: args.clone(),
                 body: body.clone(),
                 scope: scope.clone(),
@@ -101,15 +468,32 @@ impl FruExpression {
 
                 expr.evaluate(scope)
             }
+
+            FruExpression::Positioned { position, value } => {
+                debug::statement(*position, &scope)?;
+
+                value.evaluate(scope.clone()).map_err(|signal| match signal {
+                    Control::Error(mut err) => {
+                        err.add_position(*position, scope.get_file());
+                        Control::Error(err)
+                    }
+
+                    other => other,
+                })
+            }
+
             FruExpression::ScopeModifier { what, body, expr } => {
                 let what = what.evaluate(scope)?;
                 let new_scope = match extract_scope_from_value(&what) {
                     Some(x) => x,
                     None => {
-                        return Control::new_err(format!(
-                            "Expected `Scope` in scope modifier expression, got `{}`",
-                            what.get_type_identifier()
-                        ))
+                        return Control::new_kind_err(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "Expected `Scope` in scope modifier expression, got `{}`",
+                                what.get_type_identifier()
+                            ),
+                        )
                     }
                 };
 
@@ -120,12 +504,10 @@ impl FruExpression {
                 expr.evaluate(new_scope)
             }
 
-            FruExpression::Call { what, args } => {
-                let callee = what.evaluate(scope.clone())?;
-
-                let args = eval_args(args, scope)?;
-
-                Ok(callee.call(args)?)
+            FruExpression::Call { .. }
+            | FruExpression::PropAccess { .. }
+            | FruExpression::Index { .. } => {
+                Ok(self.evaluate_chain(scope)?.unwrap_or(FruValue::Nah))
             }
 
             FruExpression::CurryCall { what, args } => {
@@ -144,34 +526,170 @@ impl FruExpression {
                 Ok(instantiated.instantiate(args)?)
             }
 
-            FruExpression::PropAccess { what, ident } => {
-                let what = what.evaluate(scope.clone())?;
+            FruExpression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left_val = left.evaluate(scope.clone())?;
+                let right_val = right.evaluate(scope.clone())?;
 
-                Ok(what.get_prop(*ident)?)
+                Ok(apply_operator(&scope, *operator, left_val, right_val)?)
             }
 
-            FruExpression::Binary {
+            FruExpression::Logical {
                 operator,
                 left,
                 right,
             } => {
                 let left_val = left.evaluate(scope.clone())?;
+
+                // `false && x` and `true || x` are known without `x`
+                if let FruValue::Bool(b) = left_val {
+                    if b == (*operator == id::OR) {
+                        return Ok(FruValue::Bool(b));
+                    }
+                }
+
                 let right_val = right.evaluate(scope.clone())?;
-                let type_left = left_val.get_type_identifier();
-                let type_right = right_val.get_type_identifier();
 
-                let op = scope
-                    .get_operator(OperatorIdentifier::new(*operator, type_left, type_right))?;
+                match (&left_val, &right_val) {
+                    (FruValue::Bool(_), FruValue::Bool(b)) => Ok(FruValue::Bool(*b)),
+
+                    _ => Control::new_kind_err(
+                        ErrorKind::UnexpectedType,
+                        format!(
+                            "Expected `Bool` in `{}`, got `{}` and `{}`",
+                            operator,
+                            left_val.get_type_identifier(),
+                            right_val.get_type_identifier()
+                        ),
+                    ),
+                }
+            }
 
-                Ok(op.operate(left_val, right_val)?)
+            FruExpression::Set {
+                ident,
+                operator,
+                value,
+            } => {
+                let v = match operator {
+                    Some(operator) => {
+                        let old = scope.get_variable(*ident)?;
+                        let right = value.evaluate(scope.clone())?;
+
+                        apply_compound_operator(&scope, *operator, old, right)?
+                    }
+
+                    None => value.evaluate(scope.clone())?,
+                }
+                .fru_clone();
+
+                scope.set_variable(*ident, v.clone())?;
+
+                Ok(v)
+            }
+
+            FruExpression::SetProp {
+                what,
+                ident,
+                operator,
+                value,
+            } => {
+                // evaluated once, even for compound assignment
+                let t = what.evaluate(scope.clone())?;
+
+                let v = match operator {
+                    Some(operator) => {
+                        let old = t.get_prop(*ident)?;
+                        let right = value.evaluate(scope.clone())?;
+
+                        apply_compound_operator(&scope, *operator, old, right)?
+                    }
+
+                    None => value.evaluate(scope.clone())?,
+                }
+                .fru_clone();
+
+                t.set_prop(*ident, v.clone())?;
+
+                Ok(v)
+            }
+
+            FruExpression::SetIndex {
+                what,
+                index,
+                operator,
+                value,
+            } => {
+                let t = what.evaluate(scope.clone())?;
+                let index = index.evaluate(scope.clone())?;
+
+                let v = match operator {
+                    Some(operator) => {
+                        let old = t.get_index(index.clone())?;
+                        let right = value.evaluate(scope.clone())?;
+
+                        apply_compound_operator(&scope, *operator, old, right)?
+                    }
+
+                    None => value.evaluate(scope.clone())?,
+                }
+                .fru_clone();
+
+                t.set_index(index, v.clone())?;
+
+                Ok(v)
+            }
+
+            FruExpression::Slice { start, end } => {
+                let bound = |x: &Option<Box<FruExpression>>| -> Result<Option<f64>, Control> {
+                    match x {
+                        None => Ok(None),
+
+                        Some(x) => match x.evaluate(scope.clone())? {
+                            FruValue::Number(n) => Ok(Some(n)),
+
+                            unexpected => Control::new_kind_err(
+                                ErrorKind::UnexpectedType,
+                                format!(
+                                    "Expected `Number` in slice bound, got `{}`",
+                                    unexpected.get_type_identifier()
+                                ),
+                            ),
+                        },
+                    }
+                };
+
+                Ok(FruRange::new_value(bound(start)?, bound(end)?))
             }
 
+            FruExpression::Coalesce { left, right } => match left.evaluate(scope.clone())? {
+                FruValue::Nah => right.evaluate(scope),
+                value => Ok(value),
+            },
+
+            FruExpression::Not { value } => match value.evaluate(scope)? {
+                FruValue::Bool(b) => Ok(FruValue::Bool(!b)),
+
+                unexpected => Control::new_kind_err(
+                    ErrorKind::UnexpectedType,
+                    format!(
+                        "Expected `Bool` in negation, got `{}`",
+                        unexpected.get_type_identifier()
+                    ),
+                ),
+            },
+
             FruExpression::If {
+                position,
                 condition,
                 then_body,
                 else_body,
             } => match condition.evaluate(scope.clone())? {
                 FruValue::Bool(b) => {
+                    debug::branch(*position, b, &scope);
+
                     if b {
                         then_body.evaluate(scope.clone())
                     } else {
@@ -179,10 +697,57 @@ impl FruExpression {
                     }
                 }
 
-                unexpected => Control::new_err(format!(
-                    "Expected `Bool` in if condition, got `{}`",
-                    unexpected.get_type_identifier()
-                )),
+                unexpected => Control::new_kind_err(
+                    ErrorKind::UnexpectedType,
+                    format!(
+                        "Expected `Bool` in if condition, got `{}`",
+                        unexpected.get_type_identifier()
+                    ),
+                ),
+            },
+
+            FruExpression::Match { value, arms } => {
+                let value = value.evaluate(scope.clone())?;
+
+                for arm in arms {
+                    let arm_scope = Scope::new_with_parent(scope.clone());
+
+                    let mut taken =
+                        matches_pattern(&arm.pattern, &value, scope.clone(), &arm_scope)?;
+
+                    if let (true, Some(guard)) = (taken, &arm.guard) {
+                        taken = match guard.evaluate(arm_scope.clone())? {
+                            FruValue::Bool(b) => b,
+
+                            unexpected => {
+                                return Control::new_kind_err(
+                                    ErrorKind::UnexpectedType,
+                                    format!(
+                                        "Expected `Bool` in match guard, got `{}`",
+                                        unexpected.get_type_identifier()
+                                    ),
+                                )
+                            }
+                        };
+                    }
+
+                    debug::branch(arm.position, taken, &scope);
+
+                    if taken {
+                        return arm.body.evaluate(arm_scope);
+                    }
+                }
+
+                Control::new_kind_err(
+                    ErrorKind::UnmatchedValue,
+                    format!("no arm of match matches `{:?}`", value),
+                )
+            }
+
+            FruExpression::Loop { label, body } => loop {
+                if let Some(value) = loop_iteration(body.execute(scope.clone()), *label)? {
+                    return Ok(value);
+                }
             },
 
             FruExpression::Import { path } => {
@@ -192,16 +757,22 @@ impl FruExpression {
                     FruValue::String(path) => path,
 
                     _ => {
-                        return Control::new_err(format!(
-                            "Expected `String` in import path, got `{}`",
-                            path.get_type_identifier()
-                        ))
+                        return Control::new_kind_err(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "Expected `String` in import path, got `{}`",
+                                path.get_type_identifier()
+                            ),
+                        )
                     }
                 };
 
                 let path = PathBuf::from(path);
 
-                let result_scope = runner::execute_file(&path)?;
+                let result_scope = runner::execute_file(&path).map_err(|mut err| {
+                    err.leave_call();
+                    err
+                })?;
 
                 Ok(FruScope::new_value(result_scope))
             }
@@ -0,0 +1,201 @@
+use crate::interpreter::{error::FruError, value::fru_value::FruValue};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Parsed `[[fill]align][width][.precision]` part of a format placeholder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Option<Alignment>,
+    pub width: Option<usize>,
+    pub precision: Option<usize>,
+}
+
+/// Piece of a template like `"x = {x:>5}, {{literal}}"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplatePiece<'a> {
+    Literal(&'a str),
+    EscapedBrace(char),
+    Field(&'a str),
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            width: None,
+            precision: None,
+        }
+    }
+}
+
+impl Alignment {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Alignment::Left),
+            '^' => Some(Alignment::Center),
+            '>' => Some(Alignment::Right),
+            _ => None,
+        }
+    }
+}
+
+impl FormatSpec {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut res = FormatSpec::default();
+
+        let chars: Vec<char> = spec.chars().collect();
+        let mut i = 0;
+
+        if chars.len() >= 2 {
+            if let Some(align) = Alignment::from_char(chars[1]) {
+                res.fill = chars[0];
+                res.align = Some(align);
+                i = 2;
+            }
+        }
+
+        if res.align.is_none() && !chars.is_empty() {
+            if let Some(align) = Alignment::from_char(chars[0]) {
+                res.align = Some(align);
+                i = 1;
+            }
+        }
+
+        let width: String = chars[i..].iter().take_while(|c| c.is_ascii_digit()).collect();
+        i += width.len();
+
+        if !width.is_empty() {
+            res.width = Some(width.parse().map_err(|_| format!("invalid width `{}`", width))?);
+        }
+
+        if i < chars.len() && chars[i] == '.' {
+            i += 1;
+
+            let precision: String = chars[i..].iter().take_while(|c| c.is_ascii_digit()).collect();
+            i += precision.len();
+
+            if precision.is_empty() {
+                return Err(format!("missing precision in format spec `{}`", spec));
+            }
+
+            res.precision =
+                Some(precision.parse().map_err(|_| format!("invalid precision `{}`", precision))?);
+        }
+
+        if i != chars.len() {
+            return Err(format!("invalid format spec `{}`", spec));
+        }
+
+        Ok(res)
+    }
+
+    pub fn apply(&self, value: &FruValue) -> Result<String, FruError> {
+        let text = match (value, self.precision) {
+            (FruValue::Number(n), Some(precision)) => format!("{:.*}", precision, n),
+            (_, Some(precision)) => value.to_display_string()?.chars().take(precision).collect(),
+            (_, None) => value.to_display_string()?,
+        };
+
+        let len = text.chars().count();
+
+        let width = match self.width {
+            Some(width) if width > len => width,
+            _ => return Ok(text),
+        };
+
+        let align = self.align.unwrap_or(match value {
+            FruValue::Number(_) => Alignment::Right,
+            _ => Alignment::Left,
+        });
+
+        let padding = width - len;
+        let (before, after) = match align {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        };
+
+        let fill = |n| self.fill.to_string().repeat(n);
+
+        Ok(fill(before) + &text + &fill(after))
+    }
+}
+
+/// Splits template into literal text and `{...}` fields, braces inside fields may be nested
+pub fn split_template(template: &str) -> Result<Vec<TemplatePiece<'_>>, String> {
+    let mut res = Vec::new();
+
+    let bytes = template.as_bytes();
+    let mut literal_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' | b'}' if bytes.get(i + 1) == Some(&bytes[i]) => {
+                res.push(TemplatePiece::Literal(&template[literal_start..i]));
+                res.push(TemplatePiece::EscapedBrace(bytes[i] as char));
+                i += 2;
+                literal_start = i;
+            }
+
+            b'{' => {
+                res.push(TemplatePiece::Literal(&template[literal_start..i]));
+
+                let field_start = i + 1;
+                let mut depth = 1;
+
+                while depth > 0 {
+                    i += 1;
+                    match bytes.get(i) {
+                        Some(b'{') => depth += 1,
+                        Some(b'}') => depth -= 1,
+                        Some(_) => {}
+                        None => return Err("unclosed `{` in format string".to_string()),
+                    }
+                }
+
+                res.push(TemplatePiece::Field(&template[field_start..i]));
+                i += 1;
+                literal_start = i;
+            }
+
+            b'}' => return Err("unmatched `}` in format string".to_string()),
+
+            _ => i += 1,
+        }
+    }
+
+    res.push(TemplatePiece::Literal(&template[literal_start..]));
+
+    res.retain(|piece| *piece != TemplatePiece::Literal(""));
+
+    Ok(res)
+}
+
+/// Splits `value:spec` field into value and spec,
+/// `:` is considered a spec separator only if it is outside of any brackets and what follows is a valid spec
+pub fn split_field(field: &str) -> (&str, FormatSpec) {
+    let mut depth = 0;
+
+    for (i, c) in field.char_indices().rev() {
+        match c {
+            ')' | '}' | ']' => depth += 1,
+            '(' | '{' | '[' => depth -= 1,
+            ':' if depth == 0 => {
+                if let Ok(spec) = FormatSpec::parse(&field[i + 1..]) {
+                    return (&field[..i], spec);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (field, FormatSpec::default())
+}
@@ -47,6 +47,18 @@ impl OperatorIdentifier {
     pub fn new(op: Identifier, left: Identifier, right: Identifier) -> Self {
         Self { op, left, right }
     }
+
+    pub fn get_op(&self) -> Identifier {
+        self.op
+    }
+
+    pub fn get_left(&self) -> Identifier {
+        self.left
+    }
+
+    pub fn get_right(&self) -> Identifier {
+        self.right
+    }
 }
 
 impl Debug for Identifier {
@@ -67,7 +79,14 @@ impl Display for Identifier {
 
 impl Debug for OperatorIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Operator({} {} {})", self.left, self.op, self.right)
+        write!(f, "Operator({})", self)
+    }
+}
+
+// operator as it is written, like `Vector + Number`
+impl Display for OperatorIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.left, self.op, self.right)
     }
 }
 
@@ -84,6 +103,23 @@ pub mod id {
     pub const FUNCTION: Identifier = static_ident!("Function");
     pub const TYPE: Identifier = static_ident!("Type");
     pub const NATIVE_OBJECT: Identifier = static_ident!("NativeObject");
+    // operand type of operators, that accept anything
+    pub const ANY: Identifier = static_ident!("Any");
+
+    // special methods
+    pub const SHOW: Identifier = static_ident!("show");
+    pub const VALIDATE: Identifier = static_ident!("validate");
+    pub const COPY_WITH: Identifier = static_ident!("copy_with");
+    pub const VARIANT: Identifier = static_ident!("variant");
+    pub const GET_INDEX: Identifier = static_ident!("get_index");
+    pub const SET_INDEX: Identifier = static_ident!("set_index");
+
+    // function props
+    pub const NAME: Identifier = static_ident!("name");
+    pub const ARITY: Identifier = static_ident!("arity");
+    pub const PARAMS: Identifier = static_ident!("params");
+    pub const IS_BUILTIN: Identifier = static_ident!("is_builtin");
+    pub const SAVED_ARGS: Identifier = static_ident!("saved_args");
 
     // arithmetic
     pub const PLUS: Identifier = static_ident!("+");
@@ -103,4 +139,7 @@ pub mod id {
     pub const GREATER_EQ: Identifier = static_ident!(">=");
     pub const EQ: Identifier = static_ident!("==");
     pub const NOT_EQ: Identifier = static_ident!("!=");
+
+    // short-circuiting
+    pub const COALESCE: Identifier = static_ident!("??");
 }
@@ -1,8 +1,10 @@
-mod ast_helpers;
+pub mod ast_helpers;
 pub mod builtins;
 pub mod control;
+pub mod debug;
 pub mod error;
 pub mod expression;
+pub mod format;
 pub mod helpers;
 pub mod identifier;
 pub mod runner;
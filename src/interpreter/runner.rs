@@ -1,30 +1,97 @@
 use std::{fs::read_to_string, path::Path, rc::Rc};
 
-use crate::interpreter::{control::Control, error::FruError, scope::Scope, tree_sitter_parser};
+use crate::interpreter::{
+    control::Control,
+    error::{ErrorKind, FruError},
+    scope::Scope,
+    statement::FruStatement,
+    tree_sitter_parser,
+};
 
 pub fn execute_file(path: &Path) -> Result<Rc<Scope>, FruError> {
-    let source_code = read_to_string(path)
-        .map_err(|err| FruError::new(format!("Error reading file {path:?} {err}")))?;
+    let source_code = read_to_string(path).map_err(|err| {
+        FruError::new_kind(ErrorKind::Io, format!("Error reading file {path:?} {err}"))
+    })?;
 
-    execute_source_code(source_code)
+    execute_source_code(source_code, Some(path))
 }
 
-pub fn execute_source_code(source_code: String) -> Result<Rc<Scope>, FruError> {
-    let ast = match tree_sitter_parser::parse(source_code) {
-        Ok(ast) => ast,
-        Err(err) => return Err(FruError::new(err.to_string())),
-    };
+/// `path` is the file, the code was read from, if there is one
+pub fn execute_source_code(
+    source_code: String,
+    path: Option<&Path>,
+) -> Result<Rc<Scope>, FruError> {
+    let ast = parse(source_code, path)?;
+
+    execute_ast(&ast, path)
+}
+
+/// Syntax errors are kept with the file, so they are reported against its source
+fn parse(source_code: String, path: Option<&Path>) -> Result<Box<FruStatement>, FruError> {
+    tree_sitter_parser::parse(source_code).map_err(|err| FruError::new_syntax(err, path))
+}
+
+fn execute_ast(ast: &FruStatement, path: Option<&Path>) -> Result<Rc<Scope>, FruError> {
+    let global_scope = Scope::new_global(path);
+
+    finished(ast.execute(global_scope.clone()))?;
+
+    Ok(global_scope)
+}
+
+pub struct TestResult {
+    pub name: String,
+    pub result: Result<(), FruError>,
+}
+
+/// Runs tests, which names contain `filter`.
+/// Every test gets its own global scope, where the rest of the program is executed first
+pub fn execute_tests(
+    path: &Path,
+    source_code: String,
+    filter: &str,
+) -> Result<Vec<TestResult>, FruError> {
+    let ast = parse(source_code, Some(path))?;
 
-    let global_scope = Scope::new_global();
+    let FruStatement::SourceCode { body } = &*ast else {
+        unreachable!("parser always returns source code")
+    };
 
-    let signal = ast.execute(global_scope.clone());
+    let tests = body.iter().filter_map(|statement| match statement {
+        FruStatement::Positioned { statement, .. } => match &**statement {
+            FruStatement::Test { name, body } => Some((name, body)),
+            _ => None,
+        },
+        _ => None,
+    });
 
-    if let Err(signal) = signal {
-        Err(match signal {
-            Control::Error(err) => err,
-            unexpected => FruError::new(format!("Unexpected signal: {:?}", unexpected)),
+    Ok(tests
+        .filter(|(name, _)| name.contains(filter))
+        .map(|(name, body)| TestResult {
+            name: name.clone(),
+            result: execute_test(&ast, body, path),
         })
-    } else {
-        Ok(global_scope)
+        .collect())
+}
+
+fn execute_test(ast: &FruStatement, test: &FruStatement, path: &Path) -> Result<(), FruError> {
+    let global_scope = Scope::new_global(Some(path));
+
+    finished(ast.execute(global_scope.clone()))?;
+
+    match test.execute(Scope::new_with_parent(global_scope)) {
+        Err(Control::Return(_)) => Ok(()),
+        other => finished(other),
+    }
+}
+
+fn finished(signal: Result<(), Control>) -> Result<(), FruError> {
+    match signal {
+        Ok(()) => Ok(()),
+        Err(Control::Error(err)) => Err(err),
+        Err(unexpected) => Err(FruError::new(format!(
+            "Unexpected signal: {:?}",
+            unexpected
+        ))),
     }
 }
@@ -1,13 +1,14 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, path::Path, rc::Rc};
 
 use crate::interpreter::{
     builtins::functions,
     builtins::operators,
-    error::FruError,
+    error::{did_you_mean, ErrorKind, FruError},
     identifier::{Identifier, OperatorIdentifier},
     value::fru_object::FruObject,
     value::fru_type::FruType,
     value::fru_value::FruValue,
+    value::function::AnyFunction,
     value::operator::AnyOperator,
 };
 
@@ -15,6 +16,8 @@ pub struct Scope {
     variables: RefCell<HashMap<Identifier, FruValue>>,
     operators: RefCell<HashMap<OperatorIdentifier, AnyOperator>>,
     parent: ScopeAncestor,
+    // only global scope of file has it
+    file: Option<Rc<Path>>,
 }
 
 enum ScopeAncestor {
@@ -31,11 +34,13 @@ enum ScopeAncestor {
 }
 
 impl Scope {
-    pub fn new_global() -> Rc<Scope> {
+    /// `file` is the one, the executed code was read from, if there is one
+    pub fn new_global(file: Option<&Path>) -> Rc<Scope> {
         Rc::new(Scope {
             variables: RefCell::new(functions::builtin_functions()),
             operators: RefCell::new(operators::builtin_operators()),
             parent: ScopeAncestor::None,
+            file: file.map(Rc::from),
         })
     }
 
@@ -44,6 +49,7 @@ impl Scope {
             variables: RefCell::new(HashMap::new()),
             operators: RefCell::new(HashMap::new()),
             parent: ScopeAncestor::Parent(parent),
+            file: None,
         })
     }
 
@@ -54,6 +60,7 @@ impl Scope {
             variables: RefCell::new(HashMap::new()),
             operators: RefCell::new(HashMap::new()),
             parent: ScopeAncestor::Object { object, parent },
+            file: None,
         })
     }
 
@@ -64,20 +71,65 @@ impl Scope {
             variables: RefCell::new(HashMap::new()),
             operators: RefCell::new(HashMap::new()),
             parent: ScopeAncestor::Type { type_, parent },
+            file: None,
         })
     }
 
     pub fn get_variable(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        self.find_variable(ident).ok_or_else(|| self.undefined_variable(ident))
+    }
+
+    fn find_variable(&self, ident: Identifier) -> Option<FruValue> {
         if let Some(var) = self.variables.borrow().get(&ident) {
-            Ok(var.clone())
+            Some(var.clone())
         } else {
-            self.parent.get_variable(ident)
+            self.parent.find_variable(ident)
+        }
+    }
+
+    fn undefined_variable(&self, ident: Identifier) -> FruError {
+        let names = self.visible_names();
+        let name = ident.to_string();
+
+        FruError::new_kind(
+            ErrorKind::UndefinedVariable,
+            format!("variable `{}` does not exist", ident),
+        )
+        .with_note(
+            did_you_mean(&name, names.iter().map(String::as_str))
+                .map(|x| format!("did you mean `{}`?", x)),
+        )
+    }
+
+    /// Names of variables, fields and statics, that can be reached from this scope
+    fn visible_names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self.variables.borrow().keys().map(ToString::to_string).collect();
+
+        match &self.parent {
+            ScopeAncestor::None => {}
+
+            ScopeAncestor::Parent(parent) => names.extend(parent.visible_names()),
+
+            ScopeAncestor::Object { object, parent } => {
+                names.extend(object.get_type().get_prop_names());
+                names.extend(parent.visible_names());
+            }
+
+            ScopeAncestor::Type { type_, parent } => {
+                names.extend(type_.get_static_prop_names());
+                names.extend(parent.visible_names());
+            }
         }
+
+        names
     }
 
     pub fn let_variable(&self, ident: Identifier, value: FruValue) -> Result<(), FruError> {
         if self.variables.borrow().contains_key(&ident) {
-            return FruError::new_res(format!("variable `{:?}` already exists", ident));
+            return FruError::new_kind_res(
+                ErrorKind::AlreadyDeclared,
+                format!("variable `{:?}` already exists", ident),
+            );
         }
 
         self.variables.borrow_mut().insert(ident, value);
@@ -87,26 +139,47 @@ impl Scope {
     pub fn set_variable(&self, ident: Identifier, value: FruValue) -> Result<(), FruError> {
         if let Some(v) = self.variables.borrow_mut().get_mut(&ident) {
             *v = value;
-            Ok(())
-        } else {
-            self.parent.set_variable(ident, value)
+            return Ok(());
         }
+
+        self.parent.set_variable(ident, value).map_err(|err| match err.get_kind() {
+            ErrorKind::UndefinedVariable => self.undefined_variable(ident),
+            _ => err,
+        })
     }
 
-    pub fn get_operator(&self, ident: OperatorIdentifier) -> Result<AnyOperator, FruError> {
+    pub fn get_operator(&self, ident: OperatorIdentifier) -> Option<AnyOperator> {
         if let Some(op) = self.operators.borrow().get(&ident) {
-            Ok(op.clone())
-        } else {
-            match &self.parent {
-                ScopeAncestor::None => Err(FruError::new(format!(
-                    "operator `{:?}` does not exist",
-                    ident
-                ))),
-                ScopeAncestor::Parent(parent)
-                | ScopeAncestor::Object { parent, .. }
-                | ScopeAncestor::Type { parent, .. } => parent.get_operator(ident),
+            return Some(op.clone());
+        }
+
+        match &self.parent {
+            ScopeAncestor::None => None,
+            ScopeAncestor::Parent(parent)
+            | ScopeAncestor::Object { parent, .. }
+            | ScopeAncestor::Type { parent, .. } => parent.get_operator(ident),
+        }
+    }
+
+    /// Declarations of operator `op`, that can be reached from this scope
+    pub fn operator_overloads(&self, op: Identifier) -> Vec<OperatorIdentifier> {
+        let mut overloads: Vec<_> =
+            self.operators.borrow().keys().filter(|x| x.get_op() == op).copied().collect();
+
+        match &self.parent {
+            ScopeAncestor::None => {}
+            ScopeAncestor::Parent(parent)
+            | ScopeAncestor::Object { parent, .. }
+            | ScopeAncestor::Type { parent, .. } => {
+                for ident in parent.operator_overloads(op) {
+                    if !overloads.contains(&ident) {
+                        overloads.push(ident);
+                    }
+                }
             }
         }
+
+        overloads
     }
 
     pub fn set_operator(&self, ident: OperatorIdentifier, op: AnyOperator) {
@@ -120,29 +193,78 @@ impl Scope {
     pub fn let_set_variable(&self, ident: Identifier, value: FruValue) {
         self.variables.borrow_mut().insert(ident, value);
     }
+
+    /// Variables declared directly in this scope, sorted by name, builtin functions,
+    /// that were not redeclared, are omitted
+    pub fn get_variables(&self) -> Vec<(Identifier, FruValue)> {
+        let mut variables: Vec<_> = self
+            .variables
+            .borrow()
+            .iter()
+            .filter(|(ident, value)| match value {
+                FruValue::Function(function @ AnyFunction::BuiltinFunction(_)) => {
+                    function.get_ident() != Some(**ident)
+                }
+                _ => true,
+            })
+            .map(|(ident, value)| (*ident, value.clone()))
+            .collect();
+
+        variables.sort_by_cached_key(|(ident, _)| ident.to_string());
+
+        variables
+    }
+
+    /// File of global scope, this one is nested in, so functions and types report the file,
+    /// where they are declared
+    pub fn get_file(&self) -> Option<Rc<Path>> {
+        match &self.parent {
+            ScopeAncestor::None => self.file.clone(),
+
+            ScopeAncestor::Parent(parent)
+            | ScopeAncestor::Object { parent, .. }
+            | ScopeAncestor::Type { parent, .. } => parent.get_file(),
+        }
+    }
+
+    /// Scope, this one is nested in, with object or type, which props are visible from this one
+    pub fn get_parent(&self) -> Option<(Rc<Scope>, Option<FruValue>)> {
+        match &self.parent {
+            ScopeAncestor::None => None,
+
+            ScopeAncestor::Parent(parent) => Some((parent.clone(), None)),
+
+            ScopeAncestor::Object { object, parent } => {
+                Some((parent.clone(), Some(FruValue::Object(object.clone()))))
+            }
+
+            ScopeAncestor::Type { type_, parent } => {
+                Some((parent.clone(), Some(FruValue::Type(type_.clone()))))
+            }
+        }
+    }
 }
 
 impl ScopeAncestor {
-    fn get_variable(&self, ident: Identifier) -> Result<FruValue, FruError> {
+    fn find_variable(&self, ident: Identifier) -> Option<FruValue> {
         match self {
-            ScopeAncestor::None => {
-                FruError::new_res(format!("variable `{:?}` does not exist", ident))
-            }
-            ScopeAncestor::Parent(parent) => parent.get_variable(ident),
+            ScopeAncestor::None => None,
+            ScopeAncestor::Parent(parent) => parent.find_variable(ident),
             ScopeAncestor::Object { object, parent } => {
-                object.get_prop(ident).or_else(|_| parent.get_variable(ident))
+                object.find_prop(ident).ok().flatten().or_else(|| parent.find_variable(ident))
             }
             ScopeAncestor::Type { type_, parent } => {
-                type_.get_prop(ident).or_else(|_| parent.get_variable(ident))
+                type_.find_prop(ident).ok().flatten().or_else(|| parent.find_variable(ident))
             }
         }
     }
 
     fn set_variable(&self, ident: Identifier, value: FruValue) -> Result<(), FruError> {
         match self {
-            ScopeAncestor::None => {
-                FruError::new_res(format!("variable `{:?}` does not exist", ident))
-            }
+            ScopeAncestor::None => FruError::new_kind_res(
+                ErrorKind::UndefinedVariable,
+                format!("variable `{}` does not exist", ident),
+            ),
 
             ScopeAncestor::Parent(parent) => parent.set_variable(ident, value),
 
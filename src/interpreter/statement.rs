@@ -1,12 +1,16 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use tree_sitter::Range;
+
 use crate::interpreter::{
-    ast_helpers::{RawMethod, RawStaticField},
-    control::Control,
+    ast_helpers::{RawMethod, RawStaticField, RawVariant},
+    control::{loop_iteration, Control},
+    debug,
+    error::{ErrorKind, FruError},
     expression::FruExpression,
-    identifier::{Identifier, OperatorIdentifier},
+    identifier::{id, Identifier, OperatorIdentifier},
     scope::Scope,
-    value::fru_type::{FruField, FruType, Property, TypeType},
+    value::fru_type::{FruField, FruType, Property, TypeMembers, TypeType},
     value::fru_value::FruValue,
     value::function::FruFunction,
     value::operator::AnyOperator,
@@ -21,6 +25,11 @@ pub enum FruStatement {
     Block {
         body: Vec<FruStatement>,
     },
+    // every parsed statement is wrapped with its position in source code
+    Positioned {
+        position: Range,
+        statement: Box<FruStatement>,
+    },
     ScopeModifier {
         what: Box<FruExpression>,
         body: Vec<FruStatement>,
@@ -32,29 +41,29 @@ pub enum FruStatement {
         ident: Identifier,
         value: Box<FruExpression>,
     },
-    Set {
-        ident: Identifier,
-        value: Box<FruExpression>,
-    },
-    SetProp {
-        what: Box<FruExpression>,
-        ident: Identifier,
-        value: Box<FruExpression>,
-    },
     If {
+        // position of the whole statement, to report taken branch
+        position: Range,
         condition: Box<FruExpression>,
         then_body: Box<FruStatement>,
         else_body: Option<Box<FruStatement>>,
     },
     While {
+        position: Range,
+        label: Option<Identifier>,
         condition: Box<FruExpression>,
         body: Box<FruStatement>,
     },
     Return {
         value: Option<Box<FruExpression>>,
     },
-    Break,
-    Continue,
+    Break {
+        label: Option<Identifier>,
+        value: Option<Box<FruExpression>>, // only allowed for `loop`
+    },
+    Continue {
+        label: Option<Identifier>,
+    },
     Operator {
         ident: Identifier,
         commutative: bool,
@@ -64,6 +73,16 @@ pub enum FruStatement {
         right_type_ident: Identifier,
         body: Rc<FruStatement>,
     },
+    Test {
+        name: String,
+        body: Rc<FruStatement>,
+    },
+    // body of function, method, operator or property accessor
+    Callable {
+        name: String,
+        position: Range,
+        body: Box<FruStatement>,
+    },
     Type {
         type_type: TypeType,
         ident: Identifier,
@@ -73,6 +92,11 @@ pub enum FruStatement {
         static_properties: HashMap<Identifier, Property>,
         methods: Vec<RawMethod>,
     },
+    Enum {
+        ident: Identifier,
+        variants: Vec<RawVariant>,
+        methods: Vec<RawMethod>,
+    },
 }
 
 impl FruStatement {
@@ -92,15 +116,43 @@ impl FruStatement {
                 }
             }
 
+            FruStatement::Positioned {
+                position,
+                statement,
+            } => {
+                debug::statement(*position, &scope)?;
+
+                statement.execute(scope.clone()).map_err(|signal| match signal {
+                    Control::Error(mut err) => {
+                        err.add_position(*position, scope.get_file());
+                        Control::Error(err)
+                    }
+
+                    other => other,
+                })?
+            }
+
+            // tests are only run by test runner
+            FruStatement::Test { .. } => {}
+
+            FruStatement::Callable {
+                name,
+                position,
+                body,
+            } => debug::call(name, *position, &scope, || body.execute(scope.clone()))?,
+
             FruStatement::ScopeModifier { what, body } => {
                 let what = what.evaluate(scope)?;
                 let new_scope = match extract_scope_from_value(&what) {
                     Some(x) => x,
                     None => {
-                        return Control::new_err(format!(
-                            "Expected `Scope` in scope modifier statement, got `{}`",
-                            what.get_type_identifier()
-                        ))
+                        return Control::new_kind_err(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "Expected `Scope` in scope modifier statement, got `{}`",
+                                what.get_type_identifier()
+                            ),
+                        )
                     }
                 };
 
@@ -119,25 +171,18 @@ impl FruStatement {
                 scope.let_variable(*ident, v.fru_clone())?;
             }
 
-            FruStatement::Set { ident, value } => {
-                let v = value.evaluate(scope.clone())?;
-
-                scope.set_variable(*ident, v.fru_clone())?;
-            }
-
-            FruStatement::SetProp { what, ident, value } => {
-                let t = what.evaluate(scope.clone())?;
-                let v = value.evaluate(scope.clone())?;
-                t.set_prop(*ident, v.fru_clone())?;
-            }
-
             FruStatement::If {
+                position,
                 condition,
                 then_body,
                 else_body,
             } => {
                 let result = condition.evaluate(scope.clone())?;
 
+                if let FruValue::Bool(taken) = result {
+                    debug::branch(*position, taken, &scope);
+                }
+
                 match result {
                     FruValue::Bool(true) => then_body.execute(scope.clone())?,
 
@@ -148,33 +193,42 @@ impl FruStatement {
                     }
 
                     _ => {
-                        return Control::new_err(format!(
-                            "Expected `Bool` in if condition, got `{}`",
-                            result.get_type_identifier()
-                        ));
+                        return Control::new_kind_err(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "Expected `Bool` in if condition, got `{}`",
+                                result.get_type_identifier()
+                            ),
+                        );
                     }
                 }
             }
 
-            FruStatement::While { condition, body } => {
+            FruStatement::While {
+                position,
+                label,
+                condition,
+                body,
+            } => {
                 while {
                     match condition.evaluate(scope.clone())? {
-                        FruValue::Bool(b) => b,
+                        FruValue::Bool(b) => {
+                            debug::branch(*position, b, &scope);
+                            b
+                        }
                         other => {
-                            return Control::new_err(format!(
-                                "Expected `Bool` in while condition, got `{}`",
-                                other.get_type_identifier()
-                            ));
+                            return Control::new_kind_err(
+                                ErrorKind::UnexpectedType,
+                                format!(
+                                    "Expected `Bool` in while condition, got `{}`",
+                                    other.get_type_identifier()
+                                ),
+                            );
                         }
                     }
                 } {
-                    if let Err(signal) = body.execute(scope.clone()) {
-                        match signal {
-                            Control::Continue => continue,
-                            Control::Break => break,
-                            Control::Return(v) => return Err(Control::Return(v)),
-                            Control::Error(err) => return Err(Control::Error(err)),
-                        }
+                    if loop_iteration(body.execute(scope.clone()), *label)?.is_some() {
+                        break;
                     }
                 }
             }
@@ -186,8 +240,17 @@ impl FruStatement {
                 }));
             }
 
-            FruStatement::Break => return Err(Control::Break),
-            FruStatement::Continue => return Err(Control::Continue),
+            FruStatement::Break { label, value } => {
+                return Err(Control::Break {
+                    label: *label,
+                    value: match value {
+                        Some(x) => x.evaluate(scope)?,
+                        None => FruValue::Nah,
+                    },
+                });
+            }
+
+            FruStatement::Continue { label } => return Err(Control::Continue { label: *label }),
 
             FruStatement::Operator {
                 ident,
@@ -231,20 +294,41 @@ impl FruStatement {
                 static_properties,
                 methods,
             } => {
-                let mut methods_ = HashMap::new();
-                let mut static_methods_ = HashMap::new();
+                let (methods_, static_methods_) = evaluate_methods(methods, &scope);
 
-                for method in methods {
-                    let function = FruFunction {
-                        parameters: method.parameters.clone(),
-                        body: method.body.clone(),
-                        scope: scope.clone(),
+                let mut embedded = Vec::new();
+
+                for field in fields.iter().filter(|x| x.is_embedded) {
+                    let embedded_type = match scope.get_variable(field.ident)? {
+                        FruValue::Type(embedded_type) => embedded_type,
+
+                        other => {
+                            return Control::new_kind_err(
+                                ErrorKind::UnexpectedType,
+                                format!(
+                                    "cannot embed `{}` into `{}`, expected type, got `{}`",
+                                    field.ident,
+                                    ident,
+                                    other.get_type_identifier()
+                                ),
+                            )
+                        }
                     };
-                    if method.is_static {
-                        static_methods_.insert(method.ident, function);
-                    } else {
-                        methods_.insert(method.ident, function);
+
+                    // otherwise fields of `data` could be changed through its embedded part
+                    if *type_type == TypeType::Data
+                        && embedded_type.get_type_type() != TypeType::Data
+                    {
+                        return Control::new_kind_err(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "`data` type `{}` can only embed `data` types, `{}` is not",
+                                ident, field.ident
+                            ),
+                        );
                     }
+
+                    embedded.push(embedded_type);
                 }
 
                 let mut static_fields_evaluated = HashMap::new();
@@ -263,18 +347,88 @@ impl FruStatement {
                     FruType::new_value(
                         *ident,
                         *type_type,
-                        fields.clone(),
-                        RefCell::new(static_fields_evaluated),
-                        properties.clone(),
-                        static_properties.clone(),
+                        TypeMembers {
+                            fields: fields.clone(),
+                            static_fields: RefCell::new(static_fields_evaluated),
+                            properties: properties.clone(),
+                            static_properties: static_properties.clone(),
+                            methods: methods_,
+                            static_methods: static_methods_,
+                            embedded,
+                        },
+                        scope.clone(),
+                    ),
+                )?;
+            }
+
+            FruStatement::Enum {
+                ident,
+                variants,
+                methods,
+            } => {
+                let (methods_, static_methods_) = evaluate_methods(methods, &scope);
+
+                scope.let_variable(
+                    *ident,
+                    FruType::new_enum_value(
+                        *ident,
+                        variants,
                         methods_,
                         static_methods_,
                         scope.clone(),
                     ),
                 )?;
+
+                // variants are compared by value, can be redeclared by user later.
+                // `variant` of value is `Type` for variants with fields, so they are compared too
+                for (left, right) in [(*ident, *ident), (*ident, id::TYPE), (id::TYPE, *ident)] {
+                    for (operator, function) in [
+                        (id::EQ, AnyOperator::BuiltinOperator(variant_eq)),
+                        (id::NOT_EQ, AnyOperator::BuiltinOperator(variant_not_eq)),
+                    ] {
+                        scope
+                            .set_operator(OperatorIdentifier::new(operator, left, right), function);
+                    }
+                }
             }
         }
 
         Ok(())
     }
 }
+
+/// Methods and static methods of type, bound to scope, where the type is declared
+fn evaluate_methods(
+    methods: &[RawMethod],
+    scope: &Rc<Scope>,
+) -> (
+    HashMap<Identifier, FruFunction>,
+    HashMap<Identifier, FruFunction>,
+) {
+    let mut methods_ = HashMap::new();
+    let mut static_methods_ = HashMap::new();
+
+    for method in methods {
+        let function = FruFunction {
+            ident: Some(method.ident),
+            parameters: method.parameters.clone(),
+            body: method.body.clone(),
+            scope: scope.clone(),
+        };
+        if method.is_static {
+            static_methods_.insert(method.ident, function);
+        } else {
+            methods_.insert(method.ident, function);
+        }
+    }
+
+    (methods_, static_methods_)
+}
+
+fn variant_eq(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
+    Ok(FruValue::Bool(left == right))
+}
+
+fn variant_not_eq(left: FruValue, right: FruValue) -> Result<FruValue, FruError> {
+    Ok(FruValue::Bool(left != right))
+}
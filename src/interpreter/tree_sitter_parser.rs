@@ -3,19 +3,21 @@ use std::{boxed::Box, collections::hash_map::Entry, collections::HashMap, rc::Rc
 use macros::static_ident;
 use snailquote::unescape;
 use thiserror::Error;
-use tree_sitter::{Node, Parser, Range};
+use tree_sitter::{Node, Parser, Range, Tree};
 use tree_sitter_frugurt;
 
 use crate::interpreter::{
-    ast_helpers::{RawMethod, RawStaticField},
+    ast_helpers::{FormatStringPart, MatchArm, Pattern, RawMethod, RawStaticField, RawVariant},
+    error::ErrorKind,
     expression::FruExpression,
+    format::{split_field, split_template, TemplatePiece},
     helpers::WrappingExtension,
-    identifier::Identifier,
+    identifier::{id, Identifier},
     statement::FruStatement,
     value::{
         fru_type::{FruField, Property, TypeType},
         fru_value::FruValue,
-        function::{ArgumentList, FormalParameters},
+        function::{Argument, ArgumentList, FormalParameter, FormalParameters},
     },
 };
 
@@ -56,14 +58,10 @@ pub enum ParseError {
         error: Utf8Error,
     },
 
-    // error that occurred in tree-sitter-frugurt library
-    #[error("parsing error at {}:{}-{}:{}",
-    .position.start_point.row + 1,
-    .position.start_point.column,
-    .position.end_point.row + 1,
-    .position.end_point.column)]
-    ParsingError {
-        position: Range,
+    // syntax errors, that tree-sitter-frugurt recovered from, so all of them are reported at once
+    #[error("{}", .errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))]
+    SyntaxErrors {
+        errors: Vec<SyntaxError>,
     },
 
     // invalid ast combination, that can only be caught by this parser
@@ -79,6 +77,54 @@ pub enum ParseError {
     },
 }
 
+/// Error with position, `note` explains how to fix it, when it is known
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{} at {}:{}-{}:{}",
+.message,
+.position.start_point.row + 1,
+.position.start_point.column,
+.position.end_point.row + 1,
+.position.end_point.column)]
+pub struct SyntaxError {
+    pub position: Range,
+    pub message: String,
+    pub note: Option<String>,
+}
+
+impl ParseError {
+    /// Every error separately, the only one for errors other than `SyntaxErrors`
+    pub fn errors(&self) -> Vec<SyntaxError> {
+        let (position, message) = match self {
+            ParseError::SyntaxErrors { errors } => return errors.clone(),
+
+            ParseError::MissingAst { position, name } => (
+                position,
+                format!("node {} is not provided by tree-sitter-frugurt", name),
+            ),
+
+            ParseError::InvalidAst { position, error } | ParseError::Error { position, error } => {
+                (position, error.clone())
+            }
+
+            ParseError::Utf8Error { position, .. } => (position, "utf8 decoding error".to_string()),
+        };
+
+        vec![SyntaxError {
+            position: *position,
+            message,
+            note: None,
+        }]
+    }
+
+    /// `SyntaxError` if the source does not match the grammar, `InvalidSyntax` otherwise
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ParseError::SyntaxErrors { .. } => ErrorKind::SyntaxError,
+            _ => ErrorKind::InvalidSyntax,
+        }
+    }
+}
+
 enum TypeMember {
     NormalField(FruField),
     StaticField(RawStaticField),
@@ -105,6 +151,10 @@ impl<'a> NodeWrapper<'a> {
         self.node.range()
     }
 
+    fn parent(&self) -> Option<Self> {
+        self.node.parent().map(|x| Self::new(x, self.source))
+    }
+
     fn text(&self) -> Result<&'a str, ParseError> {
         self.node.utf8_text(self.source).map_err(|x| ParseError::Utf8Error {
             position: self.node.range(),
@@ -127,7 +177,7 @@ impl<'a> NodeWrapper<'a> {
         }
     }
 
-    fn get_child_text(&self, name: &str) -> Result<&str, ParseError> {
+    fn get_child_text(&self, name: &str) -> Result<&'a str, ParseError> {
         self.get_child(name)?.text()
     }
 
@@ -174,43 +224,269 @@ impl<'a> NodeWrapper<'a> {
     }
 }
 
-pub fn parse(data: String) -> Result<Box<FruStatement>, ParseError> {
-    let source = data.as_bytes();
-
+fn new_parser() -> Parser {
     let mut parser = Parser::new();
 
     parser // TODO: load grammar one time
         .set_language(&tree_sitter_frugurt::language())
         .expect("Error loading Frugurt grammar");
 
-    let tree = parser.parse(source, None).unwrap();
+    parser
+}
+
+/// Concrete syntax tree, used by tooling, that needs positions and tolerates errors
+pub fn parse_tree(data: &str) -> Tree {
+    new_parser().parse(data, None).unwrap()
+}
+
+pub fn parse(data: String) -> Result<Box<FruStatement>, ParseError> {
+    let source = data.as_bytes();
+
+    let tree = new_parser().parse(source, None).unwrap();
 
     let root = tree.root_node();
 
     if root.has_error() {
-        return Err(search_for_errors(root));
+        let mut errors = Vec::new();
+
+        // nothing could be parsed
+        if root.is_error() {
+            errors.push(unexpected_token_error(NodeWrapper::new(root, source)));
+        } else {
+            search_for_errors(NodeWrapper::new(root, source), &mut errors);
+        }
+
+        return Err(ParseError::SyntaxErrors { errors });
     }
 
+    validate_control_flow(NodeWrapper::new(root, source), &mut Vec::new(), false)?;
+
     parse_statement(NodeWrapper::new(root, source)).map(Box::new)
 }
 
-fn search_for_errors(ast: Node) -> ParseError {
-    let mut cur = ast.walk();
+struct LoopContext {
+    label: Option<Identifier>,
+    accepts_value: bool, // `while` always finishes with `nah`, so only `loop` can break with a value
+}
 
-    loop {
-        if cur.node().is_error() || cur.node().is_missing() {
-            return ParseError::ParsingError {
-                position: cur.node().range(),
+/// Finds `break`, `continue` and `return`, that have nowhere to go, and misplaced tests
+fn validate_control_flow(
+    ast: NodeWrapper,
+    loops: &mut Vec<LoopContext>,
+    in_function: bool,
+) -> Result<(), ParseError> {
+    let error = |error: String| {
+        Err(ParseError::Error {
+            position: ast.range(),
+            error,
+        })
+    };
+
+    match ast.grammar_name() {
+        "test_statement"
+            if ast.node.parent().is_some_and(|x| x.grammar_name() != "source_file") =>
+        {
+            return error("tests can only be declared at top level".to_string());
+        }
+
+        "function_statement"
+        | "function_expression"
+        | "type_method"
+        | "operator_statement"
+        | "type_property_item"
+        | "test_statement" => return validate_children(ast, &mut Vec::new(), true),
+
+        "while_statement" | "loop_statement" | "loop_expression" => {
+            loops.push(LoopContext {
+                label: ast.parse_optional_child("label", parse_label)?,
+                accepts_value: ast.grammar_name() != "while_statement",
+            });
+
+            let result = validate_children(ast, loops, in_function);
+
+            loops.pop();
+
+            return result;
+        }
+
+        "break_statement" | "continue_statement" => {
+            let keyword = ast.grammar_name().trim_end_matches("_statement");
+            let label = ast.parse_optional_child("label", parse_label)?;
+
+            let target = match label {
+                Some(label) => loops.iter().rev().find(|x| x.label == Some(label)),
+                None => loops.last(),
             };
+
+            match (target, label) {
+                (None, Some(label)) if !loops.is_empty() => {
+                    return error(format!("label `'{}` is not declared", label));
+                }
+
+                (None, _) => return error(format!("`{}` outside of a loop", keyword)),
+
+                (Some(target), _) if !target.accepts_value && ast.get_child("value").is_ok() => {
+                    return error("`break` with value is only allowed in `loop`".to_string());
+                }
+
+                _ => {}
+            }
         }
 
-        if cur.node().has_error() {
-            cur.goto_first_child();
-            continue;
-        } else {
-            cur.goto_next_sibling();
+        "return_statement" if !in_function => {
+            return error("`return` outside of a function".to_string());
         }
+
+        _ => {}
+    }
+
+    validate_children(ast, loops, in_function)
+}
+
+fn validate_children(
+    ast: NodeWrapper,
+    loops: &mut Vec<LoopContext>,
+    in_function: bool,
+) -> Result<(), ParseError> {
+    for child in ast.node.named_children(&mut ast.node.walk()) {
+        validate_control_flow(NodeWrapper::new(child, ast.source), loops, in_function)?;
     }
+
+    Ok(())
+}
+
+/// Collects every error and missing node, nodes inside of errors are not checked
+fn search_for_errors(ast: NodeWrapper, errors: &mut Vec<SyntaxError>) {
+    let mut cursor = ast.node.walk();
+
+    for child in ast.node.children(&mut cursor) {
+        if child.is_missing() {
+            errors.push(missing_token_error(child, ast.node));
+        } else if child.is_error() {
+            errors.push(unexpected_token_error(NodeWrapper::new(child, ast.source)));
+        } else if child.has_error() {
+            search_for_errors(NodeWrapper::new(child, ast.source), errors);
+        }
+    }
+}
+
+fn missing_token_error(node: Node, parent: Node) -> SyntaxError {
+    let message = match node.kind() {
+        // tree-sitter inserts the shortest expression, which is variable
+        "identifier" if parent.kind() == "variable" => "expected expression".to_string(),
+
+        kind if node.is_named() => format!("expected {}", kind.replace('_', " ")),
+
+        kind => format!("expected `{}`", kind),
+    };
+
+    let opening = match node.kind() {
+        "}" => Some("{"),
+        ")" => Some("("),
+        "]" => Some("["),
+        _ => None,
+    };
+
+    // bracket, that is not closed, is the first one in the parent node
+    let note = opening.and_then(|opening| {
+        let mut cursor = parent.walk();
+        let bracket = parent.children(&mut cursor).find(|x| x.kind() == opening)?;
+
+        Some(format!(
+            "to close `{}` at {}:{}",
+            opening,
+            bracket.start_position().row + 1,
+            bracket.start_position().column + 1
+        ))
+    });
+
+    SyntaxError {
+        position: node.range(),
+        message,
+        note,
+    }
+}
+
+fn unexpected_token_error(ast: NodeWrapper) -> SyntaxError {
+    let node = ast.node;
+    let expected = expected_tokens(node);
+
+    // statement, that is not finished, is followed by the next one on a new line
+    if let Some(previous) = node.prev_sibling() {
+        if expected.contains(&";") && previous.end_position().row < node.start_position().row {
+            let end = previous.range();
+
+            return SyntaxError {
+                position: Range {
+                    start_byte: end.end_byte,
+                    start_point: end.end_point,
+                    ..end
+                },
+                message: "expected `;`".to_string(),
+                note: None,
+            };
+        }
+    }
+
+    let token = node
+        .descendant_for_byte_range(node.start_byte(), node.start_byte())
+        .unwrap_or(node);
+
+    let text = NodeWrapper::new(token, ast.source).text().unwrap_or_default();
+
+    let message = if text.is_empty() {
+        "unexpected end of file".to_string()
+    } else {
+        format!("unexpected `{}`", text)
+    };
+
+    let note = if text == "impl" {
+        Some(
+            "`impl` must follow declaration of a type, like `struct Name { ... } impl { ... }`"
+                .to_string(),
+        )
+    } else if (1..=3).contains(&expected.len()) {
+        let expected: Vec<_> = expected.iter().map(|x| format!("`{}`", x)).collect();
+        Some(format!("expected {}", expected.join(" or ")))
+    } else {
+        None
+    };
+
+    SyntaxError {
+        position: node.range(),
+        message,
+        note,
+    }
+}
+
+/// Tokens, that tree-sitter would accept in place of the error, usually more than really fit there
+fn expected_tokens(node: Node) -> Vec<&'static str> {
+    let state = match node.prev_sibling() {
+        Some(mut previous) => {
+            // state of a node, that was built during error recovery, is unknown,
+            // but the state after its last token is known
+            while previous.next_parse_state() == u16::MAX && previous.child_count() > 0 {
+                previous = previous.child(previous.child_count() - 1).unwrap();
+            }
+
+            previous.next_parse_state()
+        }
+        None => match node.parent() {
+            Some(parent) => parent.parse_state(),
+            None => return Vec::new(),
+        },
+    };
+
+    let language = node.language();
+
+    let Some(lookahead) = language.lookahead_iterator(state) else {
+        return Vec::new();
+    };
+
+    lookahead
+        .filter(|x| language.node_kind_is_visible(*x) && !language.node_kind_is_named(*x))
+        .filter_map(|x| language.node_kind_for_id(x))
+        .collect()
 }
 
 fn parse_statement(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
@@ -232,29 +508,39 @@ fn parse_statement(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
             value: ast.parse_child_expression("value")?.wrap_box(),
         },
 
-        "let_statement" => FruStatement::Let {
-            ident: ast.get_child_ident("ident")?,
-            value: ast.parse_child_expression("value")?.wrap_box(),
-        },
+        "let_statement" => {
+            let ident = ast.get_child_ident("ident")?;
 
-        "set_statement" => FruStatement::Set {
-            ident: ast.get_child_ident("ident")?,
-            value: ast.parse_child_expression("value")?.wrap_box(),
-        },
+            FruStatement::Let {
+                ident,
+                value: name_function(ast.parse_child_expression("value")?, ident).wrap_box(),
+            }
+        }
 
-        "set_prop_statement" => FruStatement::SetProp {
-            what: ast.parse_child_expression("what")?.wrap_box(),
-            ident: ast.get_child_ident("ident")?,
-            value: ast.parse_child_expression("value")?.wrap_box(),
-        },
+        "function_statement" => {
+            let ident = ast.get_child_ident("ident")?;
+
+            FruStatement::Let {
+                ident,
+                value: FruExpression::Function {
+                    ident: Some(ident),
+                    args: ast.parse_child("parameters", parse_formal_parameters)?,
+                    body: parse_callable_body(ast)?.wrap_rc(),
+                }
+                .wrap_box(),
+            }
+        }
 
         "if_statement" => FruStatement::If {
+            position: ast.range(),
             condition: ast.parse_child_expression("condition")?.wrap_box(),
             then_body: ast.parse_child_statement("then_body")?.wrap_box(),
             else_body: ast.parse_optional_child("else_body", parse_statement)?.map(Box::new),
         },
 
         "while_statement" => FruStatement::While {
+            position: ast.range(),
+            label: ast.parse_optional_child("label", parse_label)?,
             condition: ast.parse_child_expression("condition")?.wrap_box(),
             body: ast.parse_child_statement("body")?.wrap_box(),
         },
@@ -263,37 +549,53 @@ fn parse_statement(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
             value: ast.parse_optional_child("value", parse_expression)?.map(Box::new),
         },
 
-        "break_statement" => FruStatement::Break,
+        "loop_statement" => FruStatement::Expression {
+            value: parse_expression(ast)?.wrap_box(),
+        },
 
-        "continue_statement" => FruStatement::Continue,
+        "break_statement" => FruStatement::Break {
+            label: ast.parse_optional_child("label", parse_label)?,
+            value: ast.parse_optional_child("value", parse_expression)?.map(Box::new),
+        },
 
-        "operator_statement" => {
-            let commutative = ast.get_child("commutative").is_ok();
+        "continue_statement" => FruStatement::Continue {
+            label: ast.parse_optional_child("label", parse_label)?,
+        },
 
-            let left_type_ident = ast.get_child_ident("left_type_ident")?;
-            let right_type_ident = ast.get_child_ident("right_type_ident")?;
+        "operator_statement" => {
+            let ident = ast.get_child_ident("ident")?;
 
-            if commutative && left_type_ident == right_type_ident {
+            // they do not always evaluate the right operand, so they are not operators to call
+            if [id::AND, id::OR, id::COALESCE].contains(&ident) {
                 return Err(ParseError::Error {
-                    position: ast.get_child("commutative")?.range(),
-                    error: format!(
-                        "commutative operators must have different types, but `{}` was used twice",
-                        left_type_ident
-                    ),
+                    position: ast.get_child("ident")?.range(),
+                    error: format!("operator `{}` can not be overloaded", ident),
                 });
             }
 
+            // commutative operator with the same types is allowed, but reported by linter
             FruStatement::Operator {
-                ident: ast.get_child_ident("ident")?,
-                commutative,
+                ident,
+                commutative: ast.get_child("commutative").is_ok(),
                 left_ident: ast.get_child_ident("left_ident")?,
-                left_type_ident,
+                left_type_ident: ast.get_child_ident("left_type_ident")?,
                 right_ident: ast.get_child_ident("right_ident")?,
-                right_type_ident,
-                body: ast.parse_child("body", parse_function_body)?.wrap_rc(),
+                right_type_ident: ast.get_child_ident("right_type_ident")?,
+                body: parse_callable_body(ast)?.wrap_rc(),
             }
         }
 
+        "test_statement" => FruStatement::Test {
+            name: match parse_expression(ast.get_child("name")?)? {
+                FruExpression::Literal {
+                    value: FruValue::String(name),
+                } => name,
+
+                _ => unreachable!("string literal is always parsed into a string"),
+            },
+            body: ast.parse_child_statement("body")?.wrap_rc(),
+        },
+
         "type_statement" => {
             let type_type = match ast.get_child_text("type_type")? {
                 "struct" => TypeType::Struct,
@@ -361,6 +663,30 @@ fn parse_statement(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
             }
         }
 
+        "enum_statement" => {
+            let variants = ast.parse_children("variants", |x| {
+                Ok(RawVariant {
+                    ident: x.get_child_ident("ident")?,
+                    fields: x.parse_children("fields", NodeWrapper::ident)?,
+                })
+            })?;
+
+            for (n, variant) in variants.iter().enumerate() {
+                if variants[..n].iter().any(|x| x.ident == variant.ident) {
+                    return Err(ParseError::Error {
+                        position: ast.range(),
+                        error: format!("Duplicate variant: `{}`", variant.ident),
+                    });
+                }
+            }
+
+            FruStatement::Enum {
+                ident: ast.get_child_ident("ident")?,
+                variants,
+                methods: ast.parse_optional_child("impl", parse_impl)?.unwrap_or_else(Vec::new),
+            }
+        }
+
         unexpected => {
             return Err(ParseError::InvalidAst {
                 position: ast.range(),
@@ -369,7 +695,22 @@ fn parse_statement(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
         }
     };
 
-    Ok(result_statement)
+    if let FruStatement::SourceCode { .. } = result_statement {
+        return Ok(result_statement);
+    }
+
+    Ok(FruStatement::Positioned {
+        position: ast.range(),
+        statement: result_statement.wrap_box(),
+    })
+}
+
+// last expression of a block is positioned like statements, so debugger can stop at it
+fn parse_positioned_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
+    Ok(FruExpression::Positioned {
+        position: ast.range(),
+        value: parse_expression(ast)?.wrap_box(),
+    })
 }
 
 fn parse_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
@@ -382,6 +723,8 @@ fn parse_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
             value: FruValue::Number(ast.text()?.parse().unwrap()),
         },
 
+        "format_string_literal" => parse_format_string(ast)?,
+
         "bool_literal" => FruExpression::Literal {
             value: FruValue::Bool(ast.text()?.parse().unwrap()),
         },
@@ -406,21 +749,22 @@ fn parse_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
         "scope_expression" => FruExpression::ScopeAccessor,
 
         "function_expression" => FruExpression::Function {
+            ident: ast.parse_optional_child("ident", NodeWrapper::ident)?,
             args: ast.parse_child("parameters", parse_formal_parameters)?,
-            body: ast.parse_child("body", parse_function_body)?.wrap_rc(),
+            body: parse_callable_body(ast)?.wrap_rc(),
         },
 
         "parenthesized_expression" => ast.parse_child_expression("expr")?,
 
         "block_expression" => FruExpression::Block {
             body: ast.parse_children("body", parse_statement)?,
-            expr: ast.parse_child_expression("expr")?.wrap_box(),
+            expr: ast.parse_child("expr", parse_positioned_expression)?.wrap_box(),
         },
 
         "scope_modifier_expression" => FruExpression::ScopeModifier {
             what: ast.parse_child_expression("what")?.wrap_box(),
             body: ast.parse_children("body", parse_statement)?,
-            expr: ast.parse_child_expression("expr")?.wrap_box(),
+            expr: ast.parse_child("expr", parse_positioned_expression)?.wrap_box(),
         },
 
         "call_expression" => FruExpression::Call {
@@ -441,20 +785,83 @@ fn parse_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
         "prop_access_expression" => FruExpression::PropAccess {
             what: ast.parse_child_expression("what")?.wrap_box(),
             ident: ast.get_child_ident("ident")?,
+            optional: ast.get_child("optional").is_ok(),
+        },
+
+        "index_expression" => FruExpression::Index {
+            what: ast.parse_child_expression("what")?.wrap_box(),
+            index: ast.parse_child_expression("index")?.wrap_box(),
+        },
+
+        "slice" => FruExpression::Slice {
+            start: ast.parse_optional_child("start", parse_expression)?.map(Box::new),
+            end: ast.parse_optional_child("end", parse_expression)?.map(Box::new),
         },
 
-        "binary_expression" => FruExpression::Binary {
-            operator: ast.get_child_ident("operator")?,
-            left: ast.parse_child_expression("left")?.wrap_box(),
-            right: ast.parse_child_expression("right")?.wrap_box(),
+        "unary_expression" => FruExpression::Not {
+            value: ast.parse_child_expression("value")?.wrap_box(),
+        },
+
+        "binary_expression" => {
+            let operator = ast.get_child_ident("operator")?;
+            let left = ast.parse_child_expression("left")?.wrap_box();
+            let right = ast.parse_child_expression("right")?.wrap_box();
+
+            match operator {
+                // `??` is not an overloadable operator, because it does not evaluate right side
+                id::COALESCE => FruExpression::Coalesce { left, right },
+
+                id::AND | id::OR => FruExpression::Logical {
+                    operator,
+                    left,
+                    right,
+                },
+
+                _ => FruExpression::Binary {
+                    operator,
+                    left,
+                    right,
+                },
+            }
+        }
+
+        "set_expression" => FruExpression::Set {
+            ident: ast.get_child_ident("ident")?,
+            operator: parse_set_operator(ast)?,
+            value: ast.parse_child_expression("value")?.wrap_box(),
+        },
+
+        "set_prop_expression" => FruExpression::SetProp {
+            what: ast.parse_child_expression("what")?.wrap_box(),
+            ident: ast.get_child_ident("ident")?,
+            operator: parse_set_operator(ast)?,
+            value: ast.parse_child_expression("value")?.wrap_box(),
+        },
+
+        "set_index_expression" => FruExpression::SetIndex {
+            what: ast.parse_child_expression("what")?.wrap_box(),
+            index: ast.parse_child_expression("index")?.wrap_box(),
+            operator: parse_set_operator(ast)?,
+            value: ast.parse_child_expression("value")?.wrap_box(),
         },
 
         "if_expression" => FruExpression::If {
+            position: ast.range(),
             condition: ast.parse_child_expression("condition")?.wrap_box(),
             then_body: ast.parse_child_expression("then_body")?.wrap_box(),
             else_body: ast.parse_child_expression("else_body")?.wrap_box(),
         },
 
+        "match_expression" => FruExpression::Match {
+            value: ast.parse_child_expression("value")?.wrap_box(),
+            arms: ast.parse_children("arms", parse_match_arm)?,
+        },
+
+        "loop_expression" | "loop_statement" => FruExpression::Loop {
+            label: ast.parse_optional_child("label", parse_label)?,
+            body: ast.parse_child_statement("body")?.wrap_box(),
+        },
+
         "import_expression" => FruExpression::Import {
             path: ast.parse_child_expression("path")?.wrap_box(),
         },
@@ -470,6 +877,104 @@ fn parse_expression(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
     Ok(result_expression)
 }
 
+// `+=` applies `+`, plain `=` applies nothing
+fn parse_set_operator(ast: NodeWrapper) -> Result<Option<Identifier>, ParseError> {
+    let operator = ast.get_child_text("operator")?;
+
+    Ok(operator.strip_suffix('=').filter(|x| !x.is_empty()).map(Identifier::new))
+}
+
+fn parse_format_string(ast: NodeWrapper) -> Result<FruExpression, ParseError> {
+    // text without `f` prefix is a valid string literal
+    let template = match unescape(&ast.text()?[1..].replace("\\\n", "\n")) {
+        Ok(s) => s,
+
+        Err(err) => {
+            return Err(ParseError::InvalidAst {
+                position: ast.range(),
+                error: err.to_string(),
+            });
+        }
+    };
+
+    let pieces = split_template(&template).map_err(|error| ParseError::Error {
+        position: ast.range(),
+        error,
+    })?;
+
+    let mut parts = Vec::new();
+
+    for piece in pieces {
+        let part = match piece {
+            TemplatePiece::Literal(s) => FormatStringPart::Literal(s.to_string()),
+
+            TemplatePiece::EscapedBrace(c) => FormatStringPart::Literal(c.to_string()),
+
+            TemplatePiece::Field(field) => {
+                let (value, spec) = split_field(field);
+
+                FormatStringPart::Interpolation {
+                    value: parse_embedded_expression(value, ast.range())?,
+                    spec,
+                }
+            }
+        };
+
+        match (parts.last_mut(), part) {
+            (Some(FormatStringPart::Literal(prev)), FormatStringPart::Literal(s)) => {
+                prev.push_str(&s)
+            }
+
+            (_, part) => parts.push(part),
+        }
+    }
+
+    Ok(FruExpression::FormatString { parts })
+}
+
+// parses expression, that is not a part of the main tree (for example, interpolated one).
+// Control flow can not leave it, so enclosing loops and functions are not taken into account
+fn parse_embedded_expression(code: &str, position: Range) -> Result<FruExpression, ParseError> {
+    let source = format!("({});", code);
+
+    let tree = new_parser().parse(source.as_bytes(), None).unwrap();
+    let root = tree.root_node();
+
+    let statement = match root.named_child(0) {
+        Some(statement)
+            if !root.has_error()
+                && root.named_child_count() == 1
+                && statement.grammar_name() == "expression_statement" =>
+        {
+            NodeWrapper::new(statement, source.as_bytes())
+        }
+
+        _ => {
+            return Err(ParseError::Error {
+                position,
+                error: format!("Invalid interpolated expression `{}`", code.trim()),
+            });
+        }
+    };
+
+    // positions in the embedded code mean nothing outside of it
+    validate_control_flow(statement, &mut Vec::new(), false).map_err(|err| match err {
+        ParseError::Error { error, .. } => ParseError::Error {
+            position,
+            error: format!("{} in interpolated expression", error),
+        },
+
+        err => err,
+    })?;
+
+    parse_expression(statement.get_child("value")?)
+}
+
+// labels are written with leading `'`, that is not a part of the name
+fn parse_label(ast: NodeWrapper) -> Result<Identifier, ParseError> {
+    Ok(Identifier::new(&ast.text()?[1..]))
+}
+
 fn parse_maybe_typed_ident(
     ast: NodeWrapper,
 ) -> Result<(Identifier, Option<Identifier>), ParseError> {
@@ -481,10 +986,81 @@ fn parse_maybe_typed_ident(
     Ok((ident, type_ident))
 }
 
+/// Body of function, method, operator or property accessor with its name and position
+fn parse_callable_body(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
+    Ok(FruStatement::Callable {
+        name: parse_callable_name(ast)?,
+        position: ast.range(),
+        body: ast.parse_child("body", parse_function_body)?.wrap_box(),
+    })
+}
+
+fn parse_callable_name(ast: NodeWrapper) -> Result<String, ParseError> {
+    let type_ident = || {
+        let mut node = ast;
+
+        while !matches!(node.grammar_name(), "type_statement" | "enum_statement") {
+            node = node.parent().ok_or(ParseError::MissingAst {
+                position: ast.range(),
+                name: "type_statement".to_string(),
+            })?;
+        }
+
+        node.get_child_text("ident")
+    };
+
+    Ok(match ast.grammar_name() {
+        // function expressions are named by `let` the same way as in `name_function`
+        "function_statement" | "function_expression" => ast
+            .get_child_text("ident")
+            .or_else(|_| match ast.parent() {
+                Some(parent) if parent.grammar_name() == "let_statement" => {
+                    parent.get_child_text("ident")
+                }
+                _ => Ok("<anonymous>"),
+            })?
+            .to_string(),
+
+        "type_method" => format!("{}.{}", type_ident()?, ast.get_child_text("ident")?),
+
+        "operator_statement" => format!(
+            "operator {} ({}, {})",
+            ast.get_child_text("ident")?,
+            ast.get_child_text("left_type_ident")?,
+            ast.get_child_text("right_type_ident")?
+        ),
+
+        "type_property_item" => {
+            let property = ast.parent().unwrap().get_child("ident")?;
+
+            format!(
+                "{}.{} {}",
+                type_ident()?,
+                property.get_child_text("ident")?,
+                ast.get_child_text("type")?
+            )
+        }
+
+        unexpected => {
+            return Err(ParseError::InvalidAst {
+                position: ast.range(),
+                error: format!("Not a callable: {}", unexpected),
+            });
+        }
+    })
+}
+
 fn parse_function_body(ast: NodeWrapper) -> Result<FruStatement, ParseError> {
     Ok(match ast.grammar_name() {
         "block_statement" => parse_statement(ast)?,
 
+        // body of getter can be any expression
+        _ if ast.parent().is_some_and(|x| x.grammar_name() == "type_property_item") => {
+            FruStatement::Return {
+                value: Some(parse_expression(ast)?.wrap_box()),
+            }
+        }
+
         "block_expression" => FruStatement::Return {
             value: Some(parse_expression(ast)?.wrap_box()),
         },
@@ -504,6 +1080,19 @@ fn parse_type_member(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
 
         "type_property" => parse_property(ast),
 
+        // embedded part is a field, named after its type
+        "type_embed" => {
+            let ident = ast.get_child_ident("ident")?;
+
+            Ok(TypeMember::NormalField(FruField {
+                is_public: true,
+                ident,
+                type_ident: Some(ident),
+                is_embedded: true,
+                default: None,
+            }))
+        }
+
         unexpected => Err(ParseError::InvalidAst {
             position: ast.range(),
             error: format!("Not a type member: {}", unexpected),
@@ -518,13 +1107,6 @@ fn parse_field(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
 
     let value = ast.parse_optional_child("value", parse_expression)?;
 
-    if !is_static && value.is_some() {
-        return Err(ParseError::Error {
-            position: ast.get_child("value")?.range(),
-            error: "Non-static field cannot have an initial value".to_string(),
-        });
-    }
-
     Ok(if is_static {
         TypeMember::StaticField(RawStaticField {
             ident,
@@ -535,13 +1117,15 @@ fn parse_field(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
             is_public,
             ident,
             type_ident,
+            is_embedded: false,
+            default: value.map(Rc::new),
         })
     })
 }
 
 fn parse_property(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
     enum Item<'a> {
-        Get(Rc<FruExpression>, NodeWrapper<'a>),
+        Get(Rc<FruStatement>, NodeWrapper<'a>),
         Set((Identifier, Rc<FruStatement>), NodeWrapper<'a>),
     }
 
@@ -552,7 +1136,7 @@ fn parse_property(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
 
     let items = ast.parse_children("items", |x| {
         Ok(match x.get_child_text("type")? {
-            "get" => Item::Get(x.parse_child_expression("body")?.wrap_rc(), x),
+            "get" => Item::Get(parse_callable_body(x)?.wrap_rc(), x),
 
             "set" => {
                 let ident = x.parse_optional_child("value_ident", parse_maybe_typed_ident)?;
@@ -560,7 +1144,7 @@ fn parse_property(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
                 Item::Set(
                     (
                         ident.map_or_else(|| static_ident!("value"), |x| x.0),
-                        x.parse_child_statement("body")?.wrap_rc(),
+                        parse_callable_body(x)?.wrap_rc(),
                     ),
                     x,
                 )
@@ -611,6 +1195,26 @@ fn parse_property(ast: NodeWrapper) -> Result<TypeMember, ParseError> {
     })
 }
 
+fn parse_match_arm(ast: NodeWrapper) -> Result<MatchArm, ParseError> {
+    let pattern = ast.get_child("pattern")?;
+
+    let pattern = match pattern.grammar_name() {
+        "wildcard_pattern" => Pattern::Wildcard,
+
+        _ => Pattern::Value {
+            value: pattern.parse_child_expression("value")?,
+            bindings: pattern.parse_children("bindings", NodeWrapper::ident)?,
+        },
+    };
+
+    Ok(MatchArm {
+        position: ast.range(),
+        pattern,
+        guard: ast.parse_optional_child("guard", parse_expression)?,
+        body: ast.parse_child("body", parse_positioned_expression)?,
+    })
+}
+
 fn parse_impl(ast: NodeWrapper) -> Result<Vec<RawMethod>, ParseError> {
     ast.parse_children("methods", parse_method)
 }
@@ -620,44 +1224,112 @@ fn parse_method(ast: NodeWrapper) -> Result<RawMethod, ParseError> {
         is_static: ast.get_child("static").is_ok(),
         ident: ast.get_child_ident("ident")?,
         parameters: ast.parse_child("parameters", parse_formal_parameters)?,
-        body: ast.parse_child("body", parse_function_body)?.wrap_rc(),
+        body: parse_callable_body(ast)?.wrap_rc(),
     })
 }
 
+/// Anonymous functions take the name of the variable they are bound to
+fn name_function(expr: FruExpression, name: Identifier) -> FruExpression {
+    match expr {
+        FruExpression::Function {
+            ident: None,
+            args,
+            body,
+        } => FruExpression::Function {
+            ident: Some(name),
+            args,
+            body,
+        },
+
+        other => other,
+    }
+}
+
 fn parse_formal_parameters(ast: NodeWrapper) -> Result<FormalParameters, ParseError> {
-    let mut args = ast.parse_children("args", |x| Ok((parse_formal_parameter(x)?, x)))?;
+    let mut res = FormalParameters {
+        args: Vec::new(),
+        rest: None,
+        kwargs: None,
+    };
 
     let mut was_default = false;
 
-    for ((ident, expr), node) in &args {
-        if expr.is_some() {
-            was_default = true;
-        } else if was_default {
+    for node in ast.parse_children("args", Ok)? {
+        let error = match node.grammar_name() {
+            "rest_parameter" if res.rest.is_some() || res.kwargs.is_some() => {
+                Some("Rest parameter should be the only one and go before `**` parameter")
+            }
+
+            "rest_parameter" => {
+                res.rest = Some(node.get_child_ident("ident")?);
+                None
+            }
+
+            "kwargs_parameter" if res.kwargs.is_some() => {
+                Some("`**` parameter should be the only one")
+            }
+
+            "kwargs_parameter" => {
+                res.kwargs = Some(node.get_child_ident("ident")?);
+                None
+            }
+
+            _ => {
+                let param = parse_formal_parameter(node)?;
+
+                if res.rest.is_some() || res.kwargs.is_some() {
+                    return Err(ParseError::Error {
+                        position: node.range(),
+                        error: format!(
+                            "Parameter `{}` should be before `..` and `**` parameters",
+                            param.ident
+                        ),
+                    });
+                }
+
+                if param.default.is_some() {
+                    was_default = true;
+                } else if was_default {
+                    return Err(ParseError::Error {
+                        position: node.range(),
+                        error: format!(
+                            "Positional parameter `{}` should be before default parameters",
+                            param.ident
+                        ),
+                    });
+                }
+
+                res.args.push(param);
+                None
+            }
+        };
+
+        if let Some(error) = error {
             return Err(ParseError::Error {
                 position: node.range(),
-                error: format!(
-                    "Positional parameter `{}` should be before default parameters",
-                    ident
-                ),
+                error: error.to_string(),
             });
         }
     }
 
-    Ok(FormalParameters {
-        args: args.drain(..).map(|(x, _)| x).collect(),
-    })
+    Ok(res)
 }
 
-fn parse_formal_parameter(
-    x: NodeWrapper,
-) -> Result<(Identifier, Option<FruExpression>), ParseError> {
+fn parse_formal_parameter(x: NodeWrapper) -> Result<FormalParameter, ParseError> {
     match x.grammar_name() {
-        "positional_parameter" => Ok((x.get_child_ident("ident")?, None)),
+        "positional_parameter" => Ok(FormalParameter {
+            ident: x.get_child_ident("ident")?,
+            default: None,
+            default_text: None,
+        }),
 
-        "default_parameter" => Ok((
-            x.get_child_ident("ident")?,
-            Some(x.parse_child_expression("value")?),
-        )),
+        "default_parameter" => Ok(FormalParameter {
+            ident: x.get_child_ident("ident")?,
+            default: Some(x.parse_child_expression("value")?),
+            default_text: Some(
+                x.get_child_text("value")?.split_whitespace().collect::<Vec<_>>().join(" "),
+            ),
+        }),
 
         unexpected => Err(ParseError::InvalidAst {
             position: x.range(),
@@ -671,8 +1343,8 @@ fn parse_argument_list(ast: NodeWrapper) -> Result<ArgumentList, ParseError> {
 
     let mut was_named = false;
 
-    for (i, (name, _)) in args.iter().enumerate() {
-        if name.is_some() {
+    for (i, arg) in args.iter().enumerate() {
+        if matches!(arg, Argument::Named(..) | Argument::KwargsSpread(_)) {
             was_named = true;
         } else if was_named {
             return Err(ParseError::Error {
@@ -692,10 +1364,12 @@ fn parse_argument_list_instantiation(ast: NodeWrapper) -> Result<ArgumentList, P
         return Ok(args);
     }
 
-    let named = args.args[0].0.is_some();
+    let is_named = |arg: &Argument| matches!(arg, Argument::Named(..) | Argument::KwargsSpread(_));
+
+    let named = is_named(&args.args[0]);
 
-    for (i, _) in &args.args {
-        if i.is_some() != named {
+    for arg in &args.args {
+        if is_named(arg) != named {
             return Err(ParseError::Error {
                 position: ast.range(),
                 error: "All arguments must be either named or not named at the same time"
@@ -707,17 +1381,21 @@ fn parse_argument_list_instantiation(ast: NodeWrapper) -> Result<ArgumentList, P
     Ok(args)
 }
 
-fn parse_argument_item(
-    ast: NodeWrapper,
-) -> Result<(Option<Identifier>, FruExpression), ParseError> {
+fn parse_argument_item(ast: NodeWrapper) -> Result<Argument, ParseError> {
     match ast.grammar_name() {
-        "positional_argument" => Ok((None, ast.parse_child_expression("value")?)),
+        "positional_argument" => Ok(Argument::Positional(ast.parse_child_expression("value")?)),
 
-        "named_argument" => Ok((
-            Some(ast.get_child_ident("ident")?),
+        "named_argument" => Ok(Argument::Named(
+            ast.get_child_ident("ident")?,
             ast.parse_child_expression("value")?,
         )),
 
+        "spread_argument" => Ok(Argument::Spread(ast.parse_child_expression("value")?)),
+
+        "kwargs_spread_argument" => {
+            Ok(Argument::KwargsSpread(ast.parse_child_expression("value")?))
+        }
+
         unexpected => Err(ParseError::InvalidAst {
             position: ast.range(),
             error: format!("Not an argument: {}", unexpected),
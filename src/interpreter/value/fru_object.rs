@@ -1,14 +1,13 @@
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
 use crate::interpreter::{
-    control::{returned, returned_nothing},
-    error::FruError,
-    identifier::Identifier,
+    control::{returned_nothing, returned_unit},
+    error::{ErrorKind, FruError},
+    identifier::{id, Identifier},
     scope::Scope,
-    value::fru_type::FruType,
-    value::fru_type::TypeType,
-    value::fru_value::FruValue,
-    value::function::FruFunction,
+    value::fru_type::{suggestion, FruType, TypeType},
+    value::fru_value::{fmt_fields, FruValue},
+    value::function::{BuiltinFunction, EvaluatedArgumentList, FruFunction},
 };
 
 #[derive(Clone)]
@@ -48,39 +47,98 @@ impl FruObject {
     }
 
     pub fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        self.find_prop(ident)?.ok_or_else(|| {
+            let names = self.get_type().get_prop_names();
+
+            FruError::new_kind(
+                ErrorKind::UndefinedProperty,
+                format!("prop `{}` not found", ident),
+            )
+            .with_note(suggestion(ident, &names))
+        })
+    }
+
+    /// `None` if there is no prop with this name, cheaper than `get_prop` in this case
+    pub fn find_prop(&self, ident: Identifier) -> Result<Option<FruValue>, FruError> {
         if let Some(k) = self.get_type().get_field_k(ident) {
-            return Ok(self.get_kth_field(k));
+            return Ok(Some(self.get_kth_field(k)));
         }
 
         if let Some(property) = self.get_type().get_property(ident) {
             let new_scope = Scope::new_with_object(self.clone());
 
             return match property.getter {
-                Some(getter) => returned(getter.evaluate(new_scope)),
+                Some(getter) => returned_unit(getter.execute(new_scope)).map(Some),
 
                 None => FruError::new_res(format!("property `{}` has no getter", ident)),
             };
         }
 
         if let Some(FruFunction {
+            ident: method_ident,
             parameters: argument_idents,
             body,
             ..
         }) = self.get_type().get_method(ident)
         {
-            return Ok(FruFunction {
-                parameters: argument_idents,
-                body,
-                scope: Scope::new_with_object(self.clone()),
+            return Ok(Some(
+                FruFunction {
+                    ident: method_ident,
+                    parameters: argument_idents,
+                    body,
+                    scope: Scope::new_with_object(self.clone()),
+                }
+                .into(),
+            ));
+        }
+
+        if let Ok(Some(static_thing)) = self.get_type().find_prop(ident) {
+            return Ok(Some(static_thing));
+        }
+
+        if ident == id::COPY_WITH {
+            return Ok(Some(
+                BuiltinFunction::new(id::COPY_WITH, b_copy_with)
+                    .bind(FruValue::Object(self.clone())),
+            ));
+        }
+
+        if ident == id::VARIANT && self.get_type().get_variant_of().is_some() {
+            return Ok(Some(self.get_variant()));
+        }
+
+        for part in self.get_embedded_parts() {
+            if let Some(value) = part.find_prop(ident)? {
+                return Ok(Some(value));
             }
-            .into());
         }
 
-        if let Ok(static_thing) = self.get_type().get_prop(ident) {
-            return Ok(static_thing);
+        Ok(None)
+    }
+
+    /// Variant of enum, that this object is, as it is stored in the enum:
+    /// type for variants with fields, the object itself for variants without them
+    pub fn get_variant(&self) -> FruValue {
+        if self.get_type().get_fields().is_empty() {
+            FruValue::Object(self.clone())
+        } else {
+            FruValue::Type(self.get_type())
         }
+    }
+
+    /// Objects, embedded into this one, in order of declaration
+    pub fn get_embedded_parts(&self) -> Vec<FruObject> {
+        let type_ = self.get_type();
 
-        FruError::new_res(format!("prop `{}` not found", ident))
+        type_
+            .get_embedded()
+            .iter()
+            .filter_map(|embedded| type_.get_field_k(embedded.get_ident()))
+            .filter_map(|k| match self.get_kth_field(k) {
+                FruValue::Object(part) => Some(part),
+                _ => None,
+            })
+            .collect()
     }
 
     pub fn set_prop(&self, ident: Identifier, value: FruValue) -> Result<(), FruError> {
@@ -109,15 +167,72 @@ impl FruObject {
             };
         }
 
-        if let Ok(()) = self.get_type().set_prop(ident, value) {
+        if let Ok(()) = self.get_type().set_prop(ident, value.clone()) {
             return Ok(());
         }
 
-        FruError::new_res(format!(
-            "prop `{}` does not exist in struct `{}`",
-            ident,
-            self.get_type().get_ident()
-        ))
+        for part in self.get_embedded_parts() {
+            match part.set_prop(ident, value.clone()) {
+                Err(err) if err.get_kind() == ErrorKind::UndefinedProperty => {}
+                result => return result,
+            }
+        }
+
+        let names = self.get_type().get_prop_names();
+
+        Err(FruError::new_kind(
+            ErrorKind::UndefinedProperty,
+            format!(
+                "prop `{}` does not exist in struct `{}`",
+                ident,
+                self.get_type().get_ident()
+            ),
+        )
+        .with_note(suggestion(ident, &names)))
+    }
+
+    /// New object of the same type, with fields from `args` and the rest copied from this one,
+    /// it goes through instantiation, so `validate` is called for it too
+    pub fn copy_with(&self, args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+        let type_ = self.get_type();
+
+        let mut fields: Vec<_> =
+            self.internal.fields.borrow().iter().map(FruValue::fru_clone).collect();
+        let mut set = Vec::new();
+
+        for (ident, value) in args.args {
+            let Some(ident) = ident else {
+                return FruError::new_kind_res(
+                    ErrorKind::ArgumentMismatch,
+                    "`copy_with` takes only named arguments",
+                );
+            };
+
+            let Some(k) = type_.get_field_k(ident) else {
+                let names: Vec<_> =
+                    type_.get_fields().iter().map(|x| x.ident.to_string()).collect();
+
+                return Err(FruError::new_kind(
+                    ErrorKind::InvalidInstantiation,
+                    format!("field `{}` does not exist", ident),
+                )
+                .with_note(suggestion(ident, &names)));
+            };
+
+            if set.contains(&ident) {
+                return FruError::new_kind_res(
+                    ErrorKind::InvalidInstantiation,
+                    format!("field `{}` is set more than once", ident),
+                );
+            }
+
+            set.push(ident);
+            fields[k] = value;
+        }
+
+        let args = type_.get_fields().iter().map(|x| Some(x.ident)).zip(fields).collect();
+
+        type_.instantiate(EvaluatedArgumentList { args })
     }
 
     pub fn fru_clone(&self) -> FruValue {
@@ -129,11 +244,18 @@ impl FruObject {
                 self.internal.fields.borrow().iter().map(FruValue::fru_clone).collect(),
             ),
 
-            TypeType::Class | TypeType::Data => FruValue::Object(self.clone()),
+            TypeType::Class | TypeType::Data | TypeType::Enum => FruValue::Object(self.clone()),
         }
     }
 }
 
+fn b_copy_with(mut args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.remove(0) {
+        (_, FruValue::Object(this)) => this.copy_with(args),
+        _ => unreachable!(),
+    }
+}
+
 impl PartialEq for FruObject {
     fn eq(&self, other: &Self) -> bool {
         if self.get_type() != other.get_type() {
@@ -146,24 +268,26 @@ impl PartialEq for FruObject {
 
 impl Debug for FruObject {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}{{", self.get_type())?;
+        let type_ = self.get_type();
 
-        let fields = self.get_type().get_fields().len();
+        // variant without fields is written just as its name, like it is accessed
+        if type_.get_variant_of().is_some() && type_.get_fields().is_empty() {
+            return write!(f, "{:?}", type_);
+        }
 
-        for (k, (field, value)) in self
+        let fields = self
             .get_type()
             .get_fields()
             .iter()
-            .zip(self.internal.fields.borrow().iter())
-            .enumerate()
-        {
-            write!(f, "{:?}={:?}", field, value)?;
-
-            if k + 1 < fields {
-                write!(f, ", ")?;
-            }
-        }
-
-        write!(f, "}}")
+            .map(|field| field.ident)
+            .zip(self.internal.fields.borrow().iter().cloned())
+            .collect();
+
+        fmt_fields(
+            f,
+            Rc::as_ptr(&self.internal) as usize,
+            format!("{:?}", type_),
+            fields,
+        )
     }
 }
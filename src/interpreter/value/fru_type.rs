@@ -1,11 +1,17 @@
-use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Debug,
+    rc::{Rc, Weak},
+};
 
 use crate::interpreter::{
-    control::{returned, returned_nothing},
-    error::FruError,
+    ast_helpers::RawVariant,
+    control::{returned, returned_nothing, returned_unit},
+    error::{did_you_mean, ErrorKind, FruError},
     expression::FruExpression,
     helpers::WrappingExtension,
-    identifier::Identifier,
+    identifier::{id, Identifier},
     scope::Scope,
     statement::FruStatement,
     value::fru_object::FruObject,
@@ -29,6 +35,10 @@ pub struct FruTypeInternal {
     static_properties: HashMap<Identifier, Property>,
     methods: HashMap<Identifier, FruFunction>,
     static_methods: HashMap<Identifier, FruFunction>,
+    // types of embedded parts, in order of declaration, each part is stored in a field named after its type
+    embedded: Vec<FruType>,
+    // enum, this type is a variant of, weak, because the enum holds its variants
+    variant_of: Option<Weak<FruTypeInternal>>,
     scope: Rc<Scope>,
 }
 
@@ -37,34 +47,56 @@ pub struct FruField {
     pub is_public: bool,
     pub ident: Identifier,
     pub type_ident: Option<Identifier>, // useless for now
+    // declared with `embed`, so props of the value are reachable through the object
+    pub is_embedded: bool,
+    // evaluated in the scope of the type on every instantiation, that does not set the field
+    pub default: Option<Rc<FruExpression>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Property {
     pub ident: Identifier,
-    pub getter: Option<Rc<FruExpression>>,
+    pub getter: Option<Rc<FruStatement>>,
     pub setter: Option<(Identifier, Rc<FruStatement>)>, // ident for value variable
 }
 
+/// Everything, that is declared in the body of type
+pub struct TypeMembers {
+    pub fields: Vec<FruField>,
+    pub static_fields: RefCell<HashMap<Identifier, FruValue>>,
+    pub properties: HashMap<Identifier, Property>,
+    pub static_properties: HashMap<Identifier, Property>,
+    pub methods: HashMap<Identifier, FruFunction>,
+    pub static_methods: HashMap<Identifier, FruFunction>,
+    pub embedded: Vec<FruType>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TypeType {
     Struct,
     Class,
     Data,
+    // only its variants are instantiated, they are `data` types
+    Enum,
 }
 
 impl FruType {
     pub fn new_value(
         ident: Identifier,
         type_type: TypeType,
-        fields: Vec<FruField>,
-        static_fields: RefCell<HashMap<Identifier, FruValue>>,
-        properties: HashMap<Identifier, Property>,
-        static_properties: HashMap<Identifier, Property>,
-        methods: HashMap<Identifier, FruFunction>,
-        static_methods: HashMap<Identifier, FruFunction>,
+        members: TypeMembers,
         scope: Rc<Scope>,
     ) -> FruValue {
+        let TypeMembers {
+            fields,
+            static_fields,
+            properties,
+            static_properties,
+            methods,
+            static_methods,
+            embedded,
+        } = members;
+
         FruValue::Type(Self {
             internal: FruTypeInternal {
                 ident,
@@ -75,12 +107,80 @@ impl FruType {
                 methods,
                 static_methods,
                 static_properties,
+                embedded,
+                variant_of: None,
                 scope,
             }
             .wrap_rc(),
         })
     }
 
+    /// Enum type, that holds its variants as static fields: types for variants with fields
+    /// and their only objects for variants without them. Methods are shared by all variants
+    pub fn new_enum_value(
+        ident: Identifier,
+        variants: &[RawVariant],
+        methods: HashMap<Identifier, FruFunction>,
+        static_methods: HashMap<Identifier, FruFunction>,
+        scope: Rc<Scope>,
+    ) -> FruValue {
+        let enum_type = Self {
+            internal: FruTypeInternal {
+                ident,
+                type_type: TypeType::Enum,
+                fields: Vec::new(),
+                static_fields: RefCell::new(HashMap::new()),
+                properties: HashMap::new(),
+                static_properties: HashMap::new(),
+                methods: HashMap::new(),
+                static_methods,
+                embedded: Vec::new(),
+                variant_of: None,
+                scope: scope.clone(),
+            }
+            .wrap_rc(),
+        };
+
+        for variant in variants {
+            let variant_type = Self {
+                internal: FruTypeInternal {
+                    ident: variant.ident,
+                    type_type: TypeType::Data,
+                    fields: variant
+                        .fields
+                        .iter()
+                        .map(|ident| FruField {
+                            is_public: true,
+                            ident: *ident,
+                            type_ident: None,
+                            is_embedded: false,
+                            default: None,
+                        })
+                        .collect(),
+                    static_fields: RefCell::new(HashMap::new()),
+                    properties: HashMap::new(),
+                    static_properties: HashMap::new(),
+                    methods: methods.clone(),
+                    static_methods: HashMap::new(),
+                    embedded: Vec::new(),
+                    variant_of: Some(Rc::downgrade(&enum_type.internal)),
+                    scope: scope.clone(),
+                }
+                .wrap_rc(),
+            };
+
+            let value = if variant.fields.is_empty() {
+                FruObject::new_object(variant_type, Vec::new())
+            } else {
+                FruValue::Type(variant_type)
+            };
+
+            enum_type.internal.static_fields.borrow_mut().insert(variant.ident, value);
+        }
+
+        FruValue::Type(enum_type)
+    }
+
     pub fn get_ident(&self) -> Identifier {
         self.internal.ident
     }
@@ -97,6 +197,16 @@ impl FruType {
         self.internal.fields.as_slice()
     }
 
+    pub fn get_embedded(&self) -> &[FruType] {
+        self.internal.embedded.as_slice()
+    }
+
+    pub fn get_variant_of(&self) -> Option<FruType> {
+        let internal = self.internal.variant_of.as_ref()?.upgrade()?;
+
+        Some(FruType { internal })
+    }
+
     pub fn get_field_k(&self, ident: Identifier) -> Option<usize> {
         for (i, field_ident) in self.internal.fields.iter().enumerate() {
             if field_ident.ident == ident {
@@ -106,6 +216,21 @@ impl FruType {
         None
     }
 
+    /// Static fields sorted by name
+    pub fn get_static_fields(&self) -> Vec<(Identifier, FruValue)> {
+        let mut fields: Vec<_> = self
+            .internal
+            .static_fields
+            .borrow()
+            .iter()
+            .map(|(ident, value)| (*ident, value.clone()))
+            .collect();
+
+        fields.sort_by_cached_key(|(ident, _)| ident.to_string());
+
+        fields
+    }
+
     pub fn get_property(&self, ident: Identifier) -> Option<Property> {
         self.internal.properties.get(&ident).cloned()
     }
@@ -114,32 +239,107 @@ impl FruType {
         self.internal.methods.get(&ident).cloned()
     }
 
+    /// Names of fields, properties and methods of instances, and of statics, sorted
+    pub fn get_prop_names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .internal
+            .fields
+            .iter()
+            .map(|x| x.ident)
+            .chain(self.internal.properties.keys().copied())
+            .chain(self.internal.methods.keys().copied())
+            .map(|x| x.to_string())
+            .collect();
+
+        names.extend(self.get_static_prop_names());
+
+        for embedded in self.get_embedded() {
+            names.extend(embedded.get_prop_names());
+        }
+
+        names.sort();
+
+        names
+    }
+
+    /// Names of static fields, properties and methods, sorted
+    pub fn get_static_prop_names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .internal
+            .static_fields
+            .borrow()
+            .keys()
+            .chain(self.internal.static_properties.keys())
+            .chain(self.internal.static_methods.keys())
+            .map(ToString::to_string)
+            .collect();
+
+        for embedded in self.get_embedded() {
+            names.extend(embedded.get_static_prop_names());
+        }
+
+        if let Some(enum_type) = self.get_variant_of() {
+            names.extend(enum_type.get_static_prop_names());
+        }
+
+        names.sort();
+
+        names
+    }
+
     /// In this case means static field of method
     pub fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        self.find_prop(ident)?.ok_or_else(|| {
+            let names = self.get_static_prop_names();
+
+            FruError::new_kind(
+                ErrorKind::UndefinedProperty,
+                format!("static prop `{}` not found", ident),
+            )
+            .with_note(suggestion(ident, &names))
+        })
+    }
+
+    /// `None` if there is no static prop with this name, cheaper than `get_prop` in this case
+    pub fn find_prop(&self, ident: Identifier) -> Result<Option<FruValue>, FruError> {
         if let Some(field) = self.internal.static_fields.borrow().get(&ident) {
-            return Ok(field.clone());
+            return Ok(Some(field.clone()));
         }
 
         if let Some(property) = self.internal.static_properties.get(&ident) {
             let new_scope = Scope::new_with_type(self.clone());
 
             return match &property.getter {
-                Some(getter) => returned(getter.evaluate(new_scope)),
+                Some(getter) => returned_unit(getter.execute(new_scope)).map(Some),
 
                 None => FruError::new_res(format!("static property `{}` has no getter", ident)),
             };
         }
 
         if let Some(static_method) = self.internal.static_methods.get(&ident) {
-            return Ok(FruFunction {
-                parameters: static_method.parameters.clone(),
-                body: static_method.body.clone(),
-                scope: Scope::new_with_type(self.clone()),
+            return Ok(Some(
+                FruFunction {
+                    ident: static_method.ident,
+                    parameters: static_method.parameters.clone(),
+                    body: static_method.body.clone(),
+                    scope: Scope::new_with_type(self.clone()),
+                }
+                .into(),
+            ));
+        }
+
+        for embedded in self.get_embedded() {
+            if let Some(value) = embedded.find_prop(ident)? {
+                return Ok(Some(value));
             }
-            .into());
         }
 
-        FruError::new_res(format!("static prop `{}` not found", ident))
+        // statics of enum, like other variants, are reachable through any of its variants
+        if let Some(enum_type) = self.get_variant_of() {
+            return enum_type.find_prop(ident);
+        }
+
+        Ok(None)
     }
 
     pub fn set_prop(&self, ident: Identifier, value: FruValue) -> Result<(), FruError> {
@@ -162,10 +362,30 @@ impl FruType {
             };
         }
 
-        FruError::new_res(format!("static prop `{}` not found", ident))
+        for embedded in self.get_embedded() {
+            match embedded.set_prop(ident, value.clone()) {
+                Err(err) if err.get_kind() == ErrorKind::UndefinedProperty => {}
+                result => return result,
+            }
+        }
+
+        FruError::new_kind_res(
+            ErrorKind::UndefinedProperty,
+            format!("static prop `{}` not found", ident),
+        )
     }
 
     pub fn instantiate(&self, mut args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+        if self.get_type_type() == TypeType::Enum {
+            return FruError::new_kind_res(
+                ErrorKind::InvalidInstantiation,
+                format!(
+                    "enum `{}` cannot be instantiated, only its variants can",
+                    self.get_ident()
+                ),
+            );
+        }
+
         let mut obj_fields = HashMap::new();
 
         let fields = self.get_fields();
@@ -176,28 +396,116 @@ impl FruType {
                 None => fields[n].ident,
             };
             if obj_fields.contains_key(&ident) {
-                return FruError::new_res(format!("field `{}` is set more than once", ident));
+                return FruError::new_kind_res(
+                    ErrorKind::InvalidInstantiation,
+                    format!("field `{}` is set more than once", ident),
+                );
             }
             obj_fields.insert(ident, value);
         }
 
         let mut args = Vec::new();
 
-        for FruField { ident, .. } in fields {
+        for FruField {
+            ident,
+            is_embedded,
+            default,
+            ..
+        } in fields
+        {
             match obj_fields.remove(ident) {
+                Some(value) if *is_embedded => {
+                    let is_part = matches!(
+                        &value,
+                        FruValue::Object(obj) if obj.get_type().get_ident() == *ident
+                            && self.get_embedded().contains(&obj.get_type())
+                    );
+
+                    if !is_part {
+                        return FruError::new_kind_res(
+                            ErrorKind::UnexpectedType,
+                            format!(
+                                "embedded `{}` of `{}` must be `{}`, got `{}`",
+                                ident,
+                                self.get_ident(),
+                                ident,
+                                value.get_type_identifier()
+                            ),
+                        );
+                    }
+
+                    args.push(value)
+                }
+
                 Some(value) => args.push(value),
-                None => return FruError::new_res(format!("missing field `{}`", ident)),
+
+                None => match default {
+                    Some(default) => args.push(returned(
+                        default.evaluate(Scope::new_with_type(self.clone())),
+                    )?),
+
+                    None => {
+                        return FruError::new_kind_res(
+                            ErrorKind::InvalidInstantiation,
+                            format!("missing field `{}`", ident),
+                        )
+                    }
+                },
             }
         }
 
         if let Some(ident) = obj_fields.keys().next() {
-            return FruError::new_res(format!("field `{}` does not exist", *ident));
+            let names: Vec<_> = fields.iter().map(|x| x.ident.to_string()).collect();
+
+            return Err(FruError::new_kind(
+                ErrorKind::InvalidInstantiation,
+                format!("field `{}` does not exist", *ident),
+            )
+            .with_note(suggestion(*ident, &names)));
+        }
+
+        let object = FruObject::new(self.clone(), args);
+
+        self.validate(&object)?;
+
+        Ok(FruValue::Object(object))
+    }
+
+    /// Calls `validate` method of the type, if there is one, `false` returned from it rejects the object,
+    /// anything other than `Bool` is an error
+    fn validate(&self, object: &FruObject) -> Result<(), FruError> {
+        if self.get_method(id::VALIDATE).is_none() {
+            return Ok(());
         }
 
-        Ok(FruObject::new_object(self.clone(), args))
+        match object
+            .get_prop(id::VALIDATE)?
+            .call(EvaluatedArgumentList { args: Vec::new() })?
+        {
+            FruValue::Bool(false) => FruError::new_kind_res(
+                ErrorKind::InvalidInstantiation,
+                format!("`validate` of `{}` rejected the object", self.get_ident()),
+            ),
+
+            FruValue::Bool(true) => Ok(()),
+
+            other => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!(
+                    "`validate` must return `Bool`, got `{}`",
+                    other.get_type_identifier()
+                ),
+            ),
+        }
     }
 }
 
+/// `did you mean` note for a prop, that was not found among `names`
+pub fn suggestion(ident: Identifier, names: &[String]) -> Option<String> {
+    did_you_mean(&ident.to_string(), names.iter().map(String::as_str))
+        .map(|x| format!("did you mean `{}`?", x))
+}
+
 impl PartialEq for FruType {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.internal, &other.internal)
@@ -220,6 +528,10 @@ impl Debug for FruField {
 
 impl Debug for FruType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(enum_type) = self.get_variant_of() {
+            write!(f, "{}.", enum_type.get_ident())?;
+        }
+
         write!(f, "{}", self.internal.ident)
     }
 }
@@ -1,16 +1,27 @@
-use std::{fmt::Debug, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fmt::{Debug, Display, Formatter},
+    rc::Rc,
+};
 
 use crate::interpreter::{
-    error::FruError,
+    error::{ErrorKind, FruError},
     identifier::id,
     identifier::Identifier,
     value::{
         fru_object::FruObject,
         fru_type::FruType,
-        function::{AnyFunction, CurriedFunction, EvaluatedArgumentList, FruFunction},
+        function::{AnyFunction, EvaluatedArgumentList, FruFunction},
         native::object::NativeObject,
     },
 };
+use crate::stdlib::range::fru_range::{extract_range_from_value, resolve_index};
+
+thread_local! {
+    // addresses of values that are being formatted right now, used to cut reference cycles
+    static FORMATTING: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
 
 pub type TFnBuiltin = fn(EvaluatedArgumentList) -> Result<FruValue, FruError>;
 pub type TOpBuiltin = fn(FruValue, FruValue) -> Result<FruValue, FruError>;
@@ -41,7 +52,12 @@ impl FruValue {
             FruValue::String(_) => id::STRING,
             FruValue::Function(_) => id::FUNCTION,
             FruValue::Type(_) => id::TYPE,
-            FruValue::Object(obj) => obj.get_type().get_ident(),
+            // variants share the type of their enum, so operators are declared for the enum
+            FruValue::Object(obj) => {
+                let type_ = obj.get_type();
+
+                type_.get_variant_of().unwrap_or(type_).get_ident()
+            }
             FruValue::NativeObject(obj) => obj.get_type_identifier(),
         }
     }
@@ -50,38 +66,23 @@ impl FruValue {
         match self {
             FruValue::Function(fun) => fun.call(args),
             FruValue::NativeObject(obj) => obj.call(args),
-            _ => FruError::new_res(format!("`{}` is not invokable", self.get_type_identifier())),
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("`{}` is not invokable", self.get_type_identifier()),
+            ),
         }
     }
 
     pub fn curry_call(&self, args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
         match self {
-            FruValue::Function(func) => {
-                match func {
-                    AnyFunction::CurriedFunction(func) => {
-                        let mut new_args = func.saved_args.clone(); // TODO: obsidian Issue 1
-                        new_args.args.extend(args.args);
-
-                        Ok(FruValue::Function(AnyFunction::CurriedFunction(Rc::new(
-                            CurriedFunction {
-                                saved_args: new_args,
-                                function: func.function.clone(),
-                            },
-                        ))))
-                    }
-
-                    normal => Ok(FruValue::Function(AnyFunction::CurriedFunction(Rc::new(
-                        CurriedFunction {
-                            saved_args: args,
-                            function: Rc::new(normal.clone()),
-                        },
-                    )))),
-                }
-            }
+            FruValue::Function(func) => Ok(FruValue::Function(func.curry_call(args))),
 
             FruValue::NativeObject(obj) => obj.curry_call(args),
 
-            _ => FruError::new_res(format!("`{}` is not invokable", self.get_type_identifier())),
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("`{}` is not invokable", self.get_type_identifier()),
+            ),
         }
     }
 
@@ -91,25 +92,27 @@ impl FruValue {
 
             FruValue::NativeObject(obj) => obj.instantiate(args),
 
-            _ => FruError::new_res(format!(
-                "`{}` is not instantiatable",
-                self.get_type_identifier()
-            )),
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("`{}` is not instantiatable", self.get_type_identifier()),
+            ),
         }
     }
 
     pub fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
         match self {
+            FruValue::Function(func) => func.get_prop(ident),
+
             FruValue::Type(t) => t.get_prop(ident),
 
             FruValue::Object(obj) => obj.get_prop(ident),
 
             FruValue::NativeObject(obj) => obj.get_prop(ident),
 
-            _ => FruError::new_res(format!(
-                "cannot access prop of `{}`",
-                self.get_type_identifier()
-            )),
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("cannot access prop of `{}`", self.get_type_identifier()),
+            ),
         }
     }
 
@@ -121,10 +124,74 @@ impl FruValue {
 
             FruValue::NativeObject(obj) => obj.set_prop(ident, value),
 
-            _ => FruError::new_res(format!(
-                "cannot set prop of `{}`",
-                self.get_type_identifier()
-            )),
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("cannot set prop of `{}`", self.get_type_identifier()),
+            ),
+        }
+    }
+
+    /// `self[index]`, strings are indexed by chars, user types define `get_index` method
+    pub fn get_index(&self, index: FruValue) -> Result<FruValue, FruError> {
+        match self {
+            FruValue::String(s) => {
+                let chars: Vec<_> = s.chars().collect();
+
+                if let Some(range) = extract_range_from_value(&index) {
+                    let (start, end) = range.resolve(chars.len())?;
+
+                    return Ok(FruValue::String(chars[start..end].iter().collect()));
+                }
+
+                match index {
+                    FruValue::Number(index) => {
+                        let index = resolve_index(index, chars.len(), "String")?;
+
+                        Ok(FruValue::String(chars[index].to_string()))
+                    }
+
+                    other => FruError::new_kind_res(
+                        ErrorKind::UnexpectedType,
+                        format!(
+                            "String can not be indexed by `{}`",
+                            other.get_type_identifier()
+                        ),
+                    ),
+                }
+            }
+
+            FruValue::Object(obj) if obj.get_type().get_method(id::GET_INDEX).is_some() => {
+                obj.get_prop(id::GET_INDEX)?.call(EvaluatedArgumentList {
+                    args: vec![(None, index)],
+                })
+            }
+
+            FruValue::NativeObject(obj) => obj.get_index(index),
+
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("cannot index `{}`", self.get_type_identifier()),
+            ),
+        }
+    }
+
+    /// `self[index] = value`, user types define `set_index` method
+    pub fn set_index(&self, index: FruValue, value: FruValue) -> Result<(), FruError> {
+        match self {
+            FruValue::Object(obj) if obj.get_type().get_method(id::SET_INDEX).is_some() => {
+                obj.get_prop(id::SET_INDEX)?.call(EvaluatedArgumentList {
+                    args: vec![(None, index), (None, value)],
+                })?;
+
+                Ok(())
+            }
+
+            FruValue::NativeObject(obj) => obj.set_index(index, value),
+
+            _ => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("cannot set index of `{}`", self.get_type_identifier()),
+            ),
         }
     }
 
@@ -137,6 +204,70 @@ impl FruValue {
             _ => self.clone(),
         }
     }
+
+    /// Text shown to the user by `print`, `str` and format strings, respects user-defined `show` method
+    pub fn to_display_string(&self) -> Result<String, FruError> {
+        if let FruValue::Object(obj) = self {
+            if obj.get_type().get_method(id::SHOW).is_some() {
+                return match obj
+                    .get_prop(id::SHOW)?
+                    .call(EvaluatedArgumentList { args: Vec::new() })?
+                {
+                    FruValue::String(s) => Ok(s),
+
+                    other => FruError::new_res(format!(
+                        "`show` method of `{}` must return `String`, got `{}`",
+                        obj.get_type().get_ident(),
+                        other.get_type_identifier()
+                    )),
+                };
+            }
+        }
+
+        Ok(self.to_string())
+    }
+}
+
+/// Writes `Name{a=1, b=2}`, or one field per line if alternate flag is set
+pub fn fmt_fields(
+    f: &mut Formatter<'_>,
+    address: usize,
+    name: impl Display,
+    fields: Vec<(Identifier, FruValue)>,
+) -> std::fmt::Result {
+    if !FORMATTING.with(|x| x.borrow_mut().insert(address)) {
+        return write!(f, "{}{{...}}", name);
+    }
+
+    let result = (|| {
+        write!(f, "{}{{", name)?;
+
+        if f.alternate() {
+            if !fields.is_empty() {
+                writeln!(f)?;
+            }
+
+            for (ident, value) in &fields {
+                let value = format!("{:#?}", value).replace('\n', "\n    ");
+
+                writeln!(f, "    {}={},", ident, value)?;
+            }
+        } else {
+            for (k, (ident, value)) in fields.iter().enumerate() {
+                if k > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{}={:?}", ident, value)?;
+            }
+        }
+
+        write!(f, "}}")
+    })();
+
+    FORMATTING.with(|x| x.borrow_mut().remove(&address));
+
+    result
 }
 
 impl From<FruFunction> for FruValue {
@@ -159,17 +290,28 @@ impl PartialEq for FruValue {
     }
 }
 
+// representation, that is used for nested values and `repr`, strings are quoted
 impl Debug for FruValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             FruValue::Nah => write!(f, "nah"),
             FruValue::Number(v) => write!(f, "{}", v),
             FruValue::Bool(v) => write!(f, "{}", v),
+            FruValue::String(v) => write!(f, "{:?}", v),
+            FruValue::Function(fun) => Debug::fmt(fun, f),
+            FruValue::Type(type_) => Debug::fmt(type_, f),
+            FruValue::Object(obj) => Debug::fmt(obj, f),
+            FruValue::NativeObject(obj) => Debug::fmt(obj, f),
+        }
+    }
+}
+
+// same as representation, except for strings, that are written as is
+impl Display for FruValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
             FruValue::String(v) => write!(f, "{}", v),
-            FruValue::Function(fun) => write!(f, "{:?}", fun),
-            FruValue::Type(type_) => write!(f, "{:?}", type_),
-            FruValue::Object(obj) => write!(f, "{:?}", obj),
-            FruValue::NativeObject(obj) => write!(f, "{}{{}}", obj.get_type_identifier()),
+            other => Debug::fmt(other, f),
         }
     }
 }
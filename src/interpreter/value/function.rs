@@ -1,14 +1,19 @@
-use std::{collections::HashSet, fmt::Debug, rc::Rc};
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display, Formatter},
+    rc::Rc,
+};
 
 use crate::interpreter::{
     control::{returned, returned_unit},
-    error::FruError,
+    error::{did_you_mean, ErrorKind, FruError},
     expression::FruExpression,
-    identifier::Identifier,
+    identifier::{id, Identifier},
     scope::Scope,
     statement::FruStatement,
     value::fru_value::{FruValue, TFnBuiltin},
 };
+use crate::stdlib::{list::fru_list::FruList, map::fru_map::FruMap};
 
 #[derive(Clone, Copy, Debug)]
 pub enum ArgumentError {
@@ -21,6 +26,8 @@ pub enum ArgumentError {
     },
     DoesNotExist {
         ident: Identifier,
+        // parameter with similar name
+        suggestion: Option<Identifier>,
     },
 }
 
@@ -33,6 +40,7 @@ pub enum AnyFunction {
 
 #[derive(Clone)]
 pub struct FruFunction {
+    pub ident: Option<Identifier>,
     pub parameters: FormalParameters,
     pub body: Rc<FruStatement>,
     pub scope: Rc<Scope>,
@@ -40,12 +48,29 @@ pub struct FruFunction {
 
 #[derive(Clone, Debug)]
 pub struct FormalParameters {
-    pub args: Vec<(Identifier, Option<FruExpression>)>,
+    pub args: Vec<FormalParameter>,
+    pub rest: Option<Identifier>, // `..rest`, collects extra positional arguments into a list
+    pub kwargs: Option<Identifier>, // `**kwargs`, collects extra named arguments into a map
+}
+
+#[derive(Clone, Debug)]
+pub struct FormalParameter {
+    pub ident: Identifier,
+    pub default: Option<FruExpression>,
+    pub default_text: Option<String>, // source code of default value, used for introspection
 }
 
 #[derive(Clone, Debug)]
 pub struct ArgumentList {
-    pub args: Vec<(Option<Identifier>, FruExpression)>,
+    pub args: Vec<Argument>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Argument {
+    Positional(FruExpression),
+    Named(Identifier, FruExpression),
+    Spread(FruExpression),       // `..list`
+    KwargsSpread(FruExpression), // `**map`
 }
 
 #[derive(Clone, Debug)]
@@ -53,8 +78,20 @@ pub struct EvaluatedArgumentList {
     pub args: Vec<(Option<Identifier>, FruValue)>,
 }
 
+impl EvaluatedArgumentList {
+    /// Copies arguments the same way `let` does: structs are copied, classes and data are shared.
+    /// Curried functions copy their saved arguments on every call,
+    /// so a call that mutates a saved struct does not affect subsequent calls
+    pub fn fru_clone(&self) -> Self {
+        Self {
+            args: self.args.iter().map(|(ident, value)| (*ident, value.fru_clone())).collect(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct BuiltinFunction {
+    ident: Identifier,
     function: TFnBuiltin,
 }
 
@@ -71,15 +108,110 @@ impl AnyFunction {
             AnyFunction::CurriedFunction(func) => func.call(args),
         }
     }
+
+    /// Saves arguments by value, see `EvaluatedArgumentList::fru_clone`
+    pub fn curry_call(&self, args: EvaluatedArgumentList) -> AnyFunction {
+        let (mut saved_args, function) = match self {
+            AnyFunction::CurriedFunction(func) => (func.saved_args.clone(), func.function.clone()),
+            normal => (
+                EvaluatedArgumentList { args: Vec::new() },
+                Rc::new(normal.clone()),
+            ),
+        };
+
+        saved_args.args.extend(args.fru_clone().args);
+
+        AnyFunction::CurriedFunction(Rc::new(CurriedFunction {
+            saved_args,
+            function,
+        }))
+    }
+
+    pub fn get_ident(&self) -> Option<Identifier> {
+        match self {
+            AnyFunction::Function(func) => func.ident,
+            AnyFunction::BuiltinFunction(func) => Some(func.ident),
+            AnyFunction::CurriedFunction(func) => func.function.get_ident(),
+        }
+    }
+
+    /// Parameters, that are not yet bound, `None` for builtins, as they accept anything
+    pub fn get_parameters(&self) -> Option<FormalParameters> {
+        match self {
+            AnyFunction::Function(func) => Some(func.parameters.clone()),
+
+            AnyFunction::BuiltinFunction(_) => None,
+
+            AnyFunction::CurriedFunction(func) => {
+                let mut parameters = func.function.get_parameters()?;
+
+                let positional = func.saved_args.args.iter().filter(|(x, _)| x.is_none()).count();
+
+                parameters.args.drain(..positional.min(parameters.args.len()));
+
+                parameters.args.retain(|param| {
+                    !func.saved_args.args.iter().any(|(ident, _)| *ident == Some(param.ident))
+                });
+
+                Some(parameters)
+            }
+        }
+    }
+
+    pub fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        Ok(match ident {
+            id::NAME => match self.get_ident() {
+                Some(ident) => FruValue::String(ident.to_string()),
+                None => FruValue::Nah,
+            },
+
+            id::ARITY => match self.get_parameters() {
+                Some(parameters) => FruValue::Number(parameters.args.len() as f64),
+                None => FruValue::Nah,
+            },
+
+            id::PARAMS => match self.get_parameters() {
+                Some(parameters) => FruList::new_value(
+                    parameters.to_strings().into_iter().map(FruValue::String).collect(),
+                ),
+                None => FruValue::Nah,
+            },
+
+            id::IS_BUILTIN => FruValue::Bool(match self {
+                AnyFunction::CurriedFunction(func) => {
+                    matches!(*func.function, AnyFunction::BuiltinFunction(_))
+                }
+                other => matches!(other, AnyFunction::BuiltinFunction(_)),
+            }),
+
+            id::SAVED_ARGS => FruValue::Number(match self {
+                AnyFunction::CurriedFunction(func) => func.saved_args.args.len() as f64,
+                _ => 0.0,
+            }),
+
+            _ => {
+                return FruError::new_kind_res(
+                    ErrorKind::UndefinedProperty,
+                    format!("function has no prop `{}`", ident),
+                )
+            }
+        })
+    }
 }
 
 impl FruFunction {
     fn call(&self, args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
         let new_scope = Scope::new_with_parent(self.scope.clone());
 
-        self.parameters.apply(args, new_scope.clone())?;
+        self.parameters.apply(args, new_scope.clone()).map_err(|err| match self.ident {
+            Some(ident) => err.map_message(|x| format!("{} in call of `{}`", x, ident)),
+            None => err,
+        })?;
 
-        returned_unit(self.body.execute(new_scope))
+        returned_unit(self.body.execute(new_scope)).map_err(|mut err| {
+            err.leave_call();
+            err
+        })
     }
 }
 
@@ -92,21 +224,45 @@ impl FormalParameters {
     ) -> Result<(), FruError> {
         let mut next_positional = 0;
 
-        let acceptable: HashSet<_> = self.args.iter().map(|(x, _)| *x).collect();
+        let acceptable: HashSet<_> = self.args.iter().map(|x| x.ident).collect();
+
+        let mut rest = Vec::new();
+        let mut kwargs: Vec<(String, FruValue)> = Vec::new();
 
         for (ident, value) in evaluated.args {
             let ident = match ident {
+                Some(ident) if acceptable.contains(&ident) => ident,
+
                 Some(ident) => {
-                    if !acceptable.contains(&ident) {
-                        return Err(ArgumentError::DoesNotExist { ident }.into());
+                    if self.kwargs.is_none() {
+                        let name = ident.to_string();
+                        let names: Vec<_> = self.args.iter().map(|x| x.ident.to_string()).collect();
+
+                        let suggestion = did_you_mean(&name, names.iter().map(String::as_str))
+                            .map(Identifier::new);
+
+                        return Err(ArgumentError::DoesNotExist { ident, suggestion }.into());
+                    }
+
+                    let key = ident.to_string();
+                    if kwargs.iter().any(|(k, _)| *k == key) {
+                        return Err(ArgumentError::SameSetTwice { ident }.into());
                     }
-                    ident
+
+                    kwargs.push((key, value));
+                    continue;
                 }
+
                 None => {
                     if next_positional >= self.args.len() {
-                        return Err(ArgumentError::TooMany.into());
+                        if self.rest.is_none() {
+                            return Err(ArgumentError::TooMany.into());
+                        }
+
+                        rest.push(value);
+                        continue;
                     }
-                    let r = self.args[next_positional].0;
+                    let r = self.args[next_positional].ident;
                     next_positional += 1;
                     r
                 }
@@ -117,12 +273,20 @@ impl FormalParameters {
                 .map_err(|_| ArgumentError::SameSetTwice { ident })?;
         }
 
-        for (ident, value) in self.args.iter().skip(next_positional) {
+        if let Some(ident) = self.rest {
+            scope.let_variable(ident, FruList::new_value(rest))?;
+        }
+
+        if let Some(ident) = self.kwargs {
+            scope.let_variable(ident, FruMap::new_value(kwargs))?;
+        }
+
+        for FormalParameter { ident, default, .. } in self.args.iter().skip(next_positional) {
             if scope.has_variable(*ident) {
                 continue;
             }
 
-            if let Some(default) = value {
+            if let Some(default) = default {
                 let default = returned(default.evaluate(scope.clone()))?;
 
                 scope.let_variable(*ident, default)?;
@@ -133,11 +297,35 @@ impl FormalParameters {
 
         Ok(())
     }
+
+    pub fn to_strings(&self) -> Vec<String> {
+        let mut res: Vec<_> = self.args.iter().map(ToString::to_string).collect();
+
+        if let Some(rest) = self.rest {
+            res.push(format!("..{}", rest));
+        }
+
+        if let Some(kwargs) = self.kwargs {
+            res.push(format!("**{}", kwargs));
+        }
+
+        res
+    }
 }
 
 impl BuiltinFunction {
-    pub fn new(function: TFnBuiltin) -> Self {
-        Self { function }
+    pub fn new(ident: Identifier, function: TFnBuiltin) -> Self {
+        Self { ident, function }
+    }
+
+    /// Curries builtin with `this` as the first argument, used for methods of native objects
+    pub fn bind(self, this: FruValue) -> FruValue {
+        FruValue::Function(AnyFunction::CurriedFunction(Rc::new(CurriedFunction {
+            saved_args: EvaluatedArgumentList {
+                args: vec![(None, this)],
+            },
+            function: Rc::new(AnyFunction::BuiltinFunction(self)),
+        })))
     }
 
     fn call(&self, args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
@@ -147,7 +335,7 @@ impl BuiltinFunction {
 
 impl CurriedFunction {
     fn call(&self, args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
-        let mut new_args = self.saved_args.clone();
+        let mut new_args = self.saved_args.fru_clone();
         new_args.args.extend(args.args);
 
         match &*self.function {
@@ -160,12 +348,61 @@ impl CurriedFunction {
     }
 }
 
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgumentError::TooMany => write!(f, "too many arguments"),
+            ArgumentError::SameSetTwice { ident } => write!(f, "argument `{}` is set twice", ident),
+            ArgumentError::NotSetPositional { ident } => write!(f, "missing argument `{}`", ident),
+            ArgumentError::DoesNotExist { ident, .. } => write!(f, "unknown argument `{}`", ident),
+        }
+    }
+}
+
+impl Display for FormalParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ident)?;
+
+        match (&self.default, &self.default_text) {
+            (_, Some(text)) => write!(f, " = {}", text),
+            (Some(_), None) => write!(f, " = ..."),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Display for FormalParameters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_strings().join(", "))
+    }
+}
+
 impl Debug for AnyFunction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyFunction::Function(_) | AnyFunction::BuiltinFunction(_) => write!(f, "Function"),
+            AnyFunction::Function(func) => match func.ident {
+                Some(ident) => write!(f, "fn {}({})", ident, func.parameters),
+                None => write!(f, "fn({})", func.parameters),
+            },
+
+            AnyFunction::BuiltinFunction(func) => write!(f, "builtin fn {}", func.ident),
+
             AnyFunction::CurriedFunction(func) => {
-                write!(f, "CurriedFunction({})", func.saved_args.args.len(),)
+                write!(f, "{:?}$(", func.function)?;
+
+                for (k, (ident, value)) in func.saved_args.args.iter().enumerate() {
+                    if k > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    if let Some(ident) = ident {
+                        write!(f, "{}: ", ident)?;
+                    }
+
+                    write!(f, "{:?}", value)?;
+                }
+
+                write!(f, ")")
             }
         }
     }
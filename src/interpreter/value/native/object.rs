@@ -1,7 +1,11 @@
-use std::{any::Any, rc::Rc};
+use std::{
+    any::Any,
+    fmt::{Debug, Formatter},
+    rc::Rc,
+};
 
 use crate::interpreter::{
-    error::FruError,
+    error::{ErrorKind, FruError},
     identifier::id,
     identifier::Identifier,
     value::{fru_value::FruValue, function::EvaluatedArgumentList},
@@ -15,38 +19,59 @@ pub trait INativeObject {
     }
 
     fn call(&self, _args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
-        FruError::new_res(format!(
-            "`{}` is not invokable ",
-            self.get_type_identifier()
-        ))
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("`{}` is not invokable ", self.get_type_identifier()),
+        )
     }
 
     fn curry_call(&self, _args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
-        FruError::new_res(format!("`{}` is not invokable", self.get_type_identifier()))
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("`{}` is not invokable", self.get_type_identifier()),
+        )
     }
 
     fn instantiate(&self, _args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
-        FruError::new_res(format!(
-            "`{}` is not instantiatable",
-            self.get_type_identifier()
-        ))
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("`{}` is not instantiatable", self.get_type_identifier()),
+        )
     }
 
     fn get_prop(&self, _ident: Identifier) -> Result<FruValue, FruError> {
-        FruError::new_res(format!(
-            "cannot access prop of `{}`",
-            self.get_type_identifier()
-        ))
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("cannot access prop of `{}`", self.get_type_identifier()),
+        )
     }
 
     fn set_prop(&self, _ident: Identifier, _value: FruValue) -> Result<(), FruError> {
-        FruError::new_res(format!(
-            "cannot set prop of `{}`",
-            self.get_type_identifier()
-        ))
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("cannot set prop of `{}`", self.get_type_identifier()),
+        )
+    }
+
+    fn get_index(&self, _index: FruValue) -> Result<FruValue, FruError> {
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("cannot index `{}`", self.get_type_identifier()),
+        )
+    }
+
+    fn set_index(&self, _index: FruValue, _value: FruValue) -> Result<(), FruError> {
+        FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("cannot set index of `{}`", self.get_type_identifier()),
+        )
     }
 
     fn fru_clone(self: Rc<Self>) -> Rc<dyn INativeObject>;
+
+    fn repr(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{{}}", self.get_type_identifier())
+    }
 }
 
 #[derive(Clone)]
@@ -83,6 +108,14 @@ impl NativeObject {
         self.internal.set_prop(ident, value)
     }
 
+    pub fn get_index(&self, index: FruValue) -> Result<FruValue, FruError> {
+        self.internal.get_index(index)
+    }
+
+    pub fn set_index(&self, index: FruValue, value: FruValue) -> Result<(), FruError> {
+        self.internal.set_index(index, value)
+    }
+
     pub fn fru_clone(&self) -> FruValue {
         FruValue::NativeObject(NativeObject {
             internal: self.internal.clone().fru_clone(),
@@ -93,3 +126,9 @@ impl NativeObject {
         self.internal.as_any().downcast_ref::<T>()
     }
 }
+
+impl Debug for NativeObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.internal.repr(f)
+    }
+}
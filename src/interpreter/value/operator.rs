@@ -34,7 +34,10 @@ impl AnyOperator {
                 new_scope.let_variable(*left_ident, left_val)?;
                 new_scope.let_variable(*right_ident, right_val)?;
 
-                returned_unit(body.execute(new_scope))
+                returned_unit(body.execute(new_scope)).map_err(|mut err| {
+                    err.leave_call();
+                    err
+                })
             }
 
             AnyOperator::BuiltinOperator(op) => op(left_val, right_val),
@@ -0,0 +1,627 @@
+use tree_sitter::{Node, Point, Tree};
+
+// guards type inference against cycles like `let a = b; let b = a;`
+const MAX_INFERENCE_DEPTH: usize = 32;
+
+/// Name resolution over the concrete syntax tree,
+/// works on code with syntax errors, as long as tree-sitter managed to recover
+pub struct Analysis<'a> {
+    source: &'a str,
+    root: Node<'a>,
+}
+
+/// Type declaration, that an expression evaluates to
+#[derive(Clone, Copy)]
+struct KnownType<'a> {
+    declaration: Node<'a>,
+    is_static: bool, // expression is the type itself, not its instance
+}
+
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: MemberKind,
+    pub detail: String,
+}
+
+pub enum MemberKind {
+    Field,
+    Property,
+    Method,
+}
+
+impl<'a> Analysis<'a> {
+    pub fn new(tree: &'a Tree, source: &'a str) -> Self {
+        Self {
+            source,
+            root: tree.root_node(),
+        }
+    }
+
+    pub fn text(&self, node: Node) -> &'a str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+
+    /// Identifier or operator under the cursor, cursor right after the name also counts
+    pub fn name_at(&self, point: Point) -> Option<Node<'a>> {
+        let before = Point::new(point.row, point.column.saturating_sub(1));
+
+        [point, before].into_iter().find_map(|p| {
+            let node = self.root.descendant_for_point_range(p, p)?;
+
+            (matches!(node.kind(), "identifier" | "operator") || is_binary_operator(node))
+                .then_some(node)
+        })
+    }
+
+    /// Declarations, that the name refers to
+    pub fn definition(&self, name: Node<'a>) -> Vec<Node<'a>> {
+        let Some(parent) = name.parent() else {
+            return Vec::new();
+        };
+
+        if let Some(declaration) = self.declaration_of(name) {
+            return vec![declaration];
+        }
+
+        match (parent.kind(), field_name(parent, name)) {
+            ("variable", _) | ("set_expression", Some("ident")) => {
+                self.resolve_variable(self.text(name), name).into_iter().collect()
+            }
+
+            ("prop_access_expression" | "set_prop_expression", Some("ident")) => {
+                self.resolve_member(parent.child_by_field_name("what"), self.text(name))
+            }
+
+            ("named_argument", Some("ident")) => {
+                let instantiation = parent.parent().and_then(|x| x.parent());
+
+                match instantiation {
+                    Some(x) if x.kind() == "instantiation_expression" => {
+                        self.resolve_member(x.child_by_field_name("what"), self.text(name))
+                    }
+                    _ => Vec::new(),
+                }
+            }
+
+            ("binary_expression", Some("operator")) => self.resolve_operator(parent),
+
+            (_, Some("type_ident" | "left_type_ident" | "right_type_ident")) => {
+                self.resolve_type(self.text(name), name).into_iter().collect()
+            }
+
+            _ => Vec::new(),
+        }
+    }
+
+    /// Markdown description of a declaration
+    pub fn hover(&self, declaration: Node<'a>) -> String {
+        let name = self.text(name_of(declaration));
+
+        let text = match declaration.kind() {
+            "let_statement" => match declaration.child_by_field_name("value") {
+                Some(value) if value.kind() == "function_expression" => {
+                    format!("fn {}{}", name, self.parameters_of(value))
+                }
+                Some(value) => format!("let {} = {}", name, self.short_text(value)),
+                None => format!("let {}", name),
+            },
+
+            "function_statement" | "function_expression" => {
+                format!("fn {}{}", name, self.parameters_of(declaration))
+            }
+
+            "type_statement" => self.describe_type(declaration),
+
+            "enum_statement" => {
+                let variants: Vec<_> = children_by_field(declaration, "variants")
+                    .into_iter()
+                    .map(|x| normalize(self.text(x)))
+                    .collect();
+
+                format!("enum {} {{ {} }}", name, variants.join(", "))
+            }
+
+            "type_field" | "type_property" | "type_method" => {
+                format!(
+                    "({}) {}",
+                    member_kind_name(declaration),
+                    self.describe_member(declaration)
+                )
+            }
+
+            "operator_statement" => {
+                let body = declaration.child_by_field_name("body").map_or(0, |x| x.start_byte());
+                let header = &self.source[declaration.start_byte()..body];
+
+                normalize(header)
+            }
+
+            "positional_parameter"
+            | "default_parameter"
+            | "rest_parameter"
+            | "kwargs_parameter" => {
+                format!("(parameter) {}", self.short_text(declaration))
+            }
+
+            _ => format!("(parameter) {}", name),
+        };
+
+        format!("```frugurt\n{}\n```", text)
+    }
+
+    /// Members of a known type, accessible after `.` or `?.` typed right before the cursor
+    pub fn completion(&self, point: Point) -> Vec<CompletionItem> {
+        let Some(line) = self.source.split('\n').nth(point.row) else {
+            return Vec::new();
+        };
+
+        let prefix = &line[..point.column.min(line.len())];
+        let prefix = prefix.trim_end_matches(is_identifier_char);
+
+        let Some(before_dot) = prefix.strip_suffix('.') else {
+            return Vec::new();
+        };
+
+        let before_dot = before_dot.strip_suffix('?').unwrap_or(before_dot);
+
+        let receiver = &before_dot[before_dot.trim_end_matches(is_identifier_char).len()..];
+
+        if receiver.is_empty() {
+            return Vec::new();
+        }
+
+        let receiver_point = Point::new(point.row, before_dot.len() - 1);
+
+        let declaration = self
+            .root
+            .descendant_for_point_range(receiver_point, receiver_point)
+            .and_then(|node| self.resolve_variable(receiver, node))
+            .or_else(|| self.find_declaration(receiver));
+
+        let Some(known) = declaration.and_then(|x| self.type_of_declaration(x, 0)) else {
+            return Vec::new();
+        };
+
+        self.members(known.declaration)
+            .into_iter()
+            .filter(|member| !known.is_static || is_static(*member))
+            .map(|member| CompletionItem {
+                label: self.text(name_of(member)).to_string(),
+                kind: match member.kind() {
+                    "type_field" | "type_embed" => MemberKind::Field,
+                    "type_property" => MemberKind::Property,
+                    _ => MemberKind::Method,
+                },
+                detail: self.describe_member(member),
+            })
+            .collect()
+    }
+
+    /// All `struct`, `class` and `data` declarations in the document
+    pub fn types(&self) -> Vec<Node<'a>> {
+        descendants(self.root)
+            .into_iter()
+            .filter(|x| x.kind() == "type_statement")
+            .collect()
+    }
+
+    /// Fields and properties in declaration order, followed by methods
+    pub fn members(&self, type_statement: Node<'a>) -> Vec<Node<'a>> {
+        let mut res = children_by_field(type_statement, "members");
+
+        if let Some(type_impl) = type_statement.child_by_field_name("impl") {
+            res.extend(children_by_field(type_impl, "methods"));
+        }
+
+        res
+    }
+
+    fn declaration_of(&self, name: Node<'a>) -> Option<Node<'a>> {
+        let parent = name.parent()?;
+
+        match (parent.kind(), field_name(parent, name)?) {
+            (
+                "let_statement"
+                | "function_statement"
+                | "function_expression"
+                | "type_statement"
+                | "enum_statement"
+                | "type_method"
+                | "positional_parameter"
+                | "default_parameter"
+                | "rest_parameter"
+                | "kwargs_parameter"
+                | "operator_statement",
+                "ident",
+            ) => Some(parent),
+
+            ("operator_statement", "left_ident" | "right_ident") => Some(name),
+
+            ("maybe_typed_identifier", "ident") => parent.parent(),
+
+            _ => None,
+        }
+    }
+
+    /// Lexical lookup of a variable visible from `from`, last declaration wins
+    fn resolve_variable(&self, name: &str, from: Node<'a>) -> Option<Node<'a>> {
+        let mut child = from;
+
+        while let Some(parent) = child.parent() {
+            match parent.kind() {
+                "source_file"
+                | "block_statement"
+                | "block_expression"
+                | "scope_modifier_statement" => {
+                    let found = children_by_field(parent, "body")
+                        .into_iter()
+                        .take_while(|x| x.start_byte() <= from.start_byte())
+                        .filter(|x| {
+                            matches!(
+                                x.kind(),
+                                "let_statement"
+                                    | "function_statement"
+                                    | "type_statement"
+                                    | "enum_statement"
+                            )
+                        })
+                        .filter(|x| self.text(name_of(*x)) == name)
+                        .filter(|x| x.end_byte() <= from.start_byte() || can_refer_to_itself(*x))
+                        .last();
+
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+
+                "function_expression" | "function_statement" | "type_method" => {
+                    let parameters = parent.child_by_field_name("parameters");
+
+                    let found = parameters
+                        .map(|x| children_by_field(x, "args"))
+                        .unwrap_or_default()
+                        .into_iter()
+                        .find(|x| self.text(name_of(*x)) == name);
+
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+
+                "operator_statement" => {
+                    let found = ["left_ident", "right_ident"]
+                        .into_iter()
+                        .filter_map(|field| parent.child_by_field_name(field))
+                        .find(|x| self.text(*x) == name);
+
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+
+                "type_property_item" => {
+                    let found = parent
+                        .child_by_field_name("value_ident")
+                        .map(name_of)
+                        .filter(|x| self.text(*x) == name);
+
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+
+                // fields and methods are visible inside methods and properties
+                "type_statement" => {
+                    let found =
+                        self.members(parent).into_iter().find(|x| self.text(name_of(*x)) == name);
+
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+
+                _ => {}
+            }
+
+            child = parent;
+        }
+
+        None
+    }
+
+    fn resolve_type(&self, name: &str, from: Node<'a>) -> Option<Node<'a>> {
+        match self.resolve_variable(name, from) {
+            Some(x) if x.kind() == "type_statement" => Some(x),
+            _ => self.types().into_iter().find(|x| self.text(name_of(*x)) == name),
+        }
+    }
+
+    /// Members of the type of `what`, or members of all types, if the type can not be inferred
+    fn resolve_member(&self, what: Option<Node<'a>>, name: &str) -> Vec<Node<'a>> {
+        match what.and_then(|x| self.infer_type(x, 0)) {
+            Some(known) => self
+                .members(known.declaration)
+                .into_iter()
+                .filter(|x| self.text(name_of(*x)) == name)
+                .filter(|x| !known.is_static || is_static(*x))
+                .collect(),
+
+            None => self
+                .types()
+                .into_iter()
+                .flat_map(|x| self.members(x))
+                .filter(|x| self.text(name_of(*x)) == name)
+                .collect(),
+        }
+    }
+
+    /// Operator declarations, that match operand types, where they are known
+    fn resolve_operator(&self, binary_expression: Node<'a>) -> Vec<Node<'a>> {
+        let Some(operator) = binary_expression.child_by_field_name("operator") else {
+            return Vec::new();
+        };
+
+        let operator = self.text(operator);
+
+        let left = binary_expression.child_by_field_name("left").and_then(|x| self.type_name(x));
+        let right = binary_expression.child_by_field_name("right").and_then(|x| self.type_name(x));
+
+        // `Any` operand accepts every type
+        let fits_side = |known: &Option<String>, side: Node| {
+            let declared = self.text(side);
+            declared == "Any" || known.as_ref().is_none_or(|x| x == declared)
+        };
+
+        let fits = |l: Node, r: Node| fits_side(&left, l) && fits_side(&right, r);
+
+        descendants(self.root)
+            .into_iter()
+            .filter(|x| x.kind() == "operator_statement")
+            .filter(|x| x.child_by_field_name("ident").map(|x| self.text(x)) == Some(operator))
+            .filter(|x| {
+                let (Some(l), Some(r)) = (
+                    x.child_by_field_name("left_type_ident"),
+                    x.child_by_field_name("right_type_ident"),
+                ) else {
+                    return false;
+                };
+
+                fits(l, r) || (x.child_by_field_name("commutative").is_some() && fits(r, l))
+            })
+            .collect()
+    }
+
+    fn type_name(&self, expression: Node<'a>) -> Option<String> {
+        match expression.kind() {
+            "number_literal" => Some("Number".to_string()),
+            "string_literal" | "format_string_literal" => Some("String".to_string()),
+            "bool_literal" => Some("Bool".to_string()),
+            "nah_literal" => Some("Nah".to_string()),
+            _ => match self.infer_type(expression, 0)? {
+                KnownType {
+                    is_static: false,
+                    declaration,
+                } => Some(self.text(name_of(declaration)).to_string()),
+                _ => None,
+            },
+        }
+    }
+
+    fn infer_type(&self, expression: Node<'a>, depth: usize) -> Option<KnownType<'a>> {
+        if depth > MAX_INFERENCE_DEPTH {
+            return None;
+        }
+
+        match expression.kind() {
+            "variable" => {
+                let ident = expression.child_by_field_name("ident")?;
+                let declaration = self.resolve_variable(self.text(ident), expression)?;
+
+                self.type_of_declaration(declaration, depth + 1)
+            }
+
+            "instantiation_expression" => {
+                let known = self.infer_type(expression.child_by_field_name("what")?, depth + 1)?;
+
+                known.is_static.then_some(KnownType {
+                    is_static: false,
+                    ..known
+                })
+            }
+
+            "parenthesized_expression" => {
+                self.infer_type(expression.child_by_field_name("expr")?, depth + 1)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn type_of_declaration(&self, declaration: Node<'a>, depth: usize) -> Option<KnownType<'a>> {
+        match declaration.kind() {
+            "type_statement" => Some(KnownType {
+                declaration,
+                is_static: true,
+            }),
+
+            "let_statement" => self.infer_type(declaration.child_by_field_name("value")?, depth),
+
+            "positional_parameter" | "default_parameter" => {
+                match declaration.child_by_field_name("type_ident") {
+                    Some(type_ident) => Some(KnownType {
+                        declaration: self.resolve_type(self.text(type_ident), declaration)?,
+                        is_static: false,
+                    }),
+
+                    None => self.infer_type(declaration.child_by_field_name("value")?, depth),
+                }
+            }
+
+            _ => None,
+        }
+    }
+
+    fn find_declaration(&self, name: &str) -> Option<Node<'a>> {
+        descendants(self.root)
+            .into_iter()
+            .filter(|x| {
+                matches!(
+                    x.kind(),
+                    "let_statement" | "function_statement" | "type_statement" | "enum_statement"
+                )
+            })
+            .find(|x| self.text(name_of(*x)) == name)
+    }
+
+    fn describe_type(&self, type_statement: Node<'a>) -> String {
+        let type_type =
+            type_statement.child_by_field_name("type_type").map_or("", |x| self.text(x));
+        let name = self.text(name_of(type_statement));
+
+        let mut res = format!("{} {} {{\n", type_type, name);
+
+        for member in children_by_field(type_statement, "members") {
+            res += &format!("    {}\n", self.describe_member(member));
+        }
+
+        res += "}";
+
+        if let Some(type_impl) = type_statement.child_by_field_name("impl") {
+            res += " impl {\n";
+
+            for method in children_by_field(type_impl, "methods") {
+                res += &format!("    {}\n", self.describe_member(method));
+            }
+
+            res += "}";
+        }
+
+        res
+    }
+
+    fn describe_member(&self, member: Node<'a>) -> String {
+        let name = self.text(name_of(member));
+        let modifiers = ["pub", "static"]
+            .into_iter()
+            .filter(|x| member.child_by_field_name(x).is_some())
+            .map(|x| format!("{} ", x))
+            .collect::<String>();
+
+        match member.kind() {
+            "type_method" => format!("{}{}{}", modifiers, name, self.parameters_of(member)),
+
+            "type_property" => format!("{}{} {{ ... }}", modifiers, name),
+
+            "type_embed" => format!("embed {};", name),
+
+            _ => match member.child_by_field_name("value") {
+                Some(value) => format!("{}{} = {};", modifiers, name, self.short_text(value)),
+                None => format!("{}{};", modifiers, name),
+            },
+        }
+    }
+
+    fn parameters_of(&self, function: Node<'a>) -> String {
+        function
+            .child_by_field_name("parameters")
+            .map_or("()".to_string(), |x| normalize(self.text(x)))
+    }
+
+    fn short_text(&self, node: Node<'a>) -> String {
+        let text = normalize(self.text(node));
+
+        if text.chars().count() > 60 {
+            text.chars().take(57).collect::<String>() + "..."
+        } else {
+            text
+        }
+    }
+}
+
+/// Identifier node, that names a declaration
+pub fn name_of(declaration: Node) -> Node {
+    match declaration.child_by_field_name("ident") {
+        Some(ident) if ident.kind() == "maybe_typed_identifier" => {
+            ident.child_by_field_name("ident").unwrap_or(ident)
+        }
+        Some(ident) => ident,
+        None => declaration,
+    }
+}
+
+pub fn children_by_field<'a>(node: Node<'a>, field: &str) -> Vec<Node<'a>> {
+    let mut cursor = node.walk();
+    node.children_by_field_name(field, &mut cursor).collect()
+}
+
+fn descendants(root: Node) -> Vec<Node> {
+    let mut res = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        res.push(node);
+
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor).collect::<Vec<_>>().into_iter().rev());
+    }
+
+    res
+}
+
+fn field_name<'a>(parent: Node<'a>, child: Node<'a>) -> Option<&'static str> {
+    let mut cursor = parent.walk();
+
+    if !cursor.goto_first_child() {
+        return None;
+    }
+
+    loop {
+        if cursor.node() == child {
+            return cursor.field_name();
+        }
+
+        if !cursor.goto_next_sibling() {
+            return None;
+        }
+    }
+}
+
+fn is_binary_operator(node: Node) -> bool {
+    node.parent().is_some_and(|parent| {
+        parent.kind() == "binary_expression" && parent.child_by_field_name("operator") == Some(node)
+    })
+}
+
+// functions may call themselves, because they are invoked after the variable is set
+fn can_refer_to_itself(declaration: Node) -> bool {
+    match declaration.kind() {
+        "let_statement" => declaration
+            .child_by_field_name("value")
+            .is_some_and(|x| x.kind() == "function_expression"),
+        _ => true,
+    }
+}
+
+fn is_static(member: Node) -> bool {
+    member.child_by_field_name("static").is_some()
+}
+
+fn member_kind_name(member: Node) -> &'static str {
+    match (member.kind(), is_static(member)) {
+        ("type_field", false) => "field",
+        ("type_field", true) => "static field",
+        ("type_property", false) => "property",
+        ("type_property", true) => "static property",
+        ("type_embed", _) => "embedded",
+        (_, false) => "method",
+        (_, true) => "static method",
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
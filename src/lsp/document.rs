@@ -0,0 +1,60 @@
+use lsp_types::{Position, Range};
+use tree_sitter::{Node, Point, Tree};
+
+use crate::interpreter::tree_sitter_parser::parse_tree;
+
+/// Opened text document, LSP positions are in UTF-16 code units, tree-sitter points are in bytes
+pub struct Document {
+    pub text: String,
+    pub tree: Tree,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        let tree = parse_tree(&text);
+
+        Self { text, tree }
+    }
+
+    pub fn to_point(&self, position: Position) -> Point {
+        let line = self.line(position.line as usize);
+
+        let mut utf16 = 0;
+        let mut column = 0;
+
+        for c in line.chars() {
+            if utf16 >= position.character as usize {
+                break;
+            }
+
+            utf16 += c.len_utf16();
+            column += c.len_utf8();
+        }
+
+        Point::new(position.line as usize, column)
+    }
+
+    pub fn to_position(&self, point: Point) -> Position {
+        let line = self.line(point.row);
+        let column = point.column.min(line.len());
+
+        let character = line[..column].chars().map(char::len_utf16).sum::<usize>();
+
+        Position::new(point.row as u32, character as u32)
+    }
+
+    pub fn to_range(&self, range: tree_sitter::Range) -> Range {
+        Range::new(
+            self.to_position(range.start_point),
+            self.to_position(range.end_point),
+        )
+    }
+
+    pub fn node_range(&self, node: Node) -> Range {
+        self.to_range(node.range())
+    }
+
+    fn line(&self, row: usize) -> &str {
+        self.text.split('\n').nth(row).unwrap_or("")
+    }
+}
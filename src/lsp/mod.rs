@@ -0,0 +1,303 @@
+use std::{collections::HashMap, error::Error};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as _},
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse,
+    Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, Location, MarkupContent, MarkupKind, NumberOrString, OneOf,
+    PublishDiagnosticsParams, ServerCapabilities, SymbolKind, TextDocumentSyncCapability,
+    TextDocumentSyncKind, Url,
+};
+use serde_json::{from_value, to_value};
+use tree_sitter::Node;
+
+use crate::interpreter::tree_sitter_parser;
+use crate::lsp::{
+    analysis::{children_by_field, name_of, Analysis, MemberKind},
+    document::Document,
+};
+
+mod analysis;
+mod document;
+
+type LspResult<T> = Result<T, Box<dyn Error + Sync + Send>>;
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+}
+
+/// Runs language server over stdio until the client asks to shut down
+pub fn run() -> LspResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = to_value(ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![".".to_string()]),
+            ..Default::default()
+        }),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    })?;
+
+    connection.initialize(capabilities)?;
+
+    let mut server = Server {
+        connection,
+        documents: HashMap::new(),
+    };
+
+    server.main_loop()?;
+
+    drop(server);
+    io_threads.join()?;
+
+    Ok(())
+}
+
+impl Server {
+    fn main_loop(&mut self) -> LspResult<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+
+                    self.handle_request(request)?;
+                }
+
+                Message::Notification(notification) => self.handle_notification(notification)?,
+
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> LspResult<()> {
+        let id = request.id.clone();
+
+        let params = request.params;
+
+        let result = match request.method.as_str() {
+            GotoDefinition::METHOD => from_value(params).map(|x| to_value(self.definition(x))),
+            HoverRequest::METHOD => from_value(params).map(|x| to_value(self.hover(x))),
+            Completion::METHOD => from_value(params).map(|x| to_value(self.completion(x))),
+            DocumentSymbolRequest::METHOD => from_value(params).map(|x| to_value(self.symbols(x))),
+
+            method => {
+                let message = format!("method `{}` is not supported", method);
+                let response = Response::new_err(id, ErrorCode::MethodNotFound as i32, message);
+
+                return Ok(self.connection.sender.send(response.into())?);
+            }
+        };
+
+        // malformed params are reported to the client, the server keeps running
+        let response = match result {
+            Ok(result) => Response::new_ok(id, result?),
+
+            Err(err) => {
+                let message = format!("invalid params: {}", err);
+
+                Response::new_err(id, ErrorCode::InvalidParams as i32, message)
+            }
+        };
+
+        Ok(self.connection.sender.send(response.into())?)
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> LspResult<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = from_value(notification.params)?;
+
+                self.update(params.text_document.uri, params.text_document.text)
+            }
+
+            DidChangeTextDocument::METHOD => {
+                let mut params: DidChangeTextDocumentParams = from_value(notification.params)?;
+
+                // full sync, last change contains the whole text
+                match params.content_changes.pop() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => Ok(()),
+                }
+            }
+
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = from_value(notification.params)?;
+
+                self.documents.remove(&params.text_document.uri);
+                self.publish_diagnostics(params.text_document.uri, Vec::new())
+            }
+
+            _ => Ok(()),
+        }
+    }
+
+    fn update(&mut self, uri: Url, text: String) -> LspResult<()> {
+        let document = Document::new(text);
+
+        let diagnostics = match tree_sitter_parser::parse(document.text.clone()) {
+            Ok(_) => Vec::new(),
+
+            Err(err) => err
+                .errors()
+                .into_iter()
+                .map(|x| Diagnostic {
+                    range: document.to_range(x.position),
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    code: Some(NumberOrString::String(err.kind().code().to_string())),
+                    source: Some("frugurt".to_string()),
+                    message: match x.note {
+                        Some(note) => format!("{}\n{}", x.message, note),
+                        None => x.message,
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+        };
+
+        self.documents.insert(uri.clone(), document);
+
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<Diagnostic>) -> LspResult<()> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+
+        Ok(self.connection.sender.send(notification.into())?)
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let analysis = Analysis::new(&document.tree, &document.text);
+
+        let point = document.to_point(params.text_document_position_params.position);
+        let name = analysis.name_at(point)?;
+
+        let locations: Vec<_> = analysis
+            .definition(name)
+            .into_iter()
+            .map(|x| Location::new(uri.clone(), document.node_range(name_of(x))))
+            .collect();
+
+        (!locations.is_empty()).then_some(GotoDefinitionResponse::Array(locations))
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let document = self.documents.get(&uri)?;
+        let analysis = Analysis::new(&document.tree, &document.text);
+
+        let point = document.to_point(params.text_document_position_params.position);
+        let name = analysis.name_at(point)?;
+
+        let text = analysis
+            .definition(name)
+            .into_iter()
+            .map(|x| analysis.hover(x))
+            .collect::<Vec<_>>()
+            .join("\n\n---\n\n");
+
+        (!text.is_empty()).then(|| Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: text,
+            }),
+            range: Some(document.node_range(name)),
+        })
+    }
+
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let document = self.documents.get(&params.text_document_position.text_document.uri)?;
+        let analysis = Analysis::new(&document.tree, &document.text);
+
+        let point = document.to_point(params.text_document_position.position);
+
+        let items = analysis
+            .completion(point)
+            .into_iter()
+            .map(|item| CompletionItem {
+                label: item.label,
+                kind: Some(match item.kind {
+                    MemberKind::Field => CompletionItemKind::FIELD,
+                    MemberKind::Property => CompletionItemKind::PROPERTY,
+                    MemberKind::Method => CompletionItemKind::METHOD,
+                }),
+                detail: Some(item.detail),
+                ..Default::default()
+            })
+            .collect();
+
+        Some(CompletionResponse::Array(items))
+    }
+
+    fn symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        let analysis = Analysis::new(&document.tree, &document.text);
+
+        let symbol = |node: Node, kind, detail: Option<&str>, children| {
+            #[allow(deprecated)] // `deprecated` field is deprecated itself
+            DocumentSymbol {
+                name: analysis.text(name_of(node)).to_string(),
+                detail: detail.map(str::to_string),
+                kind,
+                tags: None,
+                deprecated: None,
+                range: document.node_range(node),
+                selection_range: document.node_range(name_of(node)),
+                children,
+            }
+        };
+
+        let symbols = analysis
+            .types()
+            .into_iter()
+            .map(|type_statement| {
+                let type_type = type_statement.child_by_field_name("type_type");
+                let type_type = type_type.map(|x| analysis.text(x));
+
+                let mut members: Vec<_> = children_by_field(type_statement, "members")
+                    .into_iter()
+                    .map(|member| match member.kind() {
+                        "type_property" => symbol(member, SymbolKind::PROPERTY, None, None),
+                        _ => symbol(member, SymbolKind::FIELD, None, None),
+                    })
+                    .collect();
+
+                if let Some(type_impl) = type_statement.child_by_field_name("impl") {
+                    members.extend(
+                        children_by_field(type_impl, "methods")
+                            .into_iter()
+                            .map(|method| symbol(method, SymbolKind::METHOD, None, None)),
+                    );
+                }
+
+                let kind = match type_type {
+                    Some("class") => SymbolKind::CLASS,
+                    _ => SymbolKind::STRUCT,
+                };
+
+                symbol(type_statement, kind, type_type, Some(members))
+            })
+            .collect();
+
+        Some(DocumentSymbolResponse::Nested(symbols))
+    }
+}
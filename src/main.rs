@@ -1,32 +1,307 @@
-use std::{path::PathBuf, time::Instant};
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
-use crate::interpreter::runner::execute_file;
-use clap::Parser;
+use crate::checker::lint::{lint_source, LintConfig};
+use crate::coverage::Coverage;
+use crate::debugger::{cli::Console, debug_file, Debugger};
+use crate::diagnostic::Diagnostic;
+use crate::dump::{dump_ast, dump_cst};
+use crate::interpreter::{
+    error::FruError,
+    runner::{execute_file, execute_tests},
+};
+use crate::profiler::profile_file;
+use clap::{ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
 
+mod checker;
+mod coverage;
+mod debugger;
+mod diagnostic;
+mod dump;
+mod formatter;
 mod interpreter;
+mod lsp;
+mod profiler;
 mod stdlib;
 
+const LINT_CONFIG: &str = "frugurt-lint.json";
+const LCOV_FILE: &str = "lcov.info";
+
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
 struct Args {
-    #[clap(required = true, help = "File to execute")]
-    filename: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[clap(help = "File to execute")]
+    filename: Option<PathBuf>,
 
     #[clap(short, long, help = "Print execution time")]
     time: bool,
+
+    #[clap(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "profile.folded",
+        help = "Print time spent in every function and write folded stacks to FILE, `profile.folded` by default"
+    )]
+    profile: Option<PathBuf>,
+
+    #[clap(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = LCOV_FILE,
+        conflicts_with = "profile",
+        help = "Print executed lines and branches and write LCOV report to FILE, `lcov.info` by default"
+    )]
+    coverage: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = ErrorFormat::Human,
+        help = "How errors and warnings are printed to stderr"
+    )]
+    error_format: ErrorFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ErrorFormat {
+    // message with excerpt of source code
+    Human,
+    // one JSON object per line
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Start language server on stdio")]
+    Lsp,
+
+    #[command(about = "Format source files in place")]
+    Fmt {
+        #[clap(required = true, help = "Files to format")]
+        files: Vec<PathBuf>,
+
+        #[clap(
+            long,
+            help = "Only check, that files are formatted, do not change them"
+        )]
+        check: bool,
+    },
+
+    #[command(about = "Check source files for errors without running them")]
+    Check {
+        #[clap(required = true, help = "Files to check")]
+        files: Vec<PathBuf>,
+    },
+
+    #[command(about = "Report likely mistakes in source files")]
+    Lint {
+        #[clap(required = true, help = "Files to lint")]
+        files: Vec<PathBuf>,
+
+        #[clap(
+            long,
+            help = "Config with rules to turn off, `frugurt-lint.json` by default"
+        )]
+        config: Option<PathBuf>,
+    },
+
+    #[command(about = "Run tests, declared in source files")]
+    Test {
+        #[clap(help = "Files and directories with tests, current directory by default")]
+        paths: Vec<PathBuf>,
+
+        #[clap(long, help = "Run only tests, which names contain this string")]
+        filter: Option<String>,
+
+        #[clap(
+            long,
+            value_name = "FILE",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = LCOV_FILE,
+            help = "Print executed lines and branches and write LCOV report to FILE, `lcov.info` by default"
+        )]
+        coverage: Option<PathBuf>,
+    },
+
+    #[command(
+        about = "Print syntax tree of a file",
+        group = ArgGroup::new("tree").required(true).args(["cst", "ast"])
+    )]
+    Parse {
+        #[clap(help = "File to parse")]
+        file: PathBuf,
+
+        #[clap(long, help = "Print concrete syntax tree as S-expression")]
+        cst: bool,
+
+        #[clap(long, help = "Print abstract syntax tree as JSON")]
+        ast: bool,
+    },
+
+    #[command(about = "Execute file step by step")]
+    Debug {
+        #[clap(required_unless_present = "dap", help = "File to debug")]
+        file: Option<PathBuf>,
+
+        #[clap(
+            long = "break",
+            help = "Line to stop at, the program stops at the start, if there are none"
+        )]
+        breakpoints: Vec<usize>,
+
+        #[clap(long, help = "Start Debug Adapter Protocol server on stdio")]
+        dap: bool,
+    },
 }
 
 fn main() {
     let args: Args = Args::parse();
 
+    match args.command {
+        Some(Command::Lsp) => {
+            if let Err(err) = lsp::run() {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Fmt { files, check }) => {
+            if !format_files(files, check, args.error_format) {
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Check { files }) => {
+            if !check_files(files, args.error_format) {
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Lint { files, config }) => {
+            if !lint_files(files, config, args.error_format) {
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Test {
+            paths,
+            filter,
+            coverage,
+        }) => {
+            if !run_tests(
+                paths,
+                filter.unwrap_or_default(),
+                coverage,
+                args.error_format,
+            ) {
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Parse { file, cst, .. }) => {
+            if !parse(file, cst) {
+                std::process::exit(1);
+            }
+        }
+
+        Some(Command::Debug {
+            file,
+            breakpoints,
+            dap,
+        }) => {
+            let ok = match (dap, file) {
+                (true, _) => debugger::dap::run(BufReader::new(io::stdin()), io::stdout())
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok(),
+                (false, Some(file)) => {
+                    debug(file, breakpoints.into_iter().collect(), args.error_format)
+                }
+                (false, None) => unreachable!("file is required without --dap"),
+            };
+
+            if !ok {
+                std::process::exit(1);
+            }
+        }
+
+        None => match args.filename {
+            Some(filename) => execute(
+                filename,
+                args.time,
+                args.profile,
+                args.coverage,
+                args.error_format,
+            ),
+
+            None => {
+                Args::command().print_help().unwrap();
+                std::process::exit(2);
+            }
+        },
+    }
+}
+
+fn execute(
+    filename: PathBuf,
+    time: bool,
+    profile: Option<PathBuf>,
+    coverage: Option<PathBuf>,
+    format: ErrorFormat,
+) {
     let start = Instant::now();
 
-    let result = execute_file(args.filename.as_path());
+    // file, that can not be read, is reported by `execute_file`
+    let source = fs::read_to_string(&filename).unwrap_or_default();
+
+    let result = match (&profile, &coverage) {
+        (Some(folded), _) => {
+            let (result, profile) = profile_file(&filename);
+
+            eprint!("{}", profile.table(&filename));
+
+            if let Err(err) = fs::write(folded, profile.folded()) {
+                eprintln!("{}: {}", folded.display(), err);
+            }
+
+            result
+        }
+
+        (None, Some(lcov)) => {
+            let mut report = Coverage::default();
+            let result = report.cover(&filename, &source, || execute_file(&filename));
+
+            write_coverage(&report, lcov);
+
+            result.map(|_| ())
+        }
+
+        (None, None) => execute_file(filename.as_path()).map(|_| ()),
+    };
 
     if let Err(err) = &result {
-        eprintln!("{}", err);
+        report(
+            format,
+            &filename,
+            &source,
+            &runtime_diagnostics(&filename, err),
+        );
     }
 
-    if args.time {
+    if time {
         println!("Program finished in {}ms", start.elapsed().as_millis());
     }
 
@@ -34,3 +309,338 @@ fn main() {
         std::process::exit(1);
     }
 }
+
+/// Returns `false` if the file could not be read, or AST was requested and could not be built
+fn parse(file: PathBuf, cst: bool) -> bool {
+    let result = fs::read_to_string(&file).map_err(|err| err.to_string()).and_then(|source| {
+        if cst {
+            return Ok(dump_cst(&source));
+        }
+
+        let ast = dump_ast(&source).map_err(|err| err.to_string())?;
+
+        Ok(format!("{:#}\n", ast))
+    });
+
+    match result {
+        Ok(tree) => {
+            print!("{}", tree);
+            true
+        }
+
+        Err(err) => {
+            eprintln!("{}: {}", file.display(), err);
+            false
+        }
+    }
+}
+
+/// Returns `false` if the program failed or was stopped
+fn debug(file: PathBuf, breakpoints: BTreeSet<usize>, format: ErrorFormat) -> bool {
+    let Some(source) = read_source(&file, format) else {
+        return false;
+    };
+
+    let stop_on_entry = breakpoints.is_empty();
+    let breakpoints = breakpoints.into_iter().map(|x| (file.clone(), x)).collect();
+    let console = Console::new(
+        file.clone(),
+        &source,
+        BufReader::new(io::stdin()),
+        io::stdout(),
+    );
+
+    match debug_file(
+        &file,
+        Debugger::new(Box::new(console), breakpoints, stop_on_entry),
+    ) {
+        Ok(()) => true,
+
+        Err(err) => {
+            report(format, &file, &source, &runtime_diagnostics(&file, &err));
+            false
+        }
+    }
+}
+
+/// Returns `false` if some file could not be formatted, or is not formatted in check mode
+fn format_files(files: Vec<PathBuf>, check: bool, format: ErrorFormat) -> bool {
+    let mut ok = true;
+
+    for file in files {
+        let Some(source) = read_source(&file, format) else {
+            ok = false;
+            continue;
+        };
+
+        let formatted = match formatter::format_source(&source) {
+            Ok(formatted) => formatted,
+
+            Err(err) => {
+                report(format, &file, &source, &Diagnostic::syntax(&file, &err));
+                ok = false;
+                continue;
+            }
+        };
+
+        if formatted == source {
+            continue;
+        }
+
+        if check {
+            println!("{} is not formatted", file.display());
+            ok = false;
+            continue;
+        }
+
+        if let Err(err) = fs::write(&file, formatted) {
+            report(format, &file, &source, &[Diagnostic::io(&file, err)]);
+            ok = false;
+        }
+    }
+
+    ok
+}
+
+/// Returns `false` if some file could not be parsed or has errors
+fn check_files(files: Vec<PathBuf>, format: ErrorFormat) -> bool {
+    let mut ok = true;
+
+    for file in files {
+        let Some(source) = read_source(&file, format) else {
+            ok = false;
+            continue;
+        };
+
+        let diagnostics = match checker::check_source(&source) {
+            Ok(errors) => errors.iter().map(|x| Diagnostic::check(&file, x)).collect(),
+            Err(err) => Diagnostic::syntax(&file, &err),
+        };
+
+        report(format, &file, &source, &diagnostics);
+
+        ok &= diagnostics.is_empty();
+    }
+
+    ok
+}
+
+/// Returns `false` if config is invalid, some file could not be parsed or has warnings
+fn lint_files(files: Vec<PathBuf>, config: Option<PathBuf>, format: ErrorFormat) -> bool {
+    let (path, json) = match config {
+        Some(path) => match fs::read_to_string(&path) {
+            Ok(json) => (path, Some(json)),
+
+            Err(err) => {
+                eprintln!("{}: {}", path.display(), err);
+                return false;
+            }
+        },
+
+        None => (
+            PathBuf::from(LINT_CONFIG),
+            fs::read_to_string(LINT_CONFIG).ok(),
+        ),
+    };
+
+    let config = match json.map(|x| LintConfig::from_json(&x)).transpose() {
+        Ok(config) => config.unwrap_or_default(),
+
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            return false;
+        }
+    };
+
+    let mut ok = true;
+
+    for file in files {
+        let Some(source) = read_source(&file, format) else {
+            ok = false;
+            continue;
+        };
+
+        let diagnostics = match lint_source(&source, &config) {
+            Ok(warnings) => warnings.iter().map(|x| Diagnostic::lint(&file, x)).collect(),
+            Err(err) => Diagnostic::syntax(&file, &err),
+        };
+
+        report(format, &file, &source, &diagnostics);
+
+        ok &= diagnostics.is_empty();
+    }
+
+    ok
+}
+
+/// Returns `false` if some test failed or some file could not be run
+fn run_tests(
+    paths: Vec<PathBuf>,
+    filter: String,
+    coverage: Option<PathBuf>,
+    format: ErrorFormat,
+) -> bool {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(".")]
+    } else {
+        paths
+    };
+
+    let mut files = Vec::new();
+
+    for path in paths {
+        if let Err(err) = collect_source_files(path.clone(), &mut files) {
+            eprintln!("{}: {}", path.display(), err);
+            return false;
+        }
+    }
+
+    let mut passed = 0;
+    // name of the failed test or file, the file with its source and why it failed
+    let mut failures = Vec::new();
+    let mut covered = Coverage::default();
+
+    for file in files {
+        let Some(source) = read_source(&file, format) else {
+            println!("{} ... FAILED", file.display());
+            failures.push((file.display().to_string(), file, String::new(), Vec::new()));
+            continue;
+        };
+
+        let results = match coverage {
+            Some(_) => covered.cover(&file, &source, || {
+                execute_tests(&file, source.clone(), &filter)
+            }),
+            None => execute_tests(&file, source.clone(), &filter),
+        };
+
+        let results = match results {
+            Ok(results) => results,
+
+            Err(err) => {
+                println!("{} ... FAILED", file.display());
+                let diagnostics = runtime_diagnostics(&file, &err);
+                failures.push((file.display().to_string(), file, source, diagnostics));
+                continue;
+            }
+        };
+
+        for test in results {
+            match test.result {
+                Ok(()) => {
+                    println!("test {:?} ... ok", test.name);
+                    passed += 1;
+                }
+
+                Err(err) => {
+                    println!("test {:?} ... FAILED", test.name);
+                    failures.push((
+                        format!("{:?} in {}", test.name, file.display()),
+                        file.clone(),
+                        source.clone(),
+                        runtime_diagnostics(&file, &err),
+                    ));
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        match format {
+            ErrorFormat::Human => {
+                println!("\nfailures:");
+
+                for (name, file, source, diagnostics) in &failures {
+                    let errors: String =
+                        diagnostics.iter().map(|x| human(x, file, source)).collect();
+                    print!("\n{}:\n{}", name, errors);
+                }
+            }
+
+            ErrorFormat::Json => {
+                for (_, file, source, diagnostics) in &failures {
+                    report(format, file, source, diagnostics);
+                }
+            }
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        passed,
+        failures.len()
+    );
+
+    if let Some(lcov) = coverage {
+        println!();
+        write_coverage(&covered, &lcov);
+    }
+
+    failures.is_empty()
+}
+
+/// Prints summary to stderr and writes LCOV report
+fn write_coverage(report: &Coverage, lcov: &Path) {
+    eprint!("{}", report.summary());
+
+    if let Err(err) = fs::write(lcov, report.lcov()) {
+        eprintln!("{}: {}", lcov.display(), err);
+    }
+}
+
+/// Adds `path` if it is a file, or all `.fru` files in it recursively if it is a directory
+fn collect_source_files(path: PathBuf, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path);
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(&path)?
+        .map(|entry| entry.map(|x| x.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() || entry.extension().is_some_and(|x| x == "fru") {
+            collect_source_files(entry, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the file, reporting it, if it can not be read
+fn read_source(file: &Path, format: ErrorFormat) -> Option<String> {
+    fs::read_to_string(file)
+        .map_err(|err| report(format, file, "", &[Diagnostic::io(file, err)]))
+        .ok()
+}
+
+/// Syntax errors are reported one by one, against the file, that could not be parsed
+fn runtime_diagnostics(file: &Path, err: &FruError) -> Vec<Diagnostic> {
+    match err.get_syntax() {
+        Some((syntax_file, syntax)) => Diagnostic::syntax(syntax_file.unwrap_or(file), syntax),
+        None => vec![Diagnostic::runtime(file, err)],
+    }
+}
+
+/// Prints diagnostics to stderr, `source` is the code of `file`
+fn report(format: ErrorFormat, file: &Path, source: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        match format {
+            ErrorFormat::Human => eprint!("{}", human(diagnostic, file, source)),
+            ErrorFormat::Json => eprintln!("{}", diagnostic.json()),
+        }
+    }
+}
+
+/// Diagnostics about other files, like imported ones, are shown with the code of their file
+fn human(diagnostic: &Diagnostic, file: &Path, source: &str) -> String {
+    if diagnostic.file == file {
+        diagnostic.human(source)
+    } else {
+        diagnostic.human(&fs::read_to_string(&diagnostic.file).unwrap_or_default())
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::Path,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use tree_sitter::Range;
+
+use crate::interpreter::{
+    debug::{self, DebugHook},
+    error::FruError,
+    runner::execute_file,
+    scope::Scope,
+};
+
+// bottom of every stack in folded stacks
+const PROGRAM: &str = "<program>";
+
+/// Time spent in one function, method, operator or property accessor
+#[derive(Debug)]
+pub struct Entry {
+    pub name: String,
+    // file, where it is declared, `None` for code, that was not read from a file
+    pub file: Option<Rc<Path>>,
+    pub position: Range,
+    pub calls: usize,
+    // recursive calls are counted once, so it never exceeds the time of the program
+    pub inclusive: Duration,
+    pub exclusive: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct Profile {
+    // sorted by exclusive time, the longest first
+    pub entries: Vec<Entry>,
+    // call stacks, joined with `;`, and exclusive time spent in them
+    pub stacks: Vec<(String, Duration)>,
+}
+
+// name, file and position of callable
+type Key = (String, Option<Rc<Path>>, Range);
+
+struct Call {
+    key: Key,
+    start: Instant,
+    // inclusive time of calls made from this one
+    children: Duration,
+}
+
+#[derive(Default)]
+struct Recorder {
+    calls: Vec<Call>,
+    // how many calls of each callable are on the stack, to count recursion once
+    active: HashMap<Key, usize>,
+    entries: HashMap<Key, Entry>,
+    stacks: HashMap<String, Duration>,
+}
+
+struct Profiler(Rc<RefCell<Recorder>>);
+
+impl DebugHook for Profiler {
+    fn statement(&mut self, _: Range, _: &Rc<Scope>) -> Result<(), FruError> {
+        Ok(())
+    }
+
+    fn enter_call(&mut self, name: &str, position: Range, scope: &Rc<Scope>) {
+        let mut recorder = self.0.borrow_mut();
+        let key = (name.to_string(), scope.get_file(), position);
+
+        *recorder.active.entry(key.clone()).or_default() += 1;
+
+        recorder.calls.push(Call {
+            key,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    fn leave_call(&mut self) {
+        let recorder = &mut *self.0.borrow_mut();
+
+        let call = recorder.calls.pop().unwrap();
+        let inclusive = call.start.elapsed();
+        let exclusive = inclusive.saturating_sub(call.children);
+
+        if let Some(parent) = recorder.calls.last_mut() {
+            parent.children += inclusive;
+        }
+
+        let stack = [PROGRAM]
+            .into_iter()
+            .chain(recorder.calls.iter().map(|x| x.key.0.as_str()))
+            .chain([call.key.0.as_str()])
+            .collect::<Vec<_>>()
+            .join(";");
+
+        *recorder.stacks.entry(stack).or_default() += exclusive;
+
+        let active = recorder.active.get_mut(&call.key).unwrap();
+        *active -= 1;
+        let is_outermost = *active == 0;
+
+        let entry = recorder.entries.entry(call.key.clone()).or_insert_with(|| Entry {
+            name: call.key.0.clone(),
+            file: call.key.1.clone(),
+            position: call.key.2,
+            calls: 0,
+            inclusive: Duration::ZERO,
+            exclusive: Duration::ZERO,
+        });
+
+        entry.calls += 1;
+        entry.exclusive += exclusive;
+
+        if is_outermost {
+            entry.inclusive += inclusive;
+        }
+    }
+
+    fn branch(&mut self, _: Range, _: bool, _: &Rc<Scope>) {}
+}
+
+/// Executes file, measuring time of every call
+pub fn profile_file(path: &Path) -> (Result<(), FruError>, Profile) {
+    let recorder = Rc::new(RefCell::new(Recorder::default()));
+
+    debug::set_hook(Some(Box::new(Profiler(recorder.clone()))));
+
+    let start = Instant::now();
+    let result = execute_file(path).map(|_| ());
+    let total = start.elapsed();
+
+    debug::set_hook(None);
+
+    let recorder = recorder.take();
+
+    let mut entries: Vec<_> = recorder.entries.into_values().collect();
+    entries.sort_by(|a, b| b.exclusive.cmp(&a.exclusive).then_with(|| a.name.cmp(&b.name)));
+
+    let mut stacks: Vec<_> = recorder.stacks.into_iter().collect();
+
+    // time of the program itself is what is left after the calls
+    let in_calls: Duration = stacks.iter().map(|(_, time)| *time).sum();
+    stacks.push((PROGRAM.to_string(), total.saturating_sub(in_calls)));
+
+    stacks.sort();
+
+    (result, Profile { entries, stacks })
+}
+
+impl Profile {
+    /// Table with one row per callable, `file` is shown for callables, which file is unknown
+    pub fn table(&self, file: &Path) -> String {
+        let mut table = format!(
+            "{:>8} {:>12} {:>12}  {}\n",
+            "calls", "total ms", "self ms", "function"
+        );
+
+        for entry in &self.entries {
+            table += &format!(
+                "{:>8} {:>12.3} {:>12.3}  {} ({}:{}:{})\n",
+                entry.calls,
+                entry.inclusive.as_secs_f64() * 1000.0,
+                entry.exclusive.as_secs_f64() * 1000.0,
+                entry.name,
+                entry.file.as_deref().unwrap_or(file).display(),
+                entry.position.start_point.row + 1,
+                entry.position.start_point.column + 1
+            );
+        }
+
+        table
+    }
+
+    /// Stacks in the format of `flamegraph.pl` and `inferno`, with time in microseconds
+    pub fn folded(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|(stack, time)| format!("{} {}\n", stack, time.as_micros()))
+            .collect()
+    }
+}
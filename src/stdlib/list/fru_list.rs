@@ -0,0 +1,153 @@
+use std::{any::Any, cell::RefCell, fmt::Formatter, rc::Rc};
+
+use macros::static_ident;
+
+use crate::{
+    interpreter::{
+        error::{ErrorKind, FruError},
+        identifier::Identifier,
+        value::fru_value::{FruValue, TFnBuiltin},
+        value::function::{BuiltinFunction, EvaluatedArgumentList},
+        value::native::object::{INativeObject, NativeObject},
+    },
+    stdlib::range::fru_range::{extract_range_from_value, resolve_index},
+};
+
+pub struct FruList {
+    items: Rc<RefCell<Vec<FruValue>>>,
+}
+
+impl FruList {
+    pub fn new_value(items: Vec<FruValue>) -> FruValue {
+        Self::from_items(Rc::new(RefCell::new(items)))
+    }
+
+    fn from_items(items: Rc<RefCell<Vec<FruValue>>>) -> FruValue {
+        FruValue::NativeObject(NativeObject::new(Rc::new(Self { items })))
+    }
+
+    fn bind_method(&self, ident: Identifier, function: TFnBuiltin) -> FruValue {
+        BuiltinFunction::new(ident, function).bind(Self::from_items(self.items.clone()))
+    }
+}
+
+impl INativeObject for FruList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_identifier(&self) -> Identifier {
+        static_ident!("List")
+    }
+
+    fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        match ident {
+            ident if ident == static_ident!("len") => Ok(self.bind_method(ident, b_len)),
+            ident if ident == static_ident!("get") => Ok(self.bind_method(ident, b_get)),
+            _ => FruError::new_kind_res(
+                ErrorKind::UndefinedProperty,
+                format!("List has no prop `{}`", ident),
+            ),
+        }
+    }
+
+    fn get_index(&self, index: FruValue) -> Result<FruValue, FruError> {
+        let items = self.items.borrow();
+
+        if let Some(range) = extract_range_from_value(&index) {
+            let (start, end) = range.resolve(items.len())?;
+
+            return Ok(FruList::new_value(items[start..end].to_vec()));
+        }
+
+        match index {
+            FruValue::Number(index) => {
+                Ok(items[resolve_index(index, items.len(), "List")?].clone())
+            }
+
+            other => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!(
+                    "List can not be indexed by `{}`",
+                    other.get_type_identifier()
+                ),
+            ),
+        }
+    }
+
+    fn set_index(&self, index: FruValue, value: FruValue) -> Result<(), FruError> {
+        let mut items = self.items.borrow_mut();
+
+        match index {
+            FruValue::Number(index) => {
+                let index = resolve_index(index, items.len(), "List")?;
+                items[index] = value;
+                Ok(())
+            }
+
+            other => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!(
+                    "cannot set index of List by `{}`",
+                    other.get_type_identifier()
+                ),
+            ),
+        }
+    }
+
+    fn fru_clone(self: Rc<Self>) -> Rc<dyn INativeObject> {
+        self
+    }
+
+    fn repr(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let items = self.items.borrow();
+
+        let mut list = f.debug_list();
+        list.entries(items.iter());
+        list.finish()
+    }
+}
+
+pub fn extract_list_from_value(v: &FruValue) -> Option<Rc<RefCell<Vec<FruValue>>>> {
+    if let FruValue::NativeObject(o) = v {
+        o.downcast::<FruList>().map(|x| x.items.clone())
+    } else {
+        None
+    }
+}
+
+fn b_len(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, list)] => {
+            let items = extract_list_from_value(list).unwrap();
+            let len = items.borrow().len();
+            Ok(FruValue::Number(len as f64))
+        }
+
+        _ => FruError::new_res("len takes no arguments"),
+    }
+}
+
+fn b_get(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, list), (None, FruValue::Number(index))] => {
+            let items = extract_list_from_value(list).unwrap();
+            let items = items.borrow();
+
+            if index.fract() != 0.0 || *index < 0.0 || *index as usize >= items.len() {
+                return FruError::new_kind_res(
+                    ErrorKind::OutOfBounds,
+                    format!(
+                        "index {} is out of bounds for List of length {}",
+                        index,
+                        items.len()
+                    ),
+                );
+            }
+
+            Ok(items[*index as usize].clone())
+        }
+
+        _ => FruError::new_res("get takes exactly one `Number` index"),
+    }
+}
@@ -0,0 +1,172 @@
+use std::{any::Any, cell::RefCell, fmt::Formatter, rc::Rc};
+
+use macros::static_ident;
+
+use crate::{
+    interpreter::{
+        error::{ErrorKind, FruError},
+        identifier::Identifier,
+        value::fru_value::{FruValue, TFnBuiltin},
+        value::function::{BuiltinFunction, EvaluatedArgumentList},
+        value::native::object::{INativeObject, NativeObject},
+    },
+    stdlib::list::fru_list::FruList,
+};
+
+type MapItems = Rc<RefCell<Vec<(String, FruValue)>>>;
+
+/// Map with `String` keys, that preserves insertion order
+pub struct FruMap {
+    items: MapItems,
+}
+
+impl FruMap {
+    pub fn new_value(items: Vec<(String, FruValue)>) -> FruValue {
+        Self::from_items(Rc::new(RefCell::new(items)))
+    }
+
+    fn from_items(items: MapItems) -> FruValue {
+        FruValue::NativeObject(NativeObject::new(Rc::new(Self { items })))
+    }
+
+    fn bind_method(&self, ident: Identifier, function: TFnBuiltin) -> FruValue {
+        BuiltinFunction::new(ident, function).bind(Self::from_items(self.items.clone()))
+    }
+}
+
+impl INativeObject for FruMap {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_identifier(&self) -> Identifier {
+        static_ident!("Map")
+    }
+
+    fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        match ident {
+            ident if ident == static_ident!("len") => Ok(self.bind_method(ident, b_len)),
+            ident if ident == static_ident!("get") => Ok(self.bind_method(ident, b_get)),
+            ident if ident == static_ident!("has") => Ok(self.bind_method(ident, b_has)),
+            ident if ident == static_ident!("keys") => Ok(self.bind_method(ident, b_keys)),
+            _ => FruError::new_kind_res(
+                ErrorKind::UndefinedProperty,
+                format!("Map has no prop `{}`", ident),
+            ),
+        }
+    }
+
+    fn get_index(&self, index: FruValue) -> Result<FruValue, FruError> {
+        let key = expect_key(index)?;
+
+        match self.items.borrow().iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => Ok(value.clone()),
+            None => FruError::new_kind_res(
+                ErrorKind::OutOfBounds,
+                format!("key {:?} is not present in Map", key),
+            ),
+        }
+    }
+
+    fn set_index(&self, index: FruValue, value: FruValue) -> Result<(), FruError> {
+        let key = expect_key(index)?;
+
+        let mut items = self.items.borrow_mut();
+
+        match items.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = value,
+            None => items.push((key, value)),
+        }
+
+        Ok(())
+    }
+
+    fn fru_clone(self: Rc<Self>) -> Rc<dyn INativeObject> {
+        self
+    }
+
+    fn repr(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let items = self.items.borrow();
+
+        let mut map = f.debug_map();
+        map.entries(items.iter().map(|(k, v)| (k, v)));
+        map.finish()
+    }
+}
+
+pub fn extract_map_from_value(v: &FruValue) -> Option<MapItems> {
+    if let FruValue::NativeObject(o) = v {
+        o.downcast::<FruMap>().map(|x| x.items.clone())
+    } else {
+        None
+    }
+}
+
+fn expect_key(index: FruValue) -> Result<String, FruError> {
+    match index {
+        FruValue::String(key) => Ok(key),
+
+        other => FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!(
+                "Map keys must be `String`, got `{}`",
+                other.get_type_identifier()
+            ),
+        ),
+    }
+}
+
+fn b_len(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, map)] => {
+            let items = extract_map_from_value(map).unwrap();
+            let len = items.borrow().len();
+            Ok(FruValue::Number(len as f64))
+        }
+
+        _ => FruError::new_res("len takes no arguments"),
+    }
+}
+
+fn b_get(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, map), (None, FruValue::String(key))] => {
+            let items = extract_map_from_value(map).unwrap();
+            let items = items.borrow();
+
+            match items.iter().find(|(k, _)| k == key) {
+                Some((_, value)) => Ok(value.clone()),
+                None => FruError::new_kind_res(
+                    ErrorKind::OutOfBounds,
+                    format!("key {:?} is not present in Map", key),
+                ),
+            }
+        }
+
+        _ => FruError::new_res("get takes exactly one `String` key"),
+    }
+}
+
+fn b_has(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, map), (None, FruValue::String(key))] => {
+            let items = extract_map_from_value(map).unwrap();
+            let has = items.borrow().iter().any(|(k, _)| k == key);
+            Ok(FruValue::Bool(has))
+        }
+
+        _ => FruError::new_res("has takes exactly one `String` key"),
+    }
+}
+
+fn b_keys(args: EvaluatedArgumentList) -> Result<FruValue, FruError> {
+    match args.args.as_slice() {
+        [(None, map)] => {
+            let items = extract_map_from_value(map).unwrap();
+            let keys = items.borrow().iter().map(|(k, _)| FruValue::String(k.clone())).collect();
+            Ok(FruList::new_value(keys))
+        }
+
+        _ => FruError::new_res("keys takes no arguments"),
+    }
+}
@@ -1 +1,4 @@
+pub mod list;
+pub mod map;
+pub mod range;
 pub mod scope;
@@ -0,0 +1,122 @@
+use std::{any::Any, fmt::Formatter, rc::Rc};
+
+use macros::static_ident;
+
+use crate::interpreter::{
+    error::{ErrorKind, FruError},
+    identifier::Identifier,
+    value::fru_value::FruValue,
+    value::native::object::{INativeObject, NativeObject},
+};
+
+/// Bounds of a slice, like `1..3`, either bound can be omitted
+#[derive(Clone, Copy)]
+pub struct FruRange {
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+}
+
+impl FruRange {
+    pub fn new_value(start: Option<f64>, end: Option<f64>) -> FruValue {
+        FruValue::NativeObject(NativeObject::new(Rc::new(Self { start, end })))
+    }
+
+    /// Start and end indices of the slice in a collection of length `len`.
+    /// Negative bounds count from the end, bounds past the ends are clamped
+    pub fn resolve(&self, len: usize) -> Result<(usize, usize), FruError> {
+        let bound = |x: Option<f64>, default: usize| match x {
+            None => Ok(default),
+
+            Some(x) if x.fract() != 0.0 => FruError::new_kind_res(
+                ErrorKind::UnexpectedType,
+                format!("slice bound {} is not an integer", x),
+            ),
+
+            Some(x) if x < 0.0 => Ok((len as f64 + x).max(0.0) as usize),
+
+            Some(x) => Ok((x as usize).min(len)),
+        };
+
+        let start = bound(self.start, 0)?;
+        let end = bound(self.end, len)?;
+
+        Ok((start, end.max(start)))
+    }
+}
+
+/// Position of `index` in a collection of length `len`, negative indices count from the end
+pub fn resolve_index(index: f64, len: usize, type_ident: &str) -> Result<usize, FruError> {
+    if index.fract() != 0.0 {
+        return FruError::new_kind_res(
+            ErrorKind::UnexpectedType,
+            format!("index {} is not an integer", index),
+        );
+    }
+
+    let resolved = if index < 0.0 {
+        len as f64 + index
+    } else {
+        index
+    };
+
+    if resolved < 0.0 || resolved >= len as f64 {
+        return FruError::new_kind_res(
+            ErrorKind::OutOfBounds,
+            format!(
+                "index {} is out of bounds for {} of length {}",
+                index, type_ident, len
+            ),
+        );
+    }
+
+    Ok(resolved as usize)
+}
+
+impl INativeObject for FruRange {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_identifier(&self) -> Identifier {
+        static_ident!("Range")
+    }
+
+    fn get_prop(&self, ident: Identifier) -> Result<FruValue, FruError> {
+        let bound = |x: Option<f64>| x.map_or(FruValue::Nah, FruValue::Number);
+
+        match ident {
+            ident if ident == static_ident!("start") => Ok(bound(self.start)),
+            ident if ident == static_ident!("end") => Ok(bound(self.end)),
+            _ => FruError::new_kind_res(
+                ErrorKind::UndefinedProperty,
+                format!("Range has no prop `{}`", ident),
+            ),
+        }
+    }
+
+    fn fru_clone(self: Rc<Self>) -> Rc<dyn INativeObject> {
+        self
+    }
+
+    fn repr(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "{}", start)?;
+        }
+
+        write!(f, "..")?;
+
+        if let Some(end) = self.end {
+            write!(f, "{}", end)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn extract_range_from_value(v: &FruValue) -> Option<FruRange> {
+    if let FruValue::NativeObject(o) = v {
+        o.downcast::<FruRange>().copied()
+    } else {
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use std::{any::Any, rc::Rc};
+use std::{any::Any, fmt::Formatter, rc::Rc};
 
 use macros::static_ident;
 
@@ -6,7 +6,7 @@ use crate::interpreter::{
     error::FruError,
     identifier::Identifier,
     scope::Scope,
-    value::fru_value::FruValue,
+    value::fru_value::{fmt_fields, FruValue},
     value::native::object::{INativeObject, NativeObject},
 };
 
@@ -41,6 +41,15 @@ impl INativeObject for FruScope {
     fn fru_clone(self: Rc<Self>) -> Rc<dyn INativeObject> {
         self
     }
+
+    fn repr(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_fields(
+            f,
+            Rc::as_ptr(&self.scope) as usize,
+            self.get_type_identifier(),
+            self.scope.get_variables(),
+        )
+    }
 }
 
 pub fn extract_scope_from_value(v: &FruValue) -> Option<Rc<Scope>> {
@@ -0,0 +1,73 @@
+use crate::run;
+
+#[test]
+fn test_assert() {
+    run(r#"
+            assert(1 < 2);
+            assert(true, "message");
+            assert_ne(1, "1");
+            assert_approx(0.1 + 0.2, 0.3);
+            assert_approx(1, 1.5, 0.5);
+            assert_approx(1, 1.5, tolerance: 0.5);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: impossible")]
+fn test_assert_message() {
+    run(r#"
+            assert(1 > 2, "impossible");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: 1 == 1")]
+fn test_assert_ne() {
+    run(r#"
+            assert_ne(1, 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: 1 is not approximately equal to 1.1")]
+fn test_assert_approx() {
+    run(r#"
+            assert_approx(1, 1.1);
+        "#)
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_approx takes two `Number` values and optional `Number` tolerance"
+)]
+fn test_assert_approx_unknown_argument() {
+    run(r#"
+            assert_approx(1, 1.5, epsilon: 0.5);
+        "#)
+}
+
+#[test]
+fn test_assert_throws() {
+    run(r#"
+            let message = assert_throws(fn () { assert_eq(1, 2); });
+            assert_eq(message, "assertion failed: 1 != 2");
+
+            assert_throws(fn () { 1 / "1"; }, "operator");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: function did not throw")]
+fn test_assert_throws_nothing() {
+    run(r#"
+            assert_throws(fn () { 1 + 1; });
+        "#)
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: expected error containing "wrong""#)]
+fn test_assert_throws_other() {
+    run(r#"
+            assert_throws(fn () { assert(false); }, "wrong");
+        "#)
+}
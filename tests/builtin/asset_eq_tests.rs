@@ -16,7 +16,7 @@ fn test_not_eq_1() {
 }
 
 #[test]
-#[should_panic(expected = "assertion failed: 1 != 1")] // FIXME: differentiate repr and display
+#[should_panic(expected = r#"assertion failed: 1 != "1""#)]
 fn test_not_eq_2() {
     run(r#"
             assert_eq(1, "1");
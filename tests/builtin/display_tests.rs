@@ -0,0 +1,125 @@
+use crate::run;
+
+#[test]
+fn test_str_and_repr() {
+    run(r#"
+            assert_eq(str("hello"), "hello");
+            assert_eq(repr("hello"), "\"hello\"");
+            assert_eq(repr("a\nb"), "\"a\\nb\"");
+            assert_eq(repr(1.5), "1.5");
+            assert_eq(repr(nah), "nah");
+            assert_eq(repr(false), "false");
+        "#)
+}
+
+#[test]
+fn test_objects() {
+    run(r#"
+            struct Inner {
+                s;
+            }
+
+            struct Outer {
+                pub x;
+                inner;
+            }
+
+            let o = Outer :{ 1, Inner :{ "text" } };
+
+            assert_eq(str(o), "Outer{x=1, inner=Inner{s=\"text\"}}");
+            assert_eq(repr(o), "Outer{x=1, inner=Inner{s=\"text\"}}");
+            assert_eq(repr(Inner :{ "a" }, pretty: true), "Inner{\n    s=\"a\",\n}");
+            assert_eq(
+                repr(o, pretty: true),
+                "Outer{\n    x=1,\n    inner=Inner{\n        s=\"text\",\n    },\n}"
+            );
+        "#)
+}
+
+#[test]
+fn test_cycles() {
+    run(r#"
+            class Node {
+                next;
+            }
+
+            let n = Node :{ nah };
+            n.next = n;
+
+            assert_eq(str(n), "Node{next=Node{...}}");
+        "#)
+}
+
+#[test]
+fn test_functions() {
+    run(r#"
+            let f = fn (a, b, c = 1) { a + b + c };
+
+            assert_eq(str(f), "fn f(a, b, c = 1)");
+            assert_eq(str(f$(1, c: "x")), "fn f(a, b, c = 1)$(1, c: \"x\")");
+            assert_eq(str(fn (x) {}), "fn(x)");
+            assert_eq(str(print), "builtin fn print");
+        "#)
+}
+
+#[test]
+fn test_scope() {
+    run(r#"
+            let s = fn () {
+                let b = "two";
+                let a = 1;
+                scope()
+            }();
+
+            assert_eq(str(s), "Scope{a=1, b=\"two\"}");
+        "#)
+}
+
+#[test]
+fn test_show() {
+    run(r#"
+            struct Vec2 {
+                x;
+                y;
+            } impl {
+                show() {
+                    f"({x}, {y})"
+                }
+            }
+
+            struct Line {
+                a;
+                b;
+            }
+
+            let v = Vec2 :{ 1, 2 };
+
+            assert_eq(str(v), "(1, 2)");
+            assert_eq(f"v = {v}", "v = (1, 2)");
+            assert_eq(format("{:>8}", v), "  (1, 2)");
+            assert_eq(repr(v), "Vec2{x=1, y=2}");
+            assert_eq(str(Line :{ v, v }), "Line{a=Vec2{x=1, y=2}, b=Vec2{x=1, y=2}}");
+            print(v);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`show` method of `Thing` must return `String`, got `Number`")]
+fn test_show_wrong_type() {
+    run(r#"
+            struct Thing {} impl {
+                show() { 5 }
+            }
+
+            print(Thing :{});
+        "#)
+}
+
+#[test]
+fn test_global_scope() {
+    run(r#"
+            let a = 1;
+
+            assert_eq(str(scope()), "Scope{a=1}");
+        "#)
+}
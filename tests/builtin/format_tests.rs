@@ -0,0 +1,82 @@
+use crate::run;
+
+#[test]
+fn test_format() {
+    run(r#"
+            assert_eq(format("{} + {} = {}", 1, 2, 3), "1 + 2 = 3");
+            assert_eq(format("{1} {0} {1}", "a", "b"), "b a b");
+            assert_eq(format("{name} is {age}", name: "Bob", age: 42), "Bob is 42");
+            assert_eq(format("{{}} {}", 1), "{} 1");
+            assert_eq(format("no placeholders"), "no placeholders");
+        "#)
+}
+
+#[test]
+fn test_format_spec() {
+    run(r#"
+            assert_eq(format("{:.3}", 2), "2.000");
+            assert_eq(format("[{:5}]", 42), "[   42]");
+            assert_eq(format("[{:<5}]", 42), "[42   ]");
+            assert_eq(format("[{:0>5.1}]", 1.25), "[001.2]");
+            assert_eq(format("[{x:^7}]", x: true), "[ true  ]");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "format argument `1` is not provided")]
+fn test_missing_positional() {
+    run(r#"
+            format("{} {}", 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "format argument `x` is not provided")]
+fn test_missing_named() {
+    run(r#"
+            format("{x}", y: 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "invalid format spec `5x`")]
+fn test_invalid_spec() {
+    run(r#"
+            format("{:5x}", 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Expected `String` as format template, got `Number`")]
+fn test_invalid_template() {
+    run(r#"
+            format(1);
+        "#)
+}
+
+#[test]
+fn test_str() {
+    run(r#"
+            assert_eq(str(5), "5");
+            assert_eq(str(1.5), "1.5");
+            assert_eq(str(true), "true");
+            assert_eq(str(nah), "nah");
+            assert_eq(str("s"), "s");
+            assert_eq("x=" <> str(5), "x=5");
+
+            struct Vec2 {
+                x;
+                y;
+            }
+
+            assert_eq(str(Vec2 :{ 1, 2 }), "Vec2{x=1, y=2}");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "str takes exactly one positional argument")]
+fn test_str_arguments() {
+    run(r#"
+            str(1, 2);
+        "#)
+}
@@ -0,0 +1,55 @@
+use crate::run;
+
+#[test]
+fn test_partial() {
+    run(r#"
+            let f = fn (a, b, c = 0) { a * 100 + b * 10 + c };
+
+            let g = partial(f, 1, c: 3);
+
+            assert_eq(g(2), 123);
+            assert_eq(partial(g)(5), 153);
+            assert_eq(partial(print).is_builtin, true);
+        "#)
+}
+
+#[test]
+fn test_flip() {
+    run(r#"
+            let sub = fn (a, b, c = 0) { a - b - c };
+
+            assert_eq(flip(sub)(1, 10), 9);
+            assert_eq(flip(sub)(1, 10, c: 2), 7);
+            assert_eq(flip(flip(sub))(1, 10), -9);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "flipped function takes at least two positional arguments")]
+fn test_flip_error() {
+    run(r#"
+            flip(print)(1);
+        "#)
+}
+
+#[test]
+fn test_compose() {
+    run(r#"
+            let inc = fn (x) { x + 1 };
+            let double = fn (x) { x * 2 };
+            let add = fn (a, b) { a + b };
+
+            assert_eq(compose(inc, double)(5), 11);
+            assert_eq(compose(double, inc)(5), 12);
+            assert_eq(compose(inc, double, add)(1, 2), 7);
+            assert_eq(compose(str)(5), "5");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "compose takes one or more functions")]
+fn test_compose_error() {
+    run(r#"
+            compose();
+        "#)
+}
@@ -1 +1,5 @@
+mod assert_tests;
 mod asset_eq_tests;
+mod display_tests;
+mod format_tests;
+mod functional_tests;
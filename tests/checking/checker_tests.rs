@@ -0,0 +1,182 @@
+use crate::checker::check_source;
+
+fn check(code: &str) -> Vec<String> {
+    check_source(code).unwrap().into_iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_correct_code() {
+    let errors = check(
+        r#"
+        struct Vec {
+            x;
+            y;
+            static count = 0;
+
+            Length {
+                get => x + y;
+                set(value) {
+                    x = value;
+                }
+            }
+        } impl {
+            sum() {
+                x + y + Length + count + zero().x
+            }
+
+            static zero() {
+                Vec :{ 0, 0 }
+            }
+        }
+
+        operator + (a : Vec, b : Vec) {
+            Vec :{ a.x + b.x, a.y + b.y }
+        }
+
+        fn even(n) { if n == 0 { true } else { odd(n - 1) } }
+        fn odd(n) { if n == 0 { false } else { even(n - 1) } }
+
+        fn sum(..items, **opts) { items }
+
+        let v = Vec :{ y: 1, x: 2 };
+
+        while true {
+            if even(4) {
+                break;
+            }
+            continue;
+        }
+
+        print(sum(1, 2, 3, a: 4), even$(), v + v);
+        "#,
+    );
+
+    assert_eq!(errors, Vec::<String>::new());
+}
+
+#[test]
+fn test_undeclared_variable() {
+    assert_eq!(
+        check("let x = 1;\nprint(y);\nfn f() { z }"),
+        [
+            "variable `y` is not declared at 2:0-2:9",
+            "variable `z` is not declared at 3:0-3:12",
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_let() {
+    assert_eq!(
+        check("let x = 1;\n{ let x = 2; }\nlet x = 3;"),
+        ["variable `x` is already declared in this block at 3:0-3:10"]
+    );
+}
+
+#[test]
+fn test_arguments() {
+    assert_eq!(
+        check(
+            r#"fn f(a, b = 1) {}
+f(1, 2, 3);
+f(b: 2);
+f(1, a: 2);
+f(1, c: 3);
+f(1, 2, ..f);
+f$(b: 1);"#
+        ),
+        [
+            "too many arguments in call of `f`, expected at most 2, got 3 at 2:0-2:11",
+            "missing argument `a` in call of `f` at 3:0-3:8",
+            "argument `a` is set twice in call of `f` at 4:0-4:11",
+            "unknown argument `c` in call of `f` at 5:0-5:11",
+        ]
+    );
+}
+
+#[test]
+fn test_instantiation() {
+    assert_eq!(
+        check(
+            r#"struct P { x; y; }
+P :{ 1 };
+P :{ 1, 2, 3 };
+P :{ x: 1, z: 2, y: 3 };
+P :{ x: 1, x: 2, y: 3 };"#
+        ),
+        [
+            "missing field `y` in instantiation of `P` at 2:0-2:9",
+            "too many fields in instantiation of `P`, expected 2, got 3 at 3:0-3:15",
+            "field `z` does not exist in `P` at 4:0-4:24",
+            "field `x` is set more than once at 5:0-5:24",
+        ]
+    );
+}
+
+#[test]
+fn test_defaults() {
+    assert_eq!(
+        check(
+            r#"struct P { x; y = origin; static origin = 0; z = w; }
+P :{ 1 };
+P :{ y: 2 };"#
+        ),
+        [
+            "variable `w` is not declared at 1:0-1:53",
+            "missing field `x` in instantiation of `P` at 3:0-3:12",
+        ]
+    );
+}
+
+#[test]
+fn test_embedding() {
+    assert_eq!(
+        check(
+            r#"struct A { x; } impl { f() { x } static g() { 1 } }
+struct B { embed A; } impl { h() { f() + x + g() + A.x } static i() { g() + y } }
+struct C { embed D; }"#
+        ),
+        [
+            "variable `y` is not declared at 2:0-2:81",
+            "variable `D` is not declared at 3:0-3:21",
+        ]
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        check(
+            r#"enum E { A { x }, B } impl { f() { x + variant + g() } static g() { A } }
+let v = match E.B { E.A { x } if x > 0 => x + y, _ => 0 };"#
+        ),
+        ["variable `y` is not declared at 2:0-2:58"]
+    );
+}
+
+#[test]
+fn test_loop_control() {
+    // reported by the parser, so the checker is not even run
+    let err = check_source("while true { let f = fn() { continue; }; }").unwrap_err();
+
+    assert_eq!(err.to_string(), "`continue` outside of a loop at 1:28-1:37");
+}
+
+#[test]
+fn test_dynamic_scope() {
+    let errors = check(
+        r#"
+        let s = scope();
+        scope s { let x = 1; }
+        print(x);
+        scope s { print(y); }
+        "#,
+    );
+
+    assert_eq!(errors, Vec::<String>::new());
+
+    assert_eq!(
+        check("// frugurt-check: dynamic\nprint(wow);"),
+        Vec::<String>::new()
+    );
+}
@@ -0,0 +1,97 @@
+use crate::checker::lint::{lint_source, LintConfig};
+
+fn lint(code: &str) -> Vec<String> {
+    lint_with(code, "{}")
+}
+
+fn lint_with(code: &str, config: &str) -> Vec<String> {
+    let config = LintConfig::from_json(config).unwrap();
+
+    lint_source(code, &config).unwrap().into_iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_unused() {
+    assert_eq!(
+        lint("fn f(a, b, _c) {\n    let x = 1;\n    let _y = 2;\n    a\n}\nlet global = f;"),
+        [
+            "warning[unused_parameter]: parameter `b` is never used at 1:0-5:1",
+            "warning[unused_variable]: variable `x` is never used at 2:4-2:14",
+        ]
+    );
+}
+
+#[test]
+fn test_compound_assignment_is_not_use() {
+    assert_eq!(
+        lint("fn f() {\n    let x = 1;\n    x += 1;\n}\nlet global = f;"),
+        ["warning[unused_variable]: variable `x` is never used at 2:4-2:14"]
+    );
+}
+
+#[test]
+fn test_shadowing() {
+    assert_eq!(
+        lint("let x = 1;\n{\n    let x = 2;\n    print(x);\n}"),
+        ["warning[shadowing]: `x` shadows declaration from outer scope at 3:4-3:14"]
+    );
+}
+
+#[test]
+fn test_unreachable() {
+    assert_eq!(
+        lint("while true {\n    break;\n    print(1);\n    print(2);\n}"),
+        ["warning[unreachable]: unreachable statement at 3:4-3:13"]
+    );
+}
+
+#[test]
+fn test_constant_condition() {
+    assert_eq!(
+        lint("if false { print(1); }\nwhile true { break; }"),
+        ["warning[constant_condition]: condition is always `false` at 1:0-1:22"]
+    );
+}
+
+#[test]
+fn test_types() {
+    assert_eq!(
+        lint("struct Empty {}\nstruct A { x; }\ncommutative operator + (a : A, b : A) { a }"),
+        [
+            "warning[empty_type]: type `Empty` has no members at 1:0-1:15",
+            "warning[redundant_commutative]: operator is commutative, \
+            but both operands have type `A` at 3:0-3:43",
+        ]
+    );
+}
+
+#[test]
+fn test_config() {
+    let code = "struct Empty {}\nif true {}";
+
+    assert_eq!(
+        lint_with(code, r#"{ "empty_type": false, "shadowing": true }"#),
+        ["warning[constant_condition]: condition is always `true` at 2:0-2:10"]
+    );
+
+    assert_eq!(
+        LintConfig::from_json(r#"{ "typo": false }"#).unwrap_err(),
+        "unknown lint rule `typo`"
+    );
+}
+
+#[test]
+fn test_allow_comment() {
+    let code = r#"
+    let x = 1;
+    {
+        let x = 2; // frugurt-allow(shadowing)
+        print(x);
+    }
+    {
+        // frugurt-allow(unused_variable, shadowing)
+        let x = 3;
+    }"#;
+
+    assert_eq!(lint(code), Vec::<String>::new());
+}
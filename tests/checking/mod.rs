@@ -0,0 +1,2 @@
+mod checker_tests;
+mod lint_tests;
@@ -0,0 +1,177 @@
+use std::{fs, path::Path};
+
+use crate::coverage::{Branch, Coverage};
+use crate::interpreter::runner::{execute_file, execute_source_code, execute_tests};
+
+const SOURCE: &str = r#"
+fn sign(x) {
+    if x > 0 {
+        1
+    } else if x < 0 {
+        -1
+    } else {
+        0
+    }
+}
+
+let i = 0;
+while i < 3 {
+    i = i + 1;
+}
+
+let s = if i == 3 { "three" } else { "other" };
+
+test "positive" {
+    assert_eq(sign(5), 1);
+}
+"#;
+
+fn cover_program() -> Coverage {
+    let mut coverage = Coverage::default();
+
+    coverage
+        .cover(Path::new("main.fru"), SOURCE, || {
+            execute_source_code(SOURCE.to_string(), None)
+        })
+        .unwrap();
+
+    coverage
+}
+
+#[test]
+fn test_lines() {
+    let coverage = cover_program();
+    let lines: Vec<_> = coverage.files[0].lines.iter().map(|(x, y)| (*x, *y)).collect();
+
+    assert_eq!(
+        lines,
+        [
+            (2, 1),
+            (3, 0),
+            (4, 0),
+            (6, 0),
+            (8, 0),
+            (12, 1),
+            (13, 3),
+            (14, 3),
+            (17, 1),
+            (19, 1),
+            (20, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_branches() {
+    let mut coverage = Coverage::default();
+
+    // program is executed once before the only test, which takes the only positive branch
+    coverage.cover(Path::new("main.fru"), SOURCE, || {
+        execute_tests(Path::new("main.fru"), SOURCE.to_string(), "").unwrap()
+    });
+
+    let branch = |line, taken, not_taken| Branch {
+        line,
+        taken,
+        not_taken,
+    };
+
+    assert_eq!(
+        coverage.files[0].branches,
+        [
+            branch(3, Some(1), Some(0)),
+            branch(5, None, None),
+            branch(13, Some(3), Some(1)),
+            branch(17, Some(1), Some(0)),
+        ]
+    );
+}
+
+#[test]
+fn test_summary() {
+    assert_eq!(
+        cover_program().summary(),
+        "main.fru: lines 6/11 (54.5%), branches 3/8 (37.5%)
+    not executed: 3-8, 20
+total: lines 6/11 (54.5%), branches 3/8 (37.5%)
+"
+    );
+}
+
+#[test]
+fn test_lcov() {
+    let coverage = cover_program();
+    let lcov = coverage.lcov();
+    let records: Vec<_> = lcov.lines().filter(|x| !x.starts_with("DA:")).collect();
+
+    assert_eq!(
+        records,
+        [
+            "TN:",
+            "SF:main.fru",
+            "BRDA:3,0,0,-",
+            "BRDA:3,0,1,-",
+            "BRDA:5,1,0,-",
+            "BRDA:5,1,1,-",
+            "BRDA:13,2,0,3",
+            "BRDA:13,2,1,1",
+            "BRDA:17,3,0,1",
+            "BRDA:17,3,1,0",
+            "BRF:8",
+            "BRH:3",
+            "LF:11",
+            "LH:6",
+            "end_of_record",
+        ]
+    );
+
+    assert!(lcov.contains("\nDA:13,3\nDA:14,3\n"));
+}
+
+#[test]
+fn test_imported_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let module = dir.path().join("module.fru");
+    let main = dir.path().join("main.fru");
+
+    fs::write(
+        &module,
+        "fn f(x) {\n    if x { 1 } else { 2 }\n}\nlet y = 0;\n",
+    )
+    .unwrap();
+
+    let source = format!("let m = import {:?};\nm.f(true);\n", module);
+    fs::write(&main, &source).unwrap();
+
+    let mut coverage = Coverage::default();
+
+    coverage.cover(&main, &source, || execute_file(&main)).unwrap();
+
+    let files: Vec<(_, Vec<_>)> = coverage
+        .files
+        .iter()
+        .map(|x| {
+            (
+                x.path.clone(),
+                x.lines.iter().map(|(x, y)| (*x, *y)).collect(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        files,
+        [
+            (main.clone(), vec![(1, 1), (2, 1)]),
+            (module.clone(), vec![(1, 1), (2, 1), (4, 1)]),
+        ]
+    );
+
+    assert_eq!(
+        coverage.files[1].branches,
+        [Branch {
+            line: 2,
+            taken: Some(1),
+            not_taken: Some(0),
+        }]
+    );
+}
@@ -0,0 +1 @@
+mod coverage_tests;
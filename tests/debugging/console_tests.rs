@@ -0,0 +1,79 @@
+use std::io::Cursor;
+
+use crate::debugger::{cli::Console, debug_file, Breakpoints, Debugger};
+use crate::debugging::{program, Buffer, SOURCE};
+
+/// Runs program with commands and returns what console printed
+fn session(commands: &str, breakpoints: &[usize]) -> (String, bool) {
+    let (_dir, path) = program();
+    let output = Buffer::default();
+
+    let console = Console::new(
+        path.clone(),
+        SOURCE,
+        Cursor::new(commands.to_string()),
+        output.clone(),
+    );
+
+    let breakpoints: Breakpoints = breakpoints.iter().map(|x| (path.clone(), *x)).collect();
+    let stop_on_entry = breakpoints.is_empty();
+
+    let result = debug_file(
+        &path,
+        Debugger::new(Box::new(console), breakpoints, stop_on_entry),
+    );
+
+    (output.text(), result.is_ok())
+}
+
+fn stops(output: &str) -> Vec<&str> {
+    output
+        .lines()
+        .filter_map(|x| x.split_once("stopped "))
+        .map(|(_, x)| x.split_once(" at ").unwrap().0)
+        .collect()
+}
+
+#[test]
+fn test_stepping() {
+    let (output, ok) = session("n\nn\nn\ns\nbt\no\nc\n", &[]);
+
+    assert!(ok);
+    assert_eq!(
+        stops(&output),
+        ["(Entry)", "(Step)", "(Step)", "(Step)", "(Step)", "(Step)"]
+    );
+    assert!(output.contains("   12 |     let b = a + 1;"));
+    assert!(output.contains("#0 f at 12:5\n#1 <program> at 17:1"));
+    assert!(output.contains("   18 | print(p.sum());"));
+}
+
+#[test]
+fn test_inspection() {
+    let (output, ok) = session("l\np doubled * x\np let y = 2; y + x\nc\n", &[7]);
+
+    assert!(ok);
+    assert_eq!(stops(&output), ["(Breakpoint)"]);
+    assert!(output.contains(
+        "Locals:\n    doubled = 10\nFields of `Point`:\n    x = 5\nStatics of `Point`:\n    count = 1\n"
+    ));
+    assert!(output.contains("(debug) 50\n(debug) 7\n"));
+}
+
+#[test]
+fn test_breakpoints() {
+    let (output, ok) = session("d 12\nb 19\nc\nc\n", &[12]);
+
+    assert!(ok);
+    assert!(output.contains("   12 |     let b = a + 1;"));
+    assert!(output.contains("   19 | print(r);"));
+    assert_eq!(stops(&output).len(), 2);
+}
+
+#[test]
+fn test_quit() {
+    let (output, ok) = session("q\n", &[]);
+
+    assert!(!ok);
+    assert_eq!(stops(&output), ["(Entry)"]);
+}
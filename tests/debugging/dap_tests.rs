@@ -0,0 +1,308 @@
+use std::{fs, io::Cursor, path::Path};
+
+use serde_json::{json, Value};
+
+use crate::debugger::dap;
+use crate::debugging::{program, Buffer};
+
+/// Runs debug adapter with requests, sent after configuration, and returns messages it sent
+fn session(breakpoints: &[usize], stop_on_entry: bool, requests: &[(&str, Value)]) -> Vec<Value> {
+    let (_dir, path) = program();
+
+    adapter(&path, &[(&path, breakpoints)], stop_on_entry, requests)
+}
+
+/// Debugs `program` with breakpoints, set for every file separately
+fn adapter(
+    program: &Path,
+    breakpoints: &[(&Path, &[usize])],
+    stop_on_entry: bool,
+    requests: &[(&str, Value)],
+) -> Vec<Value> {
+    let mut configuration = vec![
+        ("initialize", json!({ "adapterID": "frugurt" })),
+        (
+            "launch",
+            json!({ "program": program, "stopOnEntry": stop_on_entry }),
+        ),
+    ];
+
+    for (path, lines) in breakpoints {
+        let lines: Vec<_> = lines.iter().map(|x| json!({ "line": x })).collect();
+
+        configuration.push((
+            "setBreakpoints",
+            json!({ "source": { "path": path }, "breakpoints": lines }),
+        ));
+    }
+
+    configuration.push(("configurationDone", json!({})));
+
+    let mut input = String::new();
+
+    for (seq, (command, arguments)) in configuration
+        .iter()
+        .chain(requests)
+        .chain([&("disconnect", json!({}))])
+        .enumerate()
+    {
+        let text = json!({ "seq": seq + 1, "type": "request", "command": command, "arguments": arguments })
+            .to_string();
+
+        input += &format!("Content-Length: {}\r\n\r\n{}", text.len(), text);
+    }
+
+    let output = Buffer::default();
+
+    dap::run(Cursor::new(input), output.clone()).unwrap();
+
+    let output = output.text();
+    let mut messages = Vec::new();
+    let mut rest = output.as_str();
+
+    while let Some((header, body)) = rest.split_once("\r\n\r\n") {
+        let length: usize = header.strip_prefix("Content-Length: ").unwrap().parse().unwrap();
+
+        messages.push(serde_json::from_str(&body[..length]).unwrap());
+        rest = &body[length..];
+    }
+
+    messages
+}
+
+fn responses(messages: &[Value], command: &str) -> Vec<Value> {
+    messages
+        .iter()
+        .filter(|x| x["type"] == "response" && x["command"] == command)
+        .cloned()
+        .collect()
+}
+
+fn events(messages: &[Value], event: &str) -> Vec<Value> {
+    messages
+        .iter()
+        .filter(|x| x["event"] == event)
+        .map(|x| x["body"].clone())
+        .collect()
+}
+
+fn stack_lines(messages: &[Value]) -> Vec<Vec<u64>> {
+    responses(messages, "stackTrace")
+        .iter()
+        .map(|x| {
+            x["body"]["stackFrames"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|x| x["line"].as_u64().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn variables(response: &Value) -> Vec<String> {
+    response["body"]["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            format!(
+                "{} = {}",
+                x["name"].as_str().unwrap(),
+                x["value"].as_str().unwrap()
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_run_to_end() {
+    let messages = session(&[], false, &[]);
+
+    let output: Vec<_> = events(&messages, "output").iter().map(|x| x["output"].clone()).collect();
+
+    assert_eq!(output, ["11\n", "8\n"]);
+    assert_eq!(events(&messages, "exited"), [json!({ "exitCode": 0 })]);
+    assert_eq!(events(&messages, "terminated").len(), 1);
+}
+
+#[test]
+fn test_stepping() {
+    let thread = json!({ "threadId": 1 });
+
+    let messages = session(
+        &[],
+        true,
+        &[
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("stepIn", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("stepOut", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("continue", thread.clone()),
+        ],
+    );
+
+    let reasons: Vec<_> =
+        events(&messages, "stopped").iter().map(|x| x["reason"].clone()).collect();
+
+    assert_eq!(reasons, ["entry", "step", "step", "step", "step", "step"]);
+    assert_eq!(
+        stack_lines(&messages),
+        [vec![1], vec![11], vec![17], vec![12, 17], vec![18]]
+    );
+    assert_eq!(events(&messages, "terminated").len(), 1);
+}
+
+#[test]
+fn test_inspection() {
+    let messages = session(
+        &[7],
+        false,
+        &[
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("scopes", json!({ "frameId": 1 })),
+            ("variables", json!({ "variablesReference": 1 })),
+            ("variables", json!({ "variablesReference": 2 })),
+            ("variables", json!({ "variablesReference": 3 })),
+            ("variables", json!({ "variablesReference": 4 })),
+            ("variables", json!({ "variablesReference": 6 })),
+            (
+                "evaluate",
+                json!({ "expression": "doubled * x", "frameId": 1 }),
+            ),
+            ("evaluate", json!({ "expression": "y", "frameId": 1 })),
+            ("continue", json!({ "threadId": 1 })),
+        ],
+    );
+
+    assert_eq!(events(&messages, "stopped")[0]["reason"], "breakpoint");
+    assert_eq!(stack_lines(&messages), [vec![7, 18]]);
+
+    let scopes: Vec<_> = responses(&messages, "scopes")[0]["body"]["scopes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["name"].clone())
+        .collect();
+
+    assert_eq!(
+        scopes,
+        [
+            "Locals",
+            "Fields of `Point`",
+            "Statics of `Point`",
+            "Globals"
+        ]
+    );
+
+    let variables: Vec<_> = responses(&messages, "variables").iter().map(variables).collect();
+
+    assert_eq!(variables[0], ["doubled = 10"]);
+    assert_eq!(variables[1], ["x = 5"]);
+    assert_eq!(variables[2], ["count = 1"]);
+    assert_eq!(
+        variables[3],
+        ["Point = Point", "f = fn f(a)", "p = Point{x=5}", "r = 8"]
+    );
+    // globals referenced statics of `Point` and then fields of `p`
+    assert_eq!(variables[4], ["x = 5"]);
+
+    let evaluated = responses(&messages, "evaluate");
+
+    assert_eq!(evaluated[0]["body"]["result"], "50");
+    assert_eq!(evaluated[1]["success"], false);
+    assert_eq!(evaluated[1]["message"], "variable `y` does not exist");
+}
+
+#[test]
+fn test_breakpoints_while_paused() {
+    let messages = session(
+        &[12],
+        false,
+        &[
+            ("stackTrace", json!({ "threadId": 1 })),
+            (
+                "setBreakpoints",
+                json!({ "source": {}, "breakpoints": [{ "line": 19 }] }),
+            ),
+            ("continue", json!({ "threadId": 1 })),
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("continue", json!({ "threadId": 1 })),
+        ],
+    );
+
+    assert_eq!(stack_lines(&messages), [vec![12, 17], vec![19]]);
+    assert_eq!(events(&messages, "terminated").len(), 1);
+}
+
+#[test]
+fn test_disconnect_while_paused() {
+    let messages = session(&[], true, &[]);
+
+    assert_eq!(events(&messages, "stopped").len(), 1);
+    assert!(events(&messages, "terminated").is_empty());
+    assert_eq!(responses(&messages, "disconnect")[0]["success"], true);
+}
+
+#[test]
+fn test_breakpoints_in_imported_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let module = dir.path().join("module.fru");
+    let main = dir.path().join("main.fru");
+
+    fs::write(
+        &module,
+        r#"let unused = 0;
+fn f(x) {
+    let y = x + 1;
+    y * 2
+}
+"#,
+    )
+    .unwrap();
+
+    fs::write(
+        &main,
+        format!(
+            r#"let m = import {:?};
+let a = 1;
+let b = m.f(a);
+print(b);
+"#,
+            module
+        ),
+    )
+    .unwrap();
+
+    let thread = json!({ "threadId": 1 });
+
+    let messages = adapter(
+        &main,
+        &[(&main, &[2]), (&module, &[4])],
+        false,
+        &[
+            ("stackTrace", thread.clone()),
+            ("continue", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("continue", thread.clone()),
+        ],
+    );
+
+    assert_eq!(stack_lines(&messages), [vec![2], vec![4, 3]]);
+
+    let sources: Vec<_> = responses(&messages, "stackTrace")[1]["body"]["stackFrames"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["source"]["path"].clone())
+        .collect();
+
+    assert_eq!(sources, [json!(module), json!(main)]);
+    assert_eq!(events(&messages, "terminated").len(), 1);
+}
@@ -0,0 +1,56 @@
+use std::{cell::RefCell, fs, io::Write, path::PathBuf, rc::Rc};
+
+use tempfile::TempDir;
+
+mod console_tests;
+mod dap_tests;
+
+pub const SOURCE: &str = r#"struct Point {
+    x;
+    static count = 1;
+} impl {
+    sum() {
+        let doubled = x * 2;
+        doubled + 1
+    }
+}
+
+let f = fn (a) {
+    let b = a + 1;
+    b * 2
+};
+
+let p = Point :{ 5 };
+let r = f(3);
+print(p.sum());
+print(r);
+"#;
+
+/// Writer, which content can be read after it was given away
+#[derive(Clone, Default)]
+pub struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+impl Buffer {
+    pub fn text(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn program() -> (TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.fru");
+
+    fs::write(&path, SOURCE).unwrap();
+
+    (dir, path)
+}
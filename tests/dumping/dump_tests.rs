@@ -0,0 +1,131 @@
+use serde_json::json;
+
+use crate::dump::{dump_ast, dump_cst};
+
+#[test]
+fn test_cst() {
+    assert_eq!(
+        dump_cst("let x = f(1)"),
+        r#"(source_file [0, 0] - [0, 12]
+  body: (let_statement [0, 0] - [0, 12]
+    ident: (identifier [0, 4] - [0, 5])
+    value: (call_expression [0, 8] - [0, 12]
+      what: (variable [0, 8] - [0, 9]
+        ident: (identifier [0, 8] - [0, 9]))
+      args: (argument_list [0, 9] - [0, 12]
+        args: (positional_argument [0, 10] - [0, 11]
+          value: (number_literal [0, 10] - [0, 11]))))
+    (MISSING ";" [0, 12] - [0, 12])))
+"#
+    );
+}
+
+#[test]
+fn test_ast() {
+    let position = |start: usize, end: usize| {
+        json!({
+            "start": { "row": 0, "column": start },
+            "end": { "row": 0, "column": end },
+        })
+    };
+
+    assert_eq!(
+        dump_ast("let x = f(1, a: nah);").unwrap(),
+        json!({
+            "kind": "SourceCode",
+            "body": [{
+                "kind": "Positioned",
+                "position": position(0, 21),
+                "statement": {
+                    "kind": "Let",
+                    "ident": "x",
+                    "value": {
+                        "kind": "Call",
+                        "what": { "kind": "Variable", "ident": "f" },
+                        "args": [
+                            {
+                                "kind": "Positional",
+                                "value": { "kind": "Literal", "type": "Number", "value": 1.0 },
+                            },
+                            {
+                                "kind": "Named",
+                                "ident": "a",
+                                "value": { "kind": "Literal", "type": "Nah", "value": null },
+                            },
+                        ],
+                    },
+                },
+            }],
+        })
+    );
+}
+
+#[test]
+fn test_ast_type() {
+    let ast = dump_ast(
+        r#"
+struct Point {
+    pub x;
+    y = 0;
+
+    static origin = 0;
+
+    b {
+        get => 1;
+    }
+
+    a {
+        get => 2;
+        set(v) {}
+    }
+} impl {
+    static new() {}
+}
+"#,
+    )
+    .unwrap();
+
+    let point = &ast["body"][0]["statement"];
+
+    assert_eq!(point["kind"], "Type");
+    assert_eq!(point["type_type"], "struct");
+    assert_eq!(
+        point["fields"],
+        json!([
+            {
+                "is_public": true,
+                "ident": "x",
+                "type_ident": null,
+                "is_embedded": false,
+                "default": null,
+            },
+            {
+                "is_public": false,
+                "ident": "y",
+                "type_ident": null,
+                "is_embedded": false,
+                "default": { "kind": "Literal", "type": "Number", "value": 0.0 },
+            },
+        ])
+    );
+    assert_eq!(point["static_fields"][0]["ident"], "origin");
+
+    // properties are sorted, so output does not depend on hashing
+    assert_eq!(point["properties"][0]["ident"], "a");
+    assert_eq!(point["properties"][0]["setter"]["ident"], "v");
+    assert_eq!(point["properties"][1]["ident"], "b");
+    assert_eq!(point["properties"][1]["setter"], json!(null));
+    assert_eq!(point["properties"][1]["getter"]["kind"], "Callable");
+    assert_eq!(point["properties"][1]["getter"]["name"], "Point.b get");
+
+    assert_eq!(point["methods"][0]["is_static"], true);
+    assert_eq!(point["methods"][0]["ident"], "new");
+}
+
+#[test]
+fn test_ast_error() {
+    assert_eq!(
+        dump_ast("let x = ;").unwrap_err().to_string(),
+        "expected expression at 1:7-1:7"
+    );
+}
@@ -1,7 +1,7 @@
 use crate::run;
 
 #[test]
-#[should_panic(expected = "DoesNotExist")]
+#[should_panic(expected = "unknown argument `c`")]
 fn test_named_error_1() {
     run(r#"
             let f = fn (a = 1, b = 2) {2 * a + b};
@@ -11,7 +11,7 @@ fn test_named_error_1() {
 }
 
 #[test]
-#[should_panic(expected = "SameSetTwice")]
+#[should_panic(expected = "argument `a` is set twice")]
 fn test_named_error_2() {
     run(r#"
             let f = fn (a = 1, b = 2) {2 * a + b};
@@ -21,7 +21,7 @@ fn test_named_error_2() {
 }
 
 #[test]
-#[should_panic(expected = "SameSetTwice")]
+#[should_panic(expected = "argument `a` is set twice")]
 fn test_named_error_3() {
     run(r#"
             let f = fn (a = 1, b = 2) {2 * a + b};
@@ -31,7 +31,7 @@ fn test_named_error_3() {
 }
 
 #[test]
-#[should_panic(expected = "NotSet")]
+#[should_panic(expected = "missing argument `a`")]
 fn test_named_error_4() {
     run(r#"
             let f = fn (a, b = 2) {2 * a + b};
@@ -69,7 +69,7 @@ fn test_named_error_7() {
 }
 
 #[test]
-#[should_panic(expected = "TooMany")]
+#[should_panic(expected = "too many arguments")]
 fn test_count_error_1() {
     run(r#"
             let f = fn (a, b) {};
@@ -79,7 +79,7 @@ fn test_count_error_1() {
 }
 
 #[test]
-#[should_panic(expected = "NotSet")]
+#[should_panic(expected = "missing argument `b`")]
 fn test_count_error_2() {
     run(r#"
             let f = fn (a, b) {};
@@ -95,3 +95,90 @@ fn test_error_propagation() {
             (1 / 0)();
         "#)
 }
+
+#[test]
+fn test_rest() {
+    run(r#"
+            let f = fn (a, b = 2, ..rest) {
+                a + b + rest.len()
+            };
+
+            assert_eq(f(1), 3);
+            assert_eq(f(1, 1), 2);
+            assert_eq(f(1, 1, "x", "y"), 4);
+            assert_eq(str(fn (..rest) { rest }(1, "2")), "[1, \"2\"]");
+        "#)
+}
+
+#[test]
+fn test_kwargs() {
+    run(r#"
+            let f = fn (a, **opts) {
+                opts
+            };
+
+            let opts = f(1, b: 2, c: "3");
+
+            assert_eq(str(opts), "{\"b\": 2, \"c\": \"3\"}");
+            assert_eq(opts.get("b"), 2);
+            assert_eq(opts.has("a"), false);
+            assert_eq(f(a: 1).len(), 0);
+        "#)
+}
+
+#[test]
+fn test_spread() {
+    run(r#"
+            let f = fn (a, b, c = 0) {
+                a * 100 + b * 10 + c
+            };
+
+            let wrapper = fn (..args, **kwargs) {
+                f(..args, **kwargs) + 1
+            };
+
+            assert_eq(wrapper(1, 2), 121);
+            assert_eq(wrapper(1, 2, 3), 124);
+            assert_eq(wrapper(1, b: 2, c: 3), 124);
+
+            let collect = fn (..xs) { xs };
+            assert_eq(f(..collect(1, 2), ..collect(3)), 123);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "argument `x` is set twice")]
+fn test_spread_same_set_twice() {
+    run(r#"
+            let f = fn (**opts) {};
+            let g = fn (**opts) {
+                f(**opts, x: 2);
+            };
+
+            g(x: 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot spread `Number` with `..`, expected `List`")]
+fn test_spread_error() {
+    run(r#"
+            print(..1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Rest parameter should be the only one and go before `**` parameter")]
+fn test_rest_after_kwargs() {
+    run(r#"
+            let f = fn (**opts, ..rest) {};
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Parameter `b` should be before `..` and `**` parameters")]
+fn test_parameter_after_rest() {
+    run(r#"
+            let f = fn (a, ..rest, b) {};
+        "#)
+}
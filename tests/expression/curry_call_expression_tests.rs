@@ -41,3 +41,72 @@ fn test_error_propagation_2() {
             nah$(1 / 0);
         "#)
 }
+
+#[test]
+fn test_saved_struct_is_copied() {
+    run(r#"
+            struct Counter {
+                n;
+            }
+
+            let inc = fn (counter) {
+                counter.n = counter.n + 1;
+                counter.n
+            };
+
+            let c = Counter :{ 0 };
+            let g = inc$(c);
+
+            c.n = 10;
+
+            assert_eq(g(), 1);
+            assert_eq(g(), 1);
+            assert_eq(c.n, 10);
+        "#)
+}
+
+#[test]
+fn test_saved_class_is_shared() {
+    run(r#"
+            class Counter {
+                n;
+            }
+
+            let inc = fn (counter) {
+                counter.n = counter.n + 1;
+                counter.n
+            };
+
+            let c = Counter :{ n: 0 };
+            let g = inc$(c);
+
+            assert_eq(g(), 1);
+            assert_eq(g(), 2);
+            assert_eq(c.n, 2);
+        "#)
+}
+
+#[test]
+fn test_saved_data_is_shared() {
+    run(r#"
+            class Counter {
+                n;
+            }
+
+            data Wrapper {
+                counter;
+            }
+
+            let inc = fn (wrapper) {
+                wrapper.counter.n = wrapper.counter.n + 1;
+                wrapper.counter.n
+            };
+
+            let w = Wrapper :{ Counter :{ n: 0 } };
+            let g = inc$(w)$();
+
+            assert_eq(g(), 1);
+            assert_eq(g(), 2);
+            assert_eq(w.counter.n, 2);
+        "#)
+}
@@ -106,32 +106,32 @@ fn test_named() {
 }
 
 #[test]
-#[should_panic(expected = "unexpected signal Continue")]
-fn test_unexpected_signal_1() {
+#[should_panic(expected = "`continue` outside of a loop")]
+fn test_continue_outside_of_loop() {
     run(r#"
             fn () {continue;}();
         "#)
 }
 
 #[test]
-#[should_panic(expected = "unexpected signal Break")]
-fn test_unexpected_signal_2() {
+#[should_panic(expected = "`break` outside of a loop")]
+fn test_break_outside_of_loop() {
     run(r#"
             fn () {break;}();
         "#)
 }
 
 #[test]
-#[should_panic(expected = "unexpected signal Continue")]
-fn test_unexpected_signal_default_1() {
+#[should_panic(expected = "`continue` outside of a loop")]
+fn test_continue_in_default() {
     run(r#"
             fn (a={continue; 1}) {}();
         "#)
 }
 
 #[test]
-#[should_panic(expected = "unexpected signal Break")]
-fn test_unexpected_signal_default_2() {
+#[should_panic(expected = "`break` outside of a loop")]
+fn test_break_in_default() {
     run(r#"
             fn (a={break; 1}) {}();
         "#)
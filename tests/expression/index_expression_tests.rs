@@ -0,0 +1,178 @@
+use crate::run;
+
+#[test]
+fn test_list() {
+    run(r#"
+            fn list(..items) { items }
+
+            let l = list(1, 2, 3, 4);
+
+            assert_eq(l[0], 1);
+            assert_eq(l[3], 4);
+            assert_eq(l[-1], 4);
+            assert_eq(l[-4], 1);
+
+            l[1] = 20;
+            l[-1] += 36;
+
+            assert_eq(str(l), "[1, 20, 3, 40]");
+        "#)
+}
+
+#[test]
+fn test_slice() {
+    run(r#"
+            fn list(..items) { items }
+
+            let l = list(1, 2, 3, 4);
+
+            assert_eq(str(l[1..3]), "[2, 3]");
+            assert_eq(str(l[..2]), "[1, 2]");
+            assert_eq(str(l[2..]), "[3, 4]");
+            assert_eq(str(l[..-1]), "[1, 2, 3]");
+            assert_eq(str(l[-2..]), "[3, 4]");
+            assert_eq(str(l[..]), "[1, 2, 3, 4]");
+
+            // bounds are clamped, like in python
+            assert_eq(str(l[3..100]), "[4]");
+            assert_eq(str(l[3..1]), "[]");
+        "#)
+}
+
+#[test]
+fn test_string() {
+    run(r#"
+            let s = "привет";
+
+            assert_eq(s[0], "п");
+            assert_eq(s[-1], "т");
+            assert_eq(s[1..4], "рив");
+            assert_eq(s[..-2], "прив");
+        "#)
+}
+
+#[test]
+fn test_map() {
+    run(r#"
+            fn map(**items) { items }
+
+            let m = map(a: 1);
+
+            assert_eq(m["a"], 1);
+
+            m["a"] += 1;
+            m["b"] = 5;
+
+            assert_eq(str(m), "{\"a\": 2, \"b\": 5}");
+        "#)
+}
+
+#[test]
+fn test_user_type() {
+    run(r#"
+            struct Grid {
+                cells;
+            } impl {
+                get_index(i) {
+                    if cells.has(i) { cells[i] } else { 0 }
+                }
+
+                set_index(i, value) {
+                    cells[i] = value;
+                }
+            }
+
+            fn map(**items) { items }
+
+            let g = Grid :{ map() };
+
+            assert_eq(g["x"], 0);
+
+            g["x"] = 3;
+            g["x"] *= 2;
+
+            assert_eq(g["x"], 6);
+        "#)
+}
+
+#[test]
+fn test_slice_user_type() {
+    run(r#"
+            struct Window {} impl {
+                get_index(r) {
+                    f"{r.start}..{r.end}"
+                }
+            }
+
+            let w = Window :{};
+
+            assert_eq(w[1..2], "1..2");
+            assert_eq(w[..], "nah..nah");
+        "#)
+}
+
+#[test]
+fn test_chain() {
+    run(r#"
+            fn list(..items) { items }
+
+            struct Box { items; }
+
+            let b = Box :{ list(list(1, 2), list(3)) };
+            let n = nah;
+
+            assert_eq(b.items[0][1], 2);
+            assert_eq(n?.items[0], nah);
+
+            b.items[1][0] = 30;
+            assert_eq(b.items[1][0], 30);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "index 3 is out of bounds for List of length 3")]
+fn test_out_of_bounds() {
+    run(r#"
+            fn list(..items) { items }
+
+            list(1, 2, 3)[3];
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "index -4 is out of bounds for String of length 3")]
+fn test_negative_out_of_bounds() {
+    run(r#"
+            "abc"[-4];
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "index 1.5 is not an integer")]
+fn test_fractional_index() {
+    run(r#"
+            fn list(..items) { items }
+
+            list(1, 2, 3, 4)[1.5];
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot index `Number`")]
+fn test_not_indexable() {
+    run(r#"
+            let x = 5;
+
+            x[0];
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot set index of `String`")]
+fn test_string_immutable() {
+    run(r#"
+            let s = "abc";
+
+            s[0] = "x";
+        "#)
+}
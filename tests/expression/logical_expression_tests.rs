@@ -0,0 +1,108 @@
+use crate::run;
+
+#[test]
+fn test_bool() {
+    run(r#"
+            assert_eq(true && true, true);
+            assert_eq(true && false, false);
+            assert_eq(false && true, false);
+            assert_eq(false || false, false);
+            assert_eq(false || true, true);
+            assert_eq(true || false, true);
+            assert_eq(false || true && false, false);
+        "#)
+}
+
+#[test]
+fn test_short_circuit() {
+    run(r#"
+            struct Box { value; }
+
+            let f = fn(present, x) { present && x.value > 1 };
+            let g = fn(missing, x) { missing || x.value > 1 };
+
+            assert_eq(f(false, nah), false);
+            assert_eq(f(true, Box :{ 2 }), true);
+            assert_eq(g(true, nah), true);
+            assert_eq(g(false, Box :{ 0 }), false);
+        "#)
+}
+
+#[test]
+fn test_nah_check() {
+    run(r#"
+            struct Box { value; } impl { f() { value } }
+
+            let f = fn(x) { x != nah && x.f() };
+
+            assert_eq(f(nah), false);
+            assert_eq(f(Box :{ true }), true);
+
+            assert(nah == nah);
+            assert(!(nah != nah));
+            assert(nah != 0);
+            assert(Box :{ 1 } != nah);
+            assert(!(nah == "nah"));
+        "#)
+}
+
+#[test]
+fn test_right_side_evaluated_once() {
+    run(r#"
+            let calls = 0;
+
+            let f = fn() {
+                calls = calls + 1;
+                true
+            };
+
+            assert_eq(true && f(), true);
+            assert_eq(false || f(), true);
+            assert_eq(false && f(), false);
+            assert_eq(true || f(), true);
+            assert_eq(calls, 2);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "operator `||` can not be overloaded")]
+fn test_overload() {
+    run(r#"
+            struct Flags { bits; }
+
+            operator || (a: Flags, b: Flags) {
+                Flags :{ a.bits + b.bits }
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Expected `Bool` in `&&`, got `Bool` and `Number`")]
+fn test_not_bool() {
+    run(r#"
+            true && 1;
+        "#)
+}
+
+#[test]
+fn test_not() {
+    run(r#"
+            struct Box { value; }
+
+            let b = Box :{ false };
+
+            assert_eq(!true, false);
+            assert_eq(!!true, true);
+            assert_eq(!b.value, true);
+            assert_eq(!false && false, false);
+            assert_eq(!(false && false), true);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Expected `Bool` in negation, got `Number`")]
+fn test_not_number() {
+    run(r#"
+            !1;
+        "#)
+}
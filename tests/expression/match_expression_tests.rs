@@ -0,0 +1,100 @@
+use crate::run;
+
+#[test]
+fn test_values() {
+    run(r#"
+            let name = fn(x) {
+                match x {
+                    1 => "one",
+                    "two" => "two",
+                    nah => "nothing",
+                    _ => "other",
+                }
+            };
+
+            assert_eq(name(1), "one");
+            assert_eq(name("two"), "two");
+            assert_eq(name(nah), "nothing");
+            assert_eq(name(3), "other");
+        "#)
+}
+
+#[test]
+fn test_bindings_and_guards() {
+    run(r#"
+            enum Shape {
+                Circle { r },
+                Rect { w, h },
+                Empty,
+            }
+
+            let describe = fn(s) {
+                match s {
+                    Shape.Circle { r } if r > 10 => "big circle",
+                    Shape.Circle { r } => f"circle {r}",
+                    Shape.Rect { h, w } => f"rect {w}x{h}",
+                    Shape.Empty => "empty",
+                }
+            };
+
+            assert_eq(describe(Shape.Circle :{ 11 }), "big circle");
+            assert_eq(describe(Shape.Circle :{ 1 }), "circle 1");
+            assert_eq(describe(Shape.Rect :{ 2, 3 }), "rect 2x3");
+            assert_eq(describe(Shape.Empty), "empty");
+        "#)
+}
+
+#[test]
+fn test_types() {
+    run(r#"
+            struct A { x; }
+            struct B { x; }
+
+            let f = fn(v) {
+                match v {
+                    A { x } => x,
+                    B => "b",
+                    _ => "other",
+                }
+            };
+
+            assert_eq(f(A :{ 1 }), 1);
+            assert_eq(f(B :{ 1 }), "b");
+            assert_eq(f(1), "other");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "no arm of match matches `3`")]
+fn test_unmatched() {
+    run(r#"
+            match 3 {
+                1 => 1,
+                2 => 2,
+            };
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`Shape.Circle` has no field `d`")]
+fn test_unknown_binding() {
+    run(r#"
+            enum Shape {
+                Circle { r },
+            }
+
+            match Shape.Circle :{ 1 } {
+                Shape.Circle { d } => d,
+            };
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Expected `Bool` in match guard, got `Number`")]
+fn test_guard_type_mismatch() {
+    run(r#"
+            match 1 {
+                _ if 1 => 1,
+            };
+        "#)
+}
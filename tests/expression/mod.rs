@@ -4,6 +4,11 @@ mod call_expression_tests;
 mod curry_call_expression_tests;
 mod function_expression_tests;
 mod if_expression_tests;
+mod index_expression_tests;
 mod instantiation_expression_tests;
+mod logical_expression_tests;
+mod match_expression_tests;
+mod optional_access_tests;
 mod prop_access_tests;
+mod set_expression_tests;
 mod variable_expression_tests;
@@ -0,0 +1,115 @@
+use crate::run;
+
+#[test]
+fn test_optional_prop() {
+    run(r#"
+            struct Node { value; next; }
+
+            let last = Node :{ 2, nah };
+            let first = Node :{ 1, last };
+
+            assert_eq(first?.next?.value, 2);
+            assert_eq(last?.next?.value, nah);
+            assert_eq(last.next?.next.value, nah);
+        "#)
+}
+
+#[test]
+fn test_optional_method() {
+    run(r#"
+            class Counter {
+                count = 0;
+            } impl {
+                inc() {
+                    count = count + 1;
+                    count
+                }
+            }
+
+            let c = Counter :{};
+            let missing = nah;
+
+            assert_eq(c?.inc(), 1);
+            assert_eq(missing?.inc(), nah);
+            assert_eq(missing?.inc().show(), nah);
+            assert_eq(c.count, 1);
+        "#)
+}
+
+#[test]
+fn test_optional_skips_arguments() {
+    run(r#"
+            let missing = nah;
+            let calls = 0;
+
+            let f = fn() {
+                calls = calls + 1;
+            };
+
+            missing?.call(f());
+
+            assert_eq(calls, 0);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot access prop of `Nah`")]
+fn test_plain_access_of_nah() {
+    run(r#"
+            let missing = nah;
+
+            missing.value;
+        "#)
+}
+
+#[test]
+fn test_coalesce() {
+    run(r#"
+            let missing = nah;
+
+            assert_eq(missing ?? 5, 5);
+            assert_eq(3 ?? 5, 3);
+            assert_eq(false ?? true, false);
+            assert_eq(missing ?? missing ?? "x", "x");
+            assert_eq(2 ?? 1 == 1, 2);
+            assert_eq(missing ?? 1 + 2, 3);
+        "#)
+}
+
+#[test]
+fn test_coalesce_short_circuits() {
+    run(r#"
+            let calls = 0;
+
+            let f = fn() {
+                calls = calls + 1;
+                10
+            };
+
+            assert_eq(1 ?? f(), 1);
+            assert_eq(calls, 0);
+
+            assert_eq(nah ?? f(), 10);
+            assert_eq(calls, 1);
+        "#)
+}
+
+#[test]
+fn test_coalesce_with_optional() {
+    run(r#"
+            struct Config { name; }
+
+            let get_name = fn(config) { config?.name ?? "default" };
+
+            assert_eq(get_name(Config :{ "custom" }), "custom");
+            assert_eq(get_name(nah), "default");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "operator `??` can not be overloaded")]
+fn test_coalesce_overload() {
+    run(r#"
+            operator ?? (a : Number, b : Number) { 1 }
+        "#)
+}
@@ -0,0 +1,141 @@
+use crate::run;
+
+#[test]
+fn test_compound() {
+    run(r#"
+            let x = 10;
+
+            x += 5;
+            assert_eq(x, 15);
+
+            x -= 1;
+            x *= 2;
+            x /= 4;
+            assert_eq(x, 7);
+
+            x %= 4;
+            x **= 2;
+            assert_eq(x, 9);
+
+            let s = "hello";
+            s <>= "!";
+            assert_eq(s, "hello!");
+        "#)
+}
+
+#[test]
+fn test_compound_prop() {
+    run(r#"
+            struct Counter { count; }
+
+            let c = Counter :{ 1 };
+
+            c.count += 2;
+            c.count *= 2;
+
+            assert_eq(c.count, 6);
+        "#)
+}
+
+#[test]
+fn test_target_evaluated_once() {
+    run(r#"
+            class Counter { count; }
+
+            let c = Counter :{ 1 };
+            let calls = 0;
+
+            let get = fn() {
+                calls += 1;
+                c
+            };
+
+            get().count += 10;
+
+            assert_eq(c.count, 11);
+            assert_eq(calls, 1);
+        "#)
+}
+
+#[test]
+fn test_custom_operator() {
+    run(r#"
+            struct Vector { x; y; }
+
+            operator + (a: Vector, b: Vector) {
+                Vector :{ a.x + b.x, a.y + b.y }
+            }
+
+            let v = Vector :{ 1, 2 };
+            v += Vector :{ 3, 4 };
+
+            assert_eq(v.x, 4);
+            assert_eq(v.y, 6);
+        "#)
+}
+
+#[test]
+fn test_declared_compound_operator() {
+    run(r#"
+            struct Vector { x; y; }
+
+            operator + (a: Vector, b: Vector) {
+                Vector :{ 0, 0 }
+            }
+
+            operator += (a: Vector, b: Vector) {
+                a.x = a.x + b.x;
+                a.y = a.y + b.y;
+            }
+
+            let v = Vector :{ 1, 2 };
+            let w = (v += Vector :{ 3, 4 });
+
+            assert_eq(v, Vector :{ 4, 6 });
+            assert_eq(w, Vector :{ 4, 6 });
+
+            class Holder { v; }
+
+            let h = Holder :{ Vector :{ 1, 1 } };
+            h.v += Vector :{ 1, 2 };
+
+            assert_eq(h.v, Vector :{ 2, 3 });
+        "#)
+}
+
+#[test]
+fn test_value() {
+    run(r#"
+            struct Box { value; }
+
+            let b = Box :{ 1 };
+            let x = 0;
+            let y = (x = 5);
+
+            assert_eq(y, 5);
+            assert_eq((x += 1), 6);
+            assert_eq((b.value *= 3), 3);
+            assert_eq(x, 6);
+
+            let f = fn() { x = 1 };
+
+            assert_eq(f(), 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "operator `String + Number` does not exist")]
+fn test_missing_operator() {
+    run(r#"
+            let s = "a";
+            s += 1;
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "variable `x` does not exist")]
+fn test_undefined_variable() {
+    run(r#"
+            x += 1;
+        "#)
+}
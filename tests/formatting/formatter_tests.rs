@@ -0,0 +1,196 @@
+use std::{fs, process::Command};
+
+use crate::formatter::format_source;
+
+fn assert_formatted(code: &str, expected: &str) {
+    let formatted = format_source(code).unwrap();
+
+    assert_eq!(formatted, expected);
+    assert_eq!(
+        format_source(&formatted).unwrap(),
+        formatted,
+        "formatting is not idempotent"
+    );
+}
+
+#[test]
+fn test_spacing() {
+    assert_formatted(
+        "let   x=1+2*3;print( x ,f$(1)(a:2) );let f=fn(a,..rest,**opts){a};",
+        r#"let x = 1 + 2 * 3;
+print(x, f$(1)(a: 2));
+let f = fn (a, ..rest, **opts) { a };
+"#,
+    );
+}
+
+#[test]
+fn test_optional_access() {
+    assert_formatted("let n=a ?. b?.c( 1 )??d;", "let n = a?.b?.c(1) ?? d;\n");
+    assert_formatted("let n=! !a&&b;", "let n = !!a && b;\n");
+    assert_formatted("x+=1;a.b<>=(c=d);", "x += 1;\na.b <>= (c = d);\n");
+}
+
+#[test]
+fn test_indexing() {
+    assert_formatted("let c=a [ 1 ] [ i-1 ];", "let c = a[1][i - 1];\n");
+    assert_formatted("a[ 1 .. ]+=s[ .. -1 ];", "a[1..] += s[..-1];\n");
+}
+
+#[test]
+fn test_indentation() {
+    assert_formatted(
+        r#"fn f(a, b = 1) {
+if a<b {print(a);} else {
+      while true { break; }
+  }
+ }"#,
+        r#"fn f(a, b = 1) {
+    if a < b {
+        print(a);
+    } else {
+        while true {
+            break;
+        }
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn test_types() {
+    assert_formatted(
+        r#"struct   Vec{x;y : Number;
+    Length{get=>(x*x+y*y)**0.5; set(v){x=x*v;}}
+}
+impl{
+  len(){Length}
+  static zero() { Vec :{0,0} }
+}
+operator + (a : Vec, b : Vec) { Vec :{a.x+b.x, a.y + b.y} }
+class Empty {}"#,
+        r#"struct Vec {
+    x;
+    y : Number;
+    Length {
+        get => (x * x + y * y) ** 0.5;
+        set(v) {
+            x = x * v;
+        }
+    }
+} impl {
+    len() { Length }
+    static zero() { Vec :{ 0, 0 } }
+}
+operator + (a : Vec, b : Vec) { Vec :{ a.x + b.x, a.y + b.y } }
+class Empty {}
+"#,
+    );
+}
+
+#[test]
+fn test_comments() {
+    assert_formatted(
+        r#"// header
+
+
+let x = 1;   // trailing
+/* block */ let y = {
+  // inside
+  x
+};"#,
+        r#"// header
+
+let x = 1; // trailing
+/* block */ let y = {
+    // inside
+    x
+};
+"#,
+    );
+}
+
+#[test]
+fn test_block_comment_on_own_line() {
+    assert_formatted(
+        r#"let x = 1;
+/* multiline
+   comment */
+while x < 10 { x = x + 1; }"#,
+        r#"let x = 1;
+/* multiline
+   comment */
+while x < 10 {
+    x = x + 1;
+}
+"#,
+    );
+}
+
+#[test]
+fn test_multiline_arguments() {
+    assert_formatted(
+        r#"print(
+  1,
+    2
+);"#,
+        r#"print(
+    1,
+    2,
+);
+"#,
+    );
+}
+
+#[test]
+fn test_enum_and_match() {
+    assert_formatted(
+        "enum E {A,B{x,y}} let v=match e {E.B{x} if x>1=>x,_=>0};",
+        r#"enum E {
+    A,
+    B { x, y },
+}
+let v = match e {
+    E.B { x } if x > 1 => x,
+    _ => 0,
+};
+"#,
+    );
+}
+
+#[test]
+fn test_syntax_error() {
+    assert!(format_source("let = ;").is_err());
+}
+
+#[test]
+fn test_check() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let formatted = dir.path().join("formatted.fru");
+    let unformatted = dir.path().join("unformatted.fru");
+
+    fs::write(&formatted, "let x = 1;\n").unwrap();
+    fs::write(&unformatted, "let x=1;").unwrap();
+
+    let fmt = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_frugurt"))
+            .arg("fmt")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = fmt(&["--check", formatted.to_str().unwrap()]);
+    assert!(output.status.success());
+
+    let output = fmt(&["--check", unformatted.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("unformatted.fru is not formatted"));
+    assert_eq!(fs::read_to_string(&unformatted).unwrap(), "let x=1;");
+
+    let output = fmt(&[unformatted.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(&unformatted).unwrap(), "let x = 1;\n");
+}
@@ -0,0 +1 @@
+mod formatter_tests;
@@ -1,20 +1,50 @@
 use crate::interpreter::runner::execute_source_code;
 
+#[path = "../src/checker/mod.rs"]
+mod checker;
+
+#[path = "../src/coverage/mod.rs"]
+mod coverage;
+
+#[path = "../src/debugger/mod.rs"]
+mod debugger;
+
+#[path = "../src/diagnostic/mod.rs"]
+mod diagnostic;
+
+#[path = "../src/dump/mod.rs"]
+mod dump;
+
+#[path = "../src/formatter/mod.rs"]
+mod formatter;
+
 #[path = "../src/interpreter/mod.rs"]
 mod interpreter;
 
+#[path = "../src/profiler/mod.rs"]
+mod profiler;
+
 #[path = "../src/stdlib/mod.rs"]
 mod stdlib;
 
 mod builtin;
+mod checking;
+mod covering;
+mod debugging;
+mod dumping;
 mod expression;
+mod formatting;
 mod literal_expression;
+mod lsp;
 mod oop;
+mod parsing;
+mod profiling;
+mod reporting;
 mod scope_manipulation;
 mod statement;
 
 pub fn run(code: &str) {
-    if let Err(err) = execute_source_code(code.to_owned()) {
+    if let Err(err) = execute_source_code(code.to_owned(), None) {
         panic!("{}", err)
     }
 }
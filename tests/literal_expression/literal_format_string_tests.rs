@@ -0,0 +1,123 @@
+use crate::run;
+
+#[test]
+fn test_basics() {
+    run(r#"
+            let x = 5;
+            let name = "world";
+
+            assert_eq(f"hello, {name}!", "hello, world!");
+            assert_eq(f"x = {x}, x * 2 = {x * 2}", "x = 5, x * 2 = 10");
+            assert_eq(f"", "");
+            assert_eq(f"no interpolation", "no interpolation");
+        "#)
+}
+
+#[test]
+fn test_nested_expressions() {
+    run(r#"
+            struct Vec2 {
+                x;
+                y;
+            } impl {
+                len() {
+                    (x * x + y * y) ** 0.5
+                }
+            }
+
+            let v = Vec2 :{ 3, 4 };
+            let f = fn (a, b = 1) { a + b };
+
+            assert_eq(f"v = {v.len()}", "v = 5");
+            assert_eq(f"{f(1)} {f(1, b: 2)}", "2 3");
+            assert_eq(f"{ { let a = 2; a * a } }", "4");
+            assert_eq(f"{Vec2:{1, 2}.x}", "1");
+            assert_eq(f"{if v.x < 4 { 1 } else { 2 }}", "1");
+        "#)
+}
+
+#[test]
+fn test_format_spec() {
+    run(r#"
+            let pi = 3.14159;
+            let s = "ab";
+
+            assert_eq(f"{pi:.2}", "3.14");
+            assert_eq(f"[{pi:8.3}]", "[   3.142]");
+            assert_eq(f"[{pi:<8.1}]", "[3.1     ]");
+            assert_eq(f"[{s:4}]", "[ab  ]");
+            assert_eq(f"[{s:>4}]", "[  ab]");
+            assert_eq(f"[{s:*^6}]", "[**ab**]");
+            assert_eq(f"[{s:.1}]", "[a]");
+        "#)
+}
+
+#[test]
+fn test_escaping() {
+    run(r#"
+            let x = 1;
+
+            assert_eq(f"{{x}} = {x}", "{x} = 1");
+            assert_eq(f"}}{{", "}{");
+            assert_eq(f"a\tb{x}\n", "a\tb1\n");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "unclosed `{` in format string")]
+fn test_unclosed() {
+    run(r#"
+            f"{x";
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "unmatched `}` in format string")]
+fn test_unmatched() {
+    run(r#"
+            f"x}";
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Invalid interpolated expression `1 +`")]
+fn test_invalid_expression() {
+    run(r#"
+            f"{1 +}";
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`break` outside of a loop in interpolated expression")]
+fn test_break_in_interpolation() {
+    run(r#"
+            while true {
+                print(f"{ { break; 1 } }");
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`return` outside of a function in interpolated expression")]
+fn test_return_in_interpolation() {
+    run(r#"
+            f"{ { return 2; 1 } }";
+        "#)
+}
+
+#[test]
+fn test_control_flow_inside_interpolation() {
+    run(r#"
+            let f = fn() { f"{ loop { break 1; } } { (fn() { return 2; })() }" };
+
+            assert_eq(f(), "1 2");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "variable `y` does not exist")]
+fn test_error_propagation() {
+    run(r#"
+            f"{y}";
+        "#)
+}
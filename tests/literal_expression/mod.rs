@@ -1,4 +1,5 @@
 mod literal_bool_tests;
+mod literal_format_string_tests;
 mod literal_nah_tests;
 mod literal_number_tests;
 mod literal_string_tests;
@@ -0,0 +1,175 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+use serde_json::{json, Value};
+
+/// Scripted LSP client, that talks to `frugurt lsp` over stdio
+pub struct Client {
+    process: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: i64,
+}
+
+pub const URI: &str = "file:///test.fru";
+
+impl Client {
+    pub fn start() -> Self {
+        let mut process = Command::new(env!("CARGO_BIN_EXE_frugurt"))
+            .arg("lsp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to start language server");
+
+        let stdin = process.stdin.take().unwrap();
+        let stdout = BufReader::new(process.stdout.take().unwrap());
+
+        let mut client = Self {
+            process,
+            stdin,
+            stdout,
+            next_id: 0,
+        };
+
+        client.request("initialize", json!({ "capabilities": {} }));
+        client.notify("initialized", json!({}));
+
+        client
+    }
+
+    /// Opens document and returns published diagnostics
+    pub fn open(&mut self, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didOpen",
+            json!({
+                "textDocument": { "uri": URI, "languageId": "frugurt", "version": 1, "text": text }
+            }),
+        );
+
+        self.diagnostics()
+    }
+
+    pub fn change(&mut self, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": { "uri": URI, "version": 2 },
+                "contentChanges": [{ "text": text }]
+            }),
+        );
+
+        self.diagnostics()
+    }
+
+    /// Request with text document position, where `line` and `character` are zero-based
+    pub fn at(&mut self, method: &str, line: u32, character: u32) -> Value {
+        self.request(
+            method,
+            json!({
+                "textDocument": { "uri": URI },
+                "position": { "line": line, "character": character }
+            }),
+        )
+    }
+
+    pub fn request(&mut self, method: &str, params: Value) -> Value {
+        let message = self.response(method, params);
+
+        assert!(
+            message.get("error").is_none(),
+            "request failed: {}",
+            message
+        );
+
+        message["result"].clone()
+    }
+
+    /// Error of request, that is expected to fail
+    pub fn request_error(&mut self, method: &str, params: Value) -> Value {
+        let message = self.response(method, params);
+
+        assert!(
+            message.get("error").is_some(),
+            "request succeeded: {}",
+            message
+        );
+
+        message["error"].clone()
+    }
+
+    fn response(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = self.next_id;
+
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+
+        loop {
+            let message = self.receive();
+
+            if message["id"] == id {
+                return message;
+            }
+        }
+    }
+
+    pub fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    fn diagnostics(&mut self) -> Vec<Value> {
+        loop {
+            let message = self.receive();
+
+            if message["method"] == "textDocument/publishDiagnostics" {
+                return message["params"]["diagnostics"].as_array().unwrap().clone();
+            }
+        }
+    }
+
+    fn send(&mut self, message: Value) {
+        let text = message.to_string();
+
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{}", text.len(), text).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = 0;
+
+        loop {
+            let mut header = String::new();
+            self.stdout.read_line(&mut header).unwrap();
+
+            let header = header.trim();
+
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some(value) = header.strip_prefix("Content-Length: ") {
+                length = value.parse().unwrap();
+            }
+        }
+
+        let mut body = vec![0; length];
+        self.stdout.read_exact(&mut body).unwrap();
+
+        serde_json::from_slice(&body).unwrap()
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.request("shutdown", Value::Null);
+            self.notify("exit", Value::Null);
+
+            assert!(self.process.wait().unwrap().success());
+        } else {
+            let _ = self.process.kill();
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use serde_json::Value;
+
+use crate::lsp::client::Client;
+
+const SOURCE: &str = r#"struct Vec {
+    x;
+    y;
+    static count = 0;
+} impl {
+    len() {
+        x * x + y * y
+    }
+
+    static zero() {
+        Vec :{ 0, 0 }
+    }
+}
+
+class Counter {
+    n;
+}
+
+operator + (a : Vec, b : Vec) {
+    Vec :{ a.x + b.x, a.y + b.y }
+}
+
+let v = Vec :{ 1, 2 };
+let w = v + v;
+
+fn add(a, b = 1) {
+    a + b
+}
+
+print(v.len(), add(v.x));
+"#;
+
+fn definition_lines(result: &Value) -> Vec<u64> {
+    result
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["range"]["start"]["line"].as_u64().unwrap())
+        .collect()
+}
+
+fn labels(result: &Value) -> Vec<String> {
+    result
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["label"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_diagnostics() {
+    let mut client = Client::start();
+
+    assert!(client.open(SOURCE).is_empty());
+
+    let diagnostics = client.change("let x = ;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 0);
+    assert_eq!(diagnostics[0]["severity"], 1);
+    assert_eq!(diagnostics[0]["message"], "expected expression");
+
+    // every syntax error is reported
+    let diagnostics = client.change("let x = 1 + ;\nlet y = (2;\n");
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0]["message"], "expected expression");
+    assert_eq!(
+        diagnostics[1]["message"],
+        "expected `)`\nto close `(` at 2:9"
+    );
+    assert_eq!(diagnostics[1]["range"]["start"]["line"], 1);
+
+    let diagnostics = client.change("while true { break; }\nlet x = 1;\nlet x = 2;\n");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_definition() {
+    let mut client = Client::start();
+    client.open(SOURCE);
+
+    // `v` in `print(v.len(), ...)`
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 29, 6)),
+        [22]
+    );
+
+    // `len` in `v.len()`
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 29, 9)),
+        [5]
+    );
+
+    // `x` in `v.x`
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 29, 21)),
+        [1]
+    );
+
+    // `x` inside method body refers to the field
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 6, 8)),
+        [1]
+    );
+
+    // `Vec` in instantiation
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 22, 8)),
+        [0]
+    );
+
+    // `+` in `v + v` resolves to the operator declaration
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 23, 10)),
+        [18]
+    );
+
+    // `a` in function body refers to the parameter
+    assert_eq!(
+        definition_lines(&client.at("textDocument/definition", 26, 4)),
+        [25]
+    );
+
+    // nothing to jump to from a keyword
+    assert_eq!(client.at("textDocument/definition", 22, 1), Value::Null);
+}
+
+#[test]
+fn test_hover() {
+    let mut client = Client::start();
+    client.open(SOURCE);
+
+    let hover = client.at("textDocument/hover", 29, 16);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("fn add(a, b = 1)"), "{}", text);
+
+    let hover = client.at("textDocument/hover", 22, 4);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("let v = Vec :{ 1, 2 }"), "{}", text);
+
+    let hover = client.at("textDocument/hover", 14, 8);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("class Counter {\n    n;\n}"), "{}", text);
+
+    let hover = client.at("textDocument/hover", 29, 9);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("(method) len()"), "{}", text);
+
+    let hover = client.at("textDocument/hover", 23, 10);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("operator + (a : Vec, b : Vec)"), "{}", text);
+}
+
+#[test]
+fn test_completion() {
+    let mut client = Client::start();
+    client.open(&format!("{}v.", SOURCE));
+
+    let result = client.at("textDocument/completion", 30, 2);
+    assert_eq!(labels(&result), ["x", "y", "count", "len", "zero"]);
+
+    client.change(&format!("{}Vec.", SOURCE));
+
+    let result = client.at("textDocument/completion", 30, 4);
+    assert_eq!(labels(&result), ["count", "zero"]);
+
+    client.change(&format!("{}unknown.", SOURCE));
+
+    let result = client.at("textDocument/completion", 30, 8);
+    assert!(labels(&result).is_empty());
+}
+
+#[test]
+fn test_document_symbols() {
+    let mut client = Client::start();
+    client.open(SOURCE);
+
+    let result = client.request(
+        "textDocument/documentSymbol",
+        serde_json::json!({ "textDocument": { "uri": crate::lsp::client::URI } }),
+    );
+
+    let symbols = result.as_array().unwrap();
+    assert_eq!(symbols.len(), 2);
+
+    assert_eq!(symbols[0]["name"], "Vec");
+    assert_eq!(symbols[0]["detail"], "struct");
+    assert_eq!(symbols[0]["kind"], 23); // Struct
+    assert_eq!(
+        labels_of_children(&symbols[0]),
+        ["x", "y", "count", "len", "zero"]
+    );
+
+    assert_eq!(symbols[1]["name"], "Counter");
+    assert_eq!(symbols[1]["kind"], 5); // Class
+}
+
+#[test]
+fn test_invalid_params() {
+    let mut client = Client::start();
+    client.open(SOURCE);
+
+    let error = client.request_error(
+        "textDocument/hover",
+        serde_json::json!({ "textDocument": {} }),
+    );
+
+    assert_eq!(error["code"], -32602); // InvalidParams
+
+    // the server is still running
+    let hover = client.at("textDocument/hover", 22, 4);
+    let text = hover["contents"]["value"].as_str().unwrap();
+    assert!(text.contains("let v = Vec :{ 1, 2 }"), "{}", text);
+}
+
+fn labels_of_children(symbol: &Value) -> Vec<String> {
+    symbol["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["name"].as_str().unwrap().to_string())
+        .collect()
+}
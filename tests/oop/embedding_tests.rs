@@ -0,0 +1,221 @@
+use crate::run;
+
+#[test]
+fn test_forwarding() {
+    run(r#"
+            struct Animal {
+                name;
+
+                Loud {
+                    get => name <> "!";
+                }
+            } impl {
+                speak() {
+                    name <> " makes a sound"
+                }
+            }
+
+            struct Dog {
+                embed Animal;
+                legs;
+            } impl {
+                describe() {
+                    speak() <> " on " <> name
+                }
+
+                rename(new_name) {
+                    name = new_name;
+                }
+
+                part() {
+                    Animal
+                }
+            }
+
+            let d = Dog :{ Animal :{ "Rex" }, 4 };
+
+            assert_eq(d.name, "Rex");
+            assert_eq(d.legs, 4);
+            assert_eq(d.speak(), "Rex makes a sound");
+            assert_eq(d.Loud, "Rex!");
+            assert_eq(d.describe(), "Rex makes a sound on Rex");
+            assert_eq(d.part().name, "Rex");
+
+            d.rename("Max");
+            assert_eq(d.Animal.name, "Max");
+
+            d.name = "Bob";
+            assert_eq(d.name, "Bob");
+        "#)
+}
+
+#[test]
+fn test_own_member_wins() {
+    run(r#"
+            struct A {
+                x;
+            } impl {
+                f() { 1 }
+            }
+
+            struct B {
+                x;
+            } impl {
+                f() { 2 }
+                g() { 3 }
+            }
+
+            struct C {
+                embed A;
+                embed B;
+                x;
+            }
+
+            let c = C :{ A :{ 1 }, B :{ 2 }, 3 };
+
+            assert_eq(c.x, 3);
+            assert_eq(c.f(), 1);
+            assert_eq(c.g(), 3);
+        "#)
+}
+
+#[test]
+fn test_struct_part_is_copied() {
+    run(r#"
+            struct A {
+                x;
+            }
+
+            struct B {
+                embed A;
+            }
+
+            let a = A :{ 1 };
+            let b = B :{ a };
+
+            b.x = 2;
+            assert_eq(a.x, 1);
+
+            let part = b.A;
+            part.x = 3;
+            assert_eq(b.x, 2);
+        "#)
+}
+
+#[test]
+fn test_class_part_is_shared() {
+    run(r#"
+            class A {
+                x;
+            }
+
+            class B {
+                embed A;
+            }
+
+            let a = A :{ 1 };
+            let b = B :{ a };
+
+            b.x = 2;
+            assert_eq(a.x, 2);
+        "#)
+}
+
+#[test]
+fn test_statics() {
+    run(r#"
+            struct A {
+                static count = 0;
+            } impl {
+                static next() {
+                    count = count + 1;
+                    count
+                }
+            }
+
+            struct B {
+                embed A;
+            }
+
+            assert_eq(B.next(), 1);
+            assert_eq(B.count, 1);
+
+            B.count = 5;
+            assert_eq(A.count, 5);
+        "#)
+}
+
+#[test]
+fn test_operator_fallback() {
+    run(r#"
+            struct Money {
+                amount;
+            }
+
+            struct Wallet {
+                embed Money;
+                owner;
+            }
+
+            operator + (a : Money, b : Money) {
+                a.amount + b.amount
+            }
+
+            operator + (a : Wallet, b : Money) {
+                0
+            }
+
+            let w = Wallet :{ Money :{ 3 }, "me" };
+            let m = Money :{ 4 };
+
+            assert_eq(w + m, 0);
+            assert_eq(m + w, 7);
+            assert_eq(w + w, 6);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`data` type `B` can only embed `data` types, `A` is not")]
+fn test_data_embeds_data() {
+    run(r#"
+            struct A {
+                x;
+            }
+
+            data B {
+                embed A;
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot embed `A` into `B`, expected type, got `Number`")]
+fn test_embed_not_type() {
+    run(r#"
+            let A = 1;
+
+            struct B {
+                embed A;
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "embedded `A` of `B` must be `A`, got `C`")]
+fn test_wrong_part() {
+    run(r#"
+            struct A {
+                x;
+            }
+
+            struct C {
+                x;
+            }
+
+            struct B {
+                embed A;
+            }
+
+            B :{ C :{ 1 } };
+        "#)
+}
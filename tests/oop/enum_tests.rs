@@ -0,0 +1,130 @@
+use crate::run;
+
+#[test]
+fn test_variants() {
+    run(r#"
+            enum Shape {
+                Circle { r },
+                Rect { w, h },
+                Empty,
+            }
+
+            let c = Shape.Circle :{ 2 };
+            let r = Shape.Rect :{ h: 3, w: 2 };
+
+            assert_eq(c.r, 2);
+            assert_eq(r.w * r.h, 6);
+
+            assert_eq(c, Shape.Circle :{ 2 });
+            assert(c != Shape.Circle :{ 3 });
+            assert(c != r);
+            assert(Shape.Empty == Shape.Empty);
+            assert(Shape.Empty != c);
+
+            assert_eq(str(c), "Shape.Circle{r=2}");
+            assert_eq(str(Shape.Empty), "Shape.Empty");
+        "#)
+}
+
+#[test]
+fn test_compare_variant() {
+    run(r#"
+            enum Shape {
+                Circle { r },
+                Rect { w, h },
+                Empty,
+            }
+
+            let c = Shape.Circle :{ 2 };
+
+            assert(c.variant == Shape.Circle);
+            assert(c.variant != Shape.Rect);
+            assert(c.variant != Shape.Empty);
+            assert(Shape.Empty != c.variant);
+            assert(Shape.Empty.variant == Shape.Empty);
+
+            assert(Shape.Circle == Shape.Circle);
+            assert(Shape.Circle != Shape);
+        "#)
+}
+
+#[test]
+fn test_shared_methods() {
+    run(r#"
+            enum Shape {
+                Circle { r },
+                Rect { w, h },
+                Empty,
+            } impl {
+                area() {
+                    match variant {
+                        Circle => 3 * r * r,
+                        Rect => w * h,
+                        Empty => 0,
+                    }
+                }
+
+                static square(side) {
+                    Rect :{ side, side }
+                }
+            }
+
+            assert_eq((Shape.Circle :{ 2 }).area(), 12);
+            assert_eq(Shape.square(3).area(), 9);
+            assert_eq(Shape.Empty.area(), 0);
+            assert_eq(Shape.square(1), Shape.Rect :{ 1, 1 });
+        "#)
+}
+
+#[test]
+fn test_immutable() {
+    run(r#"
+            enum Option {
+                Some { value },
+                None,
+            }
+
+            let x = Option.Some :{ 1 };
+            let y = x;
+
+            assert_eq(x.copy_with(value: 2), Option.Some :{ 2 });
+            assert_eq(y.value, 1);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "cannot set field `value`")]
+fn test_set_field() {
+    run(r#"
+            enum Option {
+                Some { value },
+                None,
+            }
+
+            let x = Option.Some :{ 1 };
+            x.value = 2;
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "enum `Shape` cannot be instantiated, only its variants can")]
+fn test_instantiate_enum() {
+    run(r#"
+            enum Shape {
+                Empty,
+            }
+
+            Shape :{};
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "Duplicate variant: `A`")]
+fn test_duplicate_variant() {
+    run(r#"
+            enum E {
+                A,
+                A { x },
+            }
+        "#)
+}
@@ -0,0 +1,188 @@
+use crate::run;
+
+#[test]
+fn test_defaults() {
+    run(r#"
+            struct Point {
+                x;
+                y = 0;
+                static offset = 10;
+                z = offset + 1;
+            }
+
+            assert_eq(Point :{ 1 }, Point :{ 1, 0, 11 });
+            assert_eq(Point :{ 1, 2 }.y, 2);
+            assert_eq(Point :{ x: 1, z: 5 }.z, 5);
+
+            Point.offset = 20;
+            assert_eq(Point :{ 1 }.z, 21);
+        "#)
+}
+
+#[test]
+fn test_default_is_evaluated_every_time() {
+    run(r#"
+            struct Inner {
+                value;
+            }
+
+            struct Outer {
+                inner = Inner :{ 0 };
+            }
+
+            let a = Outer :{};
+            let b = Outer :{};
+
+            a.inner.value = 5;
+            assert_eq(b.inner.value, 0);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "missing field `x`")]
+fn test_default_does_not_shift() {
+    run(r#"
+            struct Point {
+                x;
+                y = 0;
+            }
+
+            Point :{ y: 1 };
+        "#)
+}
+
+#[test]
+fn test_validate() {
+    run(r#"
+            struct Range {
+                from;
+                to;
+            } impl {
+                validate() {
+                    from <= to
+                }
+            }
+
+            assert_eq(Range :{ 1, 2 }.to, 2);
+
+            assert_throws(fn () { Range :{ 2, 1 } });
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`validate` of `Range` rejected the object")]
+fn test_validate_rejects() {
+    run(r#"
+            struct Range {
+                from;
+                to;
+            } impl {
+                validate() {
+                    from <= to
+                }
+            }
+
+            Range :{ 2, 1 };
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`validate` must return `Bool`, got `Number`")]
+fn test_validate_not_bool() {
+    run(r#"
+            struct Range {
+                from;
+                to;
+            } impl {
+                validate() {
+                    to - from
+                }
+            }
+
+            Range :{ 1, 2 };
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn test_validate_error() {
+    run(r#"
+            class Account {
+                balance;
+            } impl {
+                validate() {
+                    assert(balance >= 0);
+                }
+            }
+
+            Account :{ -1 };
+        "#)
+}
+
+#[test]
+fn test_copy_with() {
+    run(r#"
+            data Money {
+                amount;
+                currency = "USD";
+            } impl {
+                validate() {
+                    amount >= 0
+                }
+            }
+
+            let a = Money :{ 5 };
+            let b = a.copy_with(amount: 7);
+
+            assert_eq(a.amount, 5);
+            assert_eq(b, Money :{ 7, "USD" });
+            assert_eq(a.copy_with(), a);
+
+            assert_throws(fn () { a.copy_with(amount: -1) });
+        "#)
+}
+
+#[test]
+fn test_copy_with_struct() {
+    run(r#"
+            struct Inner {
+                value;
+            }
+
+            struct Outer {
+                inner;
+                tag;
+            }
+
+            let a = Outer :{ Inner :{ 1 }, "a" };
+            let b = a.copy_with(tag: "b");
+
+            b.inner.value = 2;
+            assert_eq(a.inner.value, 1);
+            assert_eq(b.tag, "b");
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "field `amont` does not exist")]
+fn test_copy_with_unknown_field() {
+    run(r#"
+            data Money {
+                amount;
+            }
+
+            Money :{ 1 }.copy_with(amont: 2);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`copy_with` takes only named arguments")]
+fn test_copy_with_positional() {
+    run(r#"
+            data Money {
+                amount;
+            }
+
+            Money :{ 1 }.copy_with(2);
+        "#)
+}
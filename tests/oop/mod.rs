@@ -1,6 +1,9 @@
 mod class_tests;
 mod data_tests;
+mod embedding_tests;
+mod enum_tests;
 mod general_tests;
+mod instantiation_tests;
 mod property_tests;
 mod static_tests;
 mod struct_tests;
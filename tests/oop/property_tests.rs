@@ -123,8 +123,8 @@ fn test_static_no_setter() {
 }
 
 #[test]
-#[should_panic(expected = "unexpected signal Continue")]
-fn test_unexpected_signal() {
+#[should_panic(expected = "`continue` outside of a loop")]
+fn test_continue_in_setter() {
     run(r#"
             struct Thing {
                 X {
@@ -0,0 +1 @@
+mod syntax_error_tests;
@@ -0,0 +1,135 @@
+use std::{fs, path::Path};
+
+use crate::diagnostic::Diagnostic;
+use crate::interpreter::{
+    runner::execute_file,
+    tree_sitter_parser::{parse, ParseError},
+};
+
+fn parse_error(code: &str) -> ParseError {
+    parse(code.to_string()).unwrap_err()
+}
+
+fn describe(code: &str) -> String {
+    Diagnostic::syntax(Path::new("main.fru"), &parse_error(code))
+        .iter()
+        .map(|x| x.human(code))
+        .collect()
+}
+
+fn messages(code: &str) -> Vec<String> {
+    parse_error(code).errors().into_iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_all_errors() {
+    assert_eq!(
+        messages(
+            r#"let x = 1
+let z = 1 + ;
+let w = (1 + 2;
+"#
+        ),
+        [
+            "expected `;` at 1:9-1:9",
+            "expected expression at 2:11-2:11",
+            "expected `)` at 3:14-3:14",
+        ]
+    );
+}
+
+#[test]
+fn test_unexpected_token() {
+    assert_eq!(messages("let y = 2 2;"), ["unexpected `2` at 1:10-1:11"]);
+}
+
+#[test]
+fn test_unclosed_bracket() {
+    let errors = parse_error(
+        r#"fn f() {
+    let x = 1;
+
+let y = 2;
+"#,
+    )
+    .errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "expected `}`");
+    assert_eq!(errors[0].note.as_deref(), Some("to close `{` at 1:8"));
+}
+
+#[test]
+fn test_misplaced_impl() {
+    let errors = parse_error(
+        r#"impl {
+    f() {}
+}
+"#,
+    )
+    .errors();
+
+    assert_eq!(errors[0].message, "unexpected `impl`");
+    assert_eq!(
+        errors[0].note.as_deref(),
+        Some("`impl` must follow declaration of a type, like `struct Name { ... } impl { ... }`")
+    );
+}
+
+#[test]
+fn test_describe() {
+    let source = "let x = 1 + ;\nlet y = (2;\n";
+
+    assert_eq!(
+        describe(source),
+        r#"main.fru:1:12: error[E0011]: expected expression
+    1 | let x = 1 + ;
+      |            ^
+main.fru:2:11: error[E0011]: expected `)`
+    2 | let y = (2;
+      |           ^
+      = note: to close `(` at 2:9
+"#
+    );
+}
+
+#[test]
+fn test_describe_other_errors() {
+    let source = "let f = fn () {\n    break;\n};";
+
+    assert_eq!(
+        describe(source),
+        r#"main.fru:2:5: error[E0012]: `break` outside of a loop
+    2 |     break;
+      |     ^^^^^^
+"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected expression at 1:11-1:11\nexpected `)` at 2:10-2:10")]
+fn test_run() {
+    crate::run("let x = 1 + ;\nlet y = (2;");
+}
+
+#[test]
+fn test_imported_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let imported = dir.path().join("imported.fru");
+    let main = dir.path().join("main.fru");
+
+    fs::write(&imported, "let x = (1;\n").unwrap();
+    fs::write(&main, format!("let m = import {:?};\n", imported)).unwrap();
+
+    let Err(err) = execute_file(&main) else {
+        panic!("file with syntax error was executed")
+    };
+    let (file, syntax) = err.get_syntax().unwrap();
+
+    assert_eq!(file, Some(imported.as_path()));
+    assert_eq!(err.get_message(), "expected `)` at 1:10-1:10");
+    assert_eq!(
+        syntax.errors()[0].note.as_deref(),
+        Some("to close `(` at 1:9")
+    );
+}
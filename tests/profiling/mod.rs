@@ -0,0 +1 @@
+mod profiler_tests;
@@ -0,0 +1,173 @@
+use std::{fs, path::Path};
+
+use crate::profiler::{profile_file, Profile};
+
+fn profile(code: &str) -> Profile {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.fru");
+
+    fs::write(&path, code).unwrap();
+
+    let (result, profile) = profile_file(&path);
+    result.unwrap();
+
+    profile
+}
+
+fn calls(profile: &Profile) -> Vec<(String, usize, usize)> {
+    let mut calls: Vec<_> = profile
+        .entries
+        .iter()
+        .map(|x| (x.name.clone(), x.position.start_point.row + 1, x.calls))
+        .collect();
+
+    calls.sort();
+
+    calls
+}
+
+fn stacks(profile: &Profile) -> Vec<&str> {
+    profile.stacks.iter().map(|(stack, _)| stack.as_str()).collect()
+}
+
+#[test]
+fn test_calls() {
+    let profile = profile(
+        r#"
+struct Vec {
+    x;
+    y;
+
+    sum {
+        get => x + y;
+    }
+} impl {
+    double() {
+        Vec :{ x * 2, y * 2 }
+    }
+}
+
+operator + (a : Vec, b : Vec) {
+    Vec :{ a.x + b.x, a.y + b.y }
+}
+
+let add = fn (a, b) { a + b };
+
+let v = add(Vec :{ 1, 2 }, Vec :{ 3, 4 }).double();
+let w = fn () { v.sum }();
+"#,
+    );
+
+    assert_eq!(
+        calls(&profile),
+        [
+            ("<anonymous>".to_string(), 22, 1),
+            ("Vec.double".to_string(), 10, 1),
+            ("Vec.sum get".to_string(), 7, 1),
+            ("add".to_string(), 19, 1),
+            ("operator + (Vec, Vec)".to_string(), 15, 1),
+        ]
+    );
+
+    assert_eq!(
+        stacks(&profile),
+        [
+            "<program>",
+            "<program>;<anonymous>",
+            "<program>;<anonymous>;Vec.sum get",
+            "<program>;Vec.double",
+            "<program>;add",
+            "<program>;add;operator + (Vec, Vec)",
+        ]
+    );
+}
+
+#[test]
+fn test_recursion() {
+    let profile = profile(
+        r#"
+fn fact(n) {
+    if n == 0 { 1 } else { n * fact(n - 1) }
+}
+
+fact(3);
+fact(2);
+"#,
+    );
+
+    assert_eq!(calls(&profile), [("fact".to_string(), 2, 7)]);
+
+    let entry = &profile.entries[0];
+    let total = profile.stacks.iter().map(|(_, time)| *time).sum();
+
+    // recursive calls are not counted twice in inclusive time
+    assert!(entry.exclusive <= entry.inclusive);
+    assert!(entry.inclusive <= total);
+
+    assert_eq!(
+        stacks(&profile),
+        [
+            "<program>",
+            "<program>;fact",
+            "<program>;fact;fact",
+            "<program>;fact;fact;fact",
+            "<program>;fact;fact;fact;fact",
+        ]
+    );
+}
+
+#[test]
+fn test_output() {
+    let profile = profile("fn f() {} f(); f();");
+
+    let table = profile.table("main.fru".as_ref());
+    let lines: Vec<_> = table.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("calls     total ms      self ms  function"));
+    assert!(lines[1].starts_with("       2 "));
+    assert!(lines[1].contains("  f ("));
+    assert!(lines[1].ends_with("main.fru:1:1)"));
+
+    let folded = profile.folded();
+    let stacks: Vec<_> = folded.lines().map(|x| x.rsplit_once(' ').unwrap().0).collect();
+
+    assert_eq!(stacks, ["<program>", "<program>;f"]);
+}
+
+#[test]
+fn test_imported_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let module = dir.path().join("module.fru");
+    let main = dir.path().join("main.fru");
+
+    fs::write(&module, "let x = 0;\nfn g() { 1 }\n").unwrap();
+    fs::write(
+        &main,
+        format!("fn f() {{}}\nlet m = import {:?};\nm.g();\nf();\n", module),
+    )
+    .unwrap();
+
+    let (result, profile) = profile_file(&main);
+    result.unwrap();
+
+    let mut files: Vec<_> = profile
+        .entries
+        .iter()
+        .map(|x| (x.name.clone(), x.file.as_deref().map(Path::to_path_buf)))
+        .collect();
+
+    files.sort();
+
+    assert_eq!(
+        files,
+        [
+            ("f".to_string(), Some(main.clone())),
+            ("g".to_string(), Some(module.clone()))
+        ]
+    );
+
+    let table = profile.table(&main);
+
+    assert!(table.contains(&format!("  g ({}:2:1)", module.display())));
+}
@@ -0,0 +1,329 @@
+use std::{fs, path::Path};
+
+use serde_json::json;
+
+use crate::checker::{check_source, lint::lint_source};
+use crate::diagnostic::Diagnostic;
+use crate::interpreter::{
+    error::{ErrorKind, FruError},
+    runner::{execute_file, execute_source_code},
+    tree_sitter_parser,
+};
+
+fn error(code: &str) -> FruError {
+    match execute_source_code(code.to_string(), None) {
+        Ok(_) => panic!("program finished without error"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn test_undefined_variable() {
+    let err = error(
+        r#"
+        let count = 5;
+        print(cout);
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::UndefinedVariable);
+    assert_eq!(err.get_message(), "variable `cout` does not exist");
+    assert_eq!(err.get_notes(), ["did you mean `count`?"]);
+}
+
+#[test]
+fn test_operator_candidates() {
+    let err = error(
+        r#"
+        struct Vec2 { x; y; }
+
+        operator + (a : Vec2, b : Vec2) { a }
+        operator + (a : Vec2, b : Vec2) { b }
+        operator + (a : Any, b : Vec2) { b }
+
+        Vec2 :{ 1, 2 } + 3;
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::UndefinedOperator);
+    assert_eq!(err.get_message(), "operator `Vec2 + Number` does not exist");
+    assert_eq!(err.get_notes(), ["candidates: `Any + Vec2`, `Vec2 + Vec2`"]);
+}
+
+#[test]
+fn test_operator_candidates_take_operand() {
+    let err = error(
+        r#"
+        struct V { x; }
+
+        operator + (a : Any, b : String) { b }
+
+        V :{ 1 } + V :{ 2 };
+        "#,
+    );
+
+    assert_eq!(err.get_message(), "operator `V + V` does not exist");
+    assert!(err.get_notes().is_empty(), "{:?}", err.get_notes());
+}
+
+#[test]
+fn test_no_suggestion() {
+    let err = error(
+        r#"
+        let count = 5;
+        print(total);
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::UndefinedVariable);
+    assert!(err.get_notes().is_empty());
+}
+
+#[test]
+fn test_suggestion_in_method() {
+    let err = error(
+        r#"
+        struct Counter {
+            value;
+        } impl {
+            next() {
+                valeu + 1
+            }
+        }
+
+        Counter :{ 1 }.next();
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::UndefinedVariable);
+    assert_eq!(err.get_notes(), ["did you mean `value`?"]);
+}
+
+#[test]
+fn test_unknown_argument() {
+    let err = error(
+        r#"
+        let f = fn (alpha, beta = 1) { alpha + beta };
+        f(alpah: 1);
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::ArgumentMismatch);
+    assert_eq!(err.get_message(), "unknown argument `alpah` in call of `f`");
+    assert_eq!(err.get_notes(), ["did you mean `alpha`?"]);
+}
+
+#[test]
+fn test_unknown_prop() {
+    let err = error(
+        r#"
+        struct Point {
+            x;
+            y;
+        } impl {
+            length() {
+                x + y
+            }
+        }
+
+        Point :{ 1, 2 }.lenght();
+        "#,
+    );
+
+    assert_eq!(err.get_kind(), ErrorKind::UndefinedProperty);
+    assert_eq!(err.get_notes(), ["did you mean `length`?"]);
+}
+
+#[test]
+fn test_kinds() {
+    let cases = [
+        ("1 / 0;", ErrorKind::DivisionByZero),
+        ("assert(false);", ErrorKind::AssertionFailed),
+        ("fn (..xs) { xs }(1).get(3);", ErrorKind::OutOfBounds),
+        ("let x = 1; let x = 2;", ErrorKind::AlreadyDeclared),
+        ("if 1 {}", ErrorKind::UnexpectedType),
+        ("1 +++ 2;", ErrorKind::UndefinedOperator),
+        (
+            "struct A { x; } A :{ y: 1 };",
+            ErrorKind::InvalidInstantiation,
+        ),
+        ("let x = (1;", ErrorKind::SyntaxError),
+        ("match 1 { 2 => 3 };", ErrorKind::UnmatchedValue),
+    ];
+
+    for (code, kind) in cases {
+        assert_eq!(error(code).get_kind(), kind, "{}", code);
+    }
+}
+
+#[test]
+fn test_codes() {
+    assert_eq!(ErrorKind::Other.code(), "E0000");
+    assert_eq!(ErrorKind::UndefinedVariable.code(), "E0001");
+    assert_eq!(ErrorKind::ArgumentMismatch.code(), "E0002");
+    assert_eq!(ErrorKind::Io.code(), "E0013");
+}
+
+#[test]
+fn test_check_errors() {
+    let errors = check_source(
+        r#"
+        let count = 5;
+        let f = fn (alpha) { alpha };
+
+        print(cout);
+        f(alpah: 1);
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(errors.len(), 3);
+
+    assert_eq!(errors[0].kind, ErrorKind::UndefinedVariable);
+    assert_eq!(errors[0].note.as_deref(), Some("did you mean `count`?"));
+
+    assert_eq!(errors[1].kind, ErrorKind::ArgumentMismatch);
+    assert_eq!(errors[1].note.as_deref(), Some("did you mean `alpha`?"));
+
+    assert_eq!(errors[2].kind, ErrorKind::ArgumentMismatch);
+    assert_eq!(errors[2].note, None);
+
+    let diagnostic = Diagnostic::check(Path::new("main.fru"), &errors[0]);
+
+    assert_eq!(diagnostic.code, "E0001");
+    assert_eq!(diagnostic.notes, ["did you mean `count`?"]);
+}
+
+#[test]
+fn test_human() {
+    let source = "let count = 5;\n\nlet f = fn () {\n    print(cout);\n};\n\nf();\n";
+
+    let err = error(source);
+
+    assert_eq!(
+        Diagnostic::runtime(Path::new("main.fru"), &err).human(source),
+        r#"main.fru:4:5: error[E0001]: variable `cout` does not exist
+    4 |     print(cout);
+      |     ^^^^^^^^^^^^
+      = note: did you mean `count`?
+    at main.fru:7:1
+"#
+    );
+}
+
+#[test]
+fn test_human_non_ascii() {
+    let source = "let f = fn () {\n    \"ё\"; print(cout);\n};\n\n\"ё\"; f();\n";
+
+    let err = error(source);
+
+    assert_eq!(
+        Diagnostic::runtime(Path::new("main.fru"), &err).human(source),
+        r#"main.fru:2:10: error[E0001]: variable `cout` does not exist
+    2 |     "ё"; print(cout);
+      |          ^^^^^^^^^^^^
+    at main.fru:5:6
+"#
+    );
+}
+
+#[test]
+fn test_imported_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let module = dir.path().join("m.fru");
+    let main = dir.path().join("r.fru");
+
+    let module_source = "fn bad() {\n    undefined_thing\n}\n";
+
+    fs::write(&module, module_source).unwrap();
+    fs::write(&main, format!("let m = import {:?};\n\nm.bad();\n", module)).unwrap();
+
+    let Err(err) = execute_file(&main) else {
+        panic!("program finished without error")
+    };
+
+    let diagnostic = Diagnostic::runtime(&main, &err);
+
+    assert_eq!(diagnostic.file, module);
+    assert_eq!(diagnostic.span.unwrap().start_point.row, 1);
+    assert_eq!(diagnostic.traceback.len(), 1);
+    assert_eq!(diagnostic.traceback[0].0.start_point.row, 2);
+    assert_eq!(diagnostic.traceback[0].1, main);
+
+    assert_eq!(
+        diagnostic.human(module_source),
+        format!(
+            r#"{}:2:5: error[E0001]: variable `undefined_thing` does not exist
+    2 |     undefined_thing
+      |     ^^^^^^^^^^^^^^^
+    at {}:3:1
+"#,
+            module.display(),
+            main.display()
+        )
+    );
+
+    assert_eq!(
+        diagnostic.json()["file"],
+        json!(module.display().to_string())
+    );
+    assert_eq!(
+        diagnostic.json()["traceback"][0]["file"],
+        json!(main.display().to_string())
+    );
+}
+
+#[test]
+fn test_json() {
+    let source = "let count = 5;\nprint(cout);\n";
+
+    let err = error(source);
+
+    assert_eq!(
+        Diagnostic::runtime(Path::new("main.fru"), &err).json(),
+        json!({
+            "severity": "error",
+            "code": "E0001",
+            "kind": "undefined_variable",
+            "message": "variable `cout` does not exist",
+            "file": "main.fru",
+            "span": {
+                "start": { "row": 1, "column": 0 },
+                "end": { "row": 1, "column": 12 },
+            },
+            "notes": ["did you mean `count`?"],
+            "traceback": [],
+        })
+    );
+}
+
+#[test]
+fn test_syntax_and_lint() {
+    let source = "let x = (1 + 2;\n";
+
+    let err = tree_sitter_parser::parse(source.to_string()).unwrap_err();
+    let diagnostics = Diagnostic::syntax(Path::new("main.fru"), &err);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "E0011");
+    assert_eq!(diagnostics[0].notes, ["to close `(` at 1:9"]);
+
+    let source = "fn f() {\n    let unused = 1;\n}\n";
+
+    let warnings = lint_source(source, &Default::default()).unwrap();
+    let diagnostic = Diagnostic::lint(Path::new("main.fru"), &warnings[0]);
+
+    assert_eq!(diagnostic.json()["severity"], "warning");
+    assert_eq!(diagnostic.json()["code"], "unused_variable");
+}
+
+#[test]
+fn test_io() {
+    let diagnostic = Diagnostic::io(Path::new("missing.fru"), "file not found");
+
+    assert_eq!(
+        diagnostic.human(""),
+        "missing.fru: error[E0013]: file not found\n"
+    );
+    assert_eq!(diagnostic.json()["span"], json!(null));
+}
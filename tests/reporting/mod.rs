@@ -0,0 +1 @@
+mod diagnostic_tests;
@@ -1,9 +1,76 @@
 use crate::run;
 
 #[test]
-#[should_panic(expected = "Unexpected signal: Break")]
-fn test_unexpected_signal() {
+#[should_panic(expected = "`break` outside of a loop at 2:12-2:18")]
+fn test_outside_of_loop() {
     run(r#"
             break;
         "#)
 }
+
+#[test]
+fn test_labeled() {
+    run(r#"
+            let found = nah;
+            let i = 0;
+
+            'outer: while i < 5 {
+                let j = 0;
+                while j < 5 {
+                    if i * j == 6 {
+                        found = f"{i} {j}";
+                        break 'outer;
+                    }
+                    j = j + 1;
+                }
+                i = i + 1;
+            }
+
+            assert_eq(found, "2 3");
+        "#)
+}
+
+#[test]
+fn test_loop_value() {
+    run(r#"
+            let i = 0;
+
+            let x = loop {
+                i = i + 1;
+                if i * i > 50 {
+                    break i;
+                }
+            };
+
+            assert_eq(x, 8);
+
+            let y = 'a: loop {
+                loop {
+                    break 'a "inner";
+                }
+            };
+
+            assert_eq(y, "inner");
+            assert_eq(loop { break; }, nah);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "`break` with value is only allowed in `loop` at 3:16-3:24")]
+fn test_value_in_while() {
+    run(r#"
+            while true {
+                break 1;
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "label `'inner` is not declared at 3:16-3:29")]
+fn test_unknown_label() {
+    run(r#"
+            'outer: while true {
+                break 'inner;
+            }
+        "#)
+}
@@ -1,9 +1,35 @@
 use crate::run;
 
 #[test]
-#[should_panic(expected = "Unexpected signal: Continue")]
-fn test_unexpected_signal() {
+#[should_panic(expected = "`continue` outside of a loop at 2:12-2:21")]
+fn test_outside_of_loop() {
     run(r#"
             continue;
         "#)
 }
+
+#[test]
+fn test_labeled() {
+    run(r#"
+            let pairs = 0;
+            let i = 0;
+
+            'outer: loop {
+                i = i + 1;
+                if i > 4 {
+                    break;
+                }
+
+                let j = 0;
+                while true {
+                    j = j + 1;
+                    if j > i {
+                        continue 'outer;
+                    }
+                    pairs = pairs + 1;
+                }
+            }
+
+            assert_eq(pairs, 10);
+        "#)
+}
@@ -0,0 +1,91 @@
+use crate::run;
+
+#[test]
+fn test_function_statement() {
+    run(r#"
+            fn add(a, b = 1) {
+                a + b
+            }
+
+            assert_eq(add(1), 2);
+            assert_eq(add(1, 5), 6);
+        "#)
+}
+
+#[test]
+fn test_recursion() {
+    run(r#"
+            fn fact(n) {
+                if n == 0 {
+                    return 1;
+                }
+                n * fact(n - 1)
+            }
+
+            assert_eq(fact(5), 120);
+        "#)
+}
+
+#[test]
+fn test_naming() {
+    run(r#"
+            fn f() {}
+            let g = fn () {};
+            let h = fn inner() {};
+
+            struct Foo {
+            } impl {
+                method() {}
+                static static_method() {}
+            }
+
+            assert_eq(f.name, "f");
+            assert_eq(g.name, "g");
+            assert_eq(h.name, "inner");
+            assert_eq((fn () {}).name, nah);
+            assert_eq(Foo :{}.method.name, "method");
+            assert_eq(Foo.static_method.name, "static_method");
+            assert_eq(print.name, "print");
+        "#)
+}
+
+#[test]
+fn test_metadata() {
+    run(r#"
+            fn f(a, b, c = a + b) {}
+
+            assert_eq(f.arity, 3);
+            assert_eq(f.params.len(), 3);
+            assert_eq(f.params.get(2), "c = a + b");
+            assert_eq(f.is_builtin, false);
+            assert_eq(f.saved_args, 0);
+
+            let g = f$(1, c: 5);
+
+            assert_eq(g.name, "f");
+            assert_eq(g.arity, 1);
+            assert_eq(g.params.get(0), "b");
+            assert_eq(g.saved_args, 2);
+
+            assert_eq(print.is_builtin, true);
+            assert_eq(print.arity, nah);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "too many arguments in call of `f`")]
+fn test_error_names_function() {
+    run(r#"
+            fn f(a) {}
+
+            f(1, 2);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "function has no prop `foo`")]
+fn test_unknown_prop() {
+    run(r#"
+            print.foo;
+        "#)
+}
@@ -1,6 +1,9 @@
 mod break_statement_tests;
 mod continue_statement_tests;
+mod function_statement_tests;
 mod if_statement_tests;
 mod let_set_statement_tests;
+mod operator_statement_tests;
 mod return_statement_tests;
+mod test_statement_tests;
 mod while_statement_tests;
@@ -0,0 +1,85 @@
+use crate::run;
+
+#[test]
+fn test_any_operand() {
+    run(r#"
+            struct Vec2 { x; y; }
+
+            operator * (v : Vec2, k : Any) {
+                Vec2 :{ v.x * k, v.y * k }
+            }
+
+            operator <> (a : Any, b : Any) {
+                str(a) <> str(b)
+            }
+
+            assert_eq(Vec2 :{ 1, 2 } * 3, Vec2 :{ 3, 6 });
+            assert_eq(1 <> true, "1true");
+
+            // exact builtin operator is preferred
+            assert_eq("a" <> "b", "ab");
+        "#)
+}
+
+#[test]
+fn test_resolution_order() {
+    run(r#"
+            struct A {}
+            struct B {}
+
+            operator + (a : Any, b : Any) { "any" }
+            operator + (a : Any, b : B) { "right" }
+            operator + (a : A, b : Any) { "left" }
+            operator + (a : A, b : B) { "exact" }
+
+            let a = A :{};
+            let b = B :{};
+
+            assert_eq(a + b, "exact");
+            assert_eq(a + a, "left");
+            assert_eq(b + b, "right");
+            assert_eq(b + a, "any");
+        "#)
+}
+
+#[test]
+fn test_commutative_any() {
+    run(r#"
+            struct Vec2 { x; y; }
+
+            commutative operator * (v : Vec2, k : Any) {
+                Vec2 :{ v.x * k, v.y * k }
+            }
+
+            assert_eq(2 * Vec2 :{ 1, 2 }, Vec2 :{ 2, 4 });
+        "#)
+}
+
+#[test]
+fn test_reflected_comparison() {
+    run(r#"
+            struct Money { amount; }
+
+            operator < (m : Money, n : Number) { m.amount < n }
+            operator == (m : Money, n : Number) { m.amount == n }
+
+            let m = Money :{ 5 };
+
+            assert(m < 10);
+            assert(10 > m);
+            assert(5 == m);
+            assert_eq(1 > m, false);
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "operator `Number + Money` does not exist")]
+fn test_arithmetic_not_reflected() {
+    run(r#"
+            struct Money { amount; }
+
+            operator + (m : Money, n : Number) { Money :{ m.amount + n } }
+
+            1 + Money :{ 5 };
+        "#)
+}
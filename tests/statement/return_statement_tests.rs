@@ -4,13 +4,13 @@ use crate::run;
 #[should_panic(expected = "division by zero")]
 fn test_error_propagation() {
     run(r#"
-            return 1 / 0;
+            fn () { return 1 / 0; }();
         "#)
 }
 
 #[test]
-#[should_panic(expected = "Unexpected signal: Return")]
-fn test_unexpected_signal() {
+#[should_panic(expected = "`return` outside of a function at 2:12-2:19")]
+fn test_outside_of_function() {
     run(r#"
             return;
         "#)
@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use crate::interpreter::runner::execute_tests;
+use crate::run;
+
+fn results(code: &str, filter: &str) -> Vec<(String, Option<String>)> {
+    execute_tests(Path::new("main.fru"), code.to_owned(), filter)
+        .unwrap()
+        .into_iter()
+        .map(|x| (x.name, x.result.err().map(|err| err.to_string())))
+        .collect()
+}
+
+#[test]
+fn test_skipped_by_run() {
+    run(r#"
+            test "never run" {
+                assert(false);
+            }
+        "#)
+}
+
+#[test]
+#[should_panic(expected = "tests can only be declared at top level at 3:16-3:27")]
+fn test_nested() {
+    run(r#"
+            {
+                test "a" {}
+            }
+        "#)
+}
+
+#[test]
+fn test_results() {
+    assert_eq!(
+        results(
+            r#"
+            let f = fn (x) { x * 2 };
+
+            test "passes" {
+                assert_eq(f(2), 4);
+            }
+
+            test "fails" {
+                assert_eq(f(2), 5);
+            }
+
+            test "returns" {
+                return;
+                assert(false);
+            }
+        "#,
+            ""
+        ),
+        [
+            ("passes".to_string(), None),
+            (
+                "fails".to_string(),
+                Some("assertion failed: 4 != 5".to_string())
+            ),
+            ("returns".to_string(), None),
+        ]
+    )
+}
+
+#[test]
+fn test_isolation() {
+    assert_eq!(
+        results(
+            r#"
+            let counter = 0;
+
+            test "first" {
+                counter = counter + 1;
+                assert_eq(counter, 1);
+            }
+
+            test "second" {
+                counter = counter + 1;
+                assert_eq(counter, 1);
+            }
+        "#,
+            ""
+        ),
+        [("first".to_string(), None), ("second".to_string(), None)]
+    )
+}
+
+#[test]
+fn test_filter() {
+    assert_eq!(
+        results(
+            r#"
+            test "math add" {}
+            test "math sub" {}
+            test "strings" {}
+        "#,
+            "math"
+        ),
+        [
+            ("math add".to_string(), None),
+            ("math sub".to_string(), None)
+        ]
+    )
+}
+
+#[test]
+fn test_traceback() {
+    let results = execute_tests(
+        Path::new("main.fru"),
+        r#"
+            let f = fn () {
+                assert(false);
+            };
+
+            test "a" {
+                f();
+            }
+        "#
+        .to_owned(),
+        "",
+    )
+    .unwrap();
+
+    let err = results[0].result.as_ref().unwrap_err();
+
+    assert_eq!(
+        err.get_traceback()
+            .iter()
+            .map(|(x, _)| x.start_point.row + 1)
+            .collect::<Vec<_>>(),
+        [3, 7]
+    );
+}
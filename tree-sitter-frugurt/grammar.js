@@ -0,0 +1,646 @@
+// noinspection JSUnresolvedReference
+
+// compound ones apply the operator before the first `=`
+const SET_OPERATORS = ["=", "+=", "-=", "*=", "/=", "%=", "**=", "<>="];
+
+module.exports = grammar({
+    name: "frugurt",
+
+    extras: $ => [
+        /\s/,
+        $.comment,
+    ],
+
+    word: $ => $.identifier,
+
+    conflicts: $ => [
+        [$.function_statement, $.function_expression],
+        [$.loop_statement, $.loop_expression],
+    ],
+
+    supertypes: $ => [
+        $._expression,
+        $._expression_unit,
+        $._literal,
+        $._statement,
+        $._type_member,
+    ],
+
+    rules: {
+        source_file: $ => repeat(field("body", $._statement)),
+
+        // Misc
+
+        // TODO: maybe add ' as valid symbol
+        identifier: _ => /[a-zA-Z_][a-zA-Z0-9_]*/,
+
+        label: _ => /'[a-zA-Z_][a-zA-Z0-9_]*/,
+
+        _loop_label: $ => seq(
+            field("label", $.label),
+            ":",
+        ),
+
+        maybe_typed_identifier: $ => seq(
+            field("ident", $.identifier),
+            optional(seq(
+                ":",
+                field("type_ident", $.identifier),
+            )),
+        ),
+
+        operator: _ => choice(
+            /[-+*\/%<>&|^!?]/,
+            /[-+*\/%=<>&|^!?][-+*\/%=<>&|^!?]+/, // I have no idea why {2,} does not work
+        ),
+
+        // http://stackoverflow.com/questions/13014947/regex-to-match-a-c-style-multiline-comment/36328890#36328890
+        comment: _ => token(choice(
+            seq("//", /.*/),
+            seq(
+                "/*",
+                /[^*]*\*+([^/*][^*]*\*+)*/,
+                "/",
+            ),
+        )),
+
+        // Statements
+
+        _statement: $ => choice(
+            $.block_statement,
+            $.scope_modifier_statement,
+            $.expression_statement,
+            $.let_statement,
+            $.function_statement,
+            $.if_statement,
+            $.while_statement,
+            $.loop_statement,
+            $.return_statement,
+            $.break_statement,
+            $.continue_statement,
+            $.operator_statement,
+            $.type_statement,
+            $.enum_statement,
+            $.test_statement,
+        ),
+
+        block_statement: $ => seq(
+            "{",
+            repeat(field("body", $._statement)),
+            "}",
+        ),
+
+        scope_modifier_statement: $ => seq(
+            "scope",
+            field("what", $._expression),
+            "{",
+            repeat(field("body", $._statement)),
+            "}",
+        ),
+
+        expression_statement: $ => seq(
+            field("value", $._set_or_expression),
+            ";",
+        ),
+
+        let_statement: $ => seq( // add optional typing
+            "let",
+            field("ident", $.identifier),
+            "=",
+            field("value", $._expression),
+            ";",
+        ),
+
+        function_statement: $ => prec.dynamic(1, seq(
+            "fn",
+            field("ident", $.identifier),
+            field("parameters", $.formal_parameters),
+            field("body", choice(
+                $.block_statement,
+                $.block_expression,
+            )),
+        )),
+
+        if_statement: $ => seq(
+            "if",
+            field("condition", $._expression),
+            field("then_body", $.block_statement),
+            optional(seq(
+                "else",
+                field("else_body", choice(
+                    $.if_statement,
+                    $.block_statement,
+                )),
+            )),
+        ),
+
+        while_statement: $ => seq(
+            optional($._loop_label),
+            "while",
+            field("condition", $._expression),
+            field("body", $.block_statement),
+        ),
+
+        loop_statement: $ => prec.dynamic(1, seq(
+            optional($._loop_label),
+            "loop",
+            field("body", $.block_statement),
+        )),
+
+        return_statement: $ => seq(
+            "return",
+            optional(field("value", $._expression)),
+            ";",
+        ),
+
+        break_statement: $ => seq(
+            "break",
+            optional(field("label", $.label)),
+            optional(field("value", $._expression)),
+            ";",
+        ),
+
+        continue_statement: $ => seq(
+            "continue",
+            optional(field("label", $.label)),
+            ";",
+        ),
+
+        operator_statement: $ => seq(
+            optional(field("commutative", "commutative")),
+            "operator",
+            field("ident", $.operator),
+            "(",
+            field("left_ident", $.identifier),
+            ":",
+            field("left_type_ident", $.identifier),
+            ",",
+            field("right_ident", $.identifier),
+            ":",
+            field("right_type_ident", $.identifier),
+            ")",
+            field("body", choice(
+                $.block_statement,
+                $.block_expression,
+            )),
+        ),
+
+        type_statement: $ => seq(
+            field("type_type", $.type_type),
+            field("ident", $.identifier),
+            "{",
+            repeat(field("members", $._type_member)),
+            "}",
+            optional(field("impl", $.type_impl)),
+        ),
+
+        type_type: _ => choice("struct", "class", "data"),
+
+        _type_member: $ => choice(
+            $.type_field,
+            $.type_property,
+            $.type_embed,
+        ),
+
+        type_embed: $ => seq(
+            "embed",
+            field("ident", $.identifier),
+            ";",
+        ),
+
+        type_field: $ => seq(
+            optional(field("pub", "pub")),
+            optional(field("static", "static")),
+            field("ident", $.maybe_typed_identifier),
+            optional(seq(
+                "=",
+                field("value", $._expression),
+            )),
+            ";",
+        ),
+
+        type_property: $ => seq(
+            optional(field("pub", "pub")),
+            optional(field("static", "static")),
+            field("ident", $.maybe_typed_identifier),
+            "{",
+            repeat(field("items", $.type_property_item)),
+            "}",
+        ),
+
+        type_property_item: $ => choice(
+            seq(
+                field("type", "get"),
+                field("body", $.block_expression),
+            ),
+            seq(
+                field("type", "get"),
+                "=>",
+                field("body", $._expression),
+                ";",
+            ),
+            seq(
+                field("type", "set"),
+                optional(seq(
+                    "(",
+                    field("value_ident", $.maybe_typed_identifier),
+                    ")",
+                )),
+                field("body", $.block_statement),
+            ),
+        ),
+
+        type_impl: $ => seq(
+            "impl",
+            "{",
+            repeat(field("methods", $.type_method)),
+            "}",
+        ),
+
+        type_method: $ => seq(
+            optional(field("static", "static")),
+            field("ident", $.identifier),
+            field("parameters", $.formal_parameters),
+            field("body", choice(
+                $.block_statement,
+                $.block_expression,
+            )),
+        ),
+
+        enum_statement: $ => seq(
+            "enum",
+            field("ident", $.identifier),
+            "{",
+            sepBy(field("variants", $.enum_variant)),
+            "}",
+            optional(field("impl", $.type_impl)),
+        ),
+
+        enum_variant: $ => seq(
+            field("ident", $.identifier),
+            optional(seq(
+                "{",
+                sepBy(field("fields", $.identifier)),
+                "}",
+            )),
+        ),
+
+        test_statement: $ => seq(
+            "test",
+            field("name", $.string_literal),
+            field("body", $.block_statement),
+        ),
+
+        // Expressions
+
+        _expression: $ => prec.left(choice(
+            $._expression_unit,
+            $.unary_expression,
+            $.binary_expression,
+        )),
+
+        // assignment is an expression, but only where it can not be confused with the next statement
+        _set_or_expression: $ => choice(
+            $._expression,
+            $.set_expression,
+            $.set_prop_expression,
+            $.set_index_expression,
+        ),
+
+        _expression_unit: $ => choice(
+            $._literal,
+            $.variable,
+            $.scope_expression,
+            $.function_expression,
+            $.parenthesized_expression,
+            $.block_expression,
+            $.scope_modifier_expression,
+            $.call_expression,
+            $.curry_call_expression,
+            $.instantiation_expression,
+            $.prop_access_expression,
+            $.index_expression,
+            $.if_expression,
+            $.match_expression,
+            $.loop_expression,
+            $.import_expression,
+        ),
+
+        _literal: $ => choice(
+            $.number_literal,
+            $.string_literal,
+            $.format_string_literal,
+            $.bool_literal,
+            $.nah_literal,
+        ),
+
+        // fraction must have digits, so that `1..3` is a slice
+        number_literal: _ => /[+-]?([0-9]+([.][0-9]+)?|[.][0-9]+)/,
+
+        // TODO: maybe add \uxxxx support
+        string_literal: _ => /"(?:[^\\\n"]|\\[\\"tnvfr]|\\u\{[0-9a-fA-F]+}|\\\r?\n)*"/,
+
+        // interpolated parts are parsed by the interpreter, so they can not contain `"`
+        format_string_literal: _ => /f"(?:[^\\\n"]|\\[\\"tnvfr]|\\u\{[0-9a-fA-F]+}|\\\r?\n)*"/,
+
+        bool_literal: _ => choice("true", "false"),
+
+        nah_literal: _ => "nah",
+
+        variable: $ => field("ident", $.identifier),
+
+        scope_expression: _ => seq("scope", "(", ")"),
+
+        function_expression: $ => seq(
+            "fn",
+            optional(field("ident", $.identifier)),
+            field("parameters", $.formal_parameters),
+            field("body", choice(
+                $.block_statement,
+                $.block_expression,
+            )),
+        ),
+
+        formal_parameters: $ => seq(
+            "(",
+            sepBy(field("args",
+                choice(
+                    $.positional_parameter,
+                    $.default_parameter,
+                    $.rest_parameter,
+                    $.kwargs_parameter,
+                ),
+            )),
+            ")",
+        ),
+
+        positional_parameter: $ => seq(// FIXME: make use of maybe_typed_identifier
+            field("ident", $.identifier),
+            optional(seq(
+                ":",
+                field("type_ident", $.identifier),
+            )),
+        ),
+
+        default_parameter: $ => seq( // FIXME: make use of maybe_typed_identifier
+            field("ident", $.identifier),
+            optional(seq(
+                ":",
+                field("type_ident", $.identifier),
+            )),
+            "=",
+            field("value", $._expression),
+        ),
+
+        rest_parameter: $ => seq(
+            "..",
+            field("ident", $.identifier),
+        ),
+
+        kwargs_parameter: $ => seq(
+            "**",
+            field("ident", $.identifier),
+        ),
+
+        parenthesized_expression: $ => seq(
+            "(",
+            field("expr", $._set_or_expression),
+            ")",
+        ),
+
+        block_expression: $ => seq(
+            "{",
+            repeat(field("body", $._statement)),
+            field("expr", $._set_or_expression),
+            "}",
+        ),
+
+        scope_modifier_expression: $ => seq(
+            "scope",
+            field("what", $._expression),
+            "{",
+            repeat(field("body", $._statement)),
+            field("expr", $._set_or_expression),
+            "}",
+        ),
+
+        call_expression: $ => seq(
+            field("what", $._expression_unit),
+            field("args", alias($.argument_list_call, $.argument_list)),
+        ),
+
+        curry_call_expression: $ => seq(
+            field("what", $._expression_unit),
+            field("args", alias($.argument_list_curry_call, $.argument_list)),
+        ),
+
+        instantiation_expression: $ => seq(
+            field("what", $._expression_unit),
+            field("args", alias($.argument_list_instantiation, $.argument_list)),
+        ),
+
+        argument_list_call: $ => seq(
+            "(",
+            sepBy(field("args", choice(
+                $.positional_argument,
+                $.named_argument,
+                $.spread_argument,
+                $.kwargs_spread_argument,
+            ))),
+            ")",
+        ),
+
+        argument_list_curry_call: $ => seq(
+            "$(",
+            sepBy(field("args", choice(
+                $.positional_argument,
+                $.named_argument,
+                $.spread_argument,
+                $.kwargs_spread_argument,
+            ))),
+            ")",
+        ),
+
+        argument_list_instantiation: $ => seq(
+            ":{",
+            sepBy(field("args", choice(
+                $.positional_argument,
+                $.named_argument,
+                $.spread_argument,
+                $.kwargs_spread_argument,
+            ))),
+            "}",
+        ),
+
+
+        positional_argument: $ => field("value", $._expression),
+
+        named_argument: $ => seq(
+            field("ident", $.identifier),
+            ":",
+            field("value", $._expression),
+        ),
+
+        spread_argument: $ => seq(
+            "..",
+            field("value", $._expression),
+        ),
+
+        kwargs_spread_argument: $ => seq(
+            "**",
+            field("value", $._expression),
+        ),
+
+        prop_access_expression: $ => seq(
+            field("what", $._expression_unit),
+            choice(
+                ".",
+                field("optional", "?."),
+            ),
+            field("ident", $.identifier),
+        ),
+
+        index_expression: $ => seq(
+            field("what", $._expression_unit),
+            "[",
+            field("index", choice($._expression, $.slice)),
+            "]",
+        ),
+
+        // both bounds can be omitted, like `a[1..]` or `a[..]`
+        slice: $ => seq(
+            optional(field("start", $._expression)),
+            "..",
+            optional(field("end", $._expression)),
+        ),
+
+        // right associativity makes `!a.b()` negate the whole call
+        unary_expression: $ => prec.right(seq(
+            field("operator", "!"),
+            field("value", choice(
+                $._expression_unit,
+                $.unary_expression,
+            )),
+        )),
+
+        binary_expression: $ => choice(
+            ...([
+                [1, "??"],
+                [2, "||"],
+                [3, "&&"],
+                [4, "=="],
+                [4, "!="],
+                [5, "<"],
+                [5, ">"],
+                [5, "<="],
+                [5, ">="],
+                [6, "+"],
+                [6, "-"],
+                [7, "*"],
+                [7, "/"],
+                [7, "%"],
+                [8, "**"],
+                [8, "<>"],
+                [50, $.operator],
+            ].map(
+                ([precedence, operator]) =>
+                    prec.left(precedence, seq(
+                        field("left", $._expression),
+                        field("operator", operator),
+                        field("right", $._expression),
+                    )),
+            )),
+        ),
+
+        set_expression: $ => prec.right(seq(
+            field("ident", $.identifier),
+            field("operator", choice(...SET_OPERATORS)),
+            field("value", $._expression),
+        )),
+
+        set_prop_expression: $ => prec.right(seq(
+            field("what", $._expression_unit),
+            ".",
+            field("ident", $.identifier),
+            field("operator", choice(...SET_OPERATORS)),
+            field("value", $._expression),
+        )),
+
+        set_index_expression: $ => prec.right(seq(
+            field("what", $._expression_unit),
+            "[",
+            field("index", choice($._expression, $.slice)),
+            "]",
+            field("operator", choice(...SET_OPERATORS)),
+            field("value", $._expression),
+        )),
+
+        if_expression: $ => seq(
+            "if",
+            field("condition", $._expression),
+            field("then_body", $.block_expression),
+            "else",
+            field("else_body", choice(
+                $.block_expression,
+                $.if_expression,
+            )),
+        ),
+
+        match_expression: $ => seq(
+            "match",
+            field("value", $._expression),
+            "{",
+            sepBy(field("arms", $.match_arm)),
+            "}",
+        ),
+
+        match_arm: $ => seq(
+            field("pattern", choice(
+                $.wildcard_pattern,
+                $.value_pattern,
+            )),
+            optional(seq(
+                "if",
+                field("guard", $._expression),
+            )),
+            "=>",
+            field("body", $._expression),
+        ),
+
+        wildcard_pattern: _ => "_",
+
+        // type, that the value must be an object of, with fields to bind, or value to compare with
+        value_pattern: $ => seq(
+            field("value", $._expression_unit),
+            optional(seq(
+                "{",
+                sepBy(field("bindings", $.identifier)),
+                "}",
+            )),
+        ),
+
+        loop_expression: $ => seq(
+            optional($._loop_label),
+            "loop",
+            field("body", $.block_statement),
+        ),
+
+        import_expression: $ => seq(
+            "import",
+            field("path", $._expression),
+        ),
+    },
+});
+
+
+function sepBy(rule, sep = ",") {
+    return optional(
+        seq(
+            rule,
+            repeat(seq(
+                sep,
+                rule)),
+            optional(sep),
+        ),
+    );
+}
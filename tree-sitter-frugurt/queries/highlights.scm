@@ -0,0 +1,45 @@
+[
+  "break"
+  "class"
+  "commutative"
+  "continue"
+  "data"
+  "else"
+  "embed"
+  "enum"
+  "fn"
+  "if"
+  "impl"
+  "import"
+  "let"
+  "loop"
+  "match"
+  "operator"
+  "pub"
+  "return"
+  "scope"
+  "struct"
+  "static"
+  "test"
+  "while"
+] @keyword
+
+(number_literal) @number
+(string_literal) @string
+(format_string_literal) @string
+(bool_literal)   @bool
+(nah_literal)    @nah
+(comment)        @comment
+(label)          @label
+
+(let_statement
+    ident: (identifier) @function.declaration
+    value: [
+        (function_expression)
+        (curry_call_expression)
+    ]
+)
+
+(function_statement
+    ident: (identifier) @function.declaration
+)
@@ -0,0 +1,35782 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 862
+#define LARGE_STATE_COUNT 52
+#define SYMBOL_COUNT 157
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 78
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 41
+#define MAX_ALIAS_SEQUENCE_LENGTH 13
+#define PRODUCTION_ID_COUNT 98
+
+enum ts_symbol_identifiers {
+  sym_identifier = 1,
+  sym_label = 2,
+  anon_sym_COLON = 3,
+  aux_sym_operator_token1 = 4,
+  aux_sym_operator_token2 = 5,
+  sym_comment = 6,
+  anon_sym_LBRACE = 7,
+  anon_sym_RBRACE = 8,
+  anon_sym_scope = 9,
+  anon_sym_SEMI = 10,
+  anon_sym_let = 11,
+  anon_sym_EQ = 12,
+  anon_sym_fn = 13,
+  anon_sym_if = 14,
+  anon_sym_else = 15,
+  anon_sym_while = 16,
+  anon_sym_loop = 17,
+  anon_sym_return = 18,
+  anon_sym_break = 19,
+  anon_sym_continue = 20,
+  anon_sym_commutative = 21,
+  anon_sym_operator = 22,
+  anon_sym_LPAREN = 23,
+  anon_sym_COMMA = 24,
+  anon_sym_RPAREN = 25,
+  anon_sym_struct = 26,
+  anon_sym_class = 27,
+  anon_sym_data = 28,
+  anon_sym_embed = 29,
+  anon_sym_pub = 30,
+  anon_sym_static = 31,
+  anon_sym_get = 32,
+  anon_sym_EQ_GT = 33,
+  anon_sym_set = 34,
+  anon_sym_impl = 35,
+  anon_sym_enum = 36,
+  anon_sym_test = 37,
+  sym_number_literal = 38,
+  sym_string_literal = 39,
+  sym_format_string_literal = 40,
+  anon_sym_true = 41,
+  anon_sym_false = 42,
+  sym_nah_literal = 43,
+  anon_sym_DOT_DOT = 44,
+  anon_sym_STAR_STAR = 45,
+  anon_sym_DOLLAR_LPAREN = 46,
+  anon_sym_COLON_LBRACE = 47,
+  anon_sym_DOT = 48,
+  anon_sym_QMARK_DOT = 49,
+  anon_sym_LBRACK = 50,
+  anon_sym_RBRACK = 51,
+  anon_sym_BANG = 52,
+  anon_sym_QMARK_QMARK = 53,
+  anon_sym_PIPE_PIPE = 54,
+  anon_sym_AMP_AMP = 55,
+  anon_sym_EQ_EQ = 56,
+  anon_sym_BANG_EQ = 57,
+  anon_sym_LT = 58,
+  anon_sym_GT = 59,
+  anon_sym_LT_EQ = 60,
+  anon_sym_GT_EQ = 61,
+  anon_sym_PLUS = 62,
+  anon_sym_DASH = 63,
+  anon_sym_STAR = 64,
+  anon_sym_SLASH = 65,
+  anon_sym_PERCENT = 66,
+  anon_sym_LT_GT = 67,
+  anon_sym_PLUS_EQ = 68,
+  anon_sym_DASH_EQ = 69,
+  anon_sym_STAR_EQ = 70,
+  anon_sym_SLASH_EQ = 71,
+  anon_sym_PERCENT_EQ = 72,
+  anon_sym_STAR_STAR_EQ = 73,
+  anon_sym_LT_GT_EQ = 74,
+  anon_sym_match = 75,
+  sym_wildcard_pattern = 76,
+  anon_sym_import = 77,
+  sym_source_file = 78,
+  sym__loop_label = 79,
+  sym_maybe_typed_identifier = 80,
+  sym_operator = 81,
+  sym__statement = 82,
+  sym_block_statement = 83,
+  sym_scope_modifier_statement = 84,
+  sym_expression_statement = 85,
+  sym_let_statement = 86,
+  sym_function_statement = 87,
+  sym_if_statement = 88,
+  sym_while_statement = 89,
+  sym_loop_statement = 90,
+  sym_return_statement = 91,
+  sym_break_statement = 92,
+  sym_continue_statement = 93,
+  sym_operator_statement = 94,
+  sym_type_statement = 95,
+  sym_type_type = 96,
+  sym__type_member = 97,
+  sym_type_embed = 98,
+  sym_type_field = 99,
+  sym_type_property = 100,
+  sym_type_property_item = 101,
+  sym_type_impl = 102,
+  sym_type_method = 103,
+  sym_enum_statement = 104,
+  sym_enum_variant = 105,
+  sym_test_statement = 106,
+  sym__expression = 107,
+  sym__set_or_expression = 108,
+  sym__expression_unit = 109,
+  sym__literal = 110,
+  sym_bool_literal = 111,
+  sym_variable = 112,
+  sym_scope_expression = 113,
+  sym_function_expression = 114,
+  sym_formal_parameters = 115,
+  sym_positional_parameter = 116,
+  sym_default_parameter = 117,
+  sym_rest_parameter = 118,
+  sym_kwargs_parameter = 119,
+  sym_parenthesized_expression = 120,
+  sym_block_expression = 121,
+  sym_scope_modifier_expression = 122,
+  sym_call_expression = 123,
+  sym_curry_call_expression = 124,
+  sym_instantiation_expression = 125,
+  sym_argument_list_call = 126,
+  sym_argument_list_curry_call = 127,
+  sym_argument_list_instantiation = 128,
+  sym_positional_argument = 129,
+  sym_named_argument = 130,
+  sym_spread_argument = 131,
+  sym_kwargs_spread_argument = 132,
+  sym_prop_access_expression = 133,
+  sym_index_expression = 134,
+  sym_slice = 135,
+  sym_unary_expression = 136,
+  sym_binary_expression = 137,
+  sym_set_expression = 138,
+  sym_set_prop_expression = 139,
+  sym_set_index_expression = 140,
+  sym_if_expression = 141,
+  sym_match_expression = 142,
+  sym_match_arm = 143,
+  sym_value_pattern = 144,
+  sym_loop_expression = 145,
+  sym_import_expression = 146,
+  aux_sym_source_file_repeat1 = 147,
+  aux_sym_type_statement_repeat1 = 148,
+  aux_sym_type_property_repeat1 = 149,
+  aux_sym_type_impl_repeat1 = 150,
+  aux_sym_enum_statement_repeat1 = 151,
+  aux_sym_enum_variant_repeat1 = 152,
+  aux_sym_formal_parameters_repeat1 = 153,
+  aux_sym_argument_list_call_repeat1 = 154,
+  aux_sym_match_expression_repeat1 = 155,
+  aux_sym_value_pattern_repeat1 = 156,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym_identifier] = "identifier",
+  [sym_label] = "label",
+  [anon_sym_COLON] = ":",
+  [aux_sym_operator_token1] = "operator_token1",
+  [aux_sym_operator_token2] = "operator_token2",
+  [sym_comment] = "comment",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_scope] = "scope",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_let] = "let",
+  [anon_sym_EQ] = "=",
+  [anon_sym_fn] = "fn",
+  [anon_sym_if] = "if",
+  [anon_sym_else] = "else",
+  [anon_sym_while] = "while",
+  [anon_sym_loop] = "loop",
+  [anon_sym_return] = "return",
+  [anon_sym_break] = "break",
+  [anon_sym_continue] = "continue",
+  [anon_sym_commutative] = "commutative",
+  [anon_sym_operator] = "operator",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_struct] = "struct",
+  [anon_sym_class] = "class",
+  [anon_sym_data] = "data",
+  [anon_sym_embed] = "embed",
+  [anon_sym_pub] = "pub",
+  [anon_sym_static] = "static",
+  [anon_sym_get] = "get",
+  [anon_sym_EQ_GT] = "=>",
+  [anon_sym_set] = "set",
+  [anon_sym_impl] = "impl",
+  [anon_sym_enum] = "enum",
+  [anon_sym_test] = "test",
+  [sym_number_literal] = "number_literal",
+  [sym_string_literal] = "string_literal",
+  [sym_format_string_literal] = "format_string_literal",
+  [anon_sym_true] = "true",
+  [anon_sym_false] = "false",
+  [sym_nah_literal] = "nah_literal",
+  [anon_sym_DOT_DOT] = "..",
+  [anon_sym_STAR_STAR] = "**",
+  [anon_sym_DOLLAR_LPAREN] = "$(",
+  [anon_sym_COLON_LBRACE] = ":{",
+  [anon_sym_DOT] = ".",
+  [anon_sym_QMARK_DOT] = "\?.",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_BANG] = "!",
+  [anon_sym_QMARK_QMARK] = "\?\?",
+  [anon_sym_PIPE_PIPE] = "||",
+  [anon_sym_AMP_AMP] = "&&",
+  [anon_sym_EQ_EQ] = "==",
+  [anon_sym_BANG_EQ] = "!=",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
+  [anon_sym_LT_EQ] = "<=",
+  [anon_sym_GT_EQ] = ">=",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_DASH] = "-",
+  [anon_sym_STAR] = "*",
+  [anon_sym_SLASH] = "/",
+  [anon_sym_PERCENT] = "%",
+  [anon_sym_LT_GT] = "<>",
+  [anon_sym_PLUS_EQ] = "+=",
+  [anon_sym_DASH_EQ] = "-=",
+  [anon_sym_STAR_EQ] = "*=",
+  [anon_sym_SLASH_EQ] = "/=",
+  [anon_sym_PERCENT_EQ] = "%=",
+  [anon_sym_STAR_STAR_EQ] = "**=",
+  [anon_sym_LT_GT_EQ] = "<>=",
+  [anon_sym_match] = "match",
+  [sym_wildcard_pattern] = "wildcard_pattern",
+  [anon_sym_import] = "import",
+  [sym_source_file] = "source_file",
+  [sym__loop_label] = "_loop_label",
+  [sym_maybe_typed_identifier] = "maybe_typed_identifier",
+  [sym_operator] = "operator",
+  [sym__statement] = "_statement",
+  [sym_block_statement] = "block_statement",
+  [sym_scope_modifier_statement] = "scope_modifier_statement",
+  [sym_expression_statement] = "expression_statement",
+  [sym_let_statement] = "let_statement",
+  [sym_function_statement] = "function_statement",
+  [sym_if_statement] = "if_statement",
+  [sym_while_statement] = "while_statement",
+  [sym_loop_statement] = "loop_statement",
+  [sym_return_statement] = "return_statement",
+  [sym_break_statement] = "break_statement",
+  [sym_continue_statement] = "continue_statement",
+  [sym_operator_statement] = "operator_statement",
+  [sym_type_statement] = "type_statement",
+  [sym_type_type] = "type_type",
+  [sym__type_member] = "_type_member",
+  [sym_type_embed] = "type_embed",
+  [sym_type_field] = "type_field",
+  [sym_type_property] = "type_property",
+  [sym_type_property_item] = "type_property_item",
+  [sym_type_impl] = "type_impl",
+  [sym_type_method] = "type_method",
+  [sym_enum_statement] = "enum_statement",
+  [sym_enum_variant] = "enum_variant",
+  [sym_test_statement] = "test_statement",
+  [sym__expression] = "_expression",
+  [sym__set_or_expression] = "_set_or_expression",
+  [sym__expression_unit] = "_expression_unit",
+  [sym__literal] = "_literal",
+  [sym_bool_literal] = "bool_literal",
+  [sym_variable] = "variable",
+  [sym_scope_expression] = "scope_expression",
+  [sym_function_expression] = "function_expression",
+  [sym_formal_parameters] = "formal_parameters",
+  [sym_positional_parameter] = "positional_parameter",
+  [sym_default_parameter] = "default_parameter",
+  [sym_rest_parameter] = "rest_parameter",
+  [sym_kwargs_parameter] = "kwargs_parameter",
+  [sym_parenthesized_expression] = "parenthesized_expression",
+  [sym_block_expression] = "block_expression",
+  [sym_scope_modifier_expression] = "scope_modifier_expression",
+  [sym_call_expression] = "call_expression",
+  [sym_curry_call_expression] = "curry_call_expression",
+  [sym_instantiation_expression] = "instantiation_expression",
+  [sym_argument_list_call] = "argument_list",
+  [sym_argument_list_curry_call] = "argument_list",
+  [sym_argument_list_instantiation] = "argument_list",
+  [sym_positional_argument] = "positional_argument",
+  [sym_named_argument] = "named_argument",
+  [sym_spread_argument] = "spread_argument",
+  [sym_kwargs_spread_argument] = "kwargs_spread_argument",
+  [sym_prop_access_expression] = "prop_access_expression",
+  [sym_index_expression] = "index_expression",
+  [sym_slice] = "slice",
+  [sym_unary_expression] = "unary_expression",
+  [sym_binary_expression] = "binary_expression",
+  [sym_set_expression] = "set_expression",
+  [sym_set_prop_expression] = "set_prop_expression",
+  [sym_set_index_expression] = "set_index_expression",
+  [sym_if_expression] = "if_expression",
+  [sym_match_expression] = "match_expression",
+  [sym_match_arm] = "match_arm",
+  [sym_value_pattern] = "value_pattern",
+  [sym_loop_expression] = "loop_expression",
+  [sym_import_expression] = "import_expression",
+  [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_type_statement_repeat1] = "type_statement_repeat1",
+  [aux_sym_type_property_repeat1] = "type_property_repeat1",
+  [aux_sym_type_impl_repeat1] = "type_impl_repeat1",
+  [aux_sym_enum_statement_repeat1] = "enum_statement_repeat1",
+  [aux_sym_enum_variant_repeat1] = "enum_variant_repeat1",
+  [aux_sym_formal_parameters_repeat1] = "formal_parameters_repeat1",
+  [aux_sym_argument_list_call_repeat1] = "argument_list_call_repeat1",
+  [aux_sym_match_expression_repeat1] = "match_expression_repeat1",
+  [aux_sym_value_pattern_repeat1] = "value_pattern_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym_identifier] = sym_identifier,
+  [sym_label] = sym_label,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [aux_sym_operator_token1] = aux_sym_operator_token1,
+  [aux_sym_operator_token2] = aux_sym_operator_token2,
+  [sym_comment] = sym_comment,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_scope] = anon_sym_scope,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_let] = anon_sym_let,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_fn] = anon_sym_fn,
+  [anon_sym_if] = anon_sym_if,
+  [anon_sym_else] = anon_sym_else,
+  [anon_sym_while] = anon_sym_while,
+  [anon_sym_loop] = anon_sym_loop,
+  [anon_sym_return] = anon_sym_return,
+  [anon_sym_break] = anon_sym_break,
+  [anon_sym_continue] = anon_sym_continue,
+  [anon_sym_commutative] = anon_sym_commutative,
+  [anon_sym_operator] = anon_sym_operator,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_struct] = anon_sym_struct,
+  [anon_sym_class] = anon_sym_class,
+  [anon_sym_data] = anon_sym_data,
+  [anon_sym_embed] = anon_sym_embed,
+  [anon_sym_pub] = anon_sym_pub,
+  [anon_sym_static] = anon_sym_static,
+  [anon_sym_get] = anon_sym_get,
+  [anon_sym_EQ_GT] = anon_sym_EQ_GT,
+  [anon_sym_set] = anon_sym_set,
+  [anon_sym_impl] = anon_sym_impl,
+  [anon_sym_enum] = anon_sym_enum,
+  [anon_sym_test] = anon_sym_test,
+  [sym_number_literal] = sym_number_literal,
+  [sym_string_literal] = sym_string_literal,
+  [sym_format_string_literal] = sym_format_string_literal,
+  [anon_sym_true] = anon_sym_true,
+  [anon_sym_false] = anon_sym_false,
+  [sym_nah_literal] = sym_nah_literal,
+  [anon_sym_DOT_DOT] = anon_sym_DOT_DOT,
+  [anon_sym_STAR_STAR] = anon_sym_STAR_STAR,
+  [anon_sym_DOLLAR_LPAREN] = anon_sym_DOLLAR_LPAREN,
+  [anon_sym_COLON_LBRACE] = anon_sym_COLON_LBRACE,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_QMARK_DOT] = anon_sym_QMARK_DOT,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_QMARK_QMARK] = anon_sym_QMARK_QMARK,
+  [anon_sym_PIPE_PIPE] = anon_sym_PIPE_PIPE,
+  [anon_sym_AMP_AMP] = anon_sym_AMP_AMP,
+  [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_SLASH] = anon_sym_SLASH,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [anon_sym_LT_GT] = anon_sym_LT_GT,
+  [anon_sym_PLUS_EQ] = anon_sym_PLUS_EQ,
+  [anon_sym_DASH_EQ] = anon_sym_DASH_EQ,
+  [anon_sym_STAR_EQ] = anon_sym_STAR_EQ,
+  [anon_sym_SLASH_EQ] = anon_sym_SLASH_EQ,
+  [anon_sym_PERCENT_EQ] = anon_sym_PERCENT_EQ,
+  [anon_sym_STAR_STAR_EQ] = anon_sym_STAR_STAR_EQ,
+  [anon_sym_LT_GT_EQ] = anon_sym_LT_GT_EQ,
+  [anon_sym_match] = anon_sym_match,
+  [sym_wildcard_pattern] = sym_wildcard_pattern,
+  [anon_sym_import] = anon_sym_import,
+  [sym_source_file] = sym_source_file,
+  [sym__loop_label] = sym__loop_label,
+  [sym_maybe_typed_identifier] = sym_maybe_typed_identifier,
+  [sym_operator] = sym_operator,
+  [sym__statement] = sym__statement,
+  [sym_block_statement] = sym_block_statement,
+  [sym_scope_modifier_statement] = sym_scope_modifier_statement,
+  [sym_expression_statement] = sym_expression_statement,
+  [sym_let_statement] = sym_let_statement,
+  [sym_function_statement] = sym_function_statement,
+  [sym_if_statement] = sym_if_statement,
+  [sym_while_statement] = sym_while_statement,
+  [sym_loop_statement] = sym_loop_statement,
+  [sym_return_statement] = sym_return_statement,
+  [sym_break_statement] = sym_break_statement,
+  [sym_continue_statement] = sym_continue_statement,
+  [sym_operator_statement] = sym_operator_statement,
+  [sym_type_statement] = sym_type_statement,
+  [sym_type_type] = sym_type_type,
+  [sym__type_member] = sym__type_member,
+  [sym_type_embed] = sym_type_embed,
+  [sym_type_field] = sym_type_field,
+  [sym_type_property] = sym_type_property,
+  [sym_type_property_item] = sym_type_property_item,
+  [sym_type_impl] = sym_type_impl,
+  [sym_type_method] = sym_type_method,
+  [sym_enum_statement] = sym_enum_statement,
+  [sym_enum_variant] = sym_enum_variant,
+  [sym_test_statement] = sym_test_statement,
+  [sym__expression] = sym__expression,
+  [sym__set_or_expression] = sym__set_or_expression,
+  [sym__expression_unit] = sym__expression_unit,
+  [sym__literal] = sym__literal,
+  [sym_bool_literal] = sym_bool_literal,
+  [sym_variable] = sym_variable,
+  [sym_scope_expression] = sym_scope_expression,
+  [sym_function_expression] = sym_function_expression,
+  [sym_formal_parameters] = sym_formal_parameters,
+  [sym_positional_parameter] = sym_positional_parameter,
+  [sym_default_parameter] = sym_default_parameter,
+  [sym_rest_parameter] = sym_rest_parameter,
+  [sym_kwargs_parameter] = sym_kwargs_parameter,
+  [sym_parenthesized_expression] = sym_parenthesized_expression,
+  [sym_block_expression] = sym_block_expression,
+  [sym_scope_modifier_expression] = sym_scope_modifier_expression,
+  [sym_call_expression] = sym_call_expression,
+  [sym_curry_call_expression] = sym_curry_call_expression,
+  [sym_instantiation_expression] = sym_instantiation_expression,
+  [sym_argument_list_call] = sym_argument_list_call,
+  [sym_argument_list_curry_call] = sym_argument_list_call,
+  [sym_argument_list_instantiation] = sym_argument_list_call,
+  [sym_positional_argument] = sym_positional_argument,
+  [sym_named_argument] = sym_named_argument,
+  [sym_spread_argument] = sym_spread_argument,
+  [sym_kwargs_spread_argument] = sym_kwargs_spread_argument,
+  [sym_prop_access_expression] = sym_prop_access_expression,
+  [sym_index_expression] = sym_index_expression,
+  [sym_slice] = sym_slice,
+  [sym_unary_expression] = sym_unary_expression,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_set_expression] = sym_set_expression,
+  [sym_set_prop_expression] = sym_set_prop_expression,
+  [sym_set_index_expression] = sym_set_index_expression,
+  [sym_if_expression] = sym_if_expression,
+  [sym_match_expression] = sym_match_expression,
+  [sym_match_arm] = sym_match_arm,
+  [sym_value_pattern] = sym_value_pattern,
+  [sym_loop_expression] = sym_loop_expression,
+  [sym_import_expression] = sym_import_expression,
+  [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_type_statement_repeat1] = aux_sym_type_statement_repeat1,
+  [aux_sym_type_property_repeat1] = aux_sym_type_property_repeat1,
+  [aux_sym_type_impl_repeat1] = aux_sym_type_impl_repeat1,
+  [aux_sym_enum_statement_repeat1] = aux_sym_enum_statement_repeat1,
+  [aux_sym_enum_variant_repeat1] = aux_sym_enum_variant_repeat1,
+  [aux_sym_formal_parameters_repeat1] = aux_sym_formal_parameters_repeat1,
+  [aux_sym_argument_list_call_repeat1] = aux_sym_argument_list_call_repeat1,
+  [aux_sym_match_expression_repeat1] = aux_sym_match_expression_repeat1,
+  [aux_sym_value_pattern_repeat1] = aux_sym_value_pattern_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_label] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_operator_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_operator_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_scope] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_let] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_fn] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_if] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_else] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_while] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_loop] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_return] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_break] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_continue] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_commutative] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_operator] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_struct] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_class] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_data] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_embed] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_pub] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_static] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_get] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_set] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_impl] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_enum] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_test] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_number_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_format_string_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_true] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_false] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_nah_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP_AMP] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR_STAR_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_GT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_match] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_wildcard_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_import] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__loop_label] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_maybe_typed_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_operator] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__statement] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_block_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_scope_modifier_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_expression_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_let_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_while_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_loop_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_return_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_break_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_continue_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_operator_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__type_member] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_type_embed] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_property] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_property_item] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_impl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_method] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_variant] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_test_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expression] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym__set_or_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__expression_unit] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym__literal] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_bool_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_scope_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_formal_parameters] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_positional_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_default_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rest_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_kwargs_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parenthesized_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_scope_modifier_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_curry_call_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_instantiation_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_argument_list_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_argument_list_curry_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_argument_list_instantiation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_positional_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_named_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_spread_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_kwargs_spread_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_prop_access_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_slice] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_set_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_set_prop_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_set_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_match_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_match_arm] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_value_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_loop_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_source_file_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_property_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_type_impl_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_variant_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_formal_parameters_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_argument_list_call_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_match_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_value_pattern_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_args = 1,
+  field_arms = 2,
+  field_bindings = 3,
+  field_body = 4,
+  field_commutative = 5,
+  field_condition = 6,
+  field_else_body = 7,
+  field_end = 8,
+  field_expr = 9,
+  field_fields = 10,
+  field_guard = 11,
+  field_ident = 12,
+  field_impl = 13,
+  field_index = 14,
+  field_items = 15,
+  field_label = 16,
+  field_left = 17,
+  field_left_ident = 18,
+  field_left_type_ident = 19,
+  field_members = 20,
+  field_methods = 21,
+  field_name = 22,
+  field_operator = 23,
+  field_optional = 24,
+  field_parameters = 25,
+  field_path = 26,
+  field_pattern = 27,
+  field_pub = 28,
+  field_right = 29,
+  field_right_ident = 30,
+  field_right_type_ident = 31,
+  field_start = 32,
+  field_static = 33,
+  field_then_body = 34,
+  field_type = 35,
+  field_type_ident = 36,
+  field_type_type = 37,
+  field_value = 38,
+  field_value_ident = 39,
+  field_variants = 40,
+  field_what = 41,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_args] = "args",
+  [field_arms] = "arms",
+  [field_bindings] = "bindings",
+  [field_body] = "body",
+  [field_commutative] = "commutative",
+  [field_condition] = "condition",
+  [field_else_body] = "else_body",
+  [field_end] = "end",
+  [field_expr] = "expr",
+  [field_fields] = "fields",
+  [field_guard] = "guard",
+  [field_ident] = "ident",
+  [field_impl] = "impl",
+  [field_index] = "index",
+  [field_items] = "items",
+  [field_label] = "label",
+  [field_left] = "left",
+  [field_left_ident] = "left_ident",
+  [field_left_type_ident] = "left_type_ident",
+  [field_members] = "members",
+  [field_methods] = "methods",
+  [field_name] = "name",
+  [field_operator] = "operator",
+  [field_optional] = "optional",
+  [field_parameters] = "parameters",
+  [field_path] = "path",
+  [field_pattern] = "pattern",
+  [field_pub] = "pub",
+  [field_right] = "right",
+  [field_right_ident] = "right_ident",
+  [field_right_type_ident] = "right_type_ident",
+  [field_start] = "start",
+  [field_static] = "static",
+  [field_then_body] = "then_body",
+  [field_type] = "type",
+  [field_type_ident] = "type_ident",
+  [field_type_type] = "type_type",
+  [field_value] = "value",
+  [field_value_ident] = "value_ident",
+  [field_variants] = "variants",
+  [field_what] = "what",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 1},
+  [5] = {.index = 4, .length = 1},
+  [6] = {.index = 5, .length = 2},
+  [7] = {.index = 7, .length = 1},
+  [8] = {.index = 8, .length = 1},
+  [9] = {.index = 9, .length = 2},
+  [10] = {.index = 11, .length = 2},
+  [11] = {.index = 13, .length = 3},
+  [12] = {.index = 16, .length = 1},
+  [13] = {.index = 12, .length = 1},
+  [14] = {.index = 17, .length = 2},
+  [15] = {.index = 19, .length = 2},
+  [16] = {.index = 21, .length = 2},
+  [17] = {.index = 6, .length = 1},
+  [18] = {.index = 23, .length = 1},
+  [19] = {.index = 24, .length = 2},
+  [20] = {.index = 26, .length = 2},
+  [21] = {.index = 28, .length = 3},
+  [22] = {.index = 31, .length = 2},
+  [23] = {.index = 33, .length = 3},
+  [24] = {.index = 36, .length = 2},
+  [25] = {.index = 38, .length = 1},
+  [26] = {.index = 39, .length = 3},
+  [27] = {.index = 40, .length = 1},
+  [28] = {.index = 9, .length = 1},
+  [29] = {.index = 42, .length = 2},
+  [30] = {.index = 44, .length = 3},
+  [31] = {.index = 47, .length = 2},
+  [32] = {.index = 49, .length = 1},
+  [33] = {.index = 50, .length = 1},
+  [34] = {.index = 51, .length = 1},
+  [35] = {.index = 52, .length = 2},
+  [36] = {.index = 54, .length = 2},
+  [37] = {.index = 56, .length = 2},
+  [38] = {.index = 58, .length = 2},
+  [39] = {.index = 60, .length = 2},
+  [40] = {.index = 62, .length = 2},
+  [41] = {.index = 64, .length = 2},
+  [42] = {.index = 66, .length = 2},
+  [43] = {.index = 68, .length = 3},
+  [44] = {.index = 71, .length = 2},
+  [45] = {.index = 73, .length = 2},
+  [46] = {.index = 75, .length = 2},
+  [47] = {.index = 77, .length = 3},
+  [48] = {.index = 80, .length = 3},
+  [49] = {.index = 83, .length = 2},
+  [50] = {.index = 85, .length = 4},
+  [51] = {.index = 89, .length = 2},
+  [52] = {.index = 91, .length = 3},
+  [53] = {.index = 94, .length = 3},
+  [54] = {.index = 97, .length = 1},
+  [55] = {.index = 98, .length = 3},
+  [56] = {.index = 101, .length = 2},
+  [57] = {.index = 103, .length = 2},
+  [58] = {.index = 105, .length = 1},
+  [59] = {.index = 106, .length = 3},
+  [60] = {.index = 109, .length = 2},
+  [61] = {.index = 111, .length = 2},
+  [62] = {.index = 113, .length = 2},
+  [63] = {.index = 115, .length = 1},
+  [64] = {.index = 116, .length = 4},
+  [65] = {.index = 120, .length = 4},
+  [66] = {.index = 124, .length = 3},
+  [67] = {.index = 127, .length = 2},
+  [68] = {.index = 129, .length = 1},
+  [69] = {.index = 130, .length = 3},
+  [70] = {.index = 133, .length = 4},
+  [71] = {.index = 137, .length = 2},
+  [72] = {.index = 139, .length = 3},
+  [73] = {.index = 142, .length = 2},
+  [74] = {.index = 144, .length = 2},
+  [75] = {.index = 146, .length = 2},
+  [76] = {.index = 148, .length = 1},
+  [77] = {.index = 149, .length = 3},
+  [78] = {.index = 152, .length = 2},
+  [79] = {.index = 154, .length = 1},
+  [80] = {.index = 155, .length = 2},
+  [81] = {.index = 157, .length = 4},
+  [82] = {.index = 161, .length = 3},
+  [83] = {.index = 164, .length = 1},
+  [84] = {.index = 165, .length = 3},
+  [85] = {.index = 168, .length = 2},
+  [86] = {.index = 170, .length = 3},
+  [87] = {.index = 173, .length = 3},
+  [88] = {.index = 176, .length = 3},
+  [89] = {.index = 179, .length = 3},
+  [90] = {.index = 182, .length = 3},
+  [91] = {.index = 185, .length = 4},
+  [92] = {.index = 189, .length = 4},
+  [93] = {.index = 193, .length = 2},
+  [94] = {.index = 195, .length = 4},
+  [95] = {.index = 199, .length = 3},
+  [96] = {.index = 202, .length = 6},
+  [97] = {.index = 208, .length = 7},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_ident, 0},
+  [1] =
+    {field_body, 0},
+  [2] =
+    {field_body, 0, .inherited = true},
+  [3] =
+    {field_label, 0},
+  [4] =
+    {field_body, 1},
+  [5] =
+    {field_operator, 0},
+  [6] =
+    {field_value, 1},
+  [7] =
+    {field_path, 1},
+  [8] =
+    {field_value, 0},
+  [9] =
+    {field_args, 1},
+    {field_what, 0},
+  [11] =
+    {field_body, 0, .inherited = true},
+  [12] =
+    {field_body, 1, .inherited = true},
+  [13] =
+    {field_ident, 0},
+    {field_operator, 1},
+    {field_value, 2},
+  [16] =
+    {field_expr, 1},
+  [17] =
+    {field_body, 2},
+    {field_parameters, 1},
+  [19] =
+    {field_condition, 1},
+    {field_then_body, 2},
+  [21] =
+    {field_body, 2},
+    {field_condition, 1},
+  [23] =
+    {field_label, 1},
+  [24] =
+    {field_body, 2},
+    {field_name, 1},
+  [26] =
+    {field_body, 2},
+    {field_label, 0, .inherited = true},
+  [28] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [31] =
+    {field_ident, 2},
+    {field_what, 0},
+  [33] =
+    {field_ident, 2},
+    {field_optional, 1},
+    {field_what, 0},
+  [36] =
+    {field_body, 1, .inherited = true},
+    {field_expr, 2},
+  [38] =
+    {field_what, 1},
+  [39] =
+    {field_body, 3},
+  [40] =
+    {field_ident, 1},
+    {field_parameters, 2},
+  [42] =
+    {field_label, 1},
+    {field_value, 2},
+  [44] =
+    {field_body, 3},
+    {field_condition, 2},
+    {field_label, 0, .inherited = true},
+  [47] =
+    {field_ident, 1},
+    {field_type_type, 0},
+  [49] =
+    {field_members, 0},
+  [50] =
+    {field_end, 1},
+  [51] =
+    {field_start, 0},
+  [52] =
+    {field_index, 2},
+    {field_what, 0},
+  [54] =
+    {field_expr, 3},
+    {field_what, 1},
+  [56] =
+    {field_body, 3, .inherited = true},
+    {field_what, 1},
+  [58] =
+    {field_ident, 1},
+    {field_value, 3},
+  [60] =
+    {field_ident, 0},
+    {field_type_ident, 2},
+  [62] =
+    {field_ident, 0},
+    {field_value, 2},
+  [64] =
+    {field_args, 1},
+    {field_args, 2, .inherited = true},
+  [66] =
+    {field_args, 0, .inherited = true},
+    {field_args, 1, .inherited = true},
+  [68] =
+    {field_condition, 1},
+    {field_else_body, 4},
+    {field_then_body, 2},
+  [71] =
+    {field_ident, 1},
+    {field_impl, 4},
+  [73] =
+    {field_ident, 1},
+    {field_variants, 3},
+  [75] =
+    {field_arms, 3},
+    {field_value, 1},
+  [77] =
+    {field_ident, 1},
+    {field_impl, 4},
+    {field_type_type, 0},
+  [80] =
+    {field_ident, 1},
+    {field_members, 3, .inherited = true},
+    {field_type_type, 0},
+  [83] =
+    {field_members, 0, .inherited = true},
+    {field_members, 1, .inherited = true},
+  [85] =
+    {field_ident, 2},
+    {field_operator, 3},
+    {field_value, 4},
+    {field_what, 0},
+  [89] =
+    {field_end, 2},
+    {field_start, 0},
+  [91] =
+    {field_body, 3, .inherited = true},
+    {field_expr, 4},
+    {field_what, 1},
+  [94] =
+    {field_ident, 1},
+    {field_impl, 5},
+    {field_variants, 3},
+  [97] =
+    {field_variants, 1},
+  [98] =
+    {field_ident, 1},
+    {field_variants, 3},
+    {field_variants, 4, .inherited = true},
+  [101] =
+    {field_variants, 0, .inherited = true},
+    {field_variants, 1, .inherited = true},
+  [103] =
+    {field_body, 2},
+    {field_pattern, 0},
+  [105] =
+    {field_arms, 1},
+  [106] =
+    {field_arms, 3},
+    {field_arms, 4, .inherited = true},
+    {field_value, 1},
+  [109] =
+    {field_arms, 0, .inherited = true},
+    {field_arms, 1, .inherited = true},
+  [111] =
+    {field_ident, 1},
+    {field_pub, 0},
+  [113] =
+    {field_ident, 1},
+    {field_static, 0},
+  [115] =
+    {field_items, 0},
+  [116] =
+    {field_ident, 1},
+    {field_impl, 5},
+    {field_members, 3, .inherited = true},
+    {field_type_type, 0},
+  [120] =
+    {field_index, 2},
+    {field_operator, 4},
+    {field_value, 5},
+    {field_what, 0},
+  [124] =
+    {field_ident, 0},
+    {field_type_ident, 2},
+    {field_value, 4},
+  [127] =
+    {field_fields, 2},
+    {field_ident, 0},
+  [129] =
+    {field_methods, 0},
+  [130] =
+    {field_ident, 1},
+    {field_impl, 6},
+    {field_variants, 3},
+  [133] =
+    {field_ident, 1},
+    {field_impl, 6},
+    {field_variants, 3},
+    {field_variants, 4, .inherited = true},
+  [137] =
+    {field_bindings, 2},
+    {field_value, 0},
+  [139] =
+    {field_ident, 2},
+    {field_pub, 0},
+    {field_static, 1},
+  [142] =
+    {field_body, 1},
+    {field_type, 0},
+  [144] =
+    {field_ident, 0},
+    {field_items, 2, .inherited = true},
+  [146] =
+    {field_items, 0, .inherited = true},
+    {field_items, 1, .inherited = true},
+  [148] =
+    {field_fields, 1},
+  [149] =
+    {field_fields, 2},
+    {field_fields, 3, .inherited = true},
+    {field_ident, 0},
+  [152] =
+    {field_fields, 0, .inherited = true},
+    {field_fields, 1, .inherited = true},
+  [154] =
+    {field_methods, 2, .inherited = true},
+  [155] =
+    {field_methods, 0, .inherited = true},
+    {field_methods, 1, .inherited = true},
+  [157] =
+    {field_ident, 1},
+    {field_impl, 7},
+    {field_variants, 3},
+    {field_variants, 4, .inherited = true},
+  [161] =
+    {field_body, 4},
+    {field_guard, 2},
+    {field_pattern, 0},
+  [164] =
+    {field_bindings, 1},
+  [165] =
+    {field_bindings, 2},
+    {field_bindings, 3, .inherited = true},
+    {field_value, 0},
+  [168] =
+    {field_bindings, 0, .inherited = true},
+    {field_bindings, 1, .inherited = true},
+  [170] =
+    {field_ident, 1},
+    {field_items, 3, .inherited = true},
+    {field_pub, 0},
+  [173] =
+    {field_ident, 1},
+    {field_pub, 0},
+    {field_value, 3},
+  [176] =
+    {field_ident, 1},
+    {field_items, 3, .inherited = true},
+    {field_static, 0},
+  [179] =
+    {field_ident, 1},
+    {field_static, 0},
+    {field_value, 3},
+  [182] =
+    {field_body, 2},
+    {field_ident, 0},
+    {field_parameters, 1},
+  [185] =
+    {field_ident, 2},
+    {field_items, 4, .inherited = true},
+    {field_pub, 0},
+    {field_static, 1},
+  [189] =
+    {field_ident, 2},
+    {field_pub, 0},
+    {field_static, 1},
+    {field_value, 4},
+  [193] =
+    {field_body, 2},
+    {field_type, 0},
+  [195] =
+    {field_body, 3},
+    {field_ident, 1},
+    {field_parameters, 2},
+    {field_static, 0},
+  [199] =
+    {field_body, 4},
+    {field_type, 0},
+    {field_value_ident, 2},
+  [202] =
+    {field_body, 11},
+    {field_ident, 1},
+    {field_left_ident, 3},
+    {field_left_type_ident, 5},
+    {field_right_ident, 7},
+    {field_right_type_ident, 9},
+  [208] =
+    {field_body, 12},
+    {field_commutative, 0},
+    {field_ident, 2},
+    {field_left_ident, 4},
+    {field_left_type_ident, 6},
+    {field_right_ident, 8},
+    {field_right_type_ident, 10},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 451,
+  [452] = 452,
+  [453] = 453,
+  [454] = 454,
+  [455] = 455,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 511,
+  [512] = 512,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 518,
+  [519] = 519,
+  [520] = 520,
+  [521] = 521,
+  [522] = 522,
+  [523] = 523,
+  [524] = 524,
+  [525] = 525,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 532,
+  [533] = 533,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 538,
+  [539] = 539,
+  [540] = 540,
+  [541] = 541,
+  [542] = 542,
+  [543] = 543,
+  [544] = 544,
+  [545] = 545,
+  [546] = 546,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 550,
+  [551] = 551,
+  [552] = 552,
+  [553] = 553,
+  [554] = 554,
+  [555] = 555,
+  [556] = 556,
+  [557] = 557,
+  [558] = 558,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 564,
+  [565] = 565,
+  [566] = 566,
+  [567] = 567,
+  [568] = 568,
+  [569] = 569,
+  [570] = 570,
+  [571] = 571,
+  [572] = 572,
+  [573] = 573,
+  [574] = 574,
+  [575] = 575,
+  [576] = 576,
+  [577] = 577,
+  [578] = 578,
+  [579] = 579,
+  [580] = 580,
+  [581] = 581,
+  [582] = 582,
+  [583] = 583,
+  [584] = 584,
+  [585] = 585,
+  [586] = 586,
+  [587] = 587,
+  [588] = 588,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 595,
+  [596] = 596,
+  [597] = 597,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 603,
+  [604] = 604,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 610,
+  [611] = 611,
+  [612] = 612,
+  [613] = 613,
+  [614] = 614,
+  [615] = 615,
+  [616] = 616,
+  [617] = 617,
+  [618] = 618,
+  [619] = 619,
+  [620] = 620,
+  [621] = 621,
+  [622] = 622,
+  [623] = 623,
+  [624] = 624,
+  [625] = 625,
+  [626] = 626,
+  [627] = 627,
+  [628] = 628,
+  [629] = 629,
+  [630] = 630,
+  [631] = 631,
+  [632] = 632,
+  [633] = 633,
+  [634] = 634,
+  [635] = 635,
+  [636] = 636,
+  [637] = 637,
+  [638] = 638,
+  [639] = 639,
+  [640] = 640,
+  [641] = 641,
+  [642] = 642,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 649,
+  [650] = 650,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 654,
+  [655] = 655,
+  [656] = 656,
+  [657] = 657,
+  [658] = 658,
+  [659] = 659,
+  [660] = 660,
+  [661] = 661,
+  [662] = 662,
+  [663] = 663,
+  [664] = 664,
+  [665] = 665,
+  [666] = 666,
+  [667] = 667,
+  [668] = 668,
+  [669] = 669,
+  [670] = 670,
+  [671] = 671,
+  [672] = 672,
+  [673] = 673,
+  [674] = 674,
+  [675] = 675,
+  [676] = 676,
+  [677] = 677,
+  [678] = 678,
+  [679] = 679,
+  [680] = 680,
+  [681] = 681,
+  [682] = 682,
+  [683] = 683,
+  [684] = 684,
+  [685] = 685,
+  [686] = 686,
+  [687] = 687,
+  [688] = 688,
+  [689] = 689,
+  [690] = 690,
+  [691] = 691,
+  [692] = 692,
+  [693] = 693,
+  [694] = 694,
+  [695] = 695,
+  [696] = 696,
+  [697] = 697,
+  [698] = 698,
+  [699] = 699,
+  [700] = 700,
+  [701] = 701,
+  [702] = 702,
+  [703] = 703,
+  [704] = 704,
+  [705] = 705,
+  [706] = 706,
+  [707] = 707,
+  [708] = 708,
+  [709] = 709,
+  [710] = 710,
+  [711] = 711,
+  [712] = 712,
+  [713] = 713,
+  [714] = 714,
+  [715] = 715,
+  [716] = 716,
+  [717] = 717,
+  [718] = 718,
+  [719] = 719,
+  [720] = 720,
+  [721] = 721,
+  [722] = 722,
+  [723] = 723,
+  [724] = 724,
+  [725] = 725,
+  [726] = 726,
+  [727] = 727,
+  [728] = 728,
+  [729] = 729,
+  [730] = 730,
+  [731] = 731,
+  [732] = 732,
+  [733] = 733,
+  [734] = 734,
+  [735] = 735,
+  [736] = 736,
+  [737] = 737,
+  [738] = 738,
+  [739] = 739,
+  [740] = 740,
+  [741] = 741,
+  [742] = 742,
+  [743] = 743,
+  [744] = 744,
+  [745] = 745,
+  [746] = 746,
+  [747] = 747,
+  [748] = 748,
+  [749] = 749,
+  [750] = 750,
+  [751] = 751,
+  [752] = 752,
+  [753] = 753,
+  [754] = 754,
+  [755] = 755,
+  [756] = 756,
+  [757] = 757,
+  [758] = 758,
+  [759] = 759,
+  [760] = 760,
+  [761] = 761,
+  [762] = 762,
+  [763] = 763,
+  [764] = 764,
+  [765] = 765,
+  [766] = 766,
+  [767] = 767,
+  [768] = 768,
+  [769] = 769,
+  [770] = 770,
+  [771] = 771,
+  [772] = 772,
+  [773] = 773,
+  [774] = 774,
+  [775] = 775,
+  [776] = 776,
+  [777] = 777,
+  [778] = 778,
+  [779] = 779,
+  [780] = 780,
+  [781] = 781,
+  [782] = 782,
+  [783] = 783,
+  [784] = 784,
+  [785] = 785,
+  [786] = 786,
+  [787] = 787,
+  [788] = 788,
+  [789] = 789,
+  [790] = 790,
+  [791] = 791,
+  [792] = 792,
+  [793] = 793,
+  [794] = 794,
+  [795] = 795,
+  [796] = 796,
+  [797] = 797,
+  [798] = 798,
+  [799] = 799,
+  [800] = 800,
+  [801] = 801,
+  [802] = 802,
+  [803] = 803,
+  [804] = 804,
+  [805] = 805,
+  [806] = 806,
+  [807] = 807,
+  [808] = 808,
+  [809] = 809,
+  [810] = 810,
+  [811] = 811,
+  [812] = 812,
+  [813] = 813,
+  [814] = 814,
+  [815] = 815,
+  [816] = 816,
+  [817] = 817,
+  [818] = 818,
+  [819] = 819,
+  [820] = 820,
+  [821] = 821,
+  [822] = 822,
+  [823] = 823,
+  [824] = 824,
+  [825] = 825,
+  [826] = 826,
+  [827] = 827,
+  [828] = 828,
+  [829] = 829,
+  [830] = 830,
+  [831] = 831,
+  [832] = 832,
+  [833] = 833,
+  [834] = 834,
+  [835] = 835,
+  [836] = 836,
+  [837] = 837,
+  [838] = 838,
+  [839] = 839,
+  [840] = 840,
+  [841] = 841,
+  [842] = 842,
+  [843] = 843,
+  [844] = 844,
+  [845] = 845,
+  [846] = 846,
+  [847] = 847,
+  [848] = 848,
+  [849] = 849,
+  [850] = 850,
+  [851] = 851,
+  [852] = 852,
+  [853] = 853,
+  [854] = 854,
+  [855] = 855,
+  [856] = 856,
+  [857] = 857,
+  [858] = 858,
+  [859] = 859,
+  [860] = 860,
+  [861] = 861,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(1);
+      if (lookahead == '!') ADVANCE(2);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(14);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(22);
+      if (lookahead == '+') ADVANCE(26);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(34);
+      if (lookahead == '.') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == ':') ADVANCE(52);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(55);
+      if (lookahead == '=') ADVANCE(59);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 2:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(4);
+      END_STATE();
+    case 3:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 4:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 5:
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(5);
+      if (lookahead == '"') ADVANCE(6);
+      if (lookahead == '\\') ADVANCE(7);
+      END_STATE();
+    case 6:
+      ACCEPT_TOKEN(sym_string_literal);
+      END_STATE();
+    case 7:
+      if (lookahead == '\n' ||
+          lookahead == '"' ||
+          lookahead == '\\' ||
+          lookahead == 'f' ||
+          lookahead == 'n' ||
+          lookahead == 'r' ||
+          lookahead == 't' ||
+          lookahead == 'v') ADVANCE(5);
+      if (lookahead == '\r') ADVANCE(8);
+      if (lookahead == 'u') ADVANCE(9);
+      END_STATE();
+    case 8:
+      if (lookahead == '\n') ADVANCE(5);
+      END_STATE();
+    case 9:
+      if (lookahead == '{') ADVANCE(10);
+      END_STATE();
+    case 10:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(11);
+      END_STATE();
+    case 11:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(11);
+      if (lookahead == '}') ADVANCE(5);
+      END_STATE();
+    case 12:
+      if (lookahead == '(') ADVANCE(13);
+      END_STATE();
+    case 13:
+      ACCEPT_TOKEN(anon_sym_DOLLAR_LPAREN);
+      END_STATE();
+    case 14:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(15);
+      END_STATE();
+    case 15:
+      ACCEPT_TOKEN(anon_sym_PERCENT_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 16:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '&') ADVANCE(17);
+      END_STATE();
+    case 17:
+      ACCEPT_TOKEN(anon_sym_AMP_AMP);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 18:
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(19);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(sym_label);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(19);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 22:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '*') ADVANCE(23);
+      if (lookahead == '=') ADVANCE(25);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(anon_sym_STAR_STAR);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(24);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_STAR_STAR_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(anon_sym_STAR_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '.') ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == '=') ADVANCE(32);
+      END_STATE();
+    case 27:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (lookahead == '.') ADVANCE(30);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      END_STATE();
+    case 30:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(31);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(sym_number_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(31);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_PLUS_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '.') ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == '=') ADVANCE(35);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_DASH_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(37);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '*') ADVANCE(39);
+      if (lookahead == '/') ADVANCE(49);
+      if (lookahead == '=') ADVANCE(51);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead != 0 &&
+          lookahead != '!' &&
+          lookahead != '%' &&
+          lookahead != '&' &&
+          lookahead != '*' &&
+          lookahead != '+' &&
+          lookahead != '-' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '?' < lookahead) &&
+          lookahead != '^' &&
+          lookahead != '|') ADVANCE(40);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(39);
+      if (lookahead == '*') ADVANCE(45);
+      END_STATE();
+    case 40:
+      if (lookahead != 0 &&
+          lookahead != '*') ADVANCE(40);
+      if (lookahead == '*') ADVANCE(41);
+      END_STATE();
+    case 41:
+      if (lookahead != 0 &&
+          lookahead != '*' &&
+          lookahead != '/') ADVANCE(42);
+      if (lookahead == '*') ADVANCE(41);
+      if (lookahead == '/') ADVANCE(44);
+      END_STATE();
+    case 42:
+      if (lookahead != 0 &&
+          lookahead != '*') ADVANCE(42);
+      if (lookahead == '*') ADVANCE(43);
+      END_STATE();
+    case 43:
+      if (lookahead != 0 &&
+          lookahead != '*' &&
+          lookahead != '/') ADVANCE(42);
+      if (lookahead == '*') ADVANCE(43);
+      if (lookahead == '/') ADVANCE(44);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead != 0 &&
+          lookahead != '!' &&
+          lookahead != '%' &&
+          lookahead != '&' &&
+          lookahead != '*' &&
+          lookahead != '+' &&
+          lookahead != '-' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '?' < lookahead) &&
+          lookahead != '^' &&
+          lookahead != '|') ADVANCE(42);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(46);
+      if (lookahead == '*') ADVANCE(45);
+      if (lookahead == '/') ADVANCE(48);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead != 0 &&
+          lookahead != '!' &&
+          lookahead != '%' &&
+          lookahead != '&' &&
+          lookahead != '*' &&
+          lookahead != '+' &&
+          lookahead != '-' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '?' < lookahead) &&
+          lookahead != '^' &&
+          lookahead != '|') ADVANCE(42);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(46);
+      if (lookahead == '*') ADVANCE(47);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead != 0 &&
+          lookahead != '!' &&
+          lookahead != '%' &&
+          lookahead != '&' &&
+          lookahead != '*' &&
+          lookahead != '+' &&
+          lookahead != '-' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '?' < lookahead) &&
+          lookahead != '^' &&
+          lookahead != '|') ADVANCE(42);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(46);
+      if (lookahead == '*') ADVANCE(47);
+      if (lookahead == '/') ADVANCE(48);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(aux_sym_operator_token2);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '!' &&
+          lookahead != '%' &&
+          lookahead != '&' &&
+          lookahead != '*' &&
+          lookahead != '+' &&
+          lookahead != '-' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '?' < lookahead) &&
+          lookahead != '^' &&
+          lookahead != '|') ADVANCE(50);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(49);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(50);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_SLASH_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == '{') ADVANCE(53);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_COLON_LBRACE);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(56);
+      if (lookahead == '>') ADVANCE(57);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(anon_sym_LT_GT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(58);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(anon_sym_LT_GT_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(60);
+      if (lookahead == '>') ADVANCE(61);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(63);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '>') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '.') ADVANCE(65);
+      if (lookahead == '?') ADVANCE(66);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_QMARK_DOT);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_QMARK_QMARK);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '"') ADVANCE(72);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      END_STATE();
+    case 72:
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(72);
+      if (lookahead == '"') ADVANCE(73);
+      if (lookahead == '\\') ADVANCE(74);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(sym_format_string_literal);
+      END_STATE();
+    case 74:
+      if (lookahead == '\n' ||
+          lookahead == '"' ||
+          lookahead == '\\' ||
+          lookahead == 'f' ||
+          lookahead == 'n' ||
+          lookahead == 'r' ||
+          lookahead == 't' ||
+          lookahead == 'v') ADVANCE(72);
+      if (lookahead == '\r') ADVANCE(75);
+      if (lookahead == 'u') ADVANCE(76);
+      END_STATE();
+    case 75:
+      if (lookahead == '\n') ADVANCE(72);
+      END_STATE();
+    case 76:
+      if (lookahead == '{') ADVANCE(77);
+      END_STATE();
+    case 77:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(78);
+      END_STATE();
+    case 78:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(78);
+      if (lookahead == '}') ADVANCE(72);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^') ADVANCE(3);
+      if (lookahead == '|') ADVANCE(81);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 83:
+      if (eof) ADVANCE(1);
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(83);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 85:
+      if (lookahead == '.') ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      END_STATE();
+    case 86:
+      if (lookahead == '*') ADVANCE(40);
+      if (lookahead == '/') ADVANCE(50);
+      END_STATE();
+    case 87:
+      if (eof) ADVANCE(1);
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(87);
+      END_STATE();
+    case 88:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(88);
+      END_STATE();
+    case 89:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(89);
+      END_STATE();
+    case 90:
+      if (eof) ADVANCE(1);
+      if (lookahead == '!') ADVANCE(2);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(94);
+      if (lookahead == '-') ADVANCE(95);
+      if (lookahead == '.') ADVANCE(96);
+      if (lookahead == '/') ADVANCE(97);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(90);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '*') ADVANCE(93);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_STAR_STAR);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '.') ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '.') ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '*') ADVANCE(39);
+      if (lookahead == '/') ADVANCE(49);
+      END_STATE();
+    case 98:
+      if (lookahead == '{') ADVANCE(53);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(56);
+      if (lookahead == '>') ADVANCE(100);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_LT_GT);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 101:
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(60);
+      END_STATE();
+    case 102:
+      if (lookahead == '!') ADVANCE(2);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(94);
+      if (lookahead == '-') ADVANCE(95);
+      if (lookahead == '.') ADVANCE(96);
+      if (lookahead == '/') ADVANCE(97);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(102);
+      END_STATE();
+    case 103:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(104);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(106);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(103);
+      END_STATE();
+    case 104:
+      if (lookahead == '*') ADVANCE(105);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(anon_sym_STAR_STAR);
+      END_STATE();
+    case 106:
+      if (lookahead == '.') ADVANCE(37);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 107:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(104);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(106);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(107);
+      END_STATE();
+    case 108:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(104);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(106);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(108);
+      END_STATE();
+    case 109:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(109);
+      END_STATE();
+    case 110:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == ':') ADVANCE(111);
+      if (lookahead == ';') ADVANCE(54);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(110);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 112:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(106);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(112);
+      END_STATE();
+    case 113:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == ';') ADVANCE(54);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(113);
+      END_STATE();
+    case 114:
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(114);
+      END_STATE();
+    case 115:
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(115);
+      END_STATE();
+    case 116:
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(85);
+      if (lookahead == '.') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(116);
+      END_STATE();
+    case 117:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(14);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(22);
+      if (lookahead == '+') ADVANCE(119);
+      if (lookahead == '-') ADVANCE(120);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(38);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(55);
+      if (lookahead == '=') ADVANCE(122);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(117);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(4);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(32);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(35);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(60);
+      END_STATE();
+    case 123:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(123);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 126:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(126);
+      END_STATE();
+    case 127:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(128);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(127);
+      END_STATE();
+    case 128:
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          lookahead == '<' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '=') ADVANCE(60);
+      if (lookahead == '>') ADVANCE(61);
+      END_STATE();
+    case 129:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(130);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(129);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(37);
+      END_STATE();
+    case 131:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(52);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(131);
+      END_STATE();
+    case 132:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(128);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(132);
+      END_STATE();
+    case 133:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(134);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(133);
+      END_STATE();
+    case 134:
+      if (lookahead == '.') ADVANCE(37);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '>') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '?') ADVANCE(66);
+      END_STATE();
+    case 136:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(136);
+      END_STATE();
+    case 137:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(137);
+      END_STATE();
+    case 138:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(138);
+      END_STATE();
+    case 139:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(139);
+      END_STATE();
+    case 140:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '.') ADVANCE(134);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(140);
+      END_STATE();
+    case 141:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(141);
+      END_STATE();
+    case 142:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(142);
+      END_STATE();
+    case 143:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(143);
+      END_STATE();
+    case 144:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(101);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == ']') ADVANCE(69);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(144);
+      END_STATE();
+    case 145:
+      if (lookahead == '!') ADVANCE(118);
+      if (lookahead == '%') ADVANCE(91);
+      if (lookahead == '&') ADVANCE(16);
+      if (lookahead == '*') ADVANCE(92);
+      if (lookahead == '+') ADVANCE(124);
+      if (lookahead == '-') ADVANCE(125);
+      if (lookahead == '/') ADVANCE(97);
+      if (lookahead == '<') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(128);
+      if (lookahead == '>') ADVANCE(62);
+      if (lookahead == '?') ADVANCE(135);
+      if (lookahead == '^') ADVANCE(70);
+      if (lookahead == '|') ADVANCE(80);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(145);
+      END_STATE();
+    case 146:
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == '=') ADVANCE(147);
+      if (lookahead == '?') ADVANCE(149);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(146);
+      END_STATE();
+    case 147:
+      if (lookahead == '>') ADVANCE(148);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
+      END_STATE();
+    case 149:
+      if (lookahead == '.') ADVANCE(65);
+      END_STATE();
+    case 150:
+      if (lookahead == '$') ADVANCE(12);
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '.') ADVANCE(121);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ':') ADVANCE(98);
+      if (lookahead == '=') ADVANCE(147);
+      if (lookahead == '?') ADVANCE(149);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '[') ADVANCE(68);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(150);
+      END_STATE();
+    case 151:
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(151);
+      END_STATE();
+    case 152:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '*') ADVANCE(104);
+      if (lookahead == '.') ADVANCE(134);
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(152);
+      END_STATE();
+    case 153:
+      if (lookahead == '*') ADVANCE(104);
+      if (lookahead == '.') ADVANCE(134);
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(153);
+      END_STATE();
+    case 154:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ':') ADVANCE(111);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '=') ADVANCE(155);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(154);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 156:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ':') ADVANCE(111);
+      if (lookahead == '=') ADVANCE(155);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(156);
+      END_STATE();
+    case 157:
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(157);
+      END_STATE();
+    case 158:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(158);
+      END_STATE();
+    case 159:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '=') ADVANCE(155);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(159);
+      END_STATE();
+    case 160:
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(160);
+      END_STATE();
+    case 161:
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '<' ||
+          lookahead == '>' ||
+          lookahead == '?' ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(70);
+      if (lookahead == '/') ADVANCE(162);
+      if (lookahead == '=') ADVANCE(163);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(161);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(aux_sym_operator_token1);
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      if (lookahead == '*') ADVANCE(39);
+      if (lookahead == '/') ADVANCE(49);
+      END_STATE();
+    case 163:
+      if (lookahead == '!' ||
+          lookahead == '%' ||
+          lookahead == '&' ||
+          lookahead == '*' ||
+          lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '/' ||
+          ('<' <= lookahead && lookahead <= '?') ||
+          lookahead == '^' ||
+          lookahead == '|') ADVANCE(3);
+      END_STATE();
+    case 164:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(164);
+      END_STATE();
+    case 165:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(165);
+      END_STATE();
+    case 166:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(166);
+      END_STATE();
+    case 167:
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(167);
+      END_STATE();
+    case 168:
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '{') ADVANCE(79);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(168);
+      END_STATE();
+    case 169:
+      if (lookahead == '/') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(169);
+      END_STATE();
+    case 170:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '=') ADVANCE(155);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(170);
+      END_STATE();
+    case 171:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '=') ADVANCE(155);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(171);
+      END_STATE();
+    case 172:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '=') ADVANCE(147);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(172);
+      END_STATE();
+    case 173:
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '{') ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(173);
+      END_STATE();
+    case 174:
+      if (lookahead == '\'') ADVANCE(18);
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(174);
+      END_STATE();
+    case 175:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (lookahead == '}') ADVANCE(82);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(175);
+      END_STATE();
+    case 176:
+      if (lookahead == '(') ADVANCE(20);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(176);
+      END_STATE();
+    case 177:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '=') ADVANCE(147);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(177);
+      END_STATE();
+    case 178:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ':') ADVANCE(111);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(178);
+      END_STATE();
+    case 179:
+      if (lookahead == '"') ADVANCE(5);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(179);
+      END_STATE();
+    case 180:
+      if (eof) ADVANCE(1);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(180);
+      END_STATE();
+    case 181:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ';') ADVANCE(54);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(181);
+      END_STATE();
+    case 182:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == '=') ADVANCE(155);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(182);
+      END_STATE();
+    case 183:
+      if (lookahead == ')') ADVANCE(21);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(183);
+      END_STATE();
+    case 184:
+      if (lookahead == '/') ADVANCE(86);
+      if (lookahead == ']') ADVANCE(69);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(184);
+      END_STATE();
+    case 185:
+      if (lookahead == ',') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(86);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(185);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (lookahead == '_') ADVANCE(1);
+      if (lookahead == 'b') ADVANCE(2);
+      if (lookahead == 'c') ADVANCE(7);
+      if (lookahead == 'd') ADVANCE(28);
+      if (lookahead == 'e') ADVANCE(32);
+      if (lookahead == 'f') ADVANCE(43);
+      if (lookahead == 'g') ADVANCE(49);
+      if (lookahead == 'i') ADVANCE(52);
+      if (lookahead == 'l') ADVANCE(60);
+      if (lookahead == 'm') ADVANCE(66);
+      if (lookahead == 'n') ADVANCE(71);
+      if (lookahead == 'o') ADVANCE(74);
+      if (lookahead == 'p') ADVANCE(82);
+      if (lookahead == 'r') ADVANCE(85);
+      if (lookahead == 's') ADVANCE(91);
+      if (lookahead == 't') ADVANCE(107);
+      if (lookahead == 'w') ADVANCE(114);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      ACCEPT_TOKEN(sym_wildcard_pattern);
+      END_STATE();
+    case 2:
+      if (lookahead == 'r') ADVANCE(3);
+      END_STATE();
+    case 3:
+      if (lookahead == 'e') ADVANCE(4);
+      END_STATE();
+    case 4:
+      if (lookahead == 'a') ADVANCE(5);
+      END_STATE();
+    case 5:
+      if (lookahead == 'k') ADVANCE(6);
+      END_STATE();
+    case 6:
+      ACCEPT_TOKEN(anon_sym_break);
+      END_STATE();
+    case 7:
+      if (lookahead == 'l') ADVANCE(8);
+      if (lookahead == 'o') ADVANCE(12);
+      END_STATE();
+    case 8:
+      if (lookahead == 'a') ADVANCE(9);
+      END_STATE();
+    case 9:
+      if (lookahead == 's') ADVANCE(10);
+      END_STATE();
+    case 10:
+      if (lookahead == 's') ADVANCE(11);
+      END_STATE();
+    case 11:
+      ACCEPT_TOKEN(anon_sym_class);
+      END_STATE();
+    case 12:
+      if (lookahead == 'm') ADVANCE(13);
+      if (lookahead == 'n') ADVANCE(22);
+      END_STATE();
+    case 13:
+      if (lookahead == 'm') ADVANCE(14);
+      END_STATE();
+    case 14:
+      if (lookahead == 'u') ADVANCE(15);
+      END_STATE();
+    case 15:
+      if (lookahead == 't') ADVANCE(16);
+      END_STATE();
+    case 16:
+      if (lookahead == 'a') ADVANCE(17);
+      END_STATE();
+    case 17:
+      if (lookahead == 't') ADVANCE(18);
+      END_STATE();
+    case 18:
+      if (lookahead == 'i') ADVANCE(19);
+      END_STATE();
+    case 19:
+      if (lookahead == 'v') ADVANCE(20);
+      END_STATE();
+    case 20:
+      if (lookahead == 'e') ADVANCE(21);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_commutative);
+      END_STATE();
+    case 22:
+      if (lookahead == 't') ADVANCE(23);
+      END_STATE();
+    case 23:
+      if (lookahead == 'i') ADVANCE(24);
+      END_STATE();
+    case 24:
+      if (lookahead == 'n') ADVANCE(25);
+      END_STATE();
+    case 25:
+      if (lookahead == 'u') ADVANCE(26);
+      END_STATE();
+    case 26:
+      if (lookahead == 'e') ADVANCE(27);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(anon_sym_continue);
+      END_STATE();
+    case 28:
+      if (lookahead == 'a') ADVANCE(29);
+      END_STATE();
+    case 29:
+      if (lookahead == 't') ADVANCE(30);
+      END_STATE();
+    case 30:
+      if (lookahead == 'a') ADVANCE(31);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_data);
+      END_STATE();
+    case 32:
+      if (lookahead == 'l') ADVANCE(33);
+      if (lookahead == 'm') ADVANCE(36);
+      if (lookahead == 'n') ADVANCE(40);
+      END_STATE();
+    case 33:
+      if (lookahead == 's') ADVANCE(34);
+      END_STATE();
+    case 34:
+      if (lookahead == 'e') ADVANCE(35);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_else);
+      END_STATE();
+    case 36:
+      if (lookahead == 'b') ADVANCE(37);
+      END_STATE();
+    case 37:
+      if (lookahead == 'e') ADVANCE(38);
+      END_STATE();
+    case 38:
+      if (lookahead == 'd') ADVANCE(39);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(anon_sym_embed);
+      END_STATE();
+    case 40:
+      if (lookahead == 'u') ADVANCE(41);
+      END_STATE();
+    case 41:
+      if (lookahead == 'm') ADVANCE(42);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(anon_sym_enum);
+      END_STATE();
+    case 43:
+      if (lookahead == 'a') ADVANCE(44);
+      if (lookahead == 'n') ADVANCE(48);
+      END_STATE();
+    case 44:
+      if (lookahead == 'l') ADVANCE(45);
+      END_STATE();
+    case 45:
+      if (lookahead == 's') ADVANCE(46);
+      END_STATE();
+    case 46:
+      if (lookahead == 'e') ADVANCE(47);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(anon_sym_false);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_fn);
+      END_STATE();
+    case 49:
+      if (lookahead == 'e') ADVANCE(50);
+      END_STATE();
+    case 50:
+      if (lookahead == 't') ADVANCE(51);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_get);
+      END_STATE();
+    case 52:
+      if (lookahead == 'f') ADVANCE(53);
+      if (lookahead == 'm') ADVANCE(54);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 54:
+      if (lookahead == 'p') ADVANCE(55);
+      END_STATE();
+    case 55:
+      if (lookahead == 'l') ADVANCE(56);
+      if (lookahead == 'o') ADVANCE(57);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_impl);
+      END_STATE();
+    case 57:
+      if (lookahead == 'r') ADVANCE(58);
+      END_STATE();
+    case 58:
+      if (lookahead == 't') ADVANCE(59);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(anon_sym_import);
+      END_STATE();
+    case 60:
+      if (lookahead == 'e') ADVANCE(61);
+      if (lookahead == 'o') ADVANCE(63);
+      END_STATE();
+    case 61:
+      if (lookahead == 't') ADVANCE(62);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_let);
+      END_STATE();
+    case 63:
+      if (lookahead == 'o') ADVANCE(64);
+      END_STATE();
+    case 64:
+      if (lookahead == 'p') ADVANCE(65);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_loop);
+      END_STATE();
+    case 66:
+      if (lookahead == 'a') ADVANCE(67);
+      END_STATE();
+    case 67:
+      if (lookahead == 't') ADVANCE(68);
+      END_STATE();
+    case 68:
+      if (lookahead == 'c') ADVANCE(69);
+      END_STATE();
+    case 69:
+      if (lookahead == 'h') ADVANCE(70);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(anon_sym_match);
+      END_STATE();
+    case 71:
+      if (lookahead == 'a') ADVANCE(72);
+      END_STATE();
+    case 72:
+      if (lookahead == 'h') ADVANCE(73);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(sym_nah_literal);
+      END_STATE();
+    case 74:
+      if (lookahead == 'p') ADVANCE(75);
+      END_STATE();
+    case 75:
+      if (lookahead == 'e') ADVANCE(76);
+      END_STATE();
+    case 76:
+      if (lookahead == 'r') ADVANCE(77);
+      END_STATE();
+    case 77:
+      if (lookahead == 'a') ADVANCE(78);
+      END_STATE();
+    case 78:
+      if (lookahead == 't') ADVANCE(79);
+      END_STATE();
+    case 79:
+      if (lookahead == 'o') ADVANCE(80);
+      END_STATE();
+    case 80:
+      if (lookahead == 'r') ADVANCE(81);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_operator);
+      END_STATE();
+    case 82:
+      if (lookahead == 'u') ADVANCE(83);
+      END_STATE();
+    case 83:
+      if (lookahead == 'b') ADVANCE(84);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_pub);
+      END_STATE();
+    case 85:
+      if (lookahead == 'e') ADVANCE(86);
+      END_STATE();
+    case 86:
+      if (lookahead == 't') ADVANCE(87);
+      END_STATE();
+    case 87:
+      if (lookahead == 'u') ADVANCE(88);
+      END_STATE();
+    case 88:
+      if (lookahead == 'r') ADVANCE(89);
+      END_STATE();
+    case 89:
+      if (lookahead == 'n') ADVANCE(90);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(anon_sym_return);
+      END_STATE();
+    case 91:
+      if (lookahead == 'c') ADVANCE(92);
+      if (lookahead == 'e') ADVANCE(96);
+      if (lookahead == 't') ADVANCE(98);
+      END_STATE();
+    case 92:
+      if (lookahead == 'o') ADVANCE(93);
+      END_STATE();
+    case 93:
+      if (lookahead == 'p') ADVANCE(94);
+      END_STATE();
+    case 94:
+      if (lookahead == 'e') ADVANCE(95);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_scope);
+      END_STATE();
+    case 96:
+      if (lookahead == 't') ADVANCE(97);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(anon_sym_set);
+      END_STATE();
+    case 98:
+      if (lookahead == 'a') ADVANCE(99);
+      if (lookahead == 'r') ADVANCE(103);
+      END_STATE();
+    case 99:
+      if (lookahead == 't') ADVANCE(100);
+      END_STATE();
+    case 100:
+      if (lookahead == 'i') ADVANCE(101);
+      END_STATE();
+    case 101:
+      if (lookahead == 'c') ADVANCE(102);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_static);
+      END_STATE();
+    case 103:
+      if (lookahead == 'u') ADVANCE(104);
+      END_STATE();
+    case 104:
+      if (lookahead == 'c') ADVANCE(105);
+      END_STATE();
+    case 105:
+      if (lookahead == 't') ADVANCE(106);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(anon_sym_struct);
+      END_STATE();
+    case 107:
+      if (lookahead == 'e') ADVANCE(108);
+      if (lookahead == 'r') ADVANCE(111);
+      END_STATE();
+    case 108:
+      if (lookahead == 's') ADVANCE(109);
+      END_STATE();
+    case 109:
+      if (lookahead == 't') ADVANCE(110);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(anon_sym_test);
+      END_STATE();
+    case 111:
+      if (lookahead == 'u') ADVANCE(112);
+      END_STATE();
+    case 112:
+      if (lookahead == 'e') ADVANCE(113);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(anon_sym_true);
+      END_STATE();
+    case 114:
+      if (lookahead == 'h') ADVANCE(115);
+      END_STATE();
+    case 115:
+      if (lookahead == 'i') ADVANCE(116);
+      END_STATE();
+    case 116:
+      if (lookahead == 'l') ADVANCE(117);
+      END_STATE();
+    case 117:
+      if (lookahead == 'e') ADVANCE(118);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(anon_sym_while);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 83},
+  [2] = {.lex_state = 87},
+  [3] = {.lex_state = 88},
+  [4] = {.lex_state = 83},
+  [5] = {.lex_state = 88},
+  [6] = {.lex_state = 88},
+  [7] = {.lex_state = 88},
+  [8] = {.lex_state = 88},
+  [9] = {.lex_state = 88},
+  [10] = {.lex_state = 88},
+  [11] = {.lex_state = 88},
+  [12] = {.lex_state = 88},
+  [13] = {.lex_state = 88},
+  [14] = {.lex_state = 88},
+  [15] = {.lex_state = 88},
+  [16] = {.lex_state = 88},
+  [17] = {.lex_state = 88},
+  [18] = {.lex_state = 88},
+  [19] = {.lex_state = 88},
+  [20] = {.lex_state = 88},
+  [21] = {.lex_state = 88},
+  [22] = {.lex_state = 88},
+  [23] = {.lex_state = 88},
+  [24] = {.lex_state = 88},
+  [25] = {.lex_state = 88},
+  [26] = {.lex_state = 88},
+  [27] = {.lex_state = 88},
+  [28] = {.lex_state = 88},
+  [29] = {.lex_state = 88},
+  [30] = {.lex_state = 88},
+  [31] = {.lex_state = 88},
+  [32] = {.lex_state = 88},
+  [33] = {.lex_state = 88},
+  [34] = {.lex_state = 88},
+  [35] = {.lex_state = 88},
+  [36] = {.lex_state = 89},
+  [37] = {.lex_state = 89},
+  [38] = {.lex_state = 89},
+  [39] = {.lex_state = 89},
+  [40] = {.lex_state = 89},
+  [41] = {.lex_state = 89},
+  [42] = {.lex_state = 89},
+  [43] = {.lex_state = 89},
+  [44] = {.lex_state = 89},
+  [45] = {.lex_state = 89},
+  [46] = {.lex_state = 89},
+  [47] = {.lex_state = 89},
+  [48] = {.lex_state = 89},
+  [49] = {.lex_state = 89},
+  [50] = {.lex_state = 89},
+  [51] = {.lex_state = 89},
+  [52] = {.lex_state = 90},
+  [53] = {.lex_state = 90},
+  [54] = {.lex_state = 90},
+  [55] = {.lex_state = 90},
+  [56] = {.lex_state = 90},
+  [57] = {.lex_state = 90},
+  [58] = {.lex_state = 90},
+  [59] = {.lex_state = 102},
+  [60] = {.lex_state = 102},
+  [61] = {.lex_state = 102},
+  [62] = {.lex_state = 102},
+  [63] = {.lex_state = 102},
+  [64] = {.lex_state = 102},
+  [65] = {.lex_state = 103},
+  [66] = {.lex_state = 103},
+  [67] = {.lex_state = 107},
+  [68] = {.lex_state = 103},
+  [69] = {.lex_state = 103},
+  [70] = {.lex_state = 107},
+  [71] = {.lex_state = 103},
+  [72] = {.lex_state = 103},
+  [73] = {.lex_state = 107},
+  [74] = {.lex_state = 103},
+  [75] = {.lex_state = 103},
+  [76] = {.lex_state = 107},
+  [77] = {.lex_state = 103},
+  [78] = {.lex_state = 103},
+  [79] = {.lex_state = 107},
+  [80] = {.lex_state = 103},
+  [81] = {.lex_state = 103},
+  [82] = {.lex_state = 107},
+  [83] = {.lex_state = 103},
+  [84] = {.lex_state = 103},
+  [85] = {.lex_state = 107},
+  [86] = {.lex_state = 103},
+  [87] = {.lex_state = 103},
+  [88] = {.lex_state = 107},
+  [89] = {.lex_state = 103},
+  [90] = {.lex_state = 103},
+  [91] = {.lex_state = 107},
+  [92] = {.lex_state = 103},
+  [93] = {.lex_state = 103},
+  [94] = {.lex_state = 107},
+  [95] = {.lex_state = 103},
+  [96] = {.lex_state = 103},
+  [97] = {.lex_state = 107},
+  [98] = {.lex_state = 103},
+  [99] = {.lex_state = 103},
+  [100] = {.lex_state = 107},
+  [101] = {.lex_state = 108},
+  [102] = {.lex_state = 109},
+  [103] = {.lex_state = 109},
+  [104] = {.lex_state = 109},
+  [105] = {.lex_state = 109},
+  [106] = {.lex_state = 89},
+  [107] = {.lex_state = 89},
+  [108] = {.lex_state = 89},
+  [109] = {.lex_state = 89},
+  [110] = {.lex_state = 110},
+  [111] = {.lex_state = 112},
+  [112] = {.lex_state = 112},
+  [113] = {.lex_state = 112},
+  [114] = {.lex_state = 112},
+  [115] = {.lex_state = 112},
+  [116] = {.lex_state = 113},
+  [117] = {.lex_state = 113},
+  [118] = {.lex_state = 114},
+  [119] = {.lex_state = 114},
+  [120] = {.lex_state = 89},
+  [121] = {.lex_state = 89},
+  [122] = {.lex_state = 89},
+  [123] = {.lex_state = 89},
+  [124] = {.lex_state = 89},
+  [125] = {.lex_state = 89},
+  [126] = {.lex_state = 89},
+  [127] = {.lex_state = 89},
+  [128] = {.lex_state = 89},
+  [129] = {.lex_state = 89},
+  [130] = {.lex_state = 89},
+  [131] = {.lex_state = 89},
+  [132] = {.lex_state = 89},
+  [133] = {.lex_state = 89},
+  [134] = {.lex_state = 89},
+  [135] = {.lex_state = 89},
+  [136] = {.lex_state = 89},
+  [137] = {.lex_state = 89},
+  [138] = {.lex_state = 89},
+  [139] = {.lex_state = 115},
+  [140] = {.lex_state = 89},
+  [141] = {.lex_state = 89},
+  [142] = {.lex_state = 89},
+  [143] = {.lex_state = 89},
+  [144] = {.lex_state = 89},
+  [145] = {.lex_state = 89},
+  [146] = {.lex_state = 89},
+  [147] = {.lex_state = 89},
+  [148] = {.lex_state = 115},
+  [149] = {.lex_state = 89},
+  [150] = {.lex_state = 89},
+  [151] = {.lex_state = 89},
+  [152] = {.lex_state = 115},
+  [153] = {.lex_state = 89},
+  [154] = {.lex_state = 89},
+  [155] = {.lex_state = 89},
+  [156] = {.lex_state = 89},
+  [157] = {.lex_state = 89},
+  [158] = {.lex_state = 89},
+  [159] = {.lex_state = 89},
+  [160] = {.lex_state = 89},
+  [161] = {.lex_state = 89},
+  [162] = {.lex_state = 89},
+  [163] = {.lex_state = 89},
+  [164] = {.lex_state = 89},
+  [165] = {.lex_state = 89},
+  [166] = {.lex_state = 89},
+  [167] = {.lex_state = 89},
+  [168] = {.lex_state = 89},
+  [169] = {.lex_state = 89},
+  [170] = {.lex_state = 89},
+  [171] = {.lex_state = 89},
+  [172] = {.lex_state = 89},
+  [173] = {.lex_state = 89},
+  [174] = {.lex_state = 89},
+  [175] = {.lex_state = 89},
+  [176] = {.lex_state = 89},
+  [177] = {.lex_state = 89},
+  [178] = {.lex_state = 89},
+  [179] = {.lex_state = 89},
+  [180] = {.lex_state = 89},
+  [181] = {.lex_state = 89},
+  [182] = {.lex_state = 89},
+  [183] = {.lex_state = 89},
+  [184] = {.lex_state = 89},
+  [185] = {.lex_state = 89},
+  [186] = {.lex_state = 89},
+  [187] = {.lex_state = 115},
+  [188] = {.lex_state = 115},
+  [189] = {.lex_state = 115},
+  [190] = {.lex_state = 89},
+  [191] = {.lex_state = 89},
+  [192] = {.lex_state = 89},
+  [193] = {.lex_state = 89},
+  [194] = {.lex_state = 89},
+  [195] = {.lex_state = 89},
+  [196] = {.lex_state = 89},
+  [197] = {.lex_state = 89},
+  [198] = {.lex_state = 89},
+  [199] = {.lex_state = 89},
+  [200] = {.lex_state = 89},
+  [201] = {.lex_state = 89},
+  [202] = {.lex_state = 89},
+  [203] = {.lex_state = 89},
+  [204] = {.lex_state = 89},
+  [205] = {.lex_state = 89},
+  [206] = {.lex_state = 89},
+  [207] = {.lex_state = 89},
+  [208] = {.lex_state = 115},
+  [209] = {.lex_state = 115},
+  [210] = {.lex_state = 115},
+  [211] = {.lex_state = 115},
+  [212] = {.lex_state = 115},
+  [213] = {.lex_state = 115},
+  [214] = {.lex_state = 89},
+  [215] = {.lex_state = 89},
+  [216] = {.lex_state = 89},
+  [217] = {.lex_state = 89},
+  [218] = {.lex_state = 89},
+  [219] = {.lex_state = 89},
+  [220] = {.lex_state = 89},
+  [221] = {.lex_state = 89},
+  [222] = {.lex_state = 89},
+  [223] = {.lex_state = 116},
+  [224] = {.lex_state = 89},
+  [225] = {.lex_state = 89},
+  [226] = {.lex_state = 89},
+  [227] = {.lex_state = 89},
+  [228] = {.lex_state = 89},
+  [229] = {.lex_state = 89},
+  [230] = {.lex_state = 117},
+  [231] = {.lex_state = 117},
+  [232] = {.lex_state = 117},
+  [233] = {.lex_state = 123},
+  [234] = {.lex_state = 123},
+  [235] = {.lex_state = 87},
+  [236] = {.lex_state = 87},
+  [237] = {.lex_state = 87},
+  [238] = {.lex_state = 87},
+  [239] = {.lex_state = 87},
+  [240] = {.lex_state = 87},
+  [241] = {.lex_state = 87},
+  [242] = {.lex_state = 123},
+  [243] = {.lex_state = 87},
+  [244] = {.lex_state = 87},
+  [245] = {.lex_state = 87},
+  [246] = {.lex_state = 87},
+  [247] = {.lex_state = 87},
+  [248] = {.lex_state = 87},
+  [249] = {.lex_state = 87},
+  [250] = {.lex_state = 87},
+  [251] = {.lex_state = 87},
+  [252] = {.lex_state = 123},
+  [253] = {.lex_state = 87},
+  [254] = {.lex_state = 87},
+  [255] = {.lex_state = 87},
+  [256] = {.lex_state = 87},
+  [257] = {.lex_state = 87},
+  [258] = {.lex_state = 87},
+  [259] = {.lex_state = 87},
+  [260] = {.lex_state = 87},
+  [261] = {.lex_state = 87},
+  [262] = {.lex_state = 87},
+  [263] = {.lex_state = 87},
+  [264] = {.lex_state = 87},
+  [265] = {.lex_state = 87},
+  [266] = {.lex_state = 87},
+  [267] = {.lex_state = 87},
+  [268] = {.lex_state = 87},
+  [269] = {.lex_state = 87},
+  [270] = {.lex_state = 87},
+  [271] = {.lex_state = 87},
+  [272] = {.lex_state = 87},
+  [273] = {.lex_state = 87},
+  [274] = {.lex_state = 87},
+  [275] = {.lex_state = 87},
+  [276] = {.lex_state = 87},
+  [277] = {.lex_state = 87},
+  [278] = {.lex_state = 123},
+  [279] = {.lex_state = 123},
+  [280] = {.lex_state = 123},
+  [281] = {.lex_state = 123},
+  [282] = {.lex_state = 123},
+  [283] = {.lex_state = 123},
+  [284] = {.lex_state = 123},
+  [285] = {.lex_state = 123},
+  [286] = {.lex_state = 123},
+  [287] = {.lex_state = 87},
+  [288] = {.lex_state = 87},
+  [289] = {.lex_state = 123},
+  [290] = {.lex_state = 123},
+  [291] = {.lex_state = 126},
+  [292] = {.lex_state = 123},
+  [293] = {.lex_state = 123},
+  [294] = {.lex_state = 123},
+  [295] = {.lex_state = 123},
+  [296] = {.lex_state = 123},
+  [297] = {.lex_state = 123},
+  [298] = {.lex_state = 123},
+  [299] = {.lex_state = 123},
+  [300] = {.lex_state = 123},
+  [301] = {.lex_state = 123},
+  [302] = {.lex_state = 123},
+  [303] = {.lex_state = 123},
+  [304] = {.lex_state = 123},
+  [305] = {.lex_state = 123},
+  [306] = {.lex_state = 123},
+  [307] = {.lex_state = 123},
+  [308] = {.lex_state = 123},
+  [309] = {.lex_state = 123},
+  [310] = {.lex_state = 123},
+  [311] = {.lex_state = 123},
+  [312] = {.lex_state = 123},
+  [313] = {.lex_state = 123},
+  [314] = {.lex_state = 123},
+  [315] = {.lex_state = 123},
+  [316] = {.lex_state = 123},
+  [317] = {.lex_state = 123},
+  [318] = {.lex_state = 123},
+  [319] = {.lex_state = 123},
+  [320] = {.lex_state = 123},
+  [321] = {.lex_state = 123},
+  [322] = {.lex_state = 123},
+  [323] = {.lex_state = 123},
+  [324] = {.lex_state = 123},
+  [325] = {.lex_state = 123},
+  [326] = {.lex_state = 123},
+  [327] = {.lex_state = 123},
+  [328] = {.lex_state = 123},
+  [329] = {.lex_state = 123},
+  [330] = {.lex_state = 123},
+  [331] = {.lex_state = 123},
+  [332] = {.lex_state = 123},
+  [333] = {.lex_state = 123},
+  [334] = {.lex_state = 127},
+  [335] = {.lex_state = 123},
+  [336] = {.lex_state = 127},
+  [337] = {.lex_state = 129},
+  [338] = {.lex_state = 129},
+  [339] = {.lex_state = 129},
+  [340] = {.lex_state = 131},
+  [341] = {.lex_state = 127},
+  [342] = {.lex_state = 127},
+  [343] = {.lex_state = 127},
+  [344] = {.lex_state = 127},
+  [345] = {.lex_state = 127},
+  [346] = {.lex_state = 127},
+  [347] = {.lex_state = 127},
+  [348] = {.lex_state = 127},
+  [349] = {.lex_state = 127},
+  [350] = {.lex_state = 127},
+  [351] = {.lex_state = 132},
+  [352] = {.lex_state = 129},
+  [353] = {.lex_state = 129},
+  [354] = {.lex_state = 129},
+  [355] = {.lex_state = 127},
+  [356] = {.lex_state = 127},
+  [357] = {.lex_state = 127},
+  [358] = {.lex_state = 127},
+  [359] = {.lex_state = 127},
+  [360] = {.lex_state = 127},
+  [361] = {.lex_state = 127},
+  [362] = {.lex_state = 127},
+  [363] = {.lex_state = 127},
+  [364] = {.lex_state = 127},
+  [365] = {.lex_state = 127},
+  [366] = {.lex_state = 127},
+  [367] = {.lex_state = 127},
+  [368] = {.lex_state = 129},
+  [369] = {.lex_state = 129},
+  [370] = {.lex_state = 129},
+  [371] = {.lex_state = 129},
+  [372] = {.lex_state = 129},
+  [373] = {.lex_state = 129},
+  [374] = {.lex_state = 129},
+  [375] = {.lex_state = 127},
+  [376] = {.lex_state = 127},
+  [377] = {.lex_state = 127},
+  [378] = {.lex_state = 127},
+  [379] = {.lex_state = 127},
+  [380] = {.lex_state = 127},
+  [381] = {.lex_state = 127},
+  [382] = {.lex_state = 127},
+  [383] = {.lex_state = 127},
+  [384] = {.lex_state = 127},
+  [385] = {.lex_state = 127},
+  [386] = {.lex_state = 127},
+  [387] = {.lex_state = 127},
+  [388] = {.lex_state = 127},
+  [389] = {.lex_state = 127},
+  [390] = {.lex_state = 127},
+  [391] = {.lex_state = 127},
+  [392] = {.lex_state = 127},
+  [393] = {.lex_state = 127},
+  [394] = {.lex_state = 127},
+  [395] = {.lex_state = 127},
+  [396] = {.lex_state = 127},
+  [397] = {.lex_state = 127},
+  [398] = {.lex_state = 127},
+  [399] = {.lex_state = 127},
+  [400] = {.lex_state = 127},
+  [401] = {.lex_state = 127},
+  [402] = {.lex_state = 127},
+  [403] = {.lex_state = 127},
+  [404] = {.lex_state = 127},
+  [405] = {.lex_state = 127},
+  [406] = {.lex_state = 127},
+  [407] = {.lex_state = 129},
+  [408] = {.lex_state = 129},
+  [409] = {.lex_state = 129},
+  [410] = {.lex_state = 129},
+  [411] = {.lex_state = 129},
+  [412] = {.lex_state = 129},
+  [413] = {.lex_state = 129},
+  [414] = {.lex_state = 129},
+  [415] = {.lex_state = 129},
+  [416] = {.lex_state = 129},
+  [417] = {.lex_state = 129},
+  [418] = {.lex_state = 129},
+  [419] = {.lex_state = 129},
+  [420] = {.lex_state = 129},
+  [421] = {.lex_state = 129},
+  [422] = {.lex_state = 129},
+  [423] = {.lex_state = 129},
+  [424] = {.lex_state = 129},
+  [425] = {.lex_state = 129},
+  [426] = {.lex_state = 129},
+  [427] = {.lex_state = 129},
+  [428] = {.lex_state = 129},
+  [429] = {.lex_state = 129},
+  [430] = {.lex_state = 129},
+  [431] = {.lex_state = 129},
+  [432] = {.lex_state = 129},
+  [433] = {.lex_state = 129},
+  [434] = {.lex_state = 129},
+  [435] = {.lex_state = 129},
+  [436] = {.lex_state = 129},
+  [437] = {.lex_state = 129},
+  [438] = {.lex_state = 129},
+  [439] = {.lex_state = 129},
+  [440] = {.lex_state = 129},
+  [441] = {.lex_state = 129},
+  [442] = {.lex_state = 129},
+  [443] = {.lex_state = 129},
+  [444] = {.lex_state = 129},
+  [445] = {.lex_state = 129},
+  [446] = {.lex_state = 129},
+  [447] = {.lex_state = 129},
+  [448] = {.lex_state = 129},
+  [449] = {.lex_state = 129},
+  [450] = {.lex_state = 129},
+  [451] = {.lex_state = 129},
+  [452] = {.lex_state = 133},
+  [453] = {.lex_state = 133},
+  [454] = {.lex_state = 136},
+  [455] = {.lex_state = 136},
+  [456] = {.lex_state = 136},
+  [457] = {.lex_state = 136},
+  [458] = {.lex_state = 136},
+  [459] = {.lex_state = 136},
+  [460] = {.lex_state = 136},
+  [461] = {.lex_state = 136},
+  [462] = {.lex_state = 136},
+  [463] = {.lex_state = 137},
+  [464] = {.lex_state = 138},
+  [465] = {.lex_state = 137},
+  [466] = {.lex_state = 139},
+  [467] = {.lex_state = 139},
+  [468] = {.lex_state = 139},
+  [469] = {.lex_state = 139},
+  [470] = {.lex_state = 137},
+  [471] = {.lex_state = 137},
+  [472] = {.lex_state = 138},
+  [473] = {.lex_state = 138},
+  [474] = {.lex_state = 138},
+  [475] = {.lex_state = 140},
+  [476] = {.lex_state = 140},
+  [477] = {.lex_state = 141},
+  [478] = {.lex_state = 138},
+  [479] = {.lex_state = 142},
+  [480] = {.lex_state = 141},
+  [481] = {.lex_state = 142},
+  [482] = {.lex_state = 140},
+  [483] = {.lex_state = 140},
+  [484] = {.lex_state = 140},
+  [485] = {.lex_state = 140},
+  [486] = {.lex_state = 140},
+  [487] = {.lex_state = 140},
+  [488] = {.lex_state = 140},
+  [489] = {.lex_state = 140},
+  [490] = {.lex_state = 140},
+  [491] = {.lex_state = 140},
+  [492] = {.lex_state = 140},
+  [493] = {.lex_state = 140},
+  [494] = {.lex_state = 138},
+  [495] = {.lex_state = 138},
+  [496] = {.lex_state = 138},
+  [497] = {.lex_state = 138},
+  [498] = {.lex_state = 143},
+  [499] = {.lex_state = 143},
+  [500] = {.lex_state = 138},
+  [501] = {.lex_state = 138},
+  [502] = {.lex_state = 143},
+  [503] = {.lex_state = 143},
+  [504] = {.lex_state = 144},
+  [505] = {.lex_state = 144},
+  [506] = {.lex_state = 145},
+  [507] = {.lex_state = 143},
+  [508] = {.lex_state = 143},
+  [509] = {.lex_state = 143},
+  [510] = {.lex_state = 143},
+  [511] = {.lex_state = 143},
+  [512] = {.lex_state = 145},
+  [513] = {.lex_state = 145},
+  [514] = {.lex_state = 145},
+  [515] = {.lex_state = 145},
+  [516] = {.lex_state = 145},
+  [517] = {.lex_state = 145},
+  [518] = {.lex_state = 145},
+  [519] = {.lex_state = 145},
+  [520] = {.lex_state = 145},
+  [521] = {.lex_state = 138},
+  [522] = {.lex_state = 138},
+  [523] = {.lex_state = 138},
+  [524] = {.lex_state = 138},
+  [525] = {.lex_state = 138},
+  [526] = {.lex_state = 138},
+  [527] = {.lex_state = 145},
+  [528] = {.lex_state = 145},
+  [529] = {.lex_state = 89},
+  [530] = {.lex_state = 146},
+  [531] = {.lex_state = 146},
+  [532] = {.lex_state = 146},
+  [533] = {.lex_state = 146},
+  [534] = {.lex_state = 150},
+  [535] = {.lex_state = 151},
+  [536] = {.lex_state = 151},
+  [537] = {.lex_state = 151},
+  [538] = {.lex_state = 150},
+  [539] = {.lex_state = 150},
+  [540] = {.lex_state = 150},
+  [541] = {.lex_state = 150},
+  [542] = {.lex_state = 150},
+  [543] = {.lex_state = 150},
+  [544] = {.lex_state = 150},
+  [545] = {.lex_state = 150},
+  [546] = {.lex_state = 150},
+  [547] = {.lex_state = 150},
+  [548] = {.lex_state = 150},
+  [549] = {.lex_state = 150},
+  [550] = {.lex_state = 150},
+  [551] = {.lex_state = 150},
+  [552] = {.lex_state = 150},
+  [553] = {.lex_state = 150},
+  [554] = {.lex_state = 150},
+  [555] = {.lex_state = 150},
+  [556] = {.lex_state = 150},
+  [557] = {.lex_state = 150},
+  [558] = {.lex_state = 150},
+  [559] = {.lex_state = 150},
+  [560] = {.lex_state = 150},
+  [561] = {.lex_state = 150},
+  [562] = {.lex_state = 150},
+  [563] = {.lex_state = 150},
+  [564] = {.lex_state = 150},
+  [565] = {.lex_state = 150},
+  [566] = {.lex_state = 150},
+  [567] = {.lex_state = 150},
+  [568] = {.lex_state = 150},
+  [569] = {.lex_state = 150},
+  [570] = {.lex_state = 150},
+  [571] = {.lex_state = 150},
+  [572] = {.lex_state = 150},
+  [573] = {.lex_state = 150},
+  [574] = {.lex_state = 150},
+  [575] = {.lex_state = 150},
+  [576] = {.lex_state = 150},
+  [577] = {.lex_state = 152},
+  [578] = {.lex_state = 152},
+  [579] = {.lex_state = 152},
+  [580] = {.lex_state = 153},
+  [581] = {.lex_state = 154},
+  [582] = {.lex_state = 151},
+  [583] = {.lex_state = 151},
+  [584] = {.lex_state = 151},
+  [585] = {.lex_state = 151},
+  [586] = {.lex_state = 151},
+  [587] = {.lex_state = 151},
+  [588] = {.lex_state = 151},
+  [589] = {.lex_state = 151},
+  [590] = {.lex_state = 151},
+  [591] = {.lex_state = 151},
+  [592] = {.lex_state = 151},
+  [593] = {.lex_state = 151},
+  [594] = {.lex_state = 151},
+  [595] = {.lex_state = 151},
+  [596] = {.lex_state = 151},
+  [597] = {.lex_state = 151},
+  [598] = {.lex_state = 151},
+  [599] = {.lex_state = 151},
+  [600] = {.lex_state = 151},
+  [601] = {.lex_state = 151},
+  [602] = {.lex_state = 151},
+  [603] = {.lex_state = 151},
+  [604] = {.lex_state = 151},
+  [605] = {.lex_state = 151},
+  [606] = {.lex_state = 151},
+  [607] = {.lex_state = 151},
+  [608] = {.lex_state = 151},
+  [609] = {.lex_state = 151},
+  [610] = {.lex_state = 151},
+  [611] = {.lex_state = 151},
+  [612] = {.lex_state = 151},
+  [613] = {.lex_state = 156},
+  [614] = {.lex_state = 157},
+  [615] = {.lex_state = 157},
+  [616] = {.lex_state = 158},
+  [617] = {.lex_state = 159},
+  [618] = {.lex_state = 157},
+  [619] = {.lex_state = 157},
+  [620] = {.lex_state = 157},
+  [621] = {.lex_state = 160},
+  [622] = {.lex_state = 161},
+  [623] = {.lex_state = 164},
+  [624] = {.lex_state = 160},
+  [625] = {.lex_state = 165},
+  [626] = {.lex_state = 161},
+  [627] = {.lex_state = 165},
+  [628] = {.lex_state = 166},
+  [629] = {.lex_state = 151},
+  [630] = {.lex_state = 166},
+  [631] = {.lex_state = 166},
+  [632] = {.lex_state = 167},
+  [633] = {.lex_state = 165},
+  [634] = {.lex_state = 166},
+  [635] = {.lex_state = 168},
+  [636] = {.lex_state = 167},
+  [637] = {.lex_state = 167},
+  [638] = {.lex_state = 169},
+  [639] = {.lex_state = 170},
+  [640] = {.lex_state = 166},
+  [641] = {.lex_state = 166},
+  [642] = {.lex_state = 167},
+  [643] = {.lex_state = 171},
+  [644] = {.lex_state = 166},
+  [645] = {.lex_state = 151},
+  [646] = {.lex_state = 167},
+  [647] = {.lex_state = 167},
+  [648] = {.lex_state = 170},
+  [649] = {.lex_state = 170},
+  [650] = {.lex_state = 158},
+  [651] = {.lex_state = 167},
+  [652] = {.lex_state = 151},
+  [653] = {.lex_state = 167},
+  [654] = {.lex_state = 167},
+  [655] = {.lex_state = 167},
+  [656] = {.lex_state = 170},
+  [657] = {.lex_state = 172},
+  [658] = {.lex_state = 173},
+  [659] = {.lex_state = 151},
+  [660] = {.lex_state = 167},
+  [661] = {.lex_state = 151},
+  [662] = {.lex_state = 167},
+  [663] = {.lex_state = 151},
+  [664] = {.lex_state = 167},
+  [665] = {.lex_state = 165},
+  [666] = {.lex_state = 167},
+  [667] = {.lex_state = 151},
+  [668] = {.lex_state = 165},
+  [669] = {.lex_state = 151},
+  [670] = {.lex_state = 151},
+  [671] = {.lex_state = 151},
+  [672] = {.lex_state = 165},
+  [673] = {.lex_state = 165},
+  [674] = {.lex_state = 165},
+  [675] = {.lex_state = 165},
+  [676] = {.lex_state = 166},
+  [677] = {.lex_state = 166},
+  [678] = {.lex_state = 167},
+  [679] = {.lex_state = 165},
+  [680] = {.lex_state = 167},
+  [681] = {.lex_state = 166},
+  [682] = {.lex_state = 166},
+  [683] = {.lex_state = 167},
+  [684] = {.lex_state = 167},
+  [685] = {.lex_state = 165},
+  [686] = {.lex_state = 165},
+  [687] = {.lex_state = 166},
+  [688] = {.lex_state = 166},
+  [689] = {.lex_state = 167},
+  [690] = {.lex_state = 165},
+  [691] = {.lex_state = 167},
+  [692] = {.lex_state = 166},
+  [693] = {.lex_state = 166},
+  [694] = {.lex_state = 167},
+  [695] = {.lex_state = 167},
+  [696] = {.lex_state = 165},
+  [697] = {.lex_state = 166},
+  [698] = {.lex_state = 166},
+  [699] = {.lex_state = 167},
+  [700] = {.lex_state = 165},
+  [701] = {.lex_state = 167},
+  [702] = {.lex_state = 166},
+  [703] = {.lex_state = 166},
+  [704] = {.lex_state = 167},
+  [705] = {.lex_state = 167},
+  [706] = {.lex_state = 160},
+  [707] = {.lex_state = 160},
+  [708] = {.lex_state = 160},
+  [709] = {.lex_state = 160},
+  [710] = {.lex_state = 160},
+  [711] = {.lex_state = 165},
+  [712] = {.lex_state = 174},
+  [713] = {.lex_state = 169},
+  [714] = {.lex_state = 169},
+  [715] = {.lex_state = 175},
+  [716] = {.lex_state = 165},
+  [717] = {.lex_state = 176},
+  [718] = {.lex_state = 165},
+  [719] = {.lex_state = 165},
+  [720] = {.lex_state = 175},
+  [721] = {.lex_state = 176},
+  [722] = {.lex_state = 165},
+  [723] = {.lex_state = 175},
+  [724] = {.lex_state = 166},
+  [725] = {.lex_state = 166},
+  [726] = {.lex_state = 177},
+  [727] = {.lex_state = 169},
+  [728] = {.lex_state = 175},
+  [729] = {.lex_state = 166},
+  [730] = {.lex_state = 151},
+  [731] = {.lex_state = 151},
+  [732] = {.lex_state = 169},
+  [733] = {.lex_state = 167},
+  [734] = {.lex_state = 167},
+  [735] = {.lex_state = 177},
+  [736] = {.lex_state = 167},
+  [737] = {.lex_state = 167},
+  [738] = {.lex_state = 151},
+  [739] = {.lex_state = 176},
+  [740] = {.lex_state = 169},
+  [741] = {.lex_state = 177},
+  [742] = {.lex_state = 151},
+  [743] = {.lex_state = 169},
+  [744] = {.lex_state = 167},
+  [745] = {.lex_state = 167},
+  [746] = {.lex_state = 167},
+  [747] = {.lex_state = 151},
+  [748] = {.lex_state = 176},
+  [749] = {.lex_state = 167},
+  [750] = {.lex_state = 177},
+  [751] = {.lex_state = 177},
+  [752] = {.lex_state = 151},
+  [753] = {.lex_state = 167},
+  [754] = {.lex_state = 177},
+  [755] = {.lex_state = 165},
+  [756] = {.lex_state = 165},
+  [757] = {.lex_state = 175},
+  [758] = {.lex_state = 165},
+  [759] = {.lex_state = 165},
+  [760] = {.lex_state = 175},
+  [761] = {.lex_state = 165},
+  [762] = {.lex_state = 175},
+  [763] = {.lex_state = 175},
+  [764] = {.lex_state = 165},
+  [765] = {.lex_state = 175},
+  [766] = {.lex_state = 165},
+  [767] = {.lex_state = 165},
+  [768] = {.lex_state = 175},
+  [769] = {.lex_state = 165},
+  [770] = {.lex_state = 175},
+  [771] = {.lex_state = 175},
+  [772] = {.lex_state = 175},
+  [773] = {.lex_state = 165},
+  [774] = {.lex_state = 175},
+  [775] = {.lex_state = 165},
+  [776] = {.lex_state = 175},
+  [777] = {.lex_state = 175},
+  [778] = {.lex_state = 175},
+  [779] = {.lex_state = 175},
+  [780] = {.lex_state = 175},
+  [781] = {.lex_state = 175},
+  [782] = {.lex_state = 169},
+  [783] = {.lex_state = 176},
+  [784] = {.lex_state = 176},
+  [785] = {.lex_state = 169},
+  [786] = {.lex_state = 176},
+  [787] = {.lex_state = 176},
+  [788] = {.lex_state = 176},
+  [789] = {.lex_state = 178},
+  [790] = {.lex_state = 169},
+  [791] = {.lex_state = 169},
+  [792] = {.lex_state = 169},
+  [793] = {.lex_state = 169},
+  [794] = {.lex_state = 179},
+  [795] = {.lex_state = 180},
+  [796] = {.lex_state = 169},
+  [797] = {.lex_state = 181},
+  [798] = {.lex_state = 169},
+  [799] = {.lex_state = 182},
+  [800] = {.lex_state = 181},
+  [801] = {.lex_state = 176},
+  [802] = {.lex_state = 183},
+  [803] = {.lex_state = 165},
+  [804] = {.lex_state = 165},
+  [805] = {.lex_state = 169},
+  [806] = {.lex_state = 169},
+  [807] = {.lex_state = 169},
+  [808] = {.lex_state = 165},
+  [809] = {.lex_state = 169},
+  [810] = {.lex_state = 169},
+  [811] = {.lex_state = 169},
+  [812] = {.lex_state = 176},
+  [813] = {.lex_state = 169},
+  [814] = {.lex_state = 184},
+  [815] = {.lex_state = 184},
+  [816] = {.lex_state = 169},
+  [817] = {.lex_state = 165},
+  [818] = {.lex_state = 169},
+  [819] = {.lex_state = 178},
+  [820] = {.lex_state = 169},
+  [821] = {.lex_state = 165},
+  [822] = {.lex_state = 165},
+  [823] = {.lex_state = 178},
+  [824] = {.lex_state = 169},
+  [825] = {.lex_state = 165},
+  [826] = {.lex_state = 169},
+  [827] = {.lex_state = 181},
+  [828] = {.lex_state = 165},
+  [829] = {.lex_state = 169},
+  [830] = {.lex_state = 185},
+  [831] = {.lex_state = 185},
+  [832] = {.lex_state = 169},
+  [833] = {.lex_state = 169},
+  [834] = {.lex_state = 169},
+  [835] = {.lex_state = 178},
+  [836] = {.lex_state = 183},
+  [837] = {.lex_state = 178},
+  [838] = {.lex_state = 169},
+  [839] = {.lex_state = 169},
+  [840] = {.lex_state = 169},
+  [841] = {.lex_state = 169},
+  [842] = {.lex_state = 183},
+  [843] = {.lex_state = 183},
+  [844] = {.lex_state = 183},
+  [845] = {.lex_state = 169},
+  [846] = {.lex_state = 169},
+  [847] = {.lex_state = 184},
+  [848] = {.lex_state = 183},
+  [849] = {.lex_state = 169},
+  [850] = {.lex_state = 169},
+  [851] = {.lex_state = 184},
+  [852] = {.lex_state = 183},
+  [853] = {.lex_state = 169},
+  [854] = {.lex_state = 169},
+  [855] = {.lex_state = 184},
+  [856] = {.lex_state = 169},
+  [857] = {.lex_state = 169},
+  [858] = {.lex_state = 169},
+  [859] = {.lex_state = 169},
+  [860] = {.lex_state = 169},
+  [861] = {.lex_state = 169},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
+    [sym_label] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [aux_sym_operator_token1] = ACTIONS(1),
+    [aux_sym_operator_token2] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_scope] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_let] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_fn] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_while] = ACTIONS(1),
+    [anon_sym_loop] = ACTIONS(1),
+    [anon_sym_return] = ACTIONS(1),
+    [anon_sym_break] = ACTIONS(1),
+    [anon_sym_continue] = ACTIONS(1),
+    [anon_sym_commutative] = ACTIONS(1),
+    [anon_sym_operator] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_struct] = ACTIONS(1),
+    [anon_sym_class] = ACTIONS(1),
+    [anon_sym_data] = ACTIONS(1),
+    [anon_sym_embed] = ACTIONS(1),
+    [anon_sym_pub] = ACTIONS(1),
+    [anon_sym_static] = ACTIONS(1),
+    [anon_sym_get] = ACTIONS(1),
+    [anon_sym_EQ_GT] = ACTIONS(1),
+    [anon_sym_set] = ACTIONS(1),
+    [anon_sym_impl] = ACTIONS(1),
+    [anon_sym_enum] = ACTIONS(1),
+    [anon_sym_test] = ACTIONS(1),
+    [sym_number_literal] = ACTIONS(1),
+    [sym_string_literal] = ACTIONS(1),
+    [sym_format_string_literal] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
+    [sym_nah_literal] = ACTIONS(1),
+    [anon_sym_DOT_DOT] = ACTIONS(1),
+    [anon_sym_STAR_STAR] = ACTIONS(1),
+    [anon_sym_DOLLAR_LPAREN] = ACTIONS(1),
+    [anon_sym_COLON_LBRACE] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_QMARK_DOT] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_QMARK_QMARK] = ACTIONS(1),
+    [anon_sym_PIPE_PIPE] = ACTIONS(1),
+    [anon_sym_AMP_AMP] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_LT_GT] = ACTIONS(1),
+    [anon_sym_PLUS_EQ] = ACTIONS(1),
+    [anon_sym_DASH_EQ] = ACTIONS(1),
+    [anon_sym_STAR_EQ] = ACTIONS(1),
+    [anon_sym_SLASH_EQ] = ACTIONS(1),
+    [anon_sym_PERCENT_EQ] = ACTIONS(1),
+    [anon_sym_STAR_STAR_EQ] = ACTIONS(1),
+    [anon_sym_LT_GT_EQ] = ACTIONS(1),
+    [anon_sym_match] = ACTIONS(1),
+    [sym_wildcard_pattern] = ACTIONS(1),
+    [anon_sym_import] = ACTIONS(1),
+  },
+  [1] = {
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym_source_file] = STATE(795),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(4),
+  },
+  [2] = {
+    [ts_builtin_sym_end] = ACTIONS(55),
+    [sym_identifier] = ACTIONS(57),
+    [sym_label] = ACTIONS(60),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(63),
+    [anon_sym_RBRACE] = ACTIONS(55),
+    [anon_sym_scope] = ACTIONS(66),
+    [anon_sym_let] = ACTIONS(69),
+    [anon_sym_fn] = ACTIONS(72),
+    [anon_sym_if] = ACTIONS(75),
+    [anon_sym_while] = ACTIONS(78),
+    [anon_sym_loop] = ACTIONS(81),
+    [anon_sym_return] = ACTIONS(84),
+    [anon_sym_break] = ACTIONS(87),
+    [anon_sym_continue] = ACTIONS(90),
+    [anon_sym_commutative] = ACTIONS(93),
+    [anon_sym_operator] = ACTIONS(96),
+    [anon_sym_LPAREN] = ACTIONS(99),
+    [anon_sym_struct] = ACTIONS(102),
+    [anon_sym_class] = ACTIONS(102),
+    [anon_sym_data] = ACTIONS(102),
+    [anon_sym_enum] = ACTIONS(105),
+    [anon_sym_test] = ACTIONS(108),
+    [sym_number_literal] = ACTIONS(111),
+    [sym_string_literal] = ACTIONS(111),
+    [sym_format_string_literal] = ACTIONS(111),
+    [anon_sym_true] = ACTIONS(114),
+    [anon_sym_false] = ACTIONS(114),
+    [sym_nah_literal] = ACTIONS(111),
+    [anon_sym_BANG] = ACTIONS(117),
+    [anon_sym_match] = ACTIONS(120),
+    [anon_sym_import] = ACTIONS(123),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [3] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(126),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(715),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(5),
+  },
+  [4] = {
+    [ts_builtin_sym_end] = ACTIONS(132),
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [5] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(134),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(720),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [6] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(136),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(8),
+  },
+  [7] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(138),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(723),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(9),
+  },
+  [8] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(140),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [9] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(142),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(728),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [10] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(126),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(11),
+  },
+  [11] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(134),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [12] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(144),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(715),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(13),
+  },
+  [13] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(146),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(720),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [14] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(144),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(15),
+  },
+  [15] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(146),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [16] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(148),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(757),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(17),
+  },
+  [17] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(150),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(760),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [18] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(152),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(19),
+  },
+  [19] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(154),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [20] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(136),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(772),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(21),
+  },
+  [21] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(140),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(774),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [22] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(156),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(23),
+  },
+  [23] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(158),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [24] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(152),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(765),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(25),
+  },
+  [25] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(154),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(768),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [26] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(160),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(27),
+  },
+  [27] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(162),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [28] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(156),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(757),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(29),
+  },
+  [29] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(158),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(760),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [30] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(148),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(31),
+  },
+  [31] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(150),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(19),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(25),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(713),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(797),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [32] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(160),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(778),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(33),
+  },
+  [33] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(162),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(779),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [34] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(126),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(780),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(35),
+  },
+  [35] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_RBRACE] = ACTIONS(134),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(128),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(130),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(782),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(781),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [36] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(715),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(37),
+  },
+  [37] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(720),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [38] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(723),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(39),
+  },
+  [39] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(728),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [40] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(765),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(41),
+  },
+  [41] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(768),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [42] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(757),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(43),
+  },
+  [43] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(760),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [44] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(762),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(45),
+  },
+  [45] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(763),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [46] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(778),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(47),
+  },
+  [47] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(779),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [48] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(770),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(49),
+  },
+  [49] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(771),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+  [50] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(776),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(51),
+  },
+  [51] = {
+    [sym_identifier] = ACTIONS(7),
+    [sym_label] = ACTIONS(9),
+    [sym_comment] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(13),
+    [anon_sym_scope] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(17),
+    [anon_sym_fn] = ACTIONS(164),
+    [anon_sym_if] = ACTIONS(21),
+    [anon_sym_while] = ACTIONS(23),
+    [anon_sym_loop] = ACTIONS(166),
+    [anon_sym_return] = ACTIONS(27),
+    [anon_sym_break] = ACTIONS(29),
+    [anon_sym_continue] = ACTIONS(31),
+    [anon_sym_commutative] = ACTIONS(33),
+    [anon_sym_operator] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_struct] = ACTIONS(39),
+    [anon_sym_class] = ACTIONS(39),
+    [anon_sym_data] = ACTIONS(39),
+    [anon_sym_enum] = ACTIONS(41),
+    [anon_sym_test] = ACTIONS(43),
+    [sym_number_literal] = ACTIONS(45),
+    [sym_string_literal] = ACTIONS(45),
+    [sym_format_string_literal] = ACTIONS(45),
+    [anon_sym_true] = ACTIONS(47),
+    [anon_sym_false] = ACTIONS(47),
+    [sym_nah_literal] = ACTIONS(45),
+    [anon_sym_BANG] = ACTIONS(49),
+    [anon_sym_match] = ACTIONS(51),
+    [anon_sym_import] = ACTIONS(53),
+    [sym__loop_label] = STATE(785),
+    [sym__statement] = STATE(246),
+    [sym_block_statement] = STATE(247),
+    [sym_scope_modifier_statement] = STATE(247),
+    [sym_expression_statement] = STATE(247),
+    [sym_let_statement] = STATE(247),
+    [sym_function_statement] = STATE(247),
+    [sym_if_statement] = STATE(247),
+    [sym_while_statement] = STATE(247),
+    [sym_loop_statement] = STATE(247),
+    [sym_return_statement] = STATE(247),
+    [sym_break_statement] = STATE(247),
+    [sym_continue_statement] = STATE(247),
+    [sym_operator_statement] = STATE(247),
+    [sym_type_statement] = STATE(247),
+    [sym_type_type] = STATE(796),
+    [sym_enum_statement] = STATE(247),
+    [sym_test_statement] = STATE(247),
+    [sym__expression] = STATE(463),
+    [sym__set_or_expression] = STATE(777),
+    [sym__expression_unit] = STATE(291),
+    [sym__literal] = STATE(292),
+    [sym_bool_literal] = STATE(289),
+    [sym_variable] = STATE(292),
+    [sym_scope_expression] = STATE(292),
+    [sym_function_expression] = STATE(292),
+    [sym_parenthesized_expression] = STATE(292),
+    [sym_block_expression] = STATE(292),
+    [sym_scope_modifier_expression] = STATE(292),
+    [sym_call_expression] = STATE(292),
+    [sym_curry_call_expression] = STATE(292),
+    [sym_instantiation_expression] = STATE(292),
+    [sym_prop_access_expression] = STATE(292),
+    [sym_index_expression] = STATE(292),
+    [sym_unary_expression] = STATE(452),
+    [sym_binary_expression] = STATE(452),
+    [sym_set_expression] = STATE(623),
+    [sym_set_prop_expression] = STATE(623),
+    [sym_set_index_expression] = STATE(623),
+    [sym_if_expression] = STATE(292),
+    [sym_match_expression] = STATE(292),
+    [sym_loop_expression] = STATE(292),
+    [sym_import_expression] = STATE(292),
+    [aux_sym_source_file_repeat1] = STATE(2),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(170), 1,
+      anon_sym_LPAREN,
+    ACTIONS(173), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(168), 30,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [65] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(177), 1,
+      anon_sym_LPAREN,
+    ACTIONS(180), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(175), 30,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [130] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(184), 1,
+      anon_sym_LPAREN,
+    ACTIONS(187), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(182), 30,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [195] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 55,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_SEMI,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+      anon_sym_match,
+      anon_sym_import,
+  [256] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 55,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_SEMI,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+      anon_sym_match,
+      anon_sym_import,
+  [317] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 55,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_SEMI,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+      anon_sym_match,
+      anon_sym_import,
+  [378] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 55,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_SEMI,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+      anon_sym_match,
+      anon_sym_import,
+  [439] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(197), 2,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+    ACTIONS(173), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(168), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [503] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(200), 2,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+    ACTIONS(180), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(175), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [567] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(203), 2,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+    ACTIONS(187), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(182), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [631] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_LPAREN,
+    ACTIONS(173), 25,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(168), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [695] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(200), 1,
+      anon_sym_LPAREN,
+    ACTIONS(180), 25,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(175), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [759] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(203), 1,
+      anon_sym_LPAREN,
+    ACTIONS(187), 25,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+    ACTIONS(182), 28,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [823] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(218), 1,
+      anon_sym_RPAREN,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(630), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [919] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(224), 1,
+      anon_sym_RPAREN,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(631), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1015] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(226), 1,
+      anon_sym_RBRACE,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(632), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1111] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(228), 1,
+      anon_sym_RPAREN,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1207] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(230), 1,
+      anon_sym_RPAREN,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1303] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(232), 1,
+      anon_sym_RBRACE,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1399] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(234), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1495] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(236), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1591] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(238), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1687] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(240), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(676), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1783] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(242), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(677), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1879] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(244), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(678), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [1975] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(246), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2071] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(248), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2167] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(250), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2263] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(252), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2359] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(254), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2455] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(256), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2551] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(258), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(687), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2647] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(260), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(688), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2743] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(262), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(689), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2839] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(264), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [2935] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(266), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3031] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(268), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3127] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(270), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3223] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(272), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3319] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(274), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3415] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(276), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(697), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3511] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(278), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(698), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3607] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(280), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(699), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3703] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(282), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3799] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(284), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3895] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(286), 1,
+      anon_sym_RBRACE,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [3991] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    ACTIONS(288), 1,
+      anon_sym_RPAREN,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4087] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(290), 1,
+      anon_sym_RPAREN,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4183] = 24,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(292), 1,
+      anon_sym_RBRACE,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4279] = 23,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    ACTIONS(220), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(222), 1,
+      anon_sym_STAR_STAR,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(466), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(650), 4,
+      sym_positional_argument,
+      sym_named_argument,
+      sym_spread_argument,
+      sym_kwargs_spread_argument,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4372] = 23,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    ACTIONS(294), 1,
+      anon_sym_RPAREN,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(802), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4464] = 23,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    ACTIONS(296), 1,
+      anon_sym_RPAREN,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(802), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4556] = 23,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    ACTIONS(298), 1,
+      anon_sym_RPAREN,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(802), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4648] = 23,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    ACTIONS(300), 1,
+      anon_sym_RPAREN,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(802), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4740] = 22,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(802), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4829] = 22,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(844), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [4918] = 22,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(848), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5007] = 22,
+    ACTIONS(7), 1,
+      sym_identifier,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(289), 1,
+      sym_bool_literal,
+    STATE(291), 1,
+      sym__expression_unit,
+    STATE(463), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    STATE(852), 1,
+      sym__set_or_expression,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(623), 3,
+      sym_set_expression,
+      sym_set_prop_expression,
+      sym_set_index_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5096] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(304), 1,
+      anon_sym_COLON,
+    ACTIONS(306), 1,
+      anon_sym_SEMI,
+    STATE(502), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5183] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(326), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(475), 1,
+      sym__expression,
+    STATE(814), 1,
+      sym_slice,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5270] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(326), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(476), 1,
+      sym__expression,
+    STATE(815), 1,
+      sym_slice,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5357] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(326), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(491), 1,
+      sym__expression,
+    STATE(847), 1,
+      sym_slice,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5444] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(326), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(492), 1,
+      sym__expression,
+    STATE(851), 1,
+      sym_slice,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5531] = 22,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(326), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(493), 1,
+      sym__expression,
+    STATE(855), 1,
+      sym_slice,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5618] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(334), 1,
+      anon_sym_SEMI,
+    STATE(498), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5702] = 21,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(336), 1,
+      sym_label,
+    ACTIONS(338), 1,
+      anon_sym_SEMI,
+    STATE(499), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5786] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(340), 1,
+      anon_sym_RBRACK,
+    STATE(504), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5870] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(342), 1,
+      anon_sym_RBRACK,
+    STATE(505), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [5954] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_LPAREN,
+    STATE(497), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6035] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(464), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6116] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(472), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6197] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(500), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6278] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(252), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6359] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(465), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6440] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_LPAREN,
+    STATE(501), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6521] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(473), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6602] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(474), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6683] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(454), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6764] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(455), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6845] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [6926] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(457), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7007] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7088] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7169] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(460), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7250] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(461), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7331] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(462), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7412] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(503), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7493] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(352), 1,
+      anon_sym_RBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(637), 1,
+      sym_match_arm,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7576] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7657] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(468), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7738] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(477), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7819] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(469), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7900] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [7981] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(478), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8062] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(506), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8143] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(479), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8224] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(396), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8307] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(507), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8388] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(471), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8469] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(480), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8550] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(398), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8633] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(508), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8714] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(509), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8795] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(481), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8876] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(510), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [8957] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(511), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9038] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(352), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9119] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(489), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9200] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(482), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9281] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(483), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9362] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(484), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9443] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(485), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9524] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(486), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9605] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(487), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9686] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(488), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9767] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(338), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(490), 1,
+      sym__expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9848] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(341), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [9929] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(342), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10010] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(344), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10091] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(345), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10172] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(346), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10253] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(347), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10334] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(348), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10415] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(349), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10496] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(350), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10577] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(336), 1,
+      sym__expression_unit,
+    STATE(343), 1,
+      sym__expression,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    STATE(358), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10658] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(278), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10739] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(279), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10820] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(280), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10901] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(281), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [10982] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(282), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11063] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(283), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11144] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(284), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11225] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(285), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11306] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(242), 1,
+      sym__expression_unit,
+    STATE(286), 1,
+      sym__expression,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(331), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11387] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(404), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(680), 1,
+      sym_match_arm,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11470] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(406), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11553] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(408), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11636] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(353), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11717] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(368), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11798] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(369), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11879] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(370), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [11960] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(371), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12041] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(372), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12122] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(373), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12203] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(374), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12284] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(339), 1,
+      sym__expression_unit,
+    STATE(354), 1,
+      sym__expression,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(446), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12365] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(512), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12446] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(514), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12527] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(515), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12608] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(516), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12689] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(517), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12770] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(518), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12851] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(519), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [12932] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(520), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13013] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(351), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(513), 1,
+      sym__expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(527), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13094] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(410), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(691), 1,
+      sym_match_arm,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13177] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(412), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13260] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(414), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13343] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(416), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(701), 1,
+      sym_match_arm,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13426] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(418), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13509] = 21,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(420), 1,
+      anon_sym_RBRACE,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13592] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(422), 1,
+      anon_sym_LPAREN,
+    STATE(524), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13673] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(424), 1,
+      anon_sym_LPAREN,
+    STATE(525), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13754] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    ACTIONS(426), 1,
+      anon_sym_LPAREN,
+    STATE(526), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13835] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(521), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13916] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(522), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [13997] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(523), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14078] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(494), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14159] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(495), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14240] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(233), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(496), 1,
+      sym__expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(452), 2,
+      sym_unary_expression,
+      sym_binary_expression,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14321] = 20,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      sym_identifier,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(354), 1,
+      anon_sym_scope,
+    ACTIONS(356), 1,
+      anon_sym_fn,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    ACTIONS(360), 1,
+      anon_sym_loop,
+    ACTIONS(362), 1,
+      anon_sym_LPAREN,
+    ACTIONS(368), 1,
+      anon_sym_match,
+    ACTIONS(370), 1,
+      sym_wildcard_pattern,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    STATE(534), 1,
+      sym__expression_unit,
+    STATE(538), 1,
+      sym_bool_literal,
+    STATE(726), 1,
+      sym_value_pattern,
+    STATE(736), 1,
+      sym_match_arm,
+    STATE(858), 1,
+      sym__loop_label,
+    ACTIONS(366), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(364), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(540), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14401] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(49), 1,
+      anon_sym_BANG,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(234), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(453), 1,
+      sym_unary_expression,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14478] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(328), 1,
+      anon_sym_BANG,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(337), 1,
+      sym__expression_unit,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(453), 1,
+      sym_unary_expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14555] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(334), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    STATE(360), 1,
+      sym_unary_expression,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    ACTIONS(402), 1,
+      anon_sym_BANG,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14632] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(51), 1,
+      anon_sym_match,
+    ACTIONS(53), 1,
+      anon_sym_import,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(210), 1,
+      anon_sym_scope,
+    ACTIONS(212), 1,
+      anon_sym_fn,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    ACTIONS(216), 1,
+      anon_sym_loop,
+    STATE(234), 1,
+      sym__expression_unit,
+    STATE(289), 1,
+      sym_bool_literal,
+    ACTIONS(302), 1,
+      sym_identifier,
+    STATE(332), 1,
+      sym_unary_expression,
+    ACTIONS(346), 1,
+      anon_sym_BANG,
+    STATE(798), 1,
+      sym__loop_label,
+    ACTIONS(47), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(45), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(292), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14709] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      sym_identifier,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(312), 1,
+      anon_sym_scope,
+    ACTIONS(314), 1,
+      anon_sym_fn,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_loop,
+    ACTIONS(320), 1,
+      anon_sym_LPAREN,
+    ACTIONS(330), 1,
+      anon_sym_match,
+    ACTIONS(332), 1,
+      anon_sym_import,
+    STATE(337), 1,
+      sym__expression_unit,
+    ACTIONS(400), 1,
+      anon_sym_BANG,
+    STATE(407), 1,
+      sym_bool_literal,
+    STATE(447), 1,
+      sym_unary_expression,
+    STATE(856), 1,
+      sym__loop_label,
+    ACTIONS(324), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(322), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(409), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14786] = 19,
+    ACTIONS(9), 1,
+      sym_label,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(334), 1,
+      sym__expression_unit,
+    STATE(355), 1,
+      sym_bool_literal,
+    ACTIONS(372), 1,
+      anon_sym_import,
+    ACTIONS(374), 1,
+      sym_identifier,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(378), 1,
+      anon_sym_scope,
+    ACTIONS(380), 1,
+      anon_sym_fn,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    ACTIONS(384), 1,
+      anon_sym_loop,
+    ACTIONS(386), 1,
+      anon_sym_LPAREN,
+    ACTIONS(392), 1,
+      anon_sym_BANG,
+    ACTIONS(394), 1,
+      anon_sym_match,
+    STATE(528), 1,
+      sym_unary_expression,
+    STATE(860), 1,
+      sym__loop_label,
+    ACTIONS(390), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(388), 4,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      sym_nah_literal,
+    STATE(357), 16,
+      sym__literal,
+      sym_variable,
+      sym_scope_expression,
+      sym_function_expression,
+      sym_parenthesized_expression,
+      sym_block_expression,
+      sym_scope_modifier_expression,
+      sym_call_expression,
+      sym_curry_call_expression,
+      sym_instantiation_expression,
+      sym_prop_access_expression,
+      sym_index_expression,
+      sym_if_expression,
+      sym_match_expression,
+      sym_loop_expression,
+      sym_import_expression,
+  [14863] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(430), 8,
+      anon_sym_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      anon_sym_STAR_EQ,
+      anon_sym_SLASH_EQ,
+      anon_sym_PERCENT_EQ,
+      anon_sym_STAR_STAR_EQ,
+      anon_sym_LT_GT_EQ,
+    ACTIONS(428), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [14906] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(434), 8,
+      anon_sym_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      anon_sym_STAR_EQ,
+      anon_sym_SLASH_EQ,
+      anon_sym_PERCENT_EQ,
+      anon_sym_STAR_STAR_EQ,
+      anon_sym_LT_GT_EQ,
+    ACTIONS(432), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [14949] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(438), 8,
+      anon_sym_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      anon_sym_STAR_EQ,
+      anon_sym_SLASH_EQ,
+      anon_sym_PERCENT_EQ,
+      anon_sym_STAR_STAR_EQ,
+      anon_sym_LT_GT_EQ,
+    ACTIONS(436), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [14992] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(294), 1,
+      sym_argument_list_call,
+    STATE(295), 1,
+      sym_argument_list_curry_call,
+    STATE(296), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN,
+    ACTIONS(444), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(446), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(448), 1,
+      anon_sym_DOT,
+    ACTIONS(450), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(452), 1,
+      anon_sym_LBRACK,
+    ACTIONS(440), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [15049] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(294), 1,
+      sym_argument_list_call,
+    STATE(295), 1,
+      sym_argument_list_curry_call,
+    STATE(296), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN,
+    ACTIONS(444), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(446), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(448), 1,
+      anon_sym_DOT,
+    ACTIONS(450), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(452), 1,
+      anon_sym_LBRACK,
+    ACTIONS(454), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [15106] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(267), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(456), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15149] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(268), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(460), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15192] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(269), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(462), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15235] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(270), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(464), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15278] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(272), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(466), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15321] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(273), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(468), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15364] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(275), 1,
+      sym_type_impl,
+    ACTIONS(458), 1,
+      anon_sym_impl,
+    ACTIONS(470), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15407] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(294), 1,
+      sym_argument_list_call,
+    STATE(295), 1,
+      sym_argument_list_curry_call,
+    STATE(296), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(440), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [15452] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(474), 1,
+      anon_sym_else,
+    ACTIONS(472), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15492] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 32,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15530] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 32,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15568] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(476), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15605] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(478), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15642] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15679] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(480), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15716] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(482), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15753] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(484), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15790] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(492), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(494), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(496), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(498), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(500), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(488), 12,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+  [15847] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(506), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15884] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15921] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(508), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15958] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(510), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [15995] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(512), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16032] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(514), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16069] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(516), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16106] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(518), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16143] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(520), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16180] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(522), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16217] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(524), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16254] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(526), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16291] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(528), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16328] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(530), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16365] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(532), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16402] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(534), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16439] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(536), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16476] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(538), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16513] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(540), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16550] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(542), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16587] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(544), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16624] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(546), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16661] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(548), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16698] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(550), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16735] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(552), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [16772] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 28,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [16813] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(494), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(496), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(498), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(500), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 13,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+  [16868] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(496), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(498), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(500), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 14,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+  [16921] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(498), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(500), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 15,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+  [16972] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(500), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 17,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [17021] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(502), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 21,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17068] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(504), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 23,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [17113] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(490), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 26,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [17156] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(186), 1,
+      sym_operator,
+    ACTIONS(554), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17195] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [17232] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 31,
+      ts_builtin_sym_end,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_scope,
+      anon_sym_let,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_loop,
+      anon_sym_return,
+      anon_sym_break,
+      anon_sym_continue,
+      anon_sym_commutative,
+      anon_sym_operator,
+      anon_sym_LPAREN,
+      anon_sym_struct,
+      anon_sym_class,
+      anon_sym_data,
+      anon_sym_enum,
+      anon_sym_test,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [17269] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(556), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17305] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(558), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17341] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(294), 1,
+      sym_argument_list_call,
+    STATE(295), 1,
+      sym_argument_list_curry_call,
+    STATE(296), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN,
+    ACTIONS(444), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(446), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(450), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(560), 1,
+      anon_sym_DOT,
+    ACTIONS(562), 1,
+      anon_sym_LBRACK,
+    ACTIONS(440), 21,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17395] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(564), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17431] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(428), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17467] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(566), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17503] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(568), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17539] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(570), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17575] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17611] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(173), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17647] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(572), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17683] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(574), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17719] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(576), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17755] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(578), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17791] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(580), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17827] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(582), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17863] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(584), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17899] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17935] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(180), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [17971] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(432), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18007] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(586), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18043] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(588), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18079] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(590), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18115] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(592), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18151] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(187), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18187] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(594), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18223] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(436), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18259] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(596), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18295] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(598), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18331] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(600), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18367] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(602), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18403] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(604), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18439] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(606), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18475] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(608), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18511] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(610), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18547] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(612), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18583] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(614), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18619] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(616), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18655] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(618), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18691] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(620), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18727] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(622), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18763] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(624), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18799] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(440), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18835] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(454), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18871] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18907] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(361), 1,
+      sym_argument_list_call,
+    STATE(362), 1,
+      sym_argument_list_curry_call,
+    STATE(363), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(626), 1,
+      anon_sym_LPAREN,
+    ACTIONS(628), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(630), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(632), 1,
+      anon_sym_DOT,
+    ACTIONS(634), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(636), 1,
+      anon_sym_LBRACK,
+    ACTIONS(454), 21,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18961] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 30,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [18997] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(361), 1,
+      sym_argument_list_call,
+    STATE(362), 1,
+      sym_argument_list_curry_call,
+    STATE(363), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(440), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19039] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(411), 1,
+      sym_argument_list_call,
+    STATE(412), 1,
+      sym_argument_list_curry_call,
+    STATE(413), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(638), 1,
+      anon_sym_LPAREN,
+    ACTIONS(640), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(642), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(644), 1,
+      anon_sym_DOT,
+    ACTIONS(646), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(454), 20,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19092] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(411), 1,
+      sym_argument_list_call,
+    STATE(412), 1,
+      sym_argument_list_curry_call,
+    STATE(413), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(638), 1,
+      anon_sym_LPAREN,
+    ACTIONS(640), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(642), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(644), 1,
+      anon_sym_DOT,
+    ACTIONS(646), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(648), 1,
+      anon_sym_LBRACK,
+    ACTIONS(440), 20,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19145] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(411), 1,
+      sym_argument_list_call,
+    STATE(412), 1,
+      sym_argument_list_curry_call,
+    STATE(413), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(440), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19186] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(650), 1,
+      anon_sym_COLON,
+    ACTIONS(428), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19222] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(654), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(656), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(658), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(660), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(662), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(488), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [19276] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 25,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19314] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(554), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19350] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(656), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(658), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(660), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(662), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 10,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+  [19402] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(658), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(660), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(662), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 11,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+  [19452] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(660), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(662), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 12,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+  [19500] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(662), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 14,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [19546] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(664), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 18,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19590] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(666), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 20,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [19632] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(177), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(652), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 23,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [19672] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(361), 1,
+      sym_argument_list_call,
+    STATE(362), 1,
+      sym_argument_list_curry_call,
+    STATE(363), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(626), 1,
+      anon_sym_LPAREN,
+    ACTIONS(628), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(630), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(632), 1,
+      anon_sym_DOT,
+    ACTIONS(634), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(636), 1,
+      anon_sym_LBRACK,
+    ACTIONS(440), 19,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19724] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(670), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(672), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(674), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(676), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(678), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(488), 8,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+  [19777] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 24,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19814] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(554), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19849] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(556), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19882] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(558), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19915] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(564), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19948] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(440), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [19981] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(428), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20014] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(454), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20047] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(566), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20080] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(568), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20113] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(570), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20146] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(173), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20179] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(572), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20212] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(574), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20245] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(576), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20278] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(672), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(674), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(676), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(678), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 9,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+  [20329] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(674), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(676), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(678), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 10,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+  [20378] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(676), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(678), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 11,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+  [20425] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(678), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 13,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [20470] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(680), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 17,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [20513] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(682), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 19,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [20554] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(668), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 22,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [20593] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(578), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20626] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(580), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20659] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(582), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20692] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(584), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20725] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(180), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20758] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(432), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20791] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(586), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20824] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(588), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20857] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(590), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20890] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(592), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20923] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(187), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20956] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(594), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [20989] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(436), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21022] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(596), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21055] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(598), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21088] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(600), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21121] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(602), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21154] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(604), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21187] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(606), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21220] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(608), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21253] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(610), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21286] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(612), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21319] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(614), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21352] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(616), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21385] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(618), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21418] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(620), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21451] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(622), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21484] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(624), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21517] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21550] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21583] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21616] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 27,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21649] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(556), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21681] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(558), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21713] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(564), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21745] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(428), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21777] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(566), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21809] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(568), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21841] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(570), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21873] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(173), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21905] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(572), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21937] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(574), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [21969] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(576), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22001] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(578), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22033] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(580), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22065] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(582), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22097] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(584), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22129] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(180), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22161] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(432), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22193] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(586), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22225] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(588), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22257] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(590), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22289] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(592), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22321] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(187), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22353] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(594), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22385] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(436), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22417] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(596), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22449] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(598), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22481] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(600), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22513] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(602), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22545] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(604), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22577] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(606), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22609] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(608), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22641] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(610), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22673] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(612), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22705] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(614), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22737] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(616), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22769] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(618), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22801] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(620), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22833] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(622), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22865] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(624), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22897] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(440), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22929] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(454), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22961] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [22993] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23025] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23057] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 26,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23089] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(440), 25,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23120] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(454), 25,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23151] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 22,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23186] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+  [23235] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 8,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+  [23282] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 9,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+  [23327] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 11,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [23370] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 15,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23411] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 17,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [23450] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 20,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [23487] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(554), 24,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [23520] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(698), 3,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23568] = 14,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    STATE(243), 1,
+      sym_block_statement,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(702), 1,
+      anon_sym_LBRACE,
+    STATE(811), 1,
+      sym_block_expression,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23620] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(704), 3,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23668] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(706), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23716] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(708), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23764] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(710), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23812] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(712), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23860] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(714), 3,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23908] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(716), 3,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [23956] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    STATE(255), 1,
+      sym_block_statement,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(718), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24005] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    STATE(811), 1,
+      sym_block_expression,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24054] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    STATE(263), 1,
+      sym_block_statement,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(718), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24103] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(720), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(724), 1,
+      anon_sym_RBRACK,
+    ACTIONS(726), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24152] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(720), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(726), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(740), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24201] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(742), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24248] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    STATE(243), 1,
+      sym_block_statement,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(744), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24297] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(746), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24344] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(748), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24391] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(750), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24438] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(554), 3,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24483] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 4,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24526] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 5,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+  [24567] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 7,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [24606] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 11,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24643] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 13,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [24678] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 16,
+      anon_sym_DOT_DOT,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [24711] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 18,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [24742] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(554), 20,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_DOT_DOT,
+      anon_sym_STAR_STAR,
+      anon_sym_RBRACK,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [24771] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(720), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(726), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(752), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24820] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(720), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(726), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(754), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24869] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(167), 1,
+      sym_operator,
+    ACTIONS(720), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(726), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(728), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(730), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(756), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(722), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(732), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(736), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(738), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(734), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24918] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    STATE(857), 1,
+      sym_block_expression,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [24967] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    STATE(859), 1,
+      sym_block_expression,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25016] = 13,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    STATE(861), 1,
+      sym_block_expression,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25065] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(758), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25111] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(760), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25157] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(762), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25203] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(764), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25249] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(766), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25295] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(768), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25341] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(770), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25387] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(772), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25433] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(774), 1,
+      anon_sym_RBRACK,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25479] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(776), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(780), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(782), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(784), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(786), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(788), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25525] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(794), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25571] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(796), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25617] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(798), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25663] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(800), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25709] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(802), 1,
+      anon_sym_SEMI,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25755] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 17,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [25785] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(554), 19,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [25813] = 11,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(782), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(784), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(554), 2,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(786), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(788), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25857] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(784), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(786), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(554), 3,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(788), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25899] = 9,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(786), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 4,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+    ACTIONS(788), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [25939] = 8,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(788), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(554), 6,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [25977] = 7,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(790), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 10,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26013] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(792), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(554), 12,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [26047] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(778), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(554), 15,
+      anon_sym_EQ_GT,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+  [26079] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(804), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26125] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(806), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26171] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(808), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26217] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(810), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26263] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(812), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26309] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(137), 1,
+      sym_operator,
+    ACTIONS(686), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(688), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(700), 1,
+      anon_sym_QMARK_QMARK,
+    ACTIONS(814), 1,
+      anon_sym_LBRACE,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+    ACTIONS(684), 2,
+      anon_sym_STAR_STAR,
+      anon_sym_LT_GT,
+    ACTIONS(690), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(694), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(696), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(692), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [26355] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(440), 19,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [26380] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(454), 19,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+      anon_sym_EQ_GT,
+      anon_sym_STAR_STAR,
+      anon_sym_QMARK_QMARK,
+      anon_sym_PIPE_PIPE,
+      anon_sym_AMP_AMP,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_LT_GT,
+  [26405] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(816), 17,
+      sym_identifier,
+      sym_label,
+      anon_sym_LBRACE,
+      anon_sym_scope,
+      anon_sym_fn,
+      anon_sym_if,
+      anon_sym_loop,
+      anon_sym_LPAREN,
+      sym_number_literal,
+      sym_string_literal,
+      sym_format_string_literal,
+      anon_sym_true,
+      anon_sym_false,
+      sym_nah_literal,
+      anon_sym_BANG,
+      anon_sym_match,
+      anon_sym_import,
+  [26428] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(193), 15,
+      sym_identifier,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_LPAREN,
+      anon_sym_static,
+      anon_sym_get,
+      anon_sym_EQ_GT,
+      anon_sym_set,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26449] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(195), 15,
+      sym_identifier,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_LPAREN,
+      anon_sym_static,
+      anon_sym_get,
+      anon_sym_EQ_GT,
+      anon_sym_set,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26470] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(189), 14,
+      sym_identifier,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_static,
+      anon_sym_get,
+      anon_sym_EQ_GT,
+      anon_sym_set,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26490] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(191), 14,
+      sym_identifier,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_static,
+      anon_sym_get,
+      anon_sym_EQ_GT,
+      anon_sym_set,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26510] = 12,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(542), 1,
+      sym_argument_list_call,
+    STATE(543), 1,
+      sym_argument_list_curry_call,
+    STATE(544), 1,
+      sym_argument_list_instantiation,
+    ACTIONS(820), 1,
+      anon_sym_LBRACE,
+    ACTIONS(822), 1,
+      anon_sym_LPAREN,
+    ACTIONS(824), 1,
+      anon_sym_DOLLAR_LPAREN,
+    ACTIONS(826), 1,
+      anon_sym_COLON_LBRACE,
+    ACTIONS(828), 1,
+      anon_sym_DOT,
+    ACTIONS(830), 1,
+      anon_sym_QMARK_DOT,
+    ACTIONS(832), 1,
+      anon_sym_LBRACK,
+    ACTIONS(818), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [26548] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(536), 1,
+      aux_sym_type_statement_repeat1,
+    STATE(582), 1,
+      sym__type_member,
+    STATE(639), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(834), 1,
+      sym_identifier,
+    ACTIONS(836), 1,
+      anon_sym_RBRACE,
+    ACTIONS(838), 1,
+      anon_sym_embed,
+    ACTIONS(840), 1,
+      anon_sym_pub,
+    ACTIONS(842), 1,
+      anon_sym_static,
+    STATE(583), 3,
+      sym_type_embed,
+      sym_type_field,
+      sym_type_property,
+  [26581] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(537), 1,
+      aux_sym_type_statement_repeat1,
+    STATE(582), 1,
+      sym__type_member,
+    STATE(639), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(834), 1,
+      sym_identifier,
+    ACTIONS(838), 1,
+      anon_sym_embed,
+    ACTIONS(840), 1,
+      anon_sym_pub,
+    ACTIONS(842), 1,
+      anon_sym_static,
+    ACTIONS(844), 1,
+      anon_sym_RBRACE,
+    STATE(583), 3,
+      sym_type_embed,
+      sym_type_field,
+      sym_type_property,
+  [26614] = 10,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(537), 1,
+      aux_sym_type_statement_repeat1,
+    STATE(582), 1,
+      sym__type_member,
+    STATE(639), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(846), 1,
+      sym_identifier,
+    ACTIONS(849), 1,
+      anon_sym_RBRACE,
+    ACTIONS(851), 1,
+      anon_sym_embed,
+    ACTIONS(854), 1,
+      anon_sym_pub,
+    ACTIONS(857), 1,
+      anon_sym_static,
+    STATE(583), 3,
+      sym_type_embed,
+      sym_type_field,
+      sym_type_property,
+  [26647] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(556), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26662] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(558), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26677] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(564), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26692] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(428), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26707] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(566), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26722] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(568), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26737] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(570), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26752] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(173), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26767] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(572), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26782] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(574), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26797] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(576), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26812] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(578), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26827] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(580), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26842] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(582), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26857] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(584), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26872] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(180), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26887] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(432), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26902] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(586), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26917] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(588), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26932] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(590), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26947] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(592), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26962] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(187), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26977] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(594), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [26992] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(436), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27007] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(596), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27022] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(598), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27037] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(600), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27052] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(602), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27067] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(604), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27082] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(606), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27097] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(608), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27112] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(610), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27127] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(612), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27142] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(614), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27157] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(616), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27172] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(618), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27187] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(620), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27202] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(622), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27217] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(624), 9,
+      anon_sym_LBRACE,
+      anon_sym_if,
+      anon_sym_LPAREN,
+      anon_sym_EQ_GT,
+      anon_sym_DOLLAR_LPAREN,
+      anon_sym_COLON_LBRACE,
+      anon_sym_DOT,
+      anon_sym_QMARK_DOT,
+      anon_sym_LBRACK,
+  [27232] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(860), 1,
+      sym_identifier,
+    ACTIONS(862), 1,
+      anon_sym_RPAREN,
+    ACTIONS(864), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(866), 1,
+      anon_sym_STAR_STAR,
+    STATE(628), 4,
+      sym_positional_parameter,
+      sym_default_parameter,
+      sym_rest_parameter,
+      sym_kwargs_parameter,
+  [27254] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(860), 1,
+      sym_identifier,
+    ACTIONS(864), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(866), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(868), 1,
+      anon_sym_RPAREN,
+    STATE(729), 4,
+      sym_positional_parameter,
+      sym_default_parameter,
+      sym_rest_parameter,
+      sym_kwargs_parameter,
+  [27276] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(860), 1,
+      sym_identifier,
+    ACTIONS(864), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(866), 1,
+      anon_sym_STAR_STAR,
+    ACTIONS(870), 1,
+      anon_sym_RPAREN,
+    STATE(729), 4,
+      sym_positional_parameter,
+      sym_default_parameter,
+      sym_rest_parameter,
+      sym_kwargs_parameter,
+  [27298] = 5,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(860), 1,
+      sym_identifier,
+    ACTIONS(864), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(866), 1,
+      anon_sym_STAR_STAR,
+    STATE(729), 4,
+      sym_positional_parameter,
+      sym_default_parameter,
+      sym_rest_parameter,
+      sym_kwargs_parameter,
+  [27317] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(874), 1,
+      anon_sym_COLON,
+    ACTIONS(872), 4,
+      anon_sym_LBRACE,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
+  [27330] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(876), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27341] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(878), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27352] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(593), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(880), 1,
+      anon_sym_RBRACE,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+  [27371] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(886), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27382] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(594), 1,
+      aux_sym_type_impl_repeat1,
+    STATE(661), 1,
+      sym_type_method,
+    ACTIONS(888), 1,
+      sym_identifier,
+    ACTIONS(890), 1,
+      anon_sym_RBRACE,
+    ACTIONS(892), 1,
+      anon_sym_static,
+  [27401] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(894), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27412] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(598), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(896), 1,
+      anon_sym_RBRACE,
+  [27431] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(898), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27442] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(600), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(900), 1,
+      anon_sym_RBRACE,
+  [27461] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(902), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27472] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(904), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27483] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(602), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(906), 1,
+      anon_sym_RBRACE,
+  [27502] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(604), 1,
+      aux_sym_type_impl_repeat1,
+    STATE(661), 1,
+      sym_type_method,
+    ACTIONS(888), 1,
+      sym_identifier,
+    ACTIONS(892), 1,
+      anon_sym_static,
+    ACTIONS(908), 1,
+      anon_sym_RBRACE,
+  [27521] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(606), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(910), 1,
+      anon_sym_RBRACE,
+  [27540] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(912), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27551] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(914), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27562] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(602), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(916), 1,
+      anon_sym_RBRACE,
+  [27581] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(918), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27592] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(602), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(920), 1,
+      anon_sym_RBRACE,
+  [27611] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(922), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27622] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(602), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(924), 1,
+      anon_sym_RBRACE,
+    ACTIONS(926), 1,
+      anon_sym_get,
+    ACTIONS(929), 1,
+      anon_sym_set,
+  [27641] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(932), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27652] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(604), 1,
+      aux_sym_type_impl_repeat1,
+    STATE(661), 1,
+      sym_type_method,
+    ACTIONS(934), 1,
+      sym_identifier,
+    ACTIONS(937), 1,
+      anon_sym_RBRACE,
+    ACTIONS(939), 1,
+      anon_sym_static,
+  [27671] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(942), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27682] = 6,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(602), 1,
+      aux_sym_type_property_repeat1,
+    STATE(659), 1,
+      sym_type_property_item,
+    ACTIONS(882), 1,
+      anon_sym_get,
+    ACTIONS(884), 1,
+      anon_sym_set,
+    ACTIONS(944), 1,
+      anon_sym_RBRACE,
+  [27701] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(946), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27712] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(948), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27723] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(950), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27734] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(952), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27745] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(954), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27756] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(956), 5,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_embed,
+      anon_sym_pub,
+      anon_sym_static,
+  [27767] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(960), 1,
+      anon_sym_COLON,
+    ACTIONS(962), 1,
+      anon_sym_EQ,
+    ACTIONS(958), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [27781] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(718), 1,
+      anon_sym_LBRACE,
+    ACTIONS(964), 1,
+      anon_sym_if,
+    STATE(266), 2,
+      sym_block_statement,
+      sym_if_statement,
+  [27795] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(208), 1,
+      anon_sym_LBRACE,
+    ACTIONS(214), 1,
+      anon_sym_if,
+    STATE(316), 2,
+      sym_block_expression,
+      sym_if_expression,
+  [27809] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(966), 1,
+      anon_sym_COMMA,
+    ACTIONS(969), 2,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+  [27823] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(971), 4,
+      anon_sym_LBRACE,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
+  [27833] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(310), 1,
+      anon_sym_LBRACE,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    STATE(431), 2,
+      sym_block_expression,
+      sym_if_expression,
+  [27847] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    ACTIONS(358), 1,
+      anon_sym_if,
+    STATE(562), 2,
+      sym_block_expression,
+      sym_if_expression,
+  [27861] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(376), 1,
+      anon_sym_LBRACE,
+    ACTIONS(382), 1,
+      anon_sym_if,
+    STATE(388), 2,
+      sym_block_expression,
+      sym_if_expression,
+  [27875] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(625), 1,
+      sym_formal_parameters,
+    ACTIONS(973), 1,
+      sym_identifier,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [27888] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(801), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+  [27899] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(698), 3,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [27908] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(625), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(977), 1,
+      sym_identifier,
+  [27921] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(979), 1,
+      anon_sym_LBRACE,
+    STATE(300), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [27932] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(812), 1,
+      sym_operator,
+    ACTIONS(486), 2,
+      aux_sym_operator_token1,
+      aux_sym_operator_token2,
+  [27943] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(981), 1,
+      anon_sym_LBRACE,
+    STATE(54), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [27954] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(634), 1,
+      aux_sym_formal_parameters_repeat1,
+    ACTIONS(983), 1,
+      anon_sym_COMMA,
+    ACTIONS(985), 1,
+      anon_sym_RPAREN,
+  [27967] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(636), 1,
+      sym_enum_variant,
+    ACTIONS(987), 1,
+      sym_identifier,
+    ACTIONS(989), 1,
+      anon_sym_RBRACE,
+  [27980] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(640), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(991), 1,
+      anon_sym_COMMA,
+    ACTIONS(993), 1,
+      anon_sym_RPAREN,
+  [27993] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(641), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(995), 1,
+      anon_sym_COMMA,
+    ACTIONS(997), 1,
+      anon_sym_RPAREN,
+  [28006] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(642), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(999), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1001), 1,
+      anon_sym_COMMA,
+  [28019] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(979), 1,
+      anon_sym_LBRACE,
+    STATE(313), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28030] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(644), 1,
+      aux_sym_formal_parameters_repeat1,
+    ACTIONS(1003), 1,
+      anon_sym_COMMA,
+    ACTIONS(1005), 1,
+      anon_sym_RPAREN,
+  [28043] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1007), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [28054] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(646), 1,
+      aux_sym_enum_statement_repeat1,
+    ACTIONS(1011), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1013), 1,
+      anon_sym_COMMA,
+  [28067] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(647), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1015), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1017), 1,
+      anon_sym_COMMA,
+  [28080] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(648), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(834), 1,
+      sym_identifier,
+    ACTIONS(1019), 1,
+      anon_sym_static,
+  [28093] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1021), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1023), 1,
+      anon_sym_SEMI,
+    ACTIONS(1025), 1,
+      anon_sym_EQ,
+  [28106] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1027), 1,
+      anon_sym_COMMA,
+    ACTIONS(1029), 1,
+      anon_sym_RPAREN,
+  [28119] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1031), 1,
+      anon_sym_COMMA,
+    ACTIONS(1033), 1,
+      anon_sym_RPAREN,
+  [28132] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1035), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1037), 1,
+      anon_sym_COMMA,
+  [28145] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1041), 1,
+      anon_sym_EQ,
+    ACTIONS(1039), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [28156] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(644), 1,
+      aux_sym_formal_parameters_repeat1,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1046), 1,
+      anon_sym_RPAREN,
+  [28169] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(734), 1,
+      sym_enum_variant,
+    ACTIONS(987), 1,
+      sym_identifier,
+    ACTIONS(1048), 1,
+      anon_sym_RBRACE,
+  [28182] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(653), 1,
+      aux_sym_enum_statement_repeat1,
+    ACTIONS(1050), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1052), 1,
+      anon_sym_COMMA,
+  [28195] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(655), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1054), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1056), 1,
+      anon_sym_COMMA,
+  [28208] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1058), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1060), 1,
+      anon_sym_SEMI,
+    ACTIONS(1062), 1,
+      anon_sym_EQ,
+  [28221] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1064), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1066), 1,
+      anon_sym_SEMI,
+    ACTIONS(1068), 1,
+      anon_sym_EQ,
+  [28234] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1070), 3,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [28243] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(660), 1,
+      aux_sym_enum_variant_repeat1,
+    ACTIONS(1072), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1074), 1,
+      anon_sym_COMMA,
+  [28256] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(734), 1,
+      sym_enum_variant,
+    ACTIONS(987), 1,
+      sym_identifier,
+    ACTIONS(1076), 1,
+      anon_sym_RBRACE,
+  [28269] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(653), 1,
+      aux_sym_enum_statement_repeat1,
+    ACTIONS(1078), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1080), 1,
+      anon_sym_COMMA,
+  [28282] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(662), 1,
+      aux_sym_value_pattern_repeat1,
+    ACTIONS(1083), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1085), 1,
+      anon_sym_COMMA,
+  [28295] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(655), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1087), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1089), 1,
+      anon_sym_COMMA,
+  [28308] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1092), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1094), 1,
+      anon_sym_SEMI,
+    ACTIONS(1096), 1,
+      anon_sym_EQ,
+  [28321] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(350), 1,
+      anon_sym_LBRACE,
+    STATE(663), 1,
+      sym_block_expression,
+    ACTIONS(1098), 1,
+      anon_sym_EQ_GT,
+  [28334] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(663), 1,
+      sym_block_statement,
+    ACTIONS(1100), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1102), 1,
+      anon_sym_LPAREN,
+  [28347] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1104), 3,
+      anon_sym_RBRACE,
+      anon_sym_get,
+      anon_sym_set,
+  [28356] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(664), 1,
+      aux_sym_enum_variant_repeat1,
+    ACTIONS(1106), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1108), 1,
+      anon_sym_COMMA,
+  [28369] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1110), 3,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_static,
+  [28378] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(666), 1,
+      aux_sym_value_pattern_repeat1,
+    ACTIONS(1112), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1114), 1,
+      anon_sym_COMMA,
+  [28391] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1116), 3,
+      anon_sym_RBRACE,
+      anon_sym_get,
+      anon_sym_set,
+  [28400] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(664), 1,
+      aux_sym_enum_variant_repeat1,
+    ACTIONS(1118), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1120), 1,
+      anon_sym_COMMA,
+  [28413] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    STATE(667), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28424] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(666), 1,
+      aux_sym_value_pattern_repeat1,
+    ACTIONS(1125), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1127), 1,
+      anon_sym_COMMA,
+  [28437] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1130), 3,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_static,
+  [28446] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    STATE(670), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28457] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1132), 3,
+      anon_sym_RBRACE,
+      anon_sym_get,
+      anon_sym_set,
+  [28466] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1134), 3,
+      sym_identifier,
+      anon_sym_RBRACE,
+      anon_sym_static,
+  [28475] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1136), 3,
+      anon_sym_RBRACE,
+      anon_sym_get,
+      anon_sym_set,
+  [28484] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      anon_sym_LBRACE,
+    STATE(276), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28495] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      anon_sym_LBRACE,
+    STATE(277), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28506] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1140), 1,
+      anon_sym_LBRACE,
+    STATE(416), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28517] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(981), 1,
+      anon_sym_LBRACE,
+    STATE(61), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28528] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(681), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1142), 1,
+      anon_sym_COMMA,
+    ACTIONS(1144), 1,
+      anon_sym_RPAREN,
+  [28541] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(682), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1146), 1,
+      anon_sym_COMMA,
+    ACTIONS(1148), 1,
+      anon_sym_RPAREN,
+  [28554] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(683), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1150), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1152), 1,
+      anon_sym_COMMA,
+  [28567] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1140), 1,
+      anon_sym_LBRACE,
+    STATE(428), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28578] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(684), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1154), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1156), 1,
+      anon_sym_COMMA,
+  [28591] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1158), 1,
+      anon_sym_COMMA,
+    ACTIONS(1160), 1,
+      anon_sym_RPAREN,
+  [28604] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1162), 1,
+      anon_sym_COMMA,
+    ACTIONS(1164), 1,
+      anon_sym_RPAREN,
+  [28617] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1166), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1168), 1,
+      anon_sym_COMMA,
+  [28630] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(655), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1170), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1172), 1,
+      anon_sym_COMMA,
+  [28643] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    STATE(547), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28654] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(981), 1,
+      anon_sym_LBRACE,
+    STATE(64), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28665] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(692), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1174), 1,
+      anon_sym_COMMA,
+    ACTIONS(1176), 1,
+      anon_sym_RPAREN,
+  [28678] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(693), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1178), 1,
+      anon_sym_COMMA,
+    ACTIONS(1180), 1,
+      anon_sym_RPAREN,
+  [28691] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(694), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1182), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1184), 1,
+      anon_sym_COMMA,
+  [28704] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    STATE(559), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28715] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(695), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1186), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1188), 1,
+      anon_sym_COMMA,
+  [28728] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1190), 1,
+      anon_sym_COMMA,
+    ACTIONS(1192), 1,
+      anon_sym_RPAREN,
+  [28741] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1194), 1,
+      anon_sym_COMMA,
+    ACTIONS(1196), 1,
+      anon_sym_RPAREN,
+  [28754] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1198), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1200), 1,
+      anon_sym_COMMA,
+  [28767] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(655), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1202), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1204), 1,
+      anon_sym_COMMA,
+  [28780] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1206), 1,
+      anon_sym_LBRACE,
+    STATE(366), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28791] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(702), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1208), 1,
+      anon_sym_COMMA,
+    ACTIONS(1210), 1,
+      anon_sym_RPAREN,
+  [28804] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(703), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1212), 1,
+      anon_sym_COMMA,
+    ACTIONS(1214), 1,
+      anon_sym_RPAREN,
+  [28817] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(704), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1216), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1218), 1,
+      anon_sym_COMMA,
+  [28830] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1206), 1,
+      anon_sym_LBRACE,
+    STATE(385), 2,
+      sym_block_statement,
+      sym_block_expression,
+  [28841] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(705), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1220), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1222), 1,
+      anon_sym_COMMA,
+  [28854] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1224), 1,
+      anon_sym_COMMA,
+    ACTIONS(1226), 1,
+      anon_sym_RPAREN,
+  [28867] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1228), 1,
+      anon_sym_COMMA,
+    ACTIONS(1230), 1,
+      anon_sym_RPAREN,
+  [28880] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(616), 1,
+      aux_sym_argument_list_call_repeat1,
+    ACTIONS(1232), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1234), 1,
+      anon_sym_COMMA,
+  [28893] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(655), 1,
+      aux_sym_match_expression_repeat1,
+    ACTIONS(1236), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1238), 1,
+      anon_sym_COMMA,
+  [28906] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(674), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1240), 1,
+      sym_identifier,
+  [28919] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(685), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1242), 1,
+      sym_identifier,
+  [28932] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(696), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1244), 1,
+      sym_identifier,
+  [28945] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(625), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1246), 1,
+      sym_identifier,
+  [28958] = 4,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(625), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1248), 1,
+      sym_identifier,
+  [28971] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(52), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [28981] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1252), 1,
+      sym_label,
+    ACTIONS(1254), 1,
+      anon_sym_SEMI,
+  [28991] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1256), 1,
+      anon_sym_while,
+    ACTIONS(1258), 1,
+      anon_sym_loop,
+  [29001] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1260), 2,
+      anon_sym_while,
+      anon_sym_loop,
+  [29009] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1262), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+  [29019] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(298), 1,
+      sym_block_statement,
+    ACTIONS(1266), 1,
+      anon_sym_LBRACE,
+  [29029] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(627), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29039] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(260), 1,
+      sym_block_statement,
+    ACTIONS(718), 1,
+      anon_sym_LBRACE,
+  [29049] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(53), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [29059] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1268), 1,
+      anon_sym_RBRACE,
+  [29069] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(633), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29079] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(307), 1,
+      sym_block_statement,
+    ACTIONS(1266), 1,
+      anon_sym_LBRACE,
+  [29089] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1270), 1,
+      anon_sym_RBRACE,
+  [29099] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1272), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [29107] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1274), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [29115] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1276), 1,
+      anon_sym_if,
+    ACTIONS(1278), 1,
+      anon_sym_EQ_GT,
+  [29125] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(649), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(834), 1,
+      sym_identifier,
+  [29135] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1280), 1,
+      anon_sym_RBRACE,
+  [29145] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1282), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [29153] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1284), 1,
+      sym_identifier,
+    ACTIONS(1286), 1,
+      anon_sym_RBRACE,
+  [29163] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1288), 1,
+      sym_identifier,
+    ACTIONS(1290), 1,
+      anon_sym_RBRACE,
+  [29173] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(656), 1,
+      sym_maybe_typed_identifier,
+    ACTIONS(834), 1,
+      sym_identifier,
+  [29183] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1292), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29191] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1294), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29199] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1296), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [29207] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1298), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29215] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1300), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29223] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1302), 1,
+      sym_identifier,
+    ACTIONS(1304), 1,
+      anon_sym_RBRACE,
+  [29233] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(665), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29243] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(734), 1,
+      sym_enum_variant,
+    ACTIONS(987), 1,
+      sym_identifier,
+  [29253] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1306), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [29261] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1308), 1,
+      sym_identifier,
+    ACTIONS(1310), 1,
+      anon_sym_RBRACE,
+  [29271] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(834), 1,
+      sym_identifier,
+    STATE(836), 1,
+      sym_maybe_typed_identifier,
+  [29281] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1312), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29289] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1314), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29297] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1316), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29305] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1302), 1,
+      sym_identifier,
+    ACTIONS(1318), 1,
+      anon_sym_RBRACE,
+  [29315] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(668), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29325] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1320), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29333] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1322), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [29341] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1324), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [29349] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1308), 1,
+      sym_identifier,
+    ACTIONS(1326), 1,
+      anon_sym_RBRACE,
+  [29359] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1328), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [29367] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1330), 2,
+      anon_sym_if,
+      anon_sym_EQ_GT,
+  [29375] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(671), 1,
+      sym_block_statement,
+    ACTIONS(1100), 1,
+      anon_sym_LBRACE,
+  [29385] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(59), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [29395] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1332), 1,
+      anon_sym_RBRACE,
+  [29405] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(414), 1,
+      sym_block_statement,
+    ACTIONS(1334), 1,
+      anon_sym_LBRACE,
+  [29415] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(60), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [29425] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1336), 1,
+      anon_sym_RBRACE,
+  [29435] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(422), 1,
+      sym_block_statement,
+    ACTIONS(1334), 1,
+      anon_sym_LBRACE,
+  [29445] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1338), 1,
+      anon_sym_RBRACE,
+  [29455] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1340), 1,
+      anon_sym_RBRACE,
+  [29465] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(62), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [29475] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1342), 1,
+      anon_sym_RBRACE,
+  [29485] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(545), 1,
+      sym_block_statement,
+    ACTIONS(1100), 1,
+      anon_sym_LBRACE,
+  [29495] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_block_statement,
+    ACTIONS(1250), 1,
+      anon_sym_LBRACE,
+  [29505] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1344), 1,
+      anon_sym_RBRACE,
+  [29515] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(553), 1,
+      sym_block_statement,
+    ACTIONS(1100), 1,
+      anon_sym_LBRACE,
+  [29525] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1346), 1,
+      anon_sym_RBRACE,
+  [29535] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1348), 1,
+      anon_sym_RBRACE,
+  [29545] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1350), 1,
+      anon_sym_RBRACE,
+  [29555] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(364), 1,
+      sym_block_statement,
+    ACTIONS(1352), 1,
+      anon_sym_LBRACE,
+  [29565] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1354), 1,
+      anon_sym_RBRACE,
+  [29575] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(379), 1,
+      sym_block_statement,
+    ACTIONS(1352), 1,
+      anon_sym_LBRACE,
+  [29585] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1356), 1,
+      anon_sym_RBRACE,
+  [29595] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1358), 1,
+      anon_sym_RBRACE,
+  [29605] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1360), 1,
+      anon_sym_RBRACE,
+  [29615] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1362), 1,
+      anon_sym_RBRACE,
+  [29625] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1364), 1,
+      anon_sym_RBRACE,
+  [29635] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+    ACTIONS(1366), 1,
+      anon_sym_RBRACE,
+  [29645] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1256), 1,
+      anon_sym_while,
+    ACTIONS(1368), 1,
+      anon_sym_loop,
+  [29655] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(675), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29665] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(679), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29675] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1256), 1,
+      anon_sym_while,
+    ACTIONS(1370), 1,
+      anon_sym_loop,
+  [29685] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(686), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29695] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(690), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29705] = 3,
+    ACTIONS(11), 1,
+      sym_comment,
+    STATE(700), 1,
+      sym_formal_parameters,
+    ACTIONS(975), 1,
+      anon_sym_LPAREN,
+  [29715] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(304), 1,
+      anon_sym_COLON,
+  [29722] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1372), 1,
+      sym_identifier,
+  [29729] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1374), 1,
+      anon_sym_operator,
+  [29736] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1376), 1,
+      sym_identifier,
+  [29743] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1378), 1,
+      sym_identifier,
+  [29750] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1380), 1,
+      sym_string_literal,
+  [29757] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1382), 1,
+      ts_builtin_sym_end,
+  [29764] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1384), 1,
+      sym_identifier,
+  [29771] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_SEMI,
+  [29778] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1386), 1,
+      anon_sym_loop,
+  [29785] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1388), 1,
+      anon_sym_EQ,
+  [29792] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1390), 1,
+      anon_sym_SEMI,
+  [29799] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1392), 1,
+      anon_sym_LPAREN,
+  [29806] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1394), 1,
+      anon_sym_RPAREN,
+  [29813] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1396), 1,
+      anon_sym_LBRACE,
+  [29820] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1398), 1,
+      anon_sym_LBRACE,
+  [29827] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1400), 1,
+      sym_identifier,
+  [29834] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1402), 1,
+      sym_identifier,
+  [29841] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1404), 1,
+      sym_identifier,
+  [29848] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1406), 1,
+      anon_sym_LBRACE,
+  [29855] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1408), 1,
+      sym_identifier,
+  [29862] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1410), 1,
+      sym_identifier,
+  [29869] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1412), 1,
+      anon_sym_else,
+  [29876] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1414), 1,
+      anon_sym_LPAREN,
+  [29883] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1416), 1,
+      sym_identifier,
+  [29890] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(724), 1,
+      anon_sym_RBRACK,
+  [29897] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(740), 1,
+      anon_sym_RBRACK,
+  [29904] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1418), 1,
+      sym_identifier,
+  [29911] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1420), 1,
+      anon_sym_LBRACE,
+  [29918] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1422), 1,
+      sym_identifier,
+  [29925] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1424), 1,
+      anon_sym_COLON,
+  [29932] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1426), 1,
+      sym_identifier,
+  [29939] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1428), 1,
+      anon_sym_LBRACE,
+  [29946] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1430), 1,
+      anon_sym_LBRACE,
+  [29953] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1432), 1,
+      anon_sym_COLON,
+  [29960] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1434), 1,
+      sym_identifier,
+  [29967] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1436), 1,
+      anon_sym_LBRACE,
+  [29974] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1438), 1,
+      sym_identifier,
+  [29981] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1440), 1,
+      anon_sym_SEMI,
+  [29988] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1442), 1,
+      anon_sym_LBRACE,
+  [29995] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1444), 1,
+      sym_identifier,
+  [30002] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1446), 1,
+      anon_sym_COMMA,
+  [30009] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      anon_sym_COMMA,
+  [30016] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      sym_identifier,
+  [30023] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1452), 1,
+      sym_identifier,
+  [30030] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1454), 1,
+      sym_identifier,
+  [30037] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1456), 1,
+      anon_sym_COLON,
+  [30044] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1458), 1,
+      anon_sym_RPAREN,
+  [30051] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1460), 1,
+      anon_sym_COLON,
+  [30058] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1462), 1,
+      sym_identifier,
+  [30065] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1302), 1,
+      sym_identifier,
+  [30072] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1308), 1,
+      sym_identifier,
+  [30079] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1464), 1,
+      sym_identifier,
+  [30086] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1466), 1,
+      anon_sym_RPAREN,
+  [30093] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1468), 1,
+      anon_sym_RPAREN,
+  [30100] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1470), 1,
+      anon_sym_RPAREN,
+  [30107] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1472), 1,
+      sym_identifier,
+  [30114] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1474), 1,
+      sym_identifier,
+  [30121] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(752), 1,
+      anon_sym_RBRACK,
+  [30128] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1476), 1,
+      anon_sym_RPAREN,
+  [30135] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1478), 1,
+      sym_identifier,
+  [30142] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1480), 1,
+      sym_identifier,
+  [30149] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(754), 1,
+      anon_sym_RBRACK,
+  [30156] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1482), 1,
+      anon_sym_RPAREN,
+  [30163] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1484), 1,
+      sym_identifier,
+  [30170] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1486), 1,
+      sym_identifier,
+  [30177] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(756), 1,
+      anon_sym_RBRACK,
+  [30184] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1488), 1,
+      anon_sym_loop,
+  [30191] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1490), 1,
+      anon_sym_else,
+  [30198] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1492), 1,
+      anon_sym_loop,
+  [30205] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1494), 1,
+      anon_sym_else,
+  [30212] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1496), 1,
+      anon_sym_loop,
+  [30219] = 2,
+    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(1498), 1,
+      anon_sym_else,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(52)] = 0,
+  [SMALL_STATE(53)] = 65,
+  [SMALL_STATE(54)] = 130,
+  [SMALL_STATE(55)] = 195,
+  [SMALL_STATE(56)] = 256,
+  [SMALL_STATE(57)] = 317,
+  [SMALL_STATE(58)] = 378,
+  [SMALL_STATE(59)] = 439,
+  [SMALL_STATE(60)] = 503,
+  [SMALL_STATE(61)] = 567,
+  [SMALL_STATE(62)] = 631,
+  [SMALL_STATE(63)] = 695,
+  [SMALL_STATE(64)] = 759,
+  [SMALL_STATE(65)] = 823,
+  [SMALL_STATE(66)] = 919,
+  [SMALL_STATE(67)] = 1015,
+  [SMALL_STATE(68)] = 1111,
+  [SMALL_STATE(69)] = 1207,
+  [SMALL_STATE(70)] = 1303,
+  [SMALL_STATE(71)] = 1399,
+  [SMALL_STATE(72)] = 1495,
+  [SMALL_STATE(73)] = 1591,
+  [SMALL_STATE(74)] = 1687,
+  [SMALL_STATE(75)] = 1783,
+  [SMALL_STATE(76)] = 1879,
+  [SMALL_STATE(77)] = 1975,
+  [SMALL_STATE(78)] = 2071,
+  [SMALL_STATE(79)] = 2167,
+  [SMALL_STATE(80)] = 2263,
+  [SMALL_STATE(81)] = 2359,
+  [SMALL_STATE(82)] = 2455,
+  [SMALL_STATE(83)] = 2551,
+  [SMALL_STATE(84)] = 2647,
+  [SMALL_STATE(85)] = 2743,
+  [SMALL_STATE(86)] = 2839,
+  [SMALL_STATE(87)] = 2935,
+  [SMALL_STATE(88)] = 3031,
+  [SMALL_STATE(89)] = 3127,
+  [SMALL_STATE(90)] = 3223,
+  [SMALL_STATE(91)] = 3319,
+  [SMALL_STATE(92)] = 3415,
+  [SMALL_STATE(93)] = 3511,
+  [SMALL_STATE(94)] = 3607,
+  [SMALL_STATE(95)] = 3703,
+  [SMALL_STATE(96)] = 3799,
+  [SMALL_STATE(97)] = 3895,
+  [SMALL_STATE(98)] = 3991,
+  [SMALL_STATE(99)] = 4087,
+  [SMALL_STATE(100)] = 4183,
+  [SMALL_STATE(101)] = 4279,
+  [SMALL_STATE(102)] = 4372,
+  [SMALL_STATE(103)] = 4464,
+  [SMALL_STATE(104)] = 4556,
+  [SMALL_STATE(105)] = 4648,
+  [SMALL_STATE(106)] = 4740,
+  [SMALL_STATE(107)] = 4829,
+  [SMALL_STATE(108)] = 4918,
+  [SMALL_STATE(109)] = 5007,
+  [SMALL_STATE(110)] = 5096,
+  [SMALL_STATE(111)] = 5183,
+  [SMALL_STATE(112)] = 5270,
+  [SMALL_STATE(113)] = 5357,
+  [SMALL_STATE(114)] = 5444,
+  [SMALL_STATE(115)] = 5531,
+  [SMALL_STATE(116)] = 5618,
+  [SMALL_STATE(117)] = 5702,
+  [SMALL_STATE(118)] = 5786,
+  [SMALL_STATE(119)] = 5870,
+  [SMALL_STATE(120)] = 5954,
+  [SMALL_STATE(121)] = 6035,
+  [SMALL_STATE(122)] = 6116,
+  [SMALL_STATE(123)] = 6197,
+  [SMALL_STATE(124)] = 6278,
+  [SMALL_STATE(125)] = 6359,
+  [SMALL_STATE(126)] = 6440,
+  [SMALL_STATE(127)] = 6521,
+  [SMALL_STATE(128)] = 6602,
+  [SMALL_STATE(129)] = 6683,
+  [SMALL_STATE(130)] = 6764,
+  [SMALL_STATE(131)] = 6845,
+  [SMALL_STATE(132)] = 6926,
+  [SMALL_STATE(133)] = 7007,
+  [SMALL_STATE(134)] = 7088,
+  [SMALL_STATE(135)] = 7169,
+  [SMALL_STATE(136)] = 7250,
+  [SMALL_STATE(137)] = 7331,
+  [SMALL_STATE(138)] = 7412,
+  [SMALL_STATE(139)] = 7493,
+  [SMALL_STATE(140)] = 7576,
+  [SMALL_STATE(141)] = 7657,
+  [SMALL_STATE(142)] = 7738,
+  [SMALL_STATE(143)] = 7819,
+  [SMALL_STATE(144)] = 7900,
+  [SMALL_STATE(145)] = 7981,
+  [SMALL_STATE(146)] = 8062,
+  [SMALL_STATE(147)] = 8143,
+  [SMALL_STATE(148)] = 8224,
+  [SMALL_STATE(149)] = 8307,
+  [SMALL_STATE(150)] = 8388,
+  [SMALL_STATE(151)] = 8469,
+  [SMALL_STATE(152)] = 8550,
+  [SMALL_STATE(153)] = 8633,
+  [SMALL_STATE(154)] = 8714,
+  [SMALL_STATE(155)] = 8795,
+  [SMALL_STATE(156)] = 8876,
+  [SMALL_STATE(157)] = 8957,
+  [SMALL_STATE(158)] = 9038,
+  [SMALL_STATE(159)] = 9119,
+  [SMALL_STATE(160)] = 9200,
+  [SMALL_STATE(161)] = 9281,
+  [SMALL_STATE(162)] = 9362,
+  [SMALL_STATE(163)] = 9443,
+  [SMALL_STATE(164)] = 9524,
+  [SMALL_STATE(165)] = 9605,
+  [SMALL_STATE(166)] = 9686,
+  [SMALL_STATE(167)] = 9767,
+  [SMALL_STATE(168)] = 9848,
+  [SMALL_STATE(169)] = 9929,
+  [SMALL_STATE(170)] = 10010,
+  [SMALL_STATE(171)] = 10091,
+  [SMALL_STATE(172)] = 10172,
+  [SMALL_STATE(173)] = 10253,
+  [SMALL_STATE(174)] = 10334,
+  [SMALL_STATE(175)] = 10415,
+  [SMALL_STATE(176)] = 10496,
+  [SMALL_STATE(177)] = 10577,
+  [SMALL_STATE(178)] = 10658,
+  [SMALL_STATE(179)] = 10739,
+  [SMALL_STATE(180)] = 10820,
+  [SMALL_STATE(181)] = 10901,
+  [SMALL_STATE(182)] = 10982,
+  [SMALL_STATE(183)] = 11063,
+  [SMALL_STATE(184)] = 11144,
+  [SMALL_STATE(185)] = 11225,
+  [SMALL_STATE(186)] = 11306,
+  [SMALL_STATE(187)] = 11387,
+  [SMALL_STATE(188)] = 11470,
+  [SMALL_STATE(189)] = 11553,
+  [SMALL_STATE(190)] = 11636,
+  [SMALL_STATE(191)] = 11717,
+  [SMALL_STATE(192)] = 11798,
+  [SMALL_STATE(193)] = 11879,
+  [SMALL_STATE(194)] = 11960,
+  [SMALL_STATE(195)] = 12041,
+  [SMALL_STATE(196)] = 12122,
+  [SMALL_STATE(197)] = 12203,
+  [SMALL_STATE(198)] = 12284,
+  [SMALL_STATE(199)] = 12365,
+  [SMALL_STATE(200)] = 12446,
+  [SMALL_STATE(201)] = 12527,
+  [SMALL_STATE(202)] = 12608,
+  [SMALL_STATE(203)] = 12689,
+  [SMALL_STATE(204)] = 12770,
+  [SMALL_STATE(205)] = 12851,
+  [SMALL_STATE(206)] = 12932,
+  [SMALL_STATE(207)] = 13013,
+  [SMALL_STATE(208)] = 13094,
+  [SMALL_STATE(209)] = 13177,
+  [SMALL_STATE(210)] = 13260,
+  [SMALL_STATE(211)] = 13343,
+  [SMALL_STATE(212)] = 13426,
+  [SMALL_STATE(213)] = 13509,
+  [SMALL_STATE(214)] = 13592,
+  [SMALL_STATE(215)] = 13673,
+  [SMALL_STATE(216)] = 13754,
+  [SMALL_STATE(217)] = 13835,
+  [SMALL_STATE(218)] = 13916,
+  [SMALL_STATE(219)] = 13997,
+  [SMALL_STATE(220)] = 14078,
+  [SMALL_STATE(221)] = 14159,
+  [SMALL_STATE(222)] = 14240,
+  [SMALL_STATE(223)] = 14321,
+  [SMALL_STATE(224)] = 14401,
+  [SMALL_STATE(225)] = 14478,
+  [SMALL_STATE(226)] = 14555,
+  [SMALL_STATE(227)] = 14632,
+  [SMALL_STATE(228)] = 14709,
+  [SMALL_STATE(229)] = 14786,
+  [SMALL_STATE(230)] = 14863,
+  [SMALL_STATE(231)] = 14906,
+  [SMALL_STATE(232)] = 14949,
+  [SMALL_STATE(233)] = 14992,
+  [SMALL_STATE(234)] = 15049,
+  [SMALL_STATE(235)] = 15106,
+  [SMALL_STATE(236)] = 15149,
+  [SMALL_STATE(237)] = 15192,
+  [SMALL_STATE(238)] = 15235,
+  [SMALL_STATE(239)] = 15278,
+  [SMALL_STATE(240)] = 15321,
+  [SMALL_STATE(241)] = 15364,
+  [SMALL_STATE(242)] = 15407,
+  [SMALL_STATE(243)] = 15452,
+  [SMALL_STATE(244)] = 15492,
+  [SMALL_STATE(245)] = 15530,
+  [SMALL_STATE(246)] = 15568,
+  [SMALL_STATE(247)] = 15605,
+  [SMALL_STATE(248)] = 15642,
+  [SMALL_STATE(249)] = 15679,
+  [SMALL_STATE(250)] = 15716,
+  [SMALL_STATE(251)] = 15753,
+  [SMALL_STATE(252)] = 15790,
+  [SMALL_STATE(253)] = 15847,
+  [SMALL_STATE(254)] = 15884,
+  [SMALL_STATE(255)] = 15921,
+  [SMALL_STATE(256)] = 15958,
+  [SMALL_STATE(257)] = 15995,
+  [SMALL_STATE(258)] = 16032,
+  [SMALL_STATE(259)] = 16069,
+  [SMALL_STATE(260)] = 16106,
+  [SMALL_STATE(261)] = 16143,
+  [SMALL_STATE(262)] = 16180,
+  [SMALL_STATE(263)] = 16217,
+  [SMALL_STATE(264)] = 16254,
+  [SMALL_STATE(265)] = 16291,
+  [SMALL_STATE(266)] = 16328,
+  [SMALL_STATE(267)] = 16365,
+  [SMALL_STATE(268)] = 16402,
+  [SMALL_STATE(269)] = 16439,
+  [SMALL_STATE(270)] = 16476,
+  [SMALL_STATE(271)] = 16513,
+  [SMALL_STATE(272)] = 16550,
+  [SMALL_STATE(273)] = 16587,
+  [SMALL_STATE(274)] = 16624,
+  [SMALL_STATE(275)] = 16661,
+  [SMALL_STATE(276)] = 16698,
+  [SMALL_STATE(277)] = 16735,
+  [SMALL_STATE(278)] = 16772,
+  [SMALL_STATE(279)] = 16813,
+  [SMALL_STATE(280)] = 16868,
+  [SMALL_STATE(281)] = 16921,
+  [SMALL_STATE(282)] = 16972,
+  [SMALL_STATE(283)] = 17021,
+  [SMALL_STATE(284)] = 17068,
+  [SMALL_STATE(285)] = 17113,
+  [SMALL_STATE(286)] = 17156,
+  [SMALL_STATE(287)] = 17195,
+  [SMALL_STATE(288)] = 17232,
+  [SMALL_STATE(289)] = 17269,
+  [SMALL_STATE(290)] = 17305,
+  [SMALL_STATE(291)] = 17341,
+  [SMALL_STATE(292)] = 17395,
+  [SMALL_STATE(293)] = 17431,
+  [SMALL_STATE(294)] = 17467,
+  [SMALL_STATE(295)] = 17503,
+  [SMALL_STATE(296)] = 17539,
+  [SMALL_STATE(297)] = 17575,
+  [SMALL_STATE(298)] = 17611,
+  [SMALL_STATE(299)] = 17647,
+  [SMALL_STATE(300)] = 17683,
+  [SMALL_STATE(301)] = 17719,
+  [SMALL_STATE(302)] = 17755,
+  [SMALL_STATE(303)] = 17791,
+  [SMALL_STATE(304)] = 17827,
+  [SMALL_STATE(305)] = 17863,
+  [SMALL_STATE(306)] = 17899,
+  [SMALL_STATE(307)] = 17935,
+  [SMALL_STATE(308)] = 17971,
+  [SMALL_STATE(309)] = 18007,
+  [SMALL_STATE(310)] = 18043,
+  [SMALL_STATE(311)] = 18079,
+  [SMALL_STATE(312)] = 18115,
+  [SMALL_STATE(313)] = 18151,
+  [SMALL_STATE(314)] = 18187,
+  [SMALL_STATE(315)] = 18223,
+  [SMALL_STATE(316)] = 18259,
+  [SMALL_STATE(317)] = 18295,
+  [SMALL_STATE(318)] = 18331,
+  [SMALL_STATE(319)] = 18367,
+  [SMALL_STATE(320)] = 18403,
+  [SMALL_STATE(321)] = 18439,
+  [SMALL_STATE(322)] = 18475,
+  [SMALL_STATE(323)] = 18511,
+  [SMALL_STATE(324)] = 18547,
+  [SMALL_STATE(325)] = 18583,
+  [SMALL_STATE(326)] = 18619,
+  [SMALL_STATE(327)] = 18655,
+  [SMALL_STATE(328)] = 18691,
+  [SMALL_STATE(329)] = 18727,
+  [SMALL_STATE(330)] = 18763,
+  [SMALL_STATE(331)] = 18799,
+  [SMALL_STATE(332)] = 18835,
+  [SMALL_STATE(333)] = 18871,
+  [SMALL_STATE(334)] = 18907,
+  [SMALL_STATE(335)] = 18961,
+  [SMALL_STATE(336)] = 18997,
+  [SMALL_STATE(337)] = 19039,
+  [SMALL_STATE(338)] = 19092,
+  [SMALL_STATE(339)] = 19145,
+  [SMALL_STATE(340)] = 19186,
+  [SMALL_STATE(341)] = 19222,
+  [SMALL_STATE(342)] = 19276,
+  [SMALL_STATE(343)] = 19314,
+  [SMALL_STATE(344)] = 19350,
+  [SMALL_STATE(345)] = 19402,
+  [SMALL_STATE(346)] = 19452,
+  [SMALL_STATE(347)] = 19500,
+  [SMALL_STATE(348)] = 19546,
+  [SMALL_STATE(349)] = 19590,
+  [SMALL_STATE(350)] = 19632,
+  [SMALL_STATE(351)] = 19672,
+  [SMALL_STATE(352)] = 19724,
+  [SMALL_STATE(353)] = 19777,
+  [SMALL_STATE(354)] = 19814,
+  [SMALL_STATE(355)] = 19849,
+  [SMALL_STATE(356)] = 19882,
+  [SMALL_STATE(357)] = 19915,
+  [SMALL_STATE(358)] = 19948,
+  [SMALL_STATE(359)] = 19981,
+  [SMALL_STATE(360)] = 20014,
+  [SMALL_STATE(361)] = 20047,
+  [SMALL_STATE(362)] = 20080,
+  [SMALL_STATE(363)] = 20113,
+  [SMALL_STATE(364)] = 20146,
+  [SMALL_STATE(365)] = 20179,
+  [SMALL_STATE(366)] = 20212,
+  [SMALL_STATE(367)] = 20245,
+  [SMALL_STATE(368)] = 20278,
+  [SMALL_STATE(369)] = 20329,
+  [SMALL_STATE(370)] = 20378,
+  [SMALL_STATE(371)] = 20425,
+  [SMALL_STATE(372)] = 20470,
+  [SMALL_STATE(373)] = 20513,
+  [SMALL_STATE(374)] = 20554,
+  [SMALL_STATE(375)] = 20593,
+  [SMALL_STATE(376)] = 20626,
+  [SMALL_STATE(377)] = 20659,
+  [SMALL_STATE(378)] = 20692,
+  [SMALL_STATE(379)] = 20725,
+  [SMALL_STATE(380)] = 20758,
+  [SMALL_STATE(381)] = 20791,
+  [SMALL_STATE(382)] = 20824,
+  [SMALL_STATE(383)] = 20857,
+  [SMALL_STATE(384)] = 20890,
+  [SMALL_STATE(385)] = 20923,
+  [SMALL_STATE(386)] = 20956,
+  [SMALL_STATE(387)] = 20989,
+  [SMALL_STATE(388)] = 21022,
+  [SMALL_STATE(389)] = 21055,
+  [SMALL_STATE(390)] = 21088,
+  [SMALL_STATE(391)] = 21121,
+  [SMALL_STATE(392)] = 21154,
+  [SMALL_STATE(393)] = 21187,
+  [SMALL_STATE(394)] = 21220,
+  [SMALL_STATE(395)] = 21253,
+  [SMALL_STATE(396)] = 21286,
+  [SMALL_STATE(397)] = 21319,
+  [SMALL_STATE(398)] = 21352,
+  [SMALL_STATE(399)] = 21385,
+  [SMALL_STATE(400)] = 21418,
+  [SMALL_STATE(401)] = 21451,
+  [SMALL_STATE(402)] = 21484,
+  [SMALL_STATE(403)] = 21517,
+  [SMALL_STATE(404)] = 21550,
+  [SMALL_STATE(405)] = 21583,
+  [SMALL_STATE(406)] = 21616,
+  [SMALL_STATE(407)] = 21649,
+  [SMALL_STATE(408)] = 21681,
+  [SMALL_STATE(409)] = 21713,
+  [SMALL_STATE(410)] = 21745,
+  [SMALL_STATE(411)] = 21777,
+  [SMALL_STATE(412)] = 21809,
+  [SMALL_STATE(413)] = 21841,
+  [SMALL_STATE(414)] = 21873,
+  [SMALL_STATE(415)] = 21905,
+  [SMALL_STATE(416)] = 21937,
+  [SMALL_STATE(417)] = 21969,
+  [SMALL_STATE(418)] = 22001,
+  [SMALL_STATE(419)] = 22033,
+  [SMALL_STATE(420)] = 22065,
+  [SMALL_STATE(421)] = 22097,
+  [SMALL_STATE(422)] = 22129,
+  [SMALL_STATE(423)] = 22161,
+  [SMALL_STATE(424)] = 22193,
+  [SMALL_STATE(425)] = 22225,
+  [SMALL_STATE(426)] = 22257,
+  [SMALL_STATE(427)] = 22289,
+  [SMALL_STATE(428)] = 22321,
+  [SMALL_STATE(429)] = 22353,
+  [SMALL_STATE(430)] = 22385,
+  [SMALL_STATE(431)] = 22417,
+  [SMALL_STATE(432)] = 22449,
+  [SMALL_STATE(433)] = 22481,
+  [SMALL_STATE(434)] = 22513,
+  [SMALL_STATE(435)] = 22545,
+  [SMALL_STATE(436)] = 22577,
+  [SMALL_STATE(437)] = 22609,
+  [SMALL_STATE(438)] = 22641,
+  [SMALL_STATE(439)] = 22673,
+  [SMALL_STATE(440)] = 22705,
+  [SMALL_STATE(441)] = 22737,
+  [SMALL_STATE(442)] = 22769,
+  [SMALL_STATE(443)] = 22801,
+  [SMALL_STATE(444)] = 22833,
+  [SMALL_STATE(445)] = 22865,
+  [SMALL_STATE(446)] = 22897,
+  [SMALL_STATE(447)] = 22929,
+  [SMALL_STATE(448)] = 22961,
+  [SMALL_STATE(449)] = 22993,
+  [SMALL_STATE(450)] = 23025,
+  [SMALL_STATE(451)] = 23057,
+  [SMALL_STATE(452)] = 23089,
+  [SMALL_STATE(453)] = 23120,
+  [SMALL_STATE(454)] = 23151,
+  [SMALL_STATE(455)] = 23186,
+  [SMALL_STATE(456)] = 23235,
+  [SMALL_STATE(457)] = 23282,
+  [SMALL_STATE(458)] = 23327,
+  [SMALL_STATE(459)] = 23370,
+  [SMALL_STATE(460)] = 23411,
+  [SMALL_STATE(461)] = 23450,
+  [SMALL_STATE(462)] = 23487,
+  [SMALL_STATE(463)] = 23520,
+  [SMALL_STATE(464)] = 23568,
+  [SMALL_STATE(465)] = 23620,
+  [SMALL_STATE(466)] = 23668,
+  [SMALL_STATE(467)] = 23716,
+  [SMALL_STATE(468)] = 23764,
+  [SMALL_STATE(469)] = 23812,
+  [SMALL_STATE(470)] = 23860,
+  [SMALL_STATE(471)] = 23908,
+  [SMALL_STATE(472)] = 23956,
+  [SMALL_STATE(473)] = 24005,
+  [SMALL_STATE(474)] = 24054,
+  [SMALL_STATE(475)] = 24103,
+  [SMALL_STATE(476)] = 24152,
+  [SMALL_STATE(477)] = 24201,
+  [SMALL_STATE(478)] = 24248,
+  [SMALL_STATE(479)] = 24297,
+  [SMALL_STATE(480)] = 24344,
+  [SMALL_STATE(481)] = 24391,
+  [SMALL_STATE(482)] = 24438,
+  [SMALL_STATE(483)] = 24483,
+  [SMALL_STATE(484)] = 24526,
+  [SMALL_STATE(485)] = 24567,
+  [SMALL_STATE(486)] = 24606,
+  [SMALL_STATE(487)] = 24643,
+  [SMALL_STATE(488)] = 24678,
+  [SMALL_STATE(489)] = 24711,
+  [SMALL_STATE(490)] = 24742,
+  [SMALL_STATE(491)] = 24771,
+  [SMALL_STATE(492)] = 24820,
+  [SMALL_STATE(493)] = 24869,
+  [SMALL_STATE(494)] = 24918,
+  [SMALL_STATE(495)] = 24967,
+  [SMALL_STATE(496)] = 25016,
+  [SMALL_STATE(497)] = 25065,
+  [SMALL_STATE(498)] = 25111,
+  [SMALL_STATE(499)] = 25157,
+  [SMALL_STATE(500)] = 25203,
+  [SMALL_STATE(501)] = 25249,
+  [SMALL_STATE(502)] = 25295,
+  [SMALL_STATE(503)] = 25341,
+  [SMALL_STATE(504)] = 25387,
+  [SMALL_STATE(505)] = 25433,
+  [SMALL_STATE(506)] = 25479,
+  [SMALL_STATE(507)] = 25525,
+  [SMALL_STATE(508)] = 25571,
+  [SMALL_STATE(509)] = 25617,
+  [SMALL_STATE(510)] = 25663,
+  [SMALL_STATE(511)] = 25709,
+  [SMALL_STATE(512)] = 25755,
+  [SMALL_STATE(513)] = 25785,
+  [SMALL_STATE(514)] = 25813,
+  [SMALL_STATE(515)] = 25857,
+  [SMALL_STATE(516)] = 25899,
+  [SMALL_STATE(517)] = 25939,
+  [SMALL_STATE(518)] = 25977,
+  [SMALL_STATE(519)] = 26013,
+  [SMALL_STATE(520)] = 26047,
+  [SMALL_STATE(521)] = 26079,
+  [SMALL_STATE(522)] = 26125,
+  [SMALL_STATE(523)] = 26171,
+  [SMALL_STATE(524)] = 26217,
+  [SMALL_STATE(525)] = 26263,
+  [SMALL_STATE(526)] = 26309,
+  [SMALL_STATE(527)] = 26355,
+  [SMALL_STATE(528)] = 26380,
+  [SMALL_STATE(529)] = 26405,
+  [SMALL_STATE(530)] = 26428,
+  [SMALL_STATE(531)] = 26449,
+  [SMALL_STATE(532)] = 26470,
+  [SMALL_STATE(533)] = 26490,
+  [SMALL_STATE(534)] = 26510,
+  [SMALL_STATE(535)] = 26548,
+  [SMALL_STATE(536)] = 26581,
+  [SMALL_STATE(537)] = 26614,
+  [SMALL_STATE(538)] = 26647,
+  [SMALL_STATE(539)] = 26662,
+  [SMALL_STATE(540)] = 26677,
+  [SMALL_STATE(541)] = 26692,
+  [SMALL_STATE(542)] = 26707,
+  [SMALL_STATE(543)] = 26722,
+  [SMALL_STATE(544)] = 26737,
+  [SMALL_STATE(545)] = 26752,
+  [SMALL_STATE(546)] = 26767,
+  [SMALL_STATE(547)] = 26782,
+  [SMALL_STATE(548)] = 26797,
+  [SMALL_STATE(549)] = 26812,
+  [SMALL_STATE(550)] = 26827,
+  [SMALL_STATE(551)] = 26842,
+  [SMALL_STATE(552)] = 26857,
+  [SMALL_STATE(553)] = 26872,
+  [SMALL_STATE(554)] = 26887,
+  [SMALL_STATE(555)] = 26902,
+  [SMALL_STATE(556)] = 26917,
+  [SMALL_STATE(557)] = 26932,
+  [SMALL_STATE(558)] = 26947,
+  [SMALL_STATE(559)] = 26962,
+  [SMALL_STATE(560)] = 26977,
+  [SMALL_STATE(561)] = 26992,
+  [SMALL_STATE(562)] = 27007,
+  [SMALL_STATE(563)] = 27022,
+  [SMALL_STATE(564)] = 27037,
+  [SMALL_STATE(565)] = 27052,
+  [SMALL_STATE(566)] = 27067,
+  [SMALL_STATE(567)] = 27082,
+  [SMALL_STATE(568)] = 27097,
+  [SMALL_STATE(569)] = 27112,
+  [SMALL_STATE(570)] = 27127,
+  [SMALL_STATE(571)] = 27142,
+  [SMALL_STATE(572)] = 27157,
+  [SMALL_STATE(573)] = 27172,
+  [SMALL_STATE(574)] = 27187,
+  [SMALL_STATE(575)] = 27202,
+  [SMALL_STATE(576)] = 27217,
+  [SMALL_STATE(577)] = 27232,
+  [SMALL_STATE(578)] = 27254,
+  [SMALL_STATE(579)] = 27276,
+  [SMALL_STATE(580)] = 27298,
+  [SMALL_STATE(581)] = 27317,
+  [SMALL_STATE(582)] = 27330,
+  [SMALL_STATE(583)] = 27341,
+  [SMALL_STATE(584)] = 27352,
+  [SMALL_STATE(585)] = 27371,
+  [SMALL_STATE(586)] = 27382,
+  [SMALL_STATE(587)] = 27401,
+  [SMALL_STATE(588)] = 27412,
+  [SMALL_STATE(589)] = 27431,
+  [SMALL_STATE(590)] = 27442,
+  [SMALL_STATE(591)] = 27461,
+  [SMALL_STATE(592)] = 27472,
+  [SMALL_STATE(593)] = 27483,
+  [SMALL_STATE(594)] = 27502,
+  [SMALL_STATE(595)] = 27521,
+  [SMALL_STATE(596)] = 27540,
+  [SMALL_STATE(597)] = 27551,
+  [SMALL_STATE(598)] = 27562,
+  [SMALL_STATE(599)] = 27581,
+  [SMALL_STATE(600)] = 27592,
+  [SMALL_STATE(601)] = 27611,
+  [SMALL_STATE(602)] = 27622,
+  [SMALL_STATE(603)] = 27641,
+  [SMALL_STATE(604)] = 27652,
+  [SMALL_STATE(605)] = 27671,
+  [SMALL_STATE(606)] = 27682,
+  [SMALL_STATE(607)] = 27701,
+  [SMALL_STATE(608)] = 27712,
+  [SMALL_STATE(609)] = 27723,
+  [SMALL_STATE(610)] = 27734,
+  [SMALL_STATE(611)] = 27745,
+  [SMALL_STATE(612)] = 27756,
+  [SMALL_STATE(613)] = 27767,
+  [SMALL_STATE(614)] = 27781,
+  [SMALL_STATE(615)] = 27795,
+  [SMALL_STATE(616)] = 27809,
+  [SMALL_STATE(617)] = 27823,
+  [SMALL_STATE(618)] = 27833,
+  [SMALL_STATE(619)] = 27847,
+  [SMALL_STATE(620)] = 27861,
+  [SMALL_STATE(621)] = 27875,
+  [SMALL_STATE(622)] = 27888,
+  [SMALL_STATE(623)] = 27899,
+  [SMALL_STATE(624)] = 27908,
+  [SMALL_STATE(625)] = 27921,
+  [SMALL_STATE(626)] = 27932,
+  [SMALL_STATE(627)] = 27943,
+  [SMALL_STATE(628)] = 27954,
+  [SMALL_STATE(629)] = 27967,
+  [SMALL_STATE(630)] = 27980,
+  [SMALL_STATE(631)] = 27993,
+  [SMALL_STATE(632)] = 28006,
+  [SMALL_STATE(633)] = 28019,
+  [SMALL_STATE(634)] = 28030,
+  [SMALL_STATE(635)] = 28043,
+  [SMALL_STATE(636)] = 28054,
+  [SMALL_STATE(637)] = 28067,
+  [SMALL_STATE(638)] = 28080,
+  [SMALL_STATE(639)] = 28093,
+  [SMALL_STATE(640)] = 28106,
+  [SMALL_STATE(641)] = 28119,
+  [SMALL_STATE(642)] = 28132,
+  [SMALL_STATE(643)] = 28145,
+  [SMALL_STATE(644)] = 28156,
+  [SMALL_STATE(645)] = 28169,
+  [SMALL_STATE(646)] = 28182,
+  [SMALL_STATE(647)] = 28195,
+  [SMALL_STATE(648)] = 28208,
+  [SMALL_STATE(649)] = 28221,
+  [SMALL_STATE(650)] = 28234,
+  [SMALL_STATE(651)] = 28243,
+  [SMALL_STATE(652)] = 28256,
+  [SMALL_STATE(653)] = 28269,
+  [SMALL_STATE(654)] = 28282,
+  [SMALL_STATE(655)] = 28295,
+  [SMALL_STATE(656)] = 28308,
+  [SMALL_STATE(657)] = 28321,
+  [SMALL_STATE(658)] = 28334,
+  [SMALL_STATE(659)] = 28347,
+  [SMALL_STATE(660)] = 28356,
+  [SMALL_STATE(661)] = 28369,
+  [SMALL_STATE(662)] = 28378,
+  [SMALL_STATE(663)] = 28391,
+  [SMALL_STATE(664)] = 28400,
+  [SMALL_STATE(665)] = 28413,
+  [SMALL_STATE(666)] = 28424,
+  [SMALL_STATE(667)] = 28437,
+  [SMALL_STATE(668)] = 28446,
+  [SMALL_STATE(669)] = 28457,
+  [SMALL_STATE(670)] = 28466,
+  [SMALL_STATE(671)] = 28475,
+  [SMALL_STATE(672)] = 28484,
+  [SMALL_STATE(673)] = 28495,
+  [SMALL_STATE(674)] = 28506,
+  [SMALL_STATE(675)] = 28517,
+  [SMALL_STATE(676)] = 28528,
+  [SMALL_STATE(677)] = 28541,
+  [SMALL_STATE(678)] = 28554,
+  [SMALL_STATE(679)] = 28567,
+  [SMALL_STATE(680)] = 28578,
+  [SMALL_STATE(681)] = 28591,
+  [SMALL_STATE(682)] = 28604,
+  [SMALL_STATE(683)] = 28617,
+  [SMALL_STATE(684)] = 28630,
+  [SMALL_STATE(685)] = 28643,
+  [SMALL_STATE(686)] = 28654,
+  [SMALL_STATE(687)] = 28665,
+  [SMALL_STATE(688)] = 28678,
+  [SMALL_STATE(689)] = 28691,
+  [SMALL_STATE(690)] = 28704,
+  [SMALL_STATE(691)] = 28715,
+  [SMALL_STATE(692)] = 28728,
+  [SMALL_STATE(693)] = 28741,
+  [SMALL_STATE(694)] = 28754,
+  [SMALL_STATE(695)] = 28767,
+  [SMALL_STATE(696)] = 28780,
+  [SMALL_STATE(697)] = 28791,
+  [SMALL_STATE(698)] = 28804,
+  [SMALL_STATE(699)] = 28817,
+  [SMALL_STATE(700)] = 28830,
+  [SMALL_STATE(701)] = 28841,
+  [SMALL_STATE(702)] = 28854,
+  [SMALL_STATE(703)] = 28867,
+  [SMALL_STATE(704)] = 28880,
+  [SMALL_STATE(705)] = 28893,
+  [SMALL_STATE(706)] = 28906,
+  [SMALL_STATE(707)] = 28919,
+  [SMALL_STATE(708)] = 28932,
+  [SMALL_STATE(709)] = 28945,
+  [SMALL_STATE(710)] = 28958,
+  [SMALL_STATE(711)] = 28971,
+  [SMALL_STATE(712)] = 28981,
+  [SMALL_STATE(713)] = 28991,
+  [SMALL_STATE(714)] = 29001,
+  [SMALL_STATE(715)] = 29009,
+  [SMALL_STATE(716)] = 29019,
+  [SMALL_STATE(717)] = 29029,
+  [SMALL_STATE(718)] = 29039,
+  [SMALL_STATE(719)] = 29049,
+  [SMALL_STATE(720)] = 29059,
+  [SMALL_STATE(721)] = 29069,
+  [SMALL_STATE(722)] = 29079,
+  [SMALL_STATE(723)] = 29089,
+  [SMALL_STATE(724)] = 29099,
+  [SMALL_STATE(725)] = 29107,
+  [SMALL_STATE(726)] = 29115,
+  [SMALL_STATE(727)] = 29125,
+  [SMALL_STATE(728)] = 29135,
+  [SMALL_STATE(729)] = 29145,
+  [SMALL_STATE(730)] = 29153,
+  [SMALL_STATE(731)] = 29163,
+  [SMALL_STATE(732)] = 29173,
+  [SMALL_STATE(733)] = 29183,
+  [SMALL_STATE(734)] = 29191,
+  [SMALL_STATE(735)] = 29199,
+  [SMALL_STATE(736)] = 29207,
+  [SMALL_STATE(737)] = 29215,
+  [SMALL_STATE(738)] = 29223,
+  [SMALL_STATE(739)] = 29233,
+  [SMALL_STATE(740)] = 29243,
+  [SMALL_STATE(741)] = 29253,
+  [SMALL_STATE(742)] = 29261,
+  [SMALL_STATE(743)] = 29271,
+  [SMALL_STATE(744)] = 29281,
+  [SMALL_STATE(745)] = 29289,
+  [SMALL_STATE(746)] = 29297,
+  [SMALL_STATE(747)] = 29305,
+  [SMALL_STATE(748)] = 29315,
+  [SMALL_STATE(749)] = 29325,
+  [SMALL_STATE(750)] = 29333,
+  [SMALL_STATE(751)] = 29341,
+  [SMALL_STATE(752)] = 29349,
+  [SMALL_STATE(753)] = 29359,
+  [SMALL_STATE(754)] = 29367,
+  [SMALL_STATE(755)] = 29375,
+  [SMALL_STATE(756)] = 29385,
+  [SMALL_STATE(757)] = 29395,
+  [SMALL_STATE(758)] = 29405,
+  [SMALL_STATE(759)] = 29415,
+  [SMALL_STATE(760)] = 29425,
+  [SMALL_STATE(761)] = 29435,
+  [SMALL_STATE(762)] = 29445,
+  [SMALL_STATE(763)] = 29455,
+  [SMALL_STATE(764)] = 29465,
+  [SMALL_STATE(765)] = 29475,
+  [SMALL_STATE(766)] = 29485,
+  [SMALL_STATE(767)] = 29495,
+  [SMALL_STATE(768)] = 29505,
+  [SMALL_STATE(769)] = 29515,
+  [SMALL_STATE(770)] = 29525,
+  [SMALL_STATE(771)] = 29535,
+  [SMALL_STATE(772)] = 29545,
+  [SMALL_STATE(773)] = 29555,
+  [SMALL_STATE(774)] = 29565,
+  [SMALL_STATE(775)] = 29575,
+  [SMALL_STATE(776)] = 29585,
+  [SMALL_STATE(777)] = 29595,
+  [SMALL_STATE(778)] = 29605,
+  [SMALL_STATE(779)] = 29615,
+  [SMALL_STATE(780)] = 29625,
+  [SMALL_STATE(781)] = 29635,
+  [SMALL_STATE(782)] = 29645,
+  [SMALL_STATE(783)] = 29655,
+  [SMALL_STATE(784)] = 29665,
+  [SMALL_STATE(785)] = 29675,
+  [SMALL_STATE(786)] = 29685,
+  [SMALL_STATE(787)] = 29695,
+  [SMALL_STATE(788)] = 29705,
+  [SMALL_STATE(789)] = 29715,
+  [SMALL_STATE(790)] = 29722,
+  [SMALL_STATE(791)] = 29729,
+  [SMALL_STATE(792)] = 29736,
+  [SMALL_STATE(793)] = 29743,
+  [SMALL_STATE(794)] = 29750,
+  [SMALL_STATE(795)] = 29757,
+  [SMALL_STATE(796)] = 29764,
+  [SMALL_STATE(797)] = 29771,
+  [SMALL_STATE(798)] = 29778,
+  [SMALL_STATE(799)] = 29785,
+  [SMALL_STATE(800)] = 29792,
+  [SMALL_STATE(801)] = 29799,
+  [SMALL_STATE(802)] = 29806,
+  [SMALL_STATE(803)] = 29813,
+  [SMALL_STATE(804)] = 29820,
+  [SMALL_STATE(805)] = 29827,
+  [SMALL_STATE(806)] = 29834,
+  [SMALL_STATE(807)] = 29841,
+  [SMALL_STATE(808)] = 29848,
+  [SMALL_STATE(809)] = 29855,
+  [SMALL_STATE(810)] = 29862,
+  [SMALL_STATE(811)] = 29869,
+  [SMALL_STATE(812)] = 29876,
+  [SMALL_STATE(813)] = 29883,
+  [SMALL_STATE(814)] = 29890,
+  [SMALL_STATE(815)] = 29897,
+  [SMALL_STATE(816)] = 29904,
+  [SMALL_STATE(817)] = 29911,
+  [SMALL_STATE(818)] = 29918,
+  [SMALL_STATE(819)] = 29925,
+  [SMALL_STATE(820)] = 29932,
+  [SMALL_STATE(821)] = 29939,
+  [SMALL_STATE(822)] = 29946,
+  [SMALL_STATE(823)] = 29953,
+  [SMALL_STATE(824)] = 29960,
+  [SMALL_STATE(825)] = 29967,
+  [SMALL_STATE(826)] = 29974,
+  [SMALL_STATE(827)] = 29981,
+  [SMALL_STATE(828)] = 29988,
+  [SMALL_STATE(829)] = 29995,
+  [SMALL_STATE(830)] = 30002,
+  [SMALL_STATE(831)] = 30009,
+  [SMALL_STATE(832)] = 30016,
+  [SMALL_STATE(833)] = 30023,
+  [SMALL_STATE(834)] = 30030,
+  [SMALL_STATE(835)] = 30037,
+  [SMALL_STATE(836)] = 30044,
+  [SMALL_STATE(837)] = 30051,
+  [SMALL_STATE(838)] = 30058,
+  [SMALL_STATE(839)] = 30065,
+  [SMALL_STATE(840)] = 30072,
+  [SMALL_STATE(841)] = 30079,
+  [SMALL_STATE(842)] = 30086,
+  [SMALL_STATE(843)] = 30093,
+  [SMALL_STATE(844)] = 30100,
+  [SMALL_STATE(845)] = 30107,
+  [SMALL_STATE(846)] = 30114,
+  [SMALL_STATE(847)] = 30121,
+  [SMALL_STATE(848)] = 30128,
+  [SMALL_STATE(849)] = 30135,
+  [SMALL_STATE(850)] = 30142,
+  [SMALL_STATE(851)] = 30149,
+  [SMALL_STATE(852)] = 30156,
+  [SMALL_STATE(853)] = 30163,
+  [SMALL_STATE(854)] = 30170,
+  [SMALL_STATE(855)] = 30177,
+  [SMALL_STATE(856)] = 30184,
+  [SMALL_STATE(857)] = 30191,
+  [SMALL_STATE(858)] = 30198,
+  [SMALL_STATE(859)] = 30205,
+  [SMALL_STATE(860)] = 30212,
+  [SMALL_STATE(861)] = 30219,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(789),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(790),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(621),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(711),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(712),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(791),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(622),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(792),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(793),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(794),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [55] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10),
+  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(230),
+  [60] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(789),
+  [63] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(3),
+  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(120),
+  [69] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(790),
+  [72] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(621),
+  [75] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(121),
+  [78] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(122),
+  [81] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(711),
+  [84] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(116),
+  [87] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(117),
+  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(712),
+  [93] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(791),
+  [96] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(622),
+  [99] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(106),
+  [102] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(792),
+  [105] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(793),
+  [108] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(794),
+  [111] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(289),
+  [114] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(290),
+  [117] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(224),
+  [120] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(123),
+  [123] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 10), SHIFT_REPEAT(124),
+  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(709),
+  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(756),
+  [132] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 3),
+  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(450),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(532),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(710),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(764),
+  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_loop_statement, 2, 1, 5),
+  [170] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_loop_statement, 2, 1, 5), REDUCE(sym_loop_expression, 2, 0, 5),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_loop_expression, 2, 0, 5),
+  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_loop_statement, 3, 1, 20),
+  [177] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_loop_statement, 3, 1, 20), REDUCE(sym_loop_expression, 3, 0, 20),
+  [180] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_loop_expression, 3, 0, 20),
+  [182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_statement, 4, 1, 26),
+  [184] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_function_statement, 4, 1, 26), REDUCE(sym_function_expression, 4, 0, 26),
+  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 4, 0, 26),
+  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_statement, 2, 0, 0),
+  [191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_statement, 3, 0, 13),
+  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_expression, 3, 0, 12),
+  [195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_expression, 4, 0, 24),
+  [197] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_loop_expression, 2, 0, 5), REDUCE(sym_loop_statement, 2, 1, 5),
+  [200] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_loop_expression, 3, 0, 20), REDUCE(sym_loop_statement, 3, 1, 20),
+  [203] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_function_expression, 4, 0, 26), REDUCE(sym_function_statement, 4, 1, 26),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(624),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(716),
+  [218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(419),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(549),
+  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(550),
+  [262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(551),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(564),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(566),
+  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(568),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(573),
+  [272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(574),
+  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(575),
+  [276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(546),
+  [300] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [304] = {.entry = {.count = 1, .reusable = true}}, SHIFT(714),
+  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(706),
+  [316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(758),
+  [320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 1, 0, 0),
+  [342] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 34),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(707),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(766),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(726),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(708),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(773),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(555),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(571),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(576),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 1, 0, 1),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_prop_access_expression, 3, 0, 22),
+  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [436] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index_expression, 4, 0, 35),
+  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(807),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(806),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expression, 2, 0, 6),
+  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 4, 0, 27),
+  [458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(825),
+  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_statement, 4, 0, 31),
+  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 5, 0, 45),
+  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_statement, 5, 0, 48),
+  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 6, 0, 45),
+  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 6, 0, 55),
+  [470] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 7, 0, 55),
+  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 15),
+  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(614),
+  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 2),
+  [478] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__statement, 1, 0, 0),
+  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 2, 0, 0),
+  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_statement, 2, 0, 0),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_continue_statement, 2, 0, 0),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(529),
+  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_expression, 2, 0, 7),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [506] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression_statement, 2, 0, 8),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_statement, 3, 0, 16),
+  [510] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 3, 0, 17),
+  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_statement, 3, 0, 18),
+  [514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_statement, 3, 0, 17),
+  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_continue_statement, 3, 0, 18),
+  [518] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_test_statement, 3, 0, 19),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scope_modifier_statement, 4, 0, 25),
+  [522] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_statement, 4, 0, 29),
+  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_statement, 4, 0, 30),
+  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scope_modifier_statement, 5, 0, 37),
+  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_statement, 5, 0, 38),
+  [530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 43),
+  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 5, 0, 44),
+  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_statement, 5, 0, 47),
+  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 6, 0, 53),
+  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_statement, 6, 0, 64),
+  [540] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_impl, 3, 0, 0),
+  [542] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 7, 0, 69),
+  [544] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 7, 0, 70),
+  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_impl, 4, 0, 79),
+  [548] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_statement, 8, 0, 81),
+  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operator_statement, 12, 0, 96),
+  [552] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operator_statement, 13, 0, 97),
+  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 21),
+  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__literal, 1, 0, 0),
+  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bool_literal, 1, 0, 0),
+  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(805),
+  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [564] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression_unit, 1, 0, 0),
+  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, 0, 9),
+  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_curry_call_expression, 2, 0, 9),
+  [570] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_instantiation_expression, 2, 0, 9),
+  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scope_expression, 3, 0, 0),
+  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 3, 0, 14),
+  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 12),
+  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_call, 2, 0, 0),
+  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_curry_call, 2, 0, 0),
+  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_instantiation, 2, 0, 0),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_prop_access_expression, 3, 0, 23),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_expression, 4, 0, 17),
+  [588] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_call, 3, 0, 28),
+  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_curry_call, 3, 0, 28),
+  [592] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_instantiation, 3, 0, 28),
+  [594] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scope_modifier_expression, 5, 0, 36),
+  [596] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_expression, 5, 0, 43),
+  [598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_expression, 5, 0, 46),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_call, 4, 0, 28),
+  [602] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_call, 4, 0, 41),
+  [604] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_curry_call, 4, 0, 28),
+  [606] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_curry_call, 4, 0, 41),
+  [608] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_instantiation, 4, 0, 28),
+  [610] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_instantiation, 4, 0, 41),
+  [612] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scope_modifier_expression, 6, 0, 52),
+  [614] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_expression, 6, 0, 46),
+  [616] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_expression, 6, 0, 59),
+  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_call, 5, 0, 41),
+  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_curry_call, 5, 0, 41),
+  [622] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list_instantiation, 5, 0, 41),
+  [624] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_expression, 7, 0, 59),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(854),
+  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(853),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(846),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(845),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [662] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__set_or_expression, 1, 0, 0),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_set_expression, 3, 0, 11),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_positional_argument, 1, 0, 8),
+  [708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_spread_argument, 2, 0, 17),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_kwargs_spread_argument, 2, 0, 17),
+  [712] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_argument, 3, 0, 40),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_set_prop_expression, 5, 0, 50),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_set_index_expression, 6, 0, 65),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [724] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [730] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [742] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_parameter, 3, 0, 40),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [746] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_arm, 3, 0, 57),
+  [748] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_parameter, 5, 0, 66),
+  [750] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_arm, 5, 0, 82),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(561),
+  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [772] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 2, 0, 33),
+  [774] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_slice, 3, 0, 51),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(603),
+  [796] = {.entry = {.count = 1, .reusable = true}}, SHIFT(608),
+  [798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(610),
+  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(612),
+  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(669),
+  [804] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [816] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operator, 1, 0, 0),
+  [818] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 1, 0, 8),
+  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(731),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(850),
+  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(849),
+  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [834] = {.entry = {.count = 1, .reusable = true}}, SHIFT(581),
+  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [838] = {.entry = {.count = 1, .reusable = true}}, SHIFT(820),
+  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(638),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(727),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [846] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 2, 0, 49), SHIFT_REPEAT(581),
+  [849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 2, 0, 49),
+  [851] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 2, 0, 49), SHIFT_REPEAT(820),
+  [854] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 2, 0, 49), SHIFT_REPEAT(638),
+  [857] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 2, 0, 49), SHIFT_REPEAT(727),
+  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(613),
+  [862] = {.entry = {.count = 1, .reusable = true}}, SHIFT(808),
+  [864] = {.entry = {.count = 1, .reusable = true}}, SHIFT(809),
+  [866] = {.entry = {.count = 1, .reusable = true}}, SHIFT(810),
+  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(821),
+  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(828),
+  [872] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_maybe_typed_identifier, 1, 0, 1),
+  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(826),
+  [876] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_statement_repeat1, 1, 0, 32),
+  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type_member, 1, 0, 0),
+  [880] = {.entry = {.count = 1, .reusable = true}}, SHIFT(592),
+  [882] = {.entry = {.count = 1, .reusable = true}}, SHIFT(657),
+  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(658),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 2, 0, 1),
+  [888] = {.entry = {.count = 1, .reusable = true}}, SHIFT(739),
+  [890] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [892] = {.entry = {.count = 1, .reusable = true}}, SHIFT(833),
+  [894] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_embed, 3, 0, 27),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(597),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 3, 0, 61),
+  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(599),
+  [902] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 3, 0, 62),
+  [904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 3, 0, 1),
+  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(601),
+  [908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [910] = {.entry = {.count = 1, .reusable = true}}, SHIFT(605),
+  [912] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 4, 0, 72),
+  [914] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 4, 0, 61),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(607),
+  [918] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 4, 0, 62),
+  [920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(609),
+  [922] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 4, 0, 74),
+  [924] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_property_repeat1, 2, 0, 75),
+  [926] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_property_repeat1, 2, 0, 75), SHIFT_REPEAT(657),
+  [929] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_property_repeat1, 2, 0, 75), SHIFT_REPEAT(658),
+  [932] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 4, 0, 40),
+  [934] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_impl_repeat1, 2, 0, 80), SHIFT_REPEAT(739),
+  [937] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_impl_repeat1, 2, 0, 80),
+  [939] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_impl_repeat1, 2, 0, 80), SHIFT_REPEAT(833),
+  [942] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 5, 0, 72),
+  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(611),
+  [946] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 5, 0, 86),
+  [948] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 5, 0, 87),
+  [950] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 5, 0, 88),
+  [952] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 5, 0, 89),
+  [954] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property, 6, 0, 91),
+  [956] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 6, 0, 92),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_positional_parameter, 1, 0, 1),
+  [960] = {.entry = {.count = 1, .reusable = true}}, SHIFT(816),
+  [962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [966] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_argument_list_call_repeat1, 2, 0, 42), SHIFT_REPEAT(101),
+  [969] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_call_repeat1, 2, 0, 42),
+  [971] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_maybe_typed_identifier, 3, 0, 39),
+  [973] = {.entry = {.count = 1, .reusable = true}}, SHIFT(717),
+  [975] = {.entry = {.count = 1, .reusable = true}}, SHIFT(577),
+  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(721),
+  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [983] = {.entry = {.count = 1, .reusable = true}}, SHIFT(578),
+  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(817),
+  [987] = {.entry = {.count = 1, .reusable = true}}, SHIFT(635),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [991] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [993] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [995] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [997] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [999] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [1001] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [1003] = {.entry = {.count = 1, .reusable = true}}, SHIFT(579),
+  [1005] = {.entry = {.count = 1, .reusable = true}}, SHIFT(822),
+  [1007] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 1, 0, 1),
+  [1009] = {.entry = {.count = 1, .reusable = true}}, SHIFT(730),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1013] = {.entry = {.count = 1, .reusable = true}}, SHIFT(645),
+  [1015] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [1017] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [1019] = {.entry = {.count = 1, .reusable = true}}, SHIFT(732),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, SHIFT(584),
+  [1023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(585),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [1027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [1031] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [1033] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [1035] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [1039] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_positional_parameter, 3, 0, 39),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [1043] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_formal_parameters_repeat1, 2, 0, 42), SHIFT_REPEAT(580),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_formal_parameters_repeat1, 2, 0, 42),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(652),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(588),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(589),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(590),
+  [1066] = {.entry = {.count = 1, .reusable = true}}, SHIFT(591),
+  [1068] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_call_repeat1, 2, 0, 28),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(737),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(738),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [1078] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_statement_repeat1, 2, 0, 56),
+  [1080] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_statement_repeat1, 2, 0, 56), SHIFT_REPEAT(740),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, SHIFT(741),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(742),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_expression_repeat1, 2, 0, 60),
+  [1089] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_expression_repeat1, 2, 0, 60), SHIFT_REPEAT(223),
+  [1092] = {.entry = {.count = 1, .reusable = true}}, SHIFT(595),
+  [1094] = {.entry = {.count = 1, .reusable = true}}, SHIFT(596),
+  [1096] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [1098] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [1100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [1102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(743),
+  [1104] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_property_repeat1, 1, 0, 63),
+  [1106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(746),
+  [1108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(747),
+  [1110] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_impl_repeat1, 1, 0, 68),
+  [1112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(751),
+  [1114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(752),
+  [1116] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property_item, 2, 0, 73),
+  [1118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 78),
+  [1120] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 78), SHIFT_REPEAT(839),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_value_pattern_repeat1, 2, 0, 85),
+  [1127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_value_pattern_repeat1, 2, 0, 85), SHIFT_REPEAT(840),
+  [1130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_method, 3, 0, 90),
+  [1132] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property_item, 4, 0, 93),
+  [1134] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_method, 4, 0, 94),
+  [1136] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_property_item, 5, 0, 95),
+  [1138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [1140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [1142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [1144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [1148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [1152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [1154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [1160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [1164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [1166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [1168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [1170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [1172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [1176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(556),
+  [1178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [1180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(557),
+  [1182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(558),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [1186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(563),
+  [1188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [1190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [1192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(565),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(567),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(569),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(572),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [1214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [1216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [1232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [1236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(784),
+  [1242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(787),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(788),
+  [1246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(783),
+  [1248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(786),
+  [1250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(800),
+  [1254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [1256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [1258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(719),
+  [1260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__loop_label, 2, 0, 4),
+  [1262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [1264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [1266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [1270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rest_parameter, 2, 0, 27),
+  [1274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_kwargs_parameter, 2, 0, 27),
+  [1276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [1278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [1280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [1282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_formal_parameters_repeat1, 2, 0, 28),
+  [1284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(651),
+  [1286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(733),
+  [1288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(654),
+  [1290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(735),
+  [1292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 3, 0, 1),
+  [1294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_statement_repeat1, 2, 0, 54),
+  [1296] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 3, 0, 8),
+  [1298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_expression_repeat1, 2, 0, 58),
+  [1300] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 4, 0, 67),
+  [1302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(744),
+  [1304] = {.entry = {.count = 1, .reusable = true}}, SHIFT(745),
+  [1306] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 4, 0, 71),
+  [1308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(749),
+  [1310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(750),
+  [1312] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 76),
+  [1314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 5, 0, 67),
+  [1316] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 5, 0, 77),
+  [1318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(753),
+  [1320] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_value_pattern_repeat1, 2, 0, 83),
+  [1322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 5, 0, 71),
+  [1324] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 5, 0, 84),
+  [1326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(754),
+  [1328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 6, 0, 77),
+  [1330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_pattern, 6, 0, 84),
+  [1332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [1334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(531),
+  [1338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [1340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [1342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [1344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [1346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(560),
+  [1348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(570),
+  [1350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [1352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [1354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [1356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [1358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [1366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(759),
+  [1370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(767),
+  [1372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(799),
+  [1374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(626),
+  [1376] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_type, 1, 0, 0),
+  [1378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(803),
+  [1380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(718),
+  [1382] = {.entry = {.count = 1, .reusable = true}}, ACCEPT_INPUT(),
+  [1384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(804),
+  [1386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(722),
+  [1388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [1390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [1392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(813),
+  [1394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [1396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(629),
+  [1398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [1400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [1406] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal_parameters, 2, 0, 0),
+  [1408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(724),
+  [1410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(725),
+  [1412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(615),
+  [1414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(818),
+  [1416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(819),
+  [1418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(643),
+  [1420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal_parameters, 3, 0, 28),
+  [1422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(823),
+  [1424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(824),
+  [1426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(827),
+  [1428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal_parameters, 4, 0, 28),
+  [1430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal_parameters, 4, 0, 41),
+  [1432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(829),
+  [1434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(830),
+  [1436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(586),
+  [1438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(617),
+  [1440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(587),
+  [1442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal_parameters, 5, 0, 41),
+  [1444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(831),
+  [1446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(832),
+  [1448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(834),
+  [1450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(835),
+  [1452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(748),
+  [1454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(837),
+  [1456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(838),
+  [1458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(755),
+  [1460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(841),
+  [1462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(842),
+  [1464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(843),
+  [1466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(672),
+  [1468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(673),
+  [1470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [1472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [1474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [1476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(548),
+  [1478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(552),
+  [1480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(554),
+  [1482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [1484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [1486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [1488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(761),
+  [1490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(618),
+  [1492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(769),
+  [1494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(619),
+  [1496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(775),
+  [1498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(620),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_frugurt(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym_identifier,
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif